@@ -0,0 +1,128 @@
+use crate::flatten::flatten_with;
+use crate::{Bezier, ComposedCurve, Curve, Distance, PlanarPoint, Rect, Scalar};
+use num_traits::{One, Zero};
+
+/// Summary of a path computed by [`ComposedCurve::analyze`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurveStats<P: PlanarPoint> {
+    /// Length of the path flattened with the given tolerance.
+    pub length: P::Scalar,
+    /// Bounds of the path, accurate to the tolerance.
+    pub bounds: Rect<P>,
+    /// The smallest absolute curvature, zero for straight parts.
+    pub min_curvature: P::Scalar,
+    /// The largest absolute curvature, the reciprocal of the smallest turning radius.
+    pub max_curvature: P::Scalar,
+    pub segments: usize,
+    /// Number of points where two segments meet, including the closing one of a closed path.
+    pub joints: usize,
+}
+
+impl<P: PlanarPoint> ComposedCurve<P> {
+    /// Length, bounds, curvature extremes, and joint count in a single traversal.
+    ///
+    /// Every segment is flattened once with `tolerance`, the length and the bounds are taken
+    /// from the flattened points, and the curvature is evaluated at them, so very sharp
+    /// features between the points may be missed.
+    pub fn analyze(&self, tolerance: P::Scalar) -> CurveStats<P> {
+        let start = self.start_point();
+        let mut stats = CurveStats {
+            length: P::Scalar::zero(),
+            bounds: Rect::new(start.clone(), start.clone()),
+            min_curvature: P::Scalar::zero(),
+            max_curvature: P::Scalar::zero(),
+            segments: self.segments().len(),
+            joints: self.segments().len().saturating_sub(1),
+        };
+        if !self.segments().is_empty() && start == self.end_point() {
+            stats.joints += 1;
+        }
+
+        let mut min_curvature: Option<P::Scalar> = None;
+        let mut previous = start;
+        for segment in self.segments() {
+            let mut visit = |t: P::Scalar, point: P| {
+                let curvature = curvature(segment, t).abs();
+                min_curvature = Some(min_curvature.map_or(curvature, |min| min.min(curvature)));
+                stats.max_curvature = stats.max_curvature.max(curvature);
+                stats.length = stats.length + previous.distance(&point);
+                stats.bounds = stats.bounds.union(&Rect::new(point.clone(), point.clone()));
+                previous = point;
+            };
+            visit(P::Scalar::zero(), segment.start_point());
+            flatten_with(segment, tolerance, &mut visit);
+        }
+        stats.min_curvature = min_curvature.unwrap_or(P::Scalar::zero());
+        stats
+    }
+}
+
+/// Signed curvature of a segment, zero where its derivative vanishes.
+fn curvature<P: PlanarPoint>(segment: &Bezier<P>, t: P::Scalar) -> P::Scalar {
+    let first = segment.tangent_at(t);
+    let second = second_derivative(segment, t);
+    let speed = first.length();
+    if speed == P::Scalar::zero() {
+        P::Scalar::zero()
+    } else {
+        first.cross(&second) / (speed * speed * speed)
+    }
+}
+
+fn second_derivative<P: PlanarPoint>(segment: &Bezier<P>, t: P::Scalar) -> P {
+    let one = P::Scalar::one();
+    let two = one + one;
+    match segment {
+        Bezier::C0(c) => c.point.scale(P::Scalar::zero()),
+        Bezier::C1(c) => c.p0.scale(P::Scalar::zero()),
+        Bezier::C2(c) => c.p2.sub(&c.p1.scale(two)).add(&c.p0).scale(two),
+        Bezier::C3(c) => {
+            let a = c.p2.sub(&c.p1.scale(two)).add(&c.p0);
+            let b = c.p3.sub(&c.p2.scale(two)).add(&c.p1);
+            a.lerp(&b, t).scale(two + two + two)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn circle_like_path() {
+        // Four cubic quarter circles of radius 2
+        let k = 0.5522847498 * 2.0;
+        let mut curve = ComposedCurve::new([2.0, 0.0]);
+        curve.cubic_to([2.0, k], [k, 2.0], [0.0, 2.0]);
+        curve.cubic_to([-k, 2.0], [-2.0, k], [-2.0, 0.0]);
+        curve.cubic_to([-2.0, -k], [-k, -2.0], [0.0, -2.0]);
+        curve.cubic_to([k, -2.0], [2.0, -k], [2.0, 0.0]);
+
+        let stats = curve.analyze(1e-4);
+        assert_relative_eq!(stats.length, 4.0 * core::f64::consts::PI, epsilon = 1e-2);
+        assert_relative_eq!(stats.bounds.min[0], -2.0, epsilon = 1e-3);
+        assert_relative_eq!(stats.bounds.max[1], 2.0, epsilon = 1e-3);
+        assert_relative_eq!(stats.min_curvature, 0.5, epsilon = 3e-2);
+        assert_relative_eq!(stats.max_curvature, 0.5, epsilon = 3e-2);
+        assert_eq!(stats.segments, 4);
+        assert_eq!(stats.joints, 4);
+    }
+
+    #[test]
+    fn polyline() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([3.0, 0.0]);
+        curve.line_to([3.0, 4.0]);
+        let stats = curve.analyze(0.1);
+        assert_relative_eq!(stats.length, 7.0);
+        assert_eq!(stats.bounds, Rect::new([0.0, 0.0], [3.0, 4.0]));
+        assert_eq!(stats.max_curvature, 0.0);
+        assert_eq!(stats.joints, 1);
+
+        let stats = ComposedCurve::new([1.0, 1.0]).analyze(0.1);
+        assert_eq!(stats.length, 0.0);
+        assert_eq!(stats.joints, 0);
+        assert_eq!(stats.bounds, Rect::new([1.0, 1.0], [1.0, 1.0]));
+    }
+}
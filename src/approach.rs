@@ -0,0 +1,111 @@
+use crate::{Curve, InnerProduct, Scalar};
+use alloc::vec::Vec;
+use num_traits::{NumCast, One, Zero};
+
+/// Number of samples used to estimate the largest relative speed of the trajectories.
+const SPEED_SAMPLES: usize = 128;
+/// The estimated largest relative speed is increased by this factor to stay a safe upper bound.
+const SPEED_SAFETY: f64 = 1.5;
+/// Subdivision depth limit, it keeps zero or tiny tolerances from refining the minimum
+/// down to the last bits of `t`.
+const MAX_DEPTH: usize = 32;
+
+/// Closest approach of two points moving along the curves `a` and `b` with the same `t`,
+/// returns `t` and the distance between the points at it.
+///
+/// The time range is subdivided while the distance may get smaller than the best one found
+/// by more than `tolerance`, down to intervals of `2^-32`. The distance can't change faster than
+/// the relative speed of the points, it's bounded by the largest difference of the curve tangents,
+/// sampled with a safety margin.
+pub fn closest_approach<P, A, B>(a: &A, b: &B, tolerance: P::Scalar) -> (P::Scalar, P::Scalar)
+where
+    P: InnerProduct,
+    A: Curve<P> + ?Sized,
+    B: Curve<P> + ?Sized,
+{
+    let zero = P::Scalar::zero();
+    let one = P::Scalar::one();
+    let half = one / (one + one);
+    let distance = |t: P::Scalar| a.value_at(t).sub(&b.value_at(t)).length();
+
+    let samples: P::Scalar = NumCast::from(SPEED_SAMPLES).unwrap();
+    let max_speed = (0..=SPEED_SAMPLES).fold(zero, |acc, i| {
+        let t = NumCast::from(i).map(|i: P::Scalar| i / samples).unwrap();
+        acc.max(a.tangent_at(t).sub(&b.tangent_at(t)).length())
+    });
+    let lipschitz = max_speed * NumCast::from(SPEED_SAFETY).unwrap();
+
+    let (d0, d1) = (distance(zero), distance(one));
+    let mut best = if d1 < d0 { (one, d1) } else { (zero, d0) };
+
+    let mut stack = Vec::from([(zero, d0, one, d1, 0)]);
+    while let Some((t0, d0, t1, d1, depth)) = stack.pop() {
+        let lower_bound = d0.min(d1) - lipschitz * (t1 - t0) * half;
+        if lower_bound >= best.1 - tolerance {
+            continue;
+        }
+
+        let t = (t0 + t1) * half;
+        let d = distance(t);
+        if d < best.1 {
+            best = (t, d);
+        }
+        if depth + 1 >= MAX_DEPTH || t == t0 || t == t1 {
+            continue;
+        }
+        stack.push((t0, d0, t, d, depth + 1));
+        stack.push((t, d, t1, d1, depth + 1));
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bezier1, Bezier3};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn crossing_agents() {
+        // Both reach the middle point at t = 0.5
+        let a = Bezier1::new([0.0, 0.0], [10.0, 10.0]);
+        let b = Bezier1::new([10.0, 0.0], [0.0, 10.0]);
+        let (t, distance) = closest_approach(&a, &b, 1e-6);
+        assert_relative_eq!(t, 0.5, epsilon = 1e-6);
+        assert_relative_eq!(distance, 0.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn passing_agents() {
+        let a = Bezier3::new([0.0, 0.0], [3.0, 0.0], [6.0, 0.0], [10.0, 0.0]);
+        let b = Bezier3::new([10.0, 2.0], [6.0, 1.0], [3.0, 1.0], [0.0, 2.0]);
+        let (t, distance) = closest_approach(&a, &b, 1e-6);
+
+        let brute = (0..=100_000)
+            .map(|i| {
+                let t = i as f64 / 100_000.0;
+                let (p, q) = (a.value_at(t), b.value_at(t));
+                ((p[0] - q[0]).hypot(p[1] - q[1]), t)
+            })
+            .fold(
+                (f64::INFINITY, 0.0),
+                |acc, v| if v.0 < acc.0 { v } else { acc },
+            );
+        assert_relative_eq!(distance, brute.0, epsilon = 1e-5);
+        assert_relative_eq!(t, brute.1, epsilon = 1e-3);
+
+        // Zero tolerance stops at the depth limit
+        let (exact_t, exact_distance) = closest_approach(&a, &b, 0.0);
+        assert_relative_eq!(exact_distance, distance, epsilon = 1e-5);
+        assert_relative_eq!(exact_t, t, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn ends() {
+        let a = Bezier1::new([0.0, 0.0], [1.0, 0.0]);
+        let b = Bezier1::new([0.0, 1.0], [5.0, 3.0]);
+        let (t, distance) = closest_approach(&a, &b, 1e-6);
+        assert_eq!(t, 0.0);
+        assert_relative_eq!(distance, 1.0);
+    }
+}
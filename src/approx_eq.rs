@@ -0,0 +1,188 @@
+#[cfg(feature = "alloc")]
+use crate::ComposedCurve;
+use crate::{Bezier, Bezier0, Bezier1, Bezier2, Bezier3, Curve, Distance, InnerProduct, Scalar};
+use ::approx::{AbsDiffEq, RelativeEq};
+
+/// Points are equal if the distance between them is less than `epsilon`.
+fn point_abs_diff_eq<P: InnerProduct>(a: &P, b: &P, epsilon: P::Scalar) -> bool {
+    a.distance(b) <= epsilon
+}
+
+/// Points are equal if the distance between them is less than `epsilon`,
+/// or less than `max_relative` of the longest point vector.
+fn point_relative_eq<P: InnerProduct>(
+    a: &P,
+    b: &P,
+    epsilon: P::Scalar,
+    max_relative: P::Scalar,
+) -> bool {
+    let distance = a.distance(b);
+    distance <= epsilon || distance <= a.length().max(b.length()) * max_relative
+}
+
+macro_rules! impl_approx_eq {
+    ($curve:ident, $($point:ident),+) => {
+        impl<P: InnerProduct> AbsDiffEq for $curve<P>
+        where
+            P::Scalar: AbsDiffEq<Epsilon = P::Scalar>,
+        {
+            type Epsilon = P::Scalar;
+
+            fn default_epsilon() -> Self::Epsilon {
+                P::Scalar::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                $(point_abs_diff_eq(&self.$point, &other.$point, epsilon))&&+
+            }
+        }
+
+        impl<P: InnerProduct> RelativeEq for $curve<P>
+        where
+            P::Scalar: RelativeEq<Epsilon = P::Scalar>,
+        {
+            fn default_max_relative() -> Self::Epsilon {
+                P::Scalar::default_max_relative()
+            }
+
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                $(point_relative_eq(&self.$point, &other.$point, epsilon, max_relative))&&+
+            }
+        }
+    };
+}
+
+impl_approx_eq!(Bezier0, point);
+impl_approx_eq!(Bezier1, p0, p1);
+impl_approx_eq!(Bezier2, p0, p1, p2);
+impl_approx_eq!(Bezier3, p0, p1, p2, p3);
+
+impl<P: InnerProduct> AbsDiffEq for Bezier<P>
+where
+    P::Scalar: AbsDiffEq<Epsilon = P::Scalar>,
+{
+    type Epsilon = P::Scalar;
+
+    fn default_epsilon() -> Self::Epsilon {
+        P::Scalar::default_epsilon()
+    }
+
+    /// Curves of different degrees are never equal.
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        match (self, other) {
+            (Bezier::C0(a), Bezier::C0(b)) => a.abs_diff_eq(b, epsilon),
+            (Bezier::C1(a), Bezier::C1(b)) => a.abs_diff_eq(b, epsilon),
+            (Bezier::C2(a), Bezier::C2(b)) => a.abs_diff_eq(b, epsilon),
+            (Bezier::C3(a), Bezier::C3(b)) => a.abs_diff_eq(b, epsilon),
+            _ => false,
+        }
+    }
+}
+
+impl<P: InnerProduct> RelativeEq for Bezier<P>
+where
+    P::Scalar: RelativeEq<Epsilon = P::Scalar>,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        P::Scalar::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        match (self, other) {
+            (Bezier::C0(a), Bezier::C0(b)) => a.relative_eq(b, epsilon, max_relative),
+            (Bezier::C1(a), Bezier::C1(b)) => a.relative_eq(b, epsilon, max_relative),
+            (Bezier::C2(a), Bezier::C2(b)) => a.relative_eq(b, epsilon, max_relative),
+            (Bezier::C3(a), Bezier::C3(b)) => a.relative_eq(b, epsilon, max_relative),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: InnerProduct> AbsDiffEq for ComposedCurve<P>
+where
+    P::Scalar: AbsDiffEq<Epsilon = P::Scalar>,
+{
+    type Epsilon = P::Scalar;
+
+    fn default_epsilon() -> Self::Epsilon {
+        P::Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.segments().len() == other.segments().len()
+            && point_abs_diff_eq(&self.end_point(), &other.end_point(), epsilon)
+            && self
+                .segments()
+                .iter()
+                .zip(other.segments())
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: InnerProduct> RelativeEq for ComposedCurve<P>
+where
+    P::Scalar: RelativeEq<Epsilon = P::Scalar>,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        P::Scalar::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.segments().len() == other.segments().len()
+            && point_relative_eq(&self.end_point(), &other.end_point(), epsilon, max_relative)
+            && self
+                .segments()
+                .iter()
+                .zip(other.segments())
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne};
+
+    #[test]
+    fn bezier() {
+        let a = Bezier2::new([0.0, 0.0], [1.0, 1.0], [2.0, 0.0]);
+        let b = Bezier2::new([0.0, 0.0], [1.0, 1.0 + 1e-12], [2.0, 0.0]);
+        assert_abs_diff_eq!(a, b, epsilon = 1e-9);
+        assert_relative_eq!(Bezier::C2(a), Bezier::C2(b), max_relative = 1e-9);
+        assert_relative_ne!(
+            Bezier::C2(a),
+            Bezier::C1(Bezier1::new([0.0, 0.0], [2.0, 0.0]))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn composed_curve() {
+        let mut a = ComposedCurve::new([0.0, 0.0]);
+        a.line_to([1.0, 0.0]);
+        a.cubic_to([2.0, 0.0], [2.0, 1.0], [3.0, 1.0]);
+        let b = a.map_points(|p| [p[0] * (1.0 + 1e-12), p[1]]);
+        assert_relative_eq!(a, b, max_relative = 1e-9);
+
+        let mut c = a.clone();
+        c.line_to([4.0, 1.0]);
+        assert_relative_ne!(a, c);
+    }
+}
@@ -0,0 +1,238 @@
+use crate::geometry::{circle_through, distance_to_segment};
+use crate::{Bezier1, Curve, Distance, PlanarPoint, Scalar};
+use core::fmt::Debug;
+use num_traits::{One, Zero};
+
+/// Circular arc defined by its center, start point, and signed sweep angle in radians.
+/// Positive sweep means counter-clockwise direction.
+#[derive(Clone, PartialEq)]
+pub struct Arc<P: PlanarPoint> {
+    pub center: P,
+    pub start: P,
+    pub sweep: P::Scalar,
+}
+
+impl<P: PlanarPoint + Copy> Copy for Arc<P> where P::Scalar: Copy {}
+
+impl<P: PlanarPoint + Debug> Debug for Arc<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Arc")
+            .field("center", &self.center)
+            .field("start", &self.start)
+            .field("sweep", &self.sweep)
+            .finish()
+    }
+}
+
+impl<P: PlanarPoint> Arc<P> {
+    pub fn new(center: P, start: P, sweep: P::Scalar) -> Self {
+        Self {
+            center,
+            start,
+            sweep,
+        }
+    }
+
+    /// Arc starting at `start` in the `tangent` direction and ending at `end`.
+    /// Returns `None` if the end point lies on the tangent line, so the arc degenerates to a line.
+    pub fn from_tangent(start: P, tangent: &P, end: P) -> Option<Self> {
+        let chord = end.sub(&start);
+        let normal = tangent.perpendicular();
+        let normal_length = normal.length();
+        let chord_length = chord.length();
+        let side = normal.dot(&chord);
+
+        let epsilon: P::Scalar = num_traits::NumCast::from(1e-9).unwrap();
+        if normal_length == P::Scalar::zero()
+            || side.abs() <= epsilon * normal_length * chord_length
+        {
+            return None;
+        }
+
+        let two = P::Scalar::one() + P::Scalar::one();
+        // center = start + normal * s, where |center - end| = |s| * |normal|
+        let s = chord.length_squared() / (two * side);
+        let center = start.add(&normal.scale(s));
+
+        // The tangent defines the direction, so the sweep may be longer than a half circle
+        let sweep = directed_sweep(&center, &start, &end, side > P::Scalar::zero());
+        Some(Self::new(center, start, sweep))
+    }
+
+    /// Arc from `start` through `middle` to `end`.
+    /// Returns `None` if the points are collinear.
+    pub(crate) fn through(start: P, middle: &P, end: &P) -> Option<Self> {
+        let (center, _) = circle_through(&start, middle, end)?;
+        let counter_clockwise = middle.sub(&start).cross(&end.sub(middle)) > P::Scalar::zero();
+        let sweep = directed_sweep(&center, &start, end, counter_clockwise);
+        Some(Self::new(center, start, sweep))
+    }
+
+    pub fn radius(&self) -> P::Scalar {
+        self.start.sub(&self.center).length()
+    }
+
+    pub fn is_clockwise(&self) -> bool {
+        self.sweep < P::Scalar::zero()
+    }
+
+    /// Distance from the point to the arc.
+    pub fn distance_to(&self, p: &P) -> P::Scalar {
+        let r0 = self.start.sub(&self.center);
+        let v = p.sub(&self.center);
+
+        let two_pi = (P::Scalar::one() + P::Scalar::one()) * P::Scalar::pi();
+        let mut angle = r0.cross(&v).atan2(r0.dot(&v));
+        if self.is_clockwise() {
+            angle = -angle;
+        }
+        if angle < P::Scalar::zero() {
+            angle = angle + two_pi;
+        }
+
+        if angle <= self.sweep.abs() {
+            (v.length() - r0.length()).abs()
+        } else {
+            p.distance(&self.start).min(p.distance(&self.end_point()))
+        }
+    }
+}
+
+impl<P: PlanarPoint> Curve<P> for Arc<P> {
+    fn value_at(&self, t: P::Scalar) -> P {
+        let r0 = self.start.sub(&self.center);
+        let angle = self.sweep * t;
+        self.center
+            .add(&r0.scale(angle.cos()))
+            .add(&r0.perpendicular().scale(angle.sin()))
+    }
+
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        let r0 = self.start.sub(&self.center);
+        let angle = self.sweep * t;
+        r0.scale(-angle.sin())
+            .add(&r0.perpendicular().scale(angle.cos()))
+            .scale(self.sweep)
+    }
+
+    fn start_point(&self) -> P {
+        self.start.clone()
+    }
+
+    fn estimate_length(&self, _precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        self.radius() * self.sweep.abs()
+    }
+}
+
+/// Angle from `start` to `end` around `center`, positive if `counter_clockwise`.
+fn directed_sweep<P: PlanarPoint>(
+    center: &P,
+    start: &P,
+    end: &P,
+    counter_clockwise: bool,
+) -> P::Scalar {
+    let r0 = start.sub(center);
+    let r1 = end.sub(center);
+    let sweep = r0.cross(&r1).atan2(r0.dot(&r1));
+    let full = (P::Scalar::one() + P::Scalar::one()) * P::Scalar::pi();
+    if counter_clockwise && sweep < P::Scalar::zero() {
+        sweep + full
+    } else if !counter_clockwise && sweep > P::Scalar::zero() {
+        sweep - full
+    } else {
+        sweep
+    }
+}
+
+/// A piece of an arc spline, either a circular arc or a straight line.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ArcSegment<P: PlanarPoint> {
+    Line(Bezier1<P>),
+    Arc(Arc<P>),
+}
+
+impl<P: PlanarPoint> Curve<P> for ArcSegment<P> {
+    fn value_at(&self, t: P::Scalar) -> P {
+        match self {
+            ArcSegment::Line(c) => c.value_at(t),
+            ArcSegment::Arc(c) => c.value_at(t),
+        }
+    }
+
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        match self {
+            ArcSegment::Line(c) => c.tangent_at(t),
+            ArcSegment::Arc(c) => c.tangent_at(t),
+        }
+    }
+
+    fn start_point(&self) -> P {
+        match self {
+            ArcSegment::Line(c) => c.start_point(),
+            ArcSegment::Arc(c) => c.start_point(),
+        }
+    }
+
+    fn end_point(&self) -> P {
+        match self {
+            ArcSegment::Line(c) => c.end_point(),
+            ArcSegment::Arc(c) => c.end_point(),
+        }
+    }
+
+    fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        match self {
+            ArcSegment::Line(c) => c.estimate_length(precision),
+            ArcSegment::Arc(c) => c.estimate_length(precision),
+        }
+    }
+}
+
+impl<P: PlanarPoint> ArcSegment<P> {
+    /// Distance from the point to the segment.
+    pub fn distance_to(&self, p: &P) -> P::Scalar {
+        match self {
+            ArcSegment::Line(line) => distance_to_segment(p, &line.p0, &line.p1),
+            ArcSegment::Arc(arc) => arc.distance_to(p),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn arc_from_tangent() {
+        let arc = Arc::from_tangent([1.0, 0.0], &[0.0, 1.0], [-1.0, 0.0]).unwrap();
+        assert_relative_eq!(arc.center[0], 0.0, epsilon = 1e-12);
+        assert_relative_eq!(arc.center[1], 0.0, epsilon = 1e-12);
+        assert_relative_eq!(arc.sweep, PI, epsilon = 1e-12);
+
+        let middle = arc.value_at(0.5);
+        assert_relative_eq!(middle[0], 0.0, epsilon = 1e-12);
+        assert_relative_eq!(middle[1], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(arc.estimate_length(0.0), PI, epsilon = 1e-12);
+
+        // The tangent defines the direction, so the arc takes three quarters of the circle
+        let arc = Arc::from_tangent([1.0, 0.0], &[0.0, 1.0], [0.0, -1.0]).unwrap();
+        assert_relative_eq!(arc.sweep, 1.5 * PI, epsilon = 1e-12);
+
+        assert!(Arc::from_tangent([0.0, 0.0], &[1.0, 0.0], [2.0, 0.0]).is_none());
+    }
+
+    #[test]
+    fn arc_distance() {
+        let arc = Arc::new([0.0, 0.0], [1.0, 0.0], PI / 2.0);
+        assert_relative_eq!(arc.distance_to(&[2.0, 2.0]), 8f64.sqrt() - 1.0);
+        assert_relative_eq!(arc.distance_to(&[0.0, -1.0]), 2f64.sqrt());
+    }
+}
@@ -0,0 +1,336 @@
+use crate::curve::polyline_length;
+use crate::{Bezier0, Curve, Distance, Point, Scalar};
+use core::marker::PhantomData;
+use num_traits::{One, Zero};
+
+/// Sum of two curves' values, see [`Curve::sum`].
+pub struct Sum<P: Point, A: Curve<P>, B: Curve<P>> {
+    a: A,
+    b: B,
+    phantom_data: PhantomData<P>,
+}
+
+/// Product of two curves' values, component-wise for multidimensional points, see [`Curve::product`].
+pub struct Product<P: Point, A: Curve<P>, B: Curve<P>> {
+    a: A,
+    b: B,
+    phantom_data: PhantomData<P>,
+}
+
+/// The smaller of two scalar curves' values, see [`Curve::minimum`].
+pub struct Minimum<P: Scalar + Point<Scalar = P>, A: Curve<P>, B: Curve<P>> {
+    a: A,
+    b: B,
+    phantom_data: PhantomData<P>,
+}
+
+/// The larger of two scalar curves' values, see [`Curve::maximum`].
+pub struct Maximum<P: Scalar + Point<Scalar = P>, A: Curve<P>, B: Curve<P>> {
+    a: A,
+    b: B,
+    phantom_data: PhantomData<P>,
+}
+
+/// A scalar curve with values kept between two bound curves, see [`Curve::clamp_between`].
+/// Bounds can be constants, [`Bezier0`] curves.
+pub struct Clamp<P: Scalar + Point<Scalar = P>, C: Curve<P>, L: Curve<P>, H: Curve<P>> {
+    curve: C,
+    min: L,
+    max: H,
+    phantom_data: PhantomData<P>,
+}
+
+/// The curve with negated values, see [`Curve::negate`].
+pub struct Negation<P: Point, C: Curve<P>> {
+    curve: C,
+    phantom_data: PhantomData<P>,
+}
+
+macro_rules! impl_binary {
+    ($name:ident, $($bound:tt)+) => {
+        impl<P: $($bound)+, A: Curve<P>, B: Curve<P>> $name<P, A, B> {
+            pub fn new(a: A, b: B) -> Self {
+                Self {
+                    a,
+                    b,
+                    phantom_data: PhantomData,
+                }
+            }
+
+            pub fn first(&self) -> &A {
+                &self.a
+            }
+
+            pub fn second(&self) -> &B {
+                &self.b
+            }
+        }
+    };
+}
+
+impl_binary!(Sum, Point);
+impl_binary!(Product, Point);
+impl_binary!(Minimum, Scalar + Point<Scalar = P>);
+impl_binary!(Maximum, Scalar + Point<Scalar = P>);
+
+impl<P: Scalar + Point<Scalar = P>, C: Curve<P>, L: Curve<P>, H: Curve<P>> Clamp<P, C, L, H> {
+    /// If `min` is greater than `max` at some point, the result is `max` there.
+    pub fn new(curve: C, min: L, max: H) -> Self {
+        Self {
+            curve,
+            min,
+            max,
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+
+    pub fn min(&self) -> &L {
+        &self.min
+    }
+
+    pub fn max(&self) -> &H {
+        &self.max
+    }
+}
+
+impl<P: Scalar + Point<Scalar = P>, C: Curve<P>> Clamp<P, C, Bezier0<P>, Bezier0<P>> {
+    /// Clamp between constant values.
+    pub fn constant(curve: C, min: P, max: P) -> Self {
+        Self::new(curve, Bezier0::new(min), Bezier0::new(max))
+    }
+}
+
+impl<P: Point, C: Curve<P>> Negation<P, C> {
+    pub fn new(curve: C) -> Self {
+        Self {
+            curve,
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+}
+
+/// Results of combinators aren't polynomial, their length is measured by polylines.
+const LENGTH_MIN_STEPS: usize = 16;
+
+impl<P: Point, A: Curve<P>, B: Curve<P>> Curve<P> for Sum<P, A, B> {
+    fn value_at(&self, t: P::Scalar) -> P {
+        self.a.value_at(t).add(&self.b.value_at(t))
+    }
+
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        self.a.tangent_at(t).add(&self.b.tangent_at(t))
+    }
+
+    fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        polyline_length(
+            self,
+            P::Scalar::zero(),
+            P::Scalar::one(),
+            LENGTH_MIN_STEPS,
+            precision,
+        )
+    }
+}
+
+impl<P: Point, A: Curve<P>, B: Curve<P>> Curve<P> for Product<P, A, B> {
+    fn value_at(&self, t: P::Scalar) -> P {
+        self.a.value_at(t).multiply(&self.b.value_at(t))
+    }
+
+    /// The product rule, `a' * b + a * b'`.
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        let da = self.a.tangent_at(t).multiply(&self.b.value_at(t));
+        let db = self.a.value_at(t).multiply(&self.b.tangent_at(t));
+        da.add(&db)
+    }
+
+    fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        polyline_length(
+            self,
+            P::Scalar::zero(),
+            P::Scalar::one(),
+            LENGTH_MIN_STEPS,
+            precision,
+        )
+    }
+}
+
+impl<P: Scalar + Point<Scalar = P>, A: Curve<P>, B: Curve<P>> Curve<P> for Minimum<P, A, B> {
+    fn value_at(&self, t: P) -> P {
+        self.a.value_at(t).min(self.b.value_at(t))
+    }
+
+    /// The tangent of the curve with the smaller value, of the first one if they are equal.
+    fn tangent_at(&self, t: P) -> P {
+        if self.b.value_at(t) < self.a.value_at(t) {
+            self.b.tangent_at(t)
+        } else {
+            self.a.tangent_at(t)
+        }
+    }
+
+    fn estimate_length(&self, precision: P) -> P
+    where
+        P: Distance,
+    {
+        polyline_length(self, P::zero(), P::one(), LENGTH_MIN_STEPS, precision)
+    }
+}
+
+impl<P: Scalar + Point<Scalar = P>, A: Curve<P>, B: Curve<P>> Curve<P> for Maximum<P, A, B> {
+    fn value_at(&self, t: P) -> P {
+        self.a.value_at(t).max(self.b.value_at(t))
+    }
+
+    /// The tangent of the curve with the larger value, of the first one if they are equal.
+    fn tangent_at(&self, t: P) -> P {
+        if self.b.value_at(t) > self.a.value_at(t) {
+            self.b.tangent_at(t)
+        } else {
+            self.a.tangent_at(t)
+        }
+    }
+
+    fn estimate_length(&self, precision: P) -> P
+    where
+        P: Distance,
+    {
+        polyline_length(self, P::zero(), P::one(), LENGTH_MIN_STEPS, precision)
+    }
+}
+
+impl<P, C, L, H> Curve<P> for Clamp<P, C, L, H>
+where
+    P: Scalar + Point<Scalar = P>,
+    C: Curve<P>,
+    L: Curve<P>,
+    H: Curve<P>,
+{
+    fn value_at(&self, t: P) -> P {
+        self.curve
+            .value_at(t)
+            .max(self.min.value_at(t))
+            .min(self.max.value_at(t))
+    }
+
+    /// The tangent of the bound limiting the value, or of the curve itself.
+    fn tangent_at(&self, t: P) -> P {
+        let value = self.curve.value_at(t);
+        if value > self.max.value_at(t) || self.min.value_at(t) > self.max.value_at(t) {
+            self.max.tangent_at(t)
+        } else if value < self.min.value_at(t) {
+            self.min.tangent_at(t)
+        } else {
+            self.curve.tangent_at(t)
+        }
+    }
+
+    fn estimate_length(&self, precision: P) -> P
+    where
+        P: Distance,
+    {
+        polyline_length(self, P::zero(), P::one(), LENGTH_MIN_STEPS, precision)
+    }
+}
+
+impl<P: Point, C: Curve<P>> Curve<P> for Negation<P, C> {
+    fn value_at(&self, t: P::Scalar) -> P {
+        self.curve.value_at(t).scale(-P::Scalar::one())
+    }
+
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        self.curve.tangent_at(t).scale(-P::Scalar::one())
+    }
+
+    fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        self.curve.estimate_length(precision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::easing::CubicBezierEasing;
+    use crate::{Bezier1, Bezier2, Bezier3};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn sum_and_negation() {
+        let ramp = Bezier1::new(0.0, 1.0);
+        let wobble = Bezier2::new(0.0, 0.5, 0.0);
+        let curve = ramp.sum(wobble.negate());
+        assert_relative_eq!(curve.value_at(0.5), 0.25);
+        assert_relative_eq!(curve.tangent_at(0.0), 0.0);
+        assert_relative_eq!(curve.estimate_length(0.01), 1.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn product() {
+        let ramp = Bezier1::new(0.0, 2.0);
+        let curve = ramp.product(Bezier1::new(1.0, 3.0));
+        assert_relative_eq!(curve.value_at(0.5), 2.0);
+        // d/dt (2t * (1 + 2t)) = 2 + 8t
+        assert_relative_eq!(curve.tangent_at(0.5), 6.0);
+
+        let points = Bezier1::new([1.0, 2.0], [3.0, 4.0]).product(Bezier0::new([2.0, 0.5]));
+        assert_eq!(points.value_at(1.0), [6.0, 2.0]);
+    }
+
+    #[test]
+    fn min_max() {
+        let up = Bezier1::new(0.0, 1.0);
+        let down = Bezier1::new(1.0, 0.0);
+        let low = up.minimum(down);
+        let high = up.maximum(down);
+        assert_relative_eq!(low.value_at(0.25), 0.25);
+        assert_relative_eq!(low.value_at(0.75), 0.25);
+        assert_relative_eq!(low.tangent_at(0.75), -1.0);
+        assert_relative_eq!(high.value_at(0.25), 0.75);
+        assert_relative_eq!(high.tangent_at(0.25), -1.0);
+        assert_relative_eq!(high.tangent_at(0.75), 1.0);
+    }
+
+    #[test]
+    fn clamp() {
+        let wave = Bezier3::new(0.0, 3.0, -2.0, 1.0);
+        let clamped = wave.clamp_between(Bezier0::new(0.0), Bezier1::new(0.5, 1.5));
+        for i in 0..=20 {
+            let t = i as f64 / 20.0;
+            let value = clamped.value_at(t);
+            assert!(value >= 0.0 && value <= 0.5 + t + 1e-12);
+        }
+        assert_relative_eq!(clamped.value_at(0.25), 0.75);
+        assert_relative_eq!(clamped.tangent_at(0.25), 1.0);
+
+        let constant = Clamp::constant(Bezier1::new(-1.0, 2.0), 0.0, 1.0);
+        assert_eq!(constant.value_at(0.0), 0.0);
+        assert_eq!(constant.value_at(1.0), 1.0);
+        assert_eq!(constant.tangent_at(0.0), 0.0);
+        assert_eq!(constant.tangent_at(0.5), 3.0);
+    }
+
+    #[test]
+    fn easing_modulation() {
+        let easing = CubicBezierEasing::<f64>::ease_in_out();
+        let base = Bezier1::new(0.0, 10.0);
+        let curve = base.product(Bezier0::new(0.5)).sum(easing);
+        assert_relative_eq!(curve.value_at(1.0), 6.0, epsilon = 1e-9);
+    }
+}
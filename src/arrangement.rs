@@ -0,0 +1,528 @@
+use crate::intersection::segment_intersections;
+use crate::{Bezier, ComposedCurve, Curve, Distance, Path, PlanarPoint, Scalar, SegmentBvh};
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use num_traits::{NumCast, One, ToPrimitive, Zero};
+
+/// Points closer than this many tolerances become a single vertex, intersection points
+/// computed from both curves differ by about the tolerance.
+const MERGE_FACTOR: f64 = 4.0;
+
+/// A piece of an input segment between two vertices of an [`Arrangement`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArrangementEdge<P: PlanarPoint> {
+    /// The geometry, it starts exactly at the `start` vertex and ends at the `end` one.
+    pub curve: Bezier<P>,
+    pub start: usize,
+    pub end: usize,
+    /// Index of the input path the edge comes from.
+    pub path: usize,
+    /// Index of the segment of the input path the edge is a part of.
+    pub segment: usize,
+}
+
+/// A bounded region of an [`Arrangement`], boundaries are lists of `(edge, forward)` pairs,
+/// `forward` is `false` for edges traversed from their end to their start.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArrangementFace<P: PlanarPoint> {
+    /// The outer boundary, counter-clockwise.
+    pub boundary: Vec<(usize, bool)>,
+    /// Clockwise boundaries of the parts of the arrangement inside the face.
+    pub holes: Vec<Vec<(usize, bool)>>,
+    /// Area of the face without the holes.
+    pub area: P::Scalar,
+}
+
+/// Planar subdivision induced by a set of paths: paths are split at all their intersections
+/// into edges meeting at vertices, and edges bound faces.
+///
+/// It's the base for face picking, coloring regions of a map, or boolean operations
+/// that select faces by the winding numbers of the input paths. Segments aren't intersected
+/// with themselves, so a self-intersecting single cubic gives no vertex at its loop.
+#[derive(Clone, Debug)]
+pub struct Arrangement<P: PlanarPoint> {
+    vertices: Vec<P>,
+    edges: Vec<ArrangementEdge<P>>,
+    faces: Vec<ArrangementFace<P>>,
+    /// Outer boundaries of the faces and the areas inside them, used for point location.
+    contours: Vec<(ComposedCurve<P>, P::Scalar)>,
+}
+
+impl<P: PlanarPoint> Arrangement<P> {
+    /// Build the arrangement of `paths`, curves are intersected within `tolerance`
+    /// and areas are measured on curves flattened with it.
+    pub fn new(paths: &[ComposedCurve<P>], tolerance: P::Scalar) -> Self {
+        let merge_distance = tolerance * NumCast::from(MERGE_FACTOR).unwrap();
+        let splits = split_parameters(paths, tolerance);
+
+        let mut vertices = VertexSet::new(merge_distance);
+        let mut edges: Vec<ArrangementEdge<P>> = Vec::new();
+        let mut by_ends: BTreeMap<(usize, usize), Vec<usize>> = BTreeMap::new();
+        for (path, curve) in paths.iter().enumerate() {
+            for (segment, bezier) in curve.segments().iter().enumerate() {
+                if matches!(bezier, Bezier::C0(_)) {
+                    continue;
+                }
+                for piece in split_at(bezier, &splits[path][segment]) {
+                    let start = vertices.insert(piece.start_point());
+                    let end = vertices.insert(piece.end_point());
+                    let piece = with_ends(&piece, &vertices.points[start], &vertices.points[end]);
+                    let middle = piece.value_at(NumCast::from(0.5).unwrap());
+                    let key = (start.min(end), start.max(end));
+                    let duplicate = by_ends.get(&key).is_some_and(|candidates| {
+                        candidates.iter().any(|&e| {
+                            let other = edges[e].curve.value_at(NumCast::from(0.5).unwrap());
+                            other.distance(&middle) <= merge_distance
+                        })
+                    });
+                    if (start == end
+                        && piece
+                            .control_bounds()
+                            .width()
+                            .max(piece.control_bounds().height())
+                            <= merge_distance)
+                        || duplicate
+                    {
+                        continue;
+                    }
+                    by_ends.entry(key).or_default().push(edges.len());
+                    edges.push(ArrangementEdge {
+                        curve: piece,
+                        start,
+                        end,
+                        path,
+                        segment,
+                    });
+                }
+            }
+        }
+
+        let vertices = vertices.points;
+        let mut arrangement = Self {
+            vertices,
+            edges,
+            faces: Vec::new(),
+            contours: Vec::new(),
+        };
+        arrangement.build_faces(tolerance);
+        arrangement
+    }
+
+    pub fn vertices(&self) -> &[P] {
+        &self.vertices
+    }
+
+    pub fn edges(&self) -> &[ArrangementEdge<P>] {
+        &self.edges
+    }
+
+    /// Bounded faces, the unbounded face around everything isn't included.
+    pub fn faces(&self) -> &[ArrangementFace<P>] {
+        &self.faces
+    }
+
+    /// Index of the face containing `point`, `None` for the unbounded face.
+    /// Points on the edges may be assigned to any of the neighbouring faces.
+    pub fn face_at(&self, point: &P) -> Option<usize> {
+        smallest_around(&self.contours, point, |_| true)
+    }
+
+    /// A closed curve going along `boundary`, e.g. [`ArrangementFace::boundary`].
+    pub fn boundary_curve(&self, boundary: &[(usize, bool)]) -> ComposedCurve<P> {
+        let start = match boundary.first() {
+            Some(&(edge, forward)) => self.half_edge_curve(edge, forward).start_point(),
+            None => return ComposedCurve::new(self.vertices[0].clone()),
+        };
+        let mut curve = ComposedCurve::with_capacity(start, boundary.len());
+        for &(edge, forward) in boundary {
+            curve.push_segment(self.half_edge_curve(edge, forward));
+        }
+        curve
+    }
+
+    /// The face as a path with its outer boundary and holes, ready for filling.
+    pub fn face_path(&self, face: usize) -> Path<P> {
+        let face = &self.faces[face];
+        core::iter::once(&face.boundary)
+            .chain(&face.holes)
+            .map(|boundary| self.boundary_curve(boundary))
+            .collect()
+    }
+
+    fn half_edge_curve(&self, edge: usize, forward: bool) -> Bezier<P> {
+        let curve = &self.edges[edge].curve;
+        if forward {
+            curve.clone()
+        } else {
+            reversed(curve)
+        }
+    }
+
+    fn cycle_area(&self, cycle: &[(usize, bool)], tolerance: P::Scalar) -> P::Scalar {
+        let half = P::Scalar::one() / (P::Scalar::one() + P::Scalar::one());
+        let twice_area = cycle
+            .iter()
+            .fold(P::Scalar::zero(), |sum, &(edge, forward)| {
+                let points = self.half_edge_curve(edge, forward).flatten(tolerance);
+                points
+                    .windows(2)
+                    .fold(sum, |sum, pair| sum + pair[0].cross(&pair[1]))
+            });
+        twice_area * half
+    }
+
+    /// Trace the boundary cycles of the half-edges and sort them into faces and holes.
+    fn build_faces(&mut self, tolerance: P::Scalar) {
+        // Half-edge `2 e` goes along the edge `e`, `2 e + 1` backward.
+        // Outgoing half-edges of every vertex sorted counter-clockwise.
+        let mut outgoing: Vec<Vec<usize>> = vec![Vec::new(); self.vertices.len()];
+        for (e, edge) in self.edges.iter().enumerate() {
+            outgoing[edge.start].push(2 * e);
+            outgoing[edge.end].push(2 * e + 1);
+        }
+        let mut position = vec![0; self.edges.len() * 2];
+        for list in &mut outgoing {
+            let mut keyed: Vec<_> = list.iter().map(|&h| (h, self.direction_key(h))).collect();
+            keyed.sort_by(|a, b| compare(&a.1 .0, &b.1 .0).then(compare(&a.1 .1, &b.1 .1)));
+            *list = keyed.into_iter().map(|(h, _)| h).collect();
+            for (i, &h) in list.iter().enumerate() {
+                position[h] = i;
+            }
+        }
+        let end_vertex = |h: usize| {
+            let edge = &self.edges[h / 2];
+            if h & 1 == 0 {
+                edge.end
+            } else {
+                edge.start
+            }
+        };
+        // The face is on the left, the next half-edge is the clockwise neighbour of the twin
+        let next = |h: usize| {
+            let list = &outgoing[end_vertex(h)];
+            let twin = h ^ 1;
+            list[(position[twin] + list.len() - 1) % list.len()]
+        };
+
+        let mut visited = vec![false; self.edges.len() * 2];
+        let mut cycles: Vec<Vec<(usize, bool)>> = Vec::new();
+        for h in 0..visited.len() {
+            let mut current = h;
+            let mut cycle = Vec::new();
+            while !visited[current] {
+                visited[current] = true;
+                cycle.push((current / 2, current & 1 == 0));
+                current = next(current);
+            }
+            if !cycle.is_empty() {
+                cycles.push(cycle);
+            }
+        }
+
+        let components = self.components();
+        let areas: Vec<_> = cycles
+            .iter()
+            .map(|cycle| self.cycle_area(cycle, tolerance))
+            .collect();
+        let mut faces = Vec::new();
+        let mut holes = Vec::new();
+        for (cycle, area) in cycles.into_iter().zip(areas) {
+            if area > P::Scalar::zero() {
+                self.contours.push((self.boundary_curve(&cycle), area));
+                faces.push(ArrangementFace {
+                    boundary: cycle,
+                    holes: Vec::new(),
+                    area,
+                });
+            } else {
+                holes.push((cycle, area));
+            }
+        }
+
+        // Every hole belongs to the smallest face of another component around it
+        for (hole, area) in holes {
+            let component = components[self.edges[hole[0].0].start];
+            let point = &self.vertices[self.edges[hole[0].0].start];
+            let owner = smallest_around(&self.contours, point, |i| {
+                components[self.edges[faces[i].boundary[0].0].start] != component
+            });
+            if let Some(owner) = owner {
+                faces[owner].area = faces[owner].area + area;
+                faces[owner].holes.push(hole);
+            }
+        }
+        self.faces = faces;
+    }
+
+    /// Connected component of every vertex.
+    fn components(&self) -> Vec<usize> {
+        let mut parent: Vec<usize> = (0..self.vertices.len()).collect();
+        fn root(parent: &mut [usize], mut i: usize) -> usize {
+            while parent[i] != i {
+                parent[i] = parent[parent[i]];
+                i = parent[i];
+            }
+            i
+        }
+        for edge in &self.edges {
+            let (a, b) = (root(&mut parent, edge.start), root(&mut parent, edge.end));
+            parent[a] = b;
+        }
+        (0..self.vertices.len())
+            .map(|i| root(&mut parent, i))
+            .collect()
+    }
+
+    /// Angle of the half-edge leaving its vertex, and the deviation of the curve
+    /// from the tangent to order half-edges leaving in the same direction.
+    fn direction_key(&self, h: usize) -> (P::Scalar, P::Scalar) {
+        let curve = self.half_edge_curve(h / 2, h & 1 == 0);
+        let points = curve.control_points();
+        let start = &points[0];
+        let direction = points[1..]
+            .iter()
+            .map(|p| p.sub(start))
+            .find(|d| d.length() > P::Scalar::zero())
+            .unwrap_or_else(|| start.sub(start));
+        let sample = curve
+            .value_at(NumCast::from(1.0 / 64.0).unwrap())
+            .sub(start);
+        let length = direction.length() * sample.length();
+        let deviation = if length > P::Scalar::zero() {
+            direction.cross(&sample) / length
+        } else {
+            P::Scalar::zero()
+        };
+        (direction.y().atan2(direction.x()), deviation)
+    }
+}
+
+fn compare<F: Scalar>(a: &F, b: &F) -> Ordering {
+    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+}
+
+/// Index of the smallest of the accepted contours winding around `point`.
+fn smallest_around<P: PlanarPoint>(
+    contours: &[(ComposedCurve<P>, P::Scalar)],
+    point: &P,
+    accept: impl Fn(usize) -> bool,
+) -> Option<usize> {
+    (0..contours.len())
+        .filter(|&i| accept(i) && contours[i].0.winding_at(point) != 0)
+        .min_by(|&a, &b| compare(&contours[a].1, &contours[b].1))
+}
+
+/// Sorted parameters of the intersection points inside every segment of every path.
+fn split_parameters<P: PlanarPoint>(
+    paths: &[ComposedCurve<P>],
+    tolerance: P::Scalar,
+) -> Vec<Vec<Vec<P::Scalar>>> {
+    let mut splits: Vec<Vec<Vec<P::Scalar>>> = paths
+        .iter()
+        .map(|path| vec![Vec::new(); path.segments().len()])
+        .collect();
+    let trees: Vec<_> = paths.iter().map(SegmentBvh::new).collect();
+    for (i, tree) in trees.iter().enumerate() {
+        // Within a path only distinct segments are intersected, a curve
+        // overlaps itself everywhere
+        for (k, segment) in tree.segments().iter().enumerate() {
+            let bounds = segment.control_bounds().inflate(tolerance);
+            for l in tree.query_rect(&bounds).into_iter().filter(|&l| l > k) {
+                for (t, u) in segment_intersections(segment, &tree.segments()[l], tolerance) {
+                    splits[i][k].push(t);
+                    splits[i][l].push(u);
+                }
+            }
+        }
+        for (j, other) in trees.iter().enumerate().skip(i + 1) {
+            for hit in tree.intersections(other, tolerance) {
+                splits[i][hit.segment].push(hit.t);
+                splits[j][hit.other_segment].push(hit.other_t);
+            }
+        }
+    }
+    for list in splits.iter_mut().flatten() {
+        list.sort_by(compare);
+        list.dedup();
+    }
+    splits
+}
+
+/// Pieces of `curve` between the sorted `parameters`.
+fn split_at<P: PlanarPoint>(curve: &Bezier<P>, parameters: &[P::Scalar]) -> Vec<Bezier<P>> {
+    let (zero, one) = (P::Scalar::zero(), P::Scalar::one());
+    let mut pieces = Vec::with_capacity(parameters.len() + 1);
+    let mut rest = curve.clone();
+    let mut previous = zero;
+    for &t in parameters {
+        if t <= previous || t >= one {
+            continue;
+        }
+        let (piece, remaining) = rest.split_at((t - previous) / (one - previous));
+        pieces.push(piece);
+        rest = remaining;
+        previous = t;
+    }
+    pieces.push(rest);
+    pieces
+}
+
+/// The curve with its end points moved exactly to `start` and `end`.
+fn with_ends<P: PlanarPoint>(curve: &Bezier<P>, start: &P, end: &P) -> Bezier<P> {
+    let mut curve = curve.clone();
+    match &mut curve {
+        Bezier::C0(c) => c.point = start.clone(),
+        Bezier::C1(c) => (c.p0, c.p1) = (start.clone(), end.clone()),
+        Bezier::C2(c) => (c.p0, c.p2) = (start.clone(), end.clone()),
+        Bezier::C3(c) => (c.p0, c.p3) = (start.clone(), end.clone()),
+    }
+    curve
+}
+
+fn reversed<P: PlanarPoint>(curve: &Bezier<P>) -> Bezier<P> {
+    let mut curve = curve.clone();
+    match &mut curve {
+        Bezier::C0(_) => {}
+        Bezier::C1(c) => core::mem::swap(&mut c.p0, &mut c.p1),
+        Bezier::C2(c) => core::mem::swap(&mut c.p0, &mut c.p2),
+        Bezier::C3(c) => {
+            core::mem::swap(&mut c.p0, &mut c.p3);
+            core::mem::swap(&mut c.p1, &mut c.p2);
+        }
+    }
+    curve
+}
+
+/// Points merged within a distance, found through a grid of cells of that size.
+struct VertexSet<P: PlanarPoint> {
+    points: Vec<P>,
+    cells: BTreeMap<(i64, i64), Vec<usize>>,
+    distance: P::Scalar,
+}
+
+impl<P: PlanarPoint> VertexSet<P> {
+    fn new(distance: P::Scalar) -> Self {
+        Self {
+            points: Vec::new(),
+            cells: BTreeMap::new(),
+            distance,
+        }
+    }
+
+    fn cell(&self, point: &P) -> (i64, i64) {
+        let size = if self.distance > P::Scalar::zero() {
+            self.distance
+        } else {
+            P::Scalar::one()
+        };
+        let coordinate = |v: P::Scalar| (v / size).floor().to_i64().unwrap_or(i64::MAX);
+        (coordinate(point.x()), coordinate(point.y()))
+    }
+
+    /// Index of the point closer than the distance to `point`, added if there is none.
+    fn insert(&mut self, point: P) -> usize {
+        let (x, y) = self.cell(&point);
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                let key = (x.saturating_add(dx), y.saturating_add(dy));
+                if let Some(indices) = self.cells.get(&key) {
+                    for &i in indices {
+                        if self.points[i].distance(&point) <= self.distance {
+                            return i;
+                        }
+                    }
+                }
+            }
+        }
+        self.cells
+            .entry((x, y))
+            .or_default()
+            .push(self.points.len());
+        self.points.push(point);
+        self.points.len() - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn square(x: f64, y: f64, size: f64) -> ComposedCurve<[f64; 2]> {
+        let mut curve = ComposedCurve::new([x, y]);
+        curve.line_to([x + size, y]);
+        curve.line_to([x + size, y + size]);
+        curve.line_to([x, y + size]);
+        curve.close();
+        curve
+    }
+
+    #[test]
+    fn overlapping_squares() {
+        let arrangement = Arrangement::new(&[square(0.0, 0.0, 2.0), square(1.0, 1.0, 2.0)], 1e-9);
+        assert_eq!(arrangement.vertices().len(), 10);
+        assert_eq!(arrangement.edges().len(), 12);
+
+        let mut areas: Vec<_> = arrangement.faces().iter().map(|face| face.area).collect();
+        areas.sort_by(compare);
+        assert_eq!(areas.len(), 3);
+        assert_relative_eq!(areas[0], 1.0, epsilon = 1e-9);
+        assert_relative_eq!(areas[1], 3.0, epsilon = 1e-9);
+        assert_relative_eq!(areas[2], 3.0, epsilon = 1e-9);
+
+        let middle = arrangement.face_at(&[1.5, 1.5]).unwrap();
+        assert_relative_eq!(arrangement.faces()[middle].area, 1.0, epsilon = 1e-9);
+        let first = arrangement.face_at(&[0.5, 0.5]).unwrap();
+        let second = arrangement.face_at(&[2.5, 2.5]).unwrap();
+        assert!(first != second && first != middle && second != middle);
+        assert_eq!(arrangement.face_at(&[5.0, 5.0]), None);
+
+        // Edges keep their origin
+        let edge = &arrangement.edges()[0];
+        assert_eq!((edge.path, edge.segment), (0, 0));
+    }
+
+    #[test]
+    fn nested_squares() {
+        let arrangement = Arrangement::new(&[square(0.0, 0.0, 10.0), square(4.0, 4.0, 2.0)], 1e-9);
+        assert_eq!(arrangement.faces().len(), 2);
+        let outer = arrangement.face_at(&[1.0, 1.0]).unwrap();
+        let inner = arrangement.face_at(&[5.0, 5.0]).unwrap();
+        assert_ne!(outer, inner);
+        assert_relative_eq!(arrangement.faces()[outer].area, 96.0, epsilon = 1e-9);
+        assert_eq!(arrangement.faces()[outer].holes.len(), 1);
+        assert_eq!(arrangement.face_path(outer).contours().len(), 2);
+        assert_eq!(arrangement.face_path(inner).winding_at(&[5.0, 5.0]), 1);
+        assert_eq!(arrangement.face_path(outer).winding_at(&[5.0, 5.0]), 0);
+    }
+
+    #[test]
+    fn curves_and_dangling_edges() {
+        // An arch closed by a line, cut in two by a line sticking out of it
+        let mut arch = ComposedCurve::new([0.0, 0.0]);
+        arch.cubic_to([0.0, 4.0], [4.0, 4.0], [4.0, 0.0]);
+        arch.close();
+        let mut cut = ComposedCurve::new([2.0, -1.0]);
+        cut.line_to([2.0, 5.0]);
+
+        let arrangement = Arrangement::new(&[arch, cut], 1e-6);
+        assert_eq!(arrangement.vertices().len(), 6);
+        assert_eq!(arrangement.edges().len(), 7);
+        assert_eq!(arrangement.faces().len(), 2);
+
+        let total: f64 = arrangement.faces().iter().map(|face| face.area).sum();
+        // The arch covers 3/5 of its 4×4 control box
+        let arch_area = 0.6 * 16.0;
+        assert_relative_eq!(total, arch_area, epsilon = 1e-3);
+        let left = arrangement.face_at(&[1.0, 1.0]).unwrap();
+        let right = arrangement.face_at(&[3.0, 1.0]).unwrap();
+        assert_ne!(left, right);
+        assert_relative_eq!(
+            arrangement.faces()[left].area,
+            arrangement.faces()[right].area,
+            epsilon = 1e-3
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{Curve, InnerProduct, PlanarPoint, Point, SpatialPoint};
+use ::bevy_math::curve::Interval;
+use ::bevy_math::{Vec2, Vec3, Vec3A, Vec4};
+use core::marker::PhantomData;
+
+macro_rules! impl_bevy_point {
+    ($vector:ident) => {
+        impl Point for $vector {
+            type Scalar = f32;
+
+            fn add(&self, other: &Self) -> Self {
+                *self + *other
+            }
+
+            fn sub(&self, other: &Self) -> Self {
+                *self - *other
+            }
+
+            fn multiply(&self, other: &Self) -> Self {
+                *self * *other
+            }
+
+            fn scale(&self, s: f32) -> Self {
+                *self * s
+            }
+        }
+
+        impl InnerProduct for $vector {
+            fn dot(&self, other: &Self) -> f32 {
+                $vector::dot(*self, *other)
+            }
+        }
+    };
+}
+
+macro_rules! impl_bevy_spatial {
+    ($vector:ident) => {
+        impl SpatialPoint for $vector {
+            fn from_xyz(x: f32, y: f32, z: f32) -> Self {
+                $vector::new(x, y, z)
+            }
+
+            fn x(&self) -> f32 {
+                self.x
+            }
+
+            fn y(&self) -> f32 {
+                self.y
+            }
+
+            fn z(&self) -> f32 {
+                self.z
+            }
+
+            fn cross(&self, other: &Self) -> Self {
+                $vector::cross(*self, *other)
+            }
+        }
+    };
+}
+
+impl_bevy_point!(Vec2);
+impl_bevy_point!(Vec3);
+impl_bevy_point!(Vec3A);
+impl_bevy_point!(Vec4);
+
+impl_bevy_spatial!(Vec3);
+impl_bevy_spatial!(Vec3A);
+
+impl PlanarPoint for Vec2 {
+    fn from_xy(x: f32, y: f32) -> Self {
+        Vec2::new(x, y)
+    }
+
+    fn x(&self) -> f32 {
+        self.x
+    }
+
+    fn y(&self) -> f32 {
+        self.y
+    }
+
+    fn perpendicular(&self) -> Self {
+        self.perp()
+    }
+}
+
+/// Any curve as a `bevy_math` curve over the unit interval, so it can drive Bevy animations,
+/// see [`Curve::into_bevy`].
+pub struct BevyCurve<P: Point<Scalar = f32>, C: Curve<P>> {
+    curve: C,
+    phantom_data: PhantomData<P>,
+}
+
+impl<P: Point<Scalar = f32>, C: Curve<P>> BevyCurve<P, C> {
+    pub fn new(curve: C) -> Self {
+        Self {
+            curve,
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub fn into_inner(self) -> C {
+        self.curve
+    }
+}
+
+impl<P: Point<Scalar = f32>, C: Curve<P>> ::bevy_math::curve::Curve<P> for BevyCurve<P, C> {
+    fn domain(&self) -> Interval {
+        Interval::UNIT
+    }
+
+    fn sample_unchecked(&self, t: f32) -> P {
+        self.curve.value_at(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bezier1, ComposedCurve, Distance};
+    use ::bevy_math::curve::Curve as _;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn bevy_points() {
+        let line = Bezier1::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 4.0));
+        assert_eq!(line.value_at(0.5), Vec2::new(1.0, 2.0));
+        assert_eq!(
+            Distance::distance(&Vec3::ZERO, &Vec3::new(1.0, 2.0, 2.0)),
+            3.0
+        );
+        assert_eq!(SpatialPoint::cross(&Vec3A::X, &Vec3A::Y), Vec3A::Z);
+        assert_eq!(PlanarPoint::cross(&Vec2::X, &Vec2::Y), 1.0);
+    }
+
+    #[test]
+    fn bevy_curve() {
+        let mut path = ComposedCurve::new(Vec2::ZERO);
+        path.line_to(Vec2::new(1.0, 0.0));
+        path.cubic_to(
+            Vec2::new(4.0, 0.0),
+            Vec2::new(4.0, 3.0),
+            Vec2::new(1.0, 3.0),
+        );
+
+        let curve = path.linear_speed(64, 256).into_bevy();
+        assert_eq!(curve.domain(), Interval::UNIT);
+        assert_eq!(curve.sample(1.5), None);
+        assert_relative_eq!(curve.sample_clamped(-1.0).x, 0.0);
+        assert_relative_eq!(curve.sample_unchecked(1.0).y, 3.0, epsilon = 1e-4);
+
+        let mid = curve.sample(0.5).unwrap();
+        assert_relative_eq!(mid.x, curve.into_inner().value_at(0.5).x);
+    }
+}
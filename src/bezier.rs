@@ -1,9 +1,15 @@
-use crate::{Curve, Distance, Point};
+use crate::integrate::simpson;
+use crate::polynomial::Polynomial3;
+use crate::validate::{debug_check_parameter, debug_check_parameters};
+use crate::{Curve, Distance, Error, Point, PointCast, Scalar};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::fmt::Debug;
 use num_traits::{One, Zero};
-use std::fmt::Debug;
 
 /// Single point
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bezier0<P: Point> {
     pub point: P,
 }
@@ -12,10 +18,31 @@ impl<P: Point> Bezier0<P> {
     pub fn new(point: P) -> Self {
         Self { point }
     }
+
+    /// Like [`Bezier0::new`], but fails if the point is not finite.
+    pub fn try_new(point: P) -> Result<Self, Error> {
+        let curve = Self::new(point);
+        curve.validate()?;
+        Ok(curve)
+    }
+
+    /// Convert every control point with `f`.
+    pub fn map_points<Q: Point>(&self, mut f: impl FnMut(&P) -> Q) -> Bezier0<Q> {
+        Bezier0::new(f(&self.point))
+    }
+
+    /// Convert the curve to another point type, e.g. from `f64` to `f32`.
+    pub fn cast<Q: Point>(&self) -> Bezier0<Q>
+    where
+        P: PointCast<Q>,
+    {
+        self.map_points(PointCast::cast)
+    }
 }
 
 /// Line
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bezier1<P: Point> {
     pub p0: P,
     pub p1: P,
@@ -25,10 +52,45 @@ impl<P: Point> Bezier1<P> {
     pub fn new(p0: P, p1: P) -> Self {
         Self { p0, p1 }
     }
+
+    /// Like [`Bezier1::new`], but fails if all the control points are not finite.
+    pub fn try_new(p0: P, p1: P) -> Result<Self, Error> {
+        let curve = Self::new(p0, p1);
+        curve.validate()?;
+        Ok(curve)
+    }
+
+    /// Convert every control point with `f`.
+    pub fn map_points<Q: Point>(&self, mut f: impl FnMut(&P) -> Q) -> Bezier1<Q> {
+        Bezier1::new(f(&self.p0), f(&self.p1))
+    }
+
+    /// Convert the curve to another point type, e.g. from `f64` to `f32`.
+    pub fn cast<Q: Point>(&self) -> Bezier1<Q>
+    where
+        P: PointCast<Q>,
+    {
+        self.map_points(PointCast::cast)
+    }
+
+    /// Split the curve at `t` into two curves covering `[0, t]` and `[t, 1]`.
+    pub fn split_at(&self, t: P::Scalar) -> (Self, Self) {
+        let m = self.p0.lerp(&self.p1, t);
+        (
+            Bezier1::new(self.p0.clone(), m.clone()),
+            Bezier1::new(m, self.p1.clone()),
+        )
+    }
+
+    /// The same line as a quadratic curve.
+    pub fn to_quadratic(&self) -> Bezier2<P> {
+        Bezier2::new(self.p0.clone(), self.p0.midpoint(&self.p1), self.p1.clone())
+    }
 }
 
 /// Quadratic bezier curve
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bezier2<P: Point> {
     pub p0: P,
     pub p1: P,
@@ -39,10 +101,53 @@ impl<P: Point> Bezier2<P> {
     pub fn new(p0: P, p1: P, p2: P) -> Self {
         Self { p0, p1, p2 }
     }
+
+    /// Like [`Bezier2::new`], but fails if all the control points are not finite.
+    pub fn try_new(p0: P, p1: P, p2: P) -> Result<Self, Error> {
+        let curve = Self::new(p0, p1, p2);
+        curve.validate()?;
+        Ok(curve)
+    }
+
+    /// Convert every control point with `f`.
+    pub fn map_points<Q: Point>(&self, mut f: impl FnMut(&P) -> Q) -> Bezier2<Q> {
+        Bezier2::new(f(&self.p0), f(&self.p1), f(&self.p2))
+    }
+
+    /// Convert the curve to another point type, e.g. from `f64` to `f32`.
+    pub fn cast<Q: Point>(&self) -> Bezier2<Q>
+    where
+        P: PointCast<Q>,
+    {
+        self.map_points(PointCast::cast)
+    }
+
+    /// Split the curve at `t` into two curves covering `[0, t]` and `[t, 1]`.
+    pub fn split_at(&self, t: P::Scalar) -> (Self, Self) {
+        let a = self.p0.lerp(&self.p1, t);
+        let b = self.p1.lerp(&self.p2, t);
+        let m = a.lerp(&b, t);
+        (
+            Bezier2::new(self.p0.clone(), a, m.clone()),
+            Bezier2::new(m, b, self.p2.clone()),
+        )
+    }
+
+    /// The same curve as a cubic, degree elevation is exact.
+    pub fn to_cubic(&self) -> Bezier3<P> {
+        let third = P::Scalar::one() / (two::<P::Scalar>() + P::Scalar::one());
+        Bezier3::new(
+            self.p0.clone(),
+            self.p0.lerp(&self.p1, two::<P::Scalar>() * third),
+            self.p2.lerp(&self.p1, two::<P::Scalar>() * third),
+            self.p2.clone(),
+        )
+    }
 }
 
 /// Cubic bezier curve
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bezier3<P: Point> {
     pub p0: P,
     pub p1: P,
@@ -54,9 +159,64 @@ impl<P: Point> Bezier3<P> {
     pub fn new(p0: P, p1: P, p2: P, p3: P) -> Self {
         Self { p0, p1, p2, p3 }
     }
+
+    /// Like [`Bezier3::new`], but fails if all the control points are not finite.
+    pub fn try_new(p0: P, p1: P, p2: P, p3: P) -> Result<Self, Error> {
+        let curve = Self::new(p0, p1, p2, p3);
+        curve.validate()?;
+        Ok(curve)
+    }
+
+    /// Convert every control point with `f`.
+    pub fn map_points<Q: Point>(&self, mut f: impl FnMut(&P) -> Q) -> Bezier3<Q> {
+        Bezier3::new(f(&self.p0), f(&self.p1), f(&self.p2), f(&self.p3))
+    }
+
+    /// Convert the curve to another point type, e.g. from `f64` to `f32`.
+    pub fn cast<Q: Point>(&self) -> Bezier3<Q>
+    where
+        P: PointCast<Q>,
+    {
+        self.map_points(PointCast::cast)
+    }
+
+    /// Convert the curve to the power basis, so it can be evaluated with Horner's rule.
+    pub fn to_polynomial(&self) -> Polynomial3<P> {
+        let three = two::<P::Scalar>() + P::Scalar::one();
+
+        let a = self.p0.clone();
+        let b = self.p1.sub(&self.p0).scale(three);
+        let c = self
+            .p0
+            .sub(&self.p1.scale(two()))
+            .add(&self.p2)
+            .scale(three);
+        let d = self
+            .p3
+            .sub(&self.p0)
+            .add(&self.p1.sub(&self.p2).scale(three));
+
+        Polynomial3::new(a, b, c, d)
+    }
+
+    /// Split the curve at `t` into two curves covering `[0, t]` and `[t, 1]`.
+    pub fn split_at(&self, t: P::Scalar) -> (Self, Self) {
+        let a = self.p0.lerp(&self.p1, t);
+        let b = self.p1.lerp(&self.p2, t);
+        let c = self.p2.lerp(&self.p3, t);
+        let ab = a.lerp(&b, t);
+        let bc = b.lerp(&c, t);
+        let m = ab.lerp(&bc, t);
+        (
+            Bezier3::new(self.p0.clone(), a, ab, m.clone()),
+            Bezier3::new(m, bc, c, self.p3.clone()),
+        )
+    }
 }
 
+/// Bezier curve of any supported order, segments of [`ComposedCurve`](crate::ComposedCurve) are stored this way.
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Bezier<P: Point> {
     C0(Bezier0<P>),
     C1(Bezier1<P>),
@@ -64,12 +224,102 @@ pub enum Bezier<P: Point> {
     C3(Bezier3<P>),
 }
 
+impl<P: Point> Bezier<P> {
+    /// Convert every control point with `f`.
+    pub fn map_points<Q: Point>(&self, f: impl FnMut(&P) -> Q) -> Bezier<Q> {
+        match self {
+            Bezier::C0(c) => Bezier::C0(c.map_points(f)),
+            Bezier::C1(c) => Bezier::C1(c.map_points(f)),
+            Bezier::C2(c) => Bezier::C2(c.map_points(f)),
+            Bezier::C3(c) => Bezier::C3(c.map_points(f)),
+        }
+    }
+
+    /// Convert the curve to another point type, e.g. from `f64` to `f32`.
+    pub fn cast<Q: Point>(&self) -> Bezier<Q>
+    where
+        P: PointCast<Q>,
+    {
+        self.map_points(PointCast::cast)
+    }
+
+    /// Split the curve at `t` into two curves covering `[0, t]` and `[t, 1]`.
+    pub fn split_at(&self, t: P::Scalar) -> (Self, Self) {
+        match self {
+            Bezier::C0(c) => (Bezier::C0(c.clone()), Bezier::C0(c.clone())),
+            Bezier::C1(c) => {
+                let (a, b) = c.split_at(t);
+                (Bezier::C1(a), Bezier::C1(b))
+            }
+            Bezier::C2(c) => {
+                let (a, b) = c.split_at(t);
+                (Bezier::C2(a), Bezier::C2(b))
+            }
+            Bezier::C3(c) => {
+                let (a, b) = c.split_at(t);
+                (Bezier::C3(a), Bezier::C3(b))
+            }
+        }
+    }
+}
+
 impl<P: Point> Copy for Bezier<P> where P: Copy {}
 impl<P: Point> Copy for Bezier0<P> where P: Copy {}
 impl<P: Point> Copy for Bezier1<P> where P: Copy {}
 impl<P: Point> Copy for Bezier2<P> where P: Copy {}
 impl<P: Point> Copy for Bezier3<P> where P: Copy {}
 
+fn two<F: Scalar>() -> F {
+    F::one() + F::one()
+}
+
+/// Maximum subdivision depth of [`Curve::estimate_length`], it also bounds the work
+/// when the precision can't be reached, e.g. when it's zero.
+const MAX_LENGTH_DEPTH: usize = 16;
+
+/// Length estimate subdividing `curve` until the chord length and the control polygon length
+/// returned by `bounds` are close enough, it uses a fixed size stack instead of recursion.
+///
+/// Curves with non-finite control points have a non-finite length, it's returned without subdividing.
+fn subdivided_length<P: Distance, C>(
+    curve: C,
+    precision: P::Scalar,
+    bounds: impl Fn(&C) -> (P::Scalar, P::Scalar),
+    split: impl Fn(&C) -> (C, C),
+) -> P::Scalar {
+    let zero = P::Scalar::zero();
+    let half = P::Scalar::one() / two::<P::Scalar>();
+
+    // Every level holds at most one pending half, and the deepest level both halves
+    let mut stack: [Option<(C, usize)>; MAX_LENGTH_DEPTH + 1] = core::array::from_fn(|_| None);
+    stack[0] = Some((curve, 0));
+    let mut size = 1;
+    let mut length = zero;
+    while size > 0 {
+        size -= 1;
+        let (curve, depth) = stack[size].take().unwrap();
+        let (min, max) = bounds(&curve);
+        if max == zero {
+            continue;
+        }
+        // Infinity and NaN aren't equal to themselves after subtraction
+        #[allow(clippy::eq_op)]
+        let finite = max - max == zero;
+        if !finite {
+            return (min + max) * half;
+        }
+        if depth == MAX_LENGTH_DEPTH || (max - min) / max < precision {
+            length = length + (min + max) * half;
+        } else {
+            let (first, second) = split(&curve);
+            stack[size] = Some((second, depth + 1));
+            stack[size + 1] = Some((first, depth + 1));
+            size += 2;
+        }
+    }
+    length
+}
+
 macro_rules! for_every_level {
     ($curve:ident, $name:ident, $block:block) => {
         match $curve {
@@ -82,19 +332,19 @@ macro_rules! for_every_level {
 }
 
 impl<P: Point + Debug> Debug for Bezier<P> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Bezier")
             .field(for_every_level!(self, c, { c }))
             .finish()
     }
 }
 impl<P: Point + Debug> Debug for Bezier0<P> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Bezier0").field(&self.point).finish()
     }
 }
 impl<P: Point + Debug> Debug for Bezier1<P> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Bezier1")
             .field(&self.p0)
             .field(&self.p1)
@@ -103,7 +353,7 @@ impl<P: Point + Debug> Debug for Bezier1<P> {
 }
 
 impl<P: Point + Debug> Debug for Bezier2<P> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Bezier2")
             .field(&self.p0)
             .field(&self.p1)
@@ -113,7 +363,7 @@ impl<P: Point + Debug> Debug for Bezier2<P> {
 }
 
 impl<P: Point + Debug> Debug for Bezier3<P> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Bezier3")
             .field(&self.p0)
             .field(&self.p1)
@@ -124,14 +374,28 @@ impl<P: Point + Debug> Debug for Bezier3<P> {
 }
 
 impl<P: Point> Curve<P> for Bezier0<P> {
-    fn value_at(&self, _t: P::Scalar) -> P {
+    fn value_at(&self, t: P::Scalar) -> P {
+        debug_check_parameter(t);
         self.point.clone()
     }
 
-    fn tangent_at(&self, _t: P::Scalar) -> P {
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        debug_check_parameter(t);
         self.point.scale(P::Scalar::zero())
     }
 
+    fn value_at_many(&self, ts: &[P::Scalar], out: &mut [P]) {
+        assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+        debug_check_parameters(ts);
+        out.fill(self.point.clone());
+    }
+
+    fn tangent_at_many(&self, ts: &[P::Scalar], out: &mut [P]) {
+        assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+        debug_check_parameters(ts);
+        out.fill(self.point.scale(P::Scalar::zero()));
+    }
+
     fn start_point(&self) -> P {
         self.point.clone()
     }
@@ -140,23 +404,49 @@ impl<P: Point> Curve<P> for Bezier0<P> {
         self.point.clone()
     }
 
+    #[cfg(feature = "alloc")]
+    fn control_points(&self) -> Vec<P> {
+        vec![self.point.clone()]
+    }
+
     fn estimate_length(&self, _precision: P::Scalar) -> P::Scalar
     where
         P: Distance,
     {
         P::Scalar::zero()
     }
+
+    fn integrate(&self, t0: P::Scalar, t1: P::Scalar, _precision: P::Scalar) -> P
+    where
+        P: Distance,
+    {
+        simpson(self, t0, t1)
+    }
 }
 
 impl<P: Point> Curve<P> for Bezier1<P> {
     fn value_at(&self, t: P::Scalar) -> P {
-        self.p0.add(&self.p1.sub(&self.p0).scale(t))
+        debug_check_parameter(t);
+        self.p0.lerp(&self.p1, t)
     }
 
-    fn tangent_at(&self, _t: P::Scalar) -> P {
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        debug_check_parameter(t);
         self.p1.sub(&self.p0)
     }
 
+    fn value_at_many(&self, ts: &[P::Scalar], out: &mut [P]) {
+        assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+        debug_check_parameters(ts);
+        P::polynomial_many(&[self.p0.clone(), self.p1.sub(&self.p0)], ts, out);
+    }
+
+    fn tangent_at_many(&self, ts: &[P::Scalar], out: &mut [P]) {
+        assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+        debug_check_parameters(ts);
+        out.fill(self.p1.sub(&self.p0));
+    }
+
     fn start_point(&self) -> P {
         self.p0.clone()
     }
@@ -165,16 +455,29 @@ impl<P: Point> Curve<P> for Bezier1<P> {
         self.p1.clone()
     }
 
+    #[cfg(feature = "alloc")]
+    fn control_points(&self) -> Vec<P> {
+        vec![self.p0.clone(), self.p1.clone()]
+    }
+
     fn estimate_length(&self, _precision: P::Scalar) -> P::Scalar
     where
         P: Distance,
     {
         self.p0.distance(&self.p1)
     }
+
+    fn integrate(&self, t0: P::Scalar, t1: P::Scalar, _precision: P::Scalar) -> P
+    where
+        P: Distance,
+    {
+        simpson(self, t0, t1)
+    }
 }
 
 impl<P: Point> Curve<P> for Bezier2<P> {
     fn value_at(&self, t: P::Scalar) -> P {
+        debug_check_parameter(t);
         let t2 = t * t;
         let nt: P::Scalar = P::Scalar::one() - t;
         let nt2 = nt * nt;
@@ -188,6 +491,7 @@ impl<P: Point> Curve<P> for Bezier2<P> {
     }
 
     fn tangent_at(&self, t: P::Scalar) -> P {
+        debug_check_parameter(t);
         let p0 = &self.p0;
         let p1 = &self.p1;
         let p2 = &self.p2;
@@ -203,6 +507,32 @@ impl<P: Point> Curve<P> for Bezier2<P> {
         v1.add(&v2)
     }
 
+    fn value_at_many(&self, ts: &[P::Scalar], out: &mut [P]) {
+        assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+        debug_check_parameters(ts);
+
+        // Power basis: a + b * t + c * t^2
+        let a = &self.p0;
+        let b = self.p1.sub(&self.p0).scale(two());
+        let c = self.p0.sub(&self.p1.scale(two())).add(&self.p2);
+
+        P::polynomial_many(&[a.clone(), b, c], ts, out);
+    }
+
+    fn tangent_at_many(&self, ts: &[P::Scalar], out: &mut [P]) {
+        assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+        debug_check_parameters(ts);
+
+        let b = self.p1.sub(&self.p0).scale(two());
+        let c2 = self
+            .p0
+            .sub(&self.p1.scale(two()))
+            .add(&self.p2)
+            .scale(two());
+
+        P::polynomial_many(&[b, c2], ts, out);
+    }
+
     fn start_point(&self) -> P {
         self.p0.clone()
     }
@@ -211,38 +541,46 @@ impl<P: Point> Curve<P> for Bezier2<P> {
         self.p2.clone()
     }
 
+    #[cfg(feature = "alloc")]
+    fn control_points(&self) -> Vec<P> {
+        vec![self.p0.clone(), self.p1.clone(), self.p2.clone()]
+    }
+
     fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
     where
         P: Distance,
     {
-        let p0 = &self.p0;
-        let p1 = &self.p1;
-        let p2 = &self.p2;
-
-        let min: P::Scalar = p0.distance(p1);
-        let max: P::Scalar = p0.distance(p1) + p1.distance(p2);
-
-        let half: P::Scalar = P::Scalar::one() / (P::Scalar::one() + P::Scalar::one());
-
-        if max == P::Scalar::zero() {
-            P::Scalar::zero()
-        } else if (max - min) / max < precision {
-            (min + max) * half
-        } else {
-            let m01 = p0.add(p1).scale(half);
-            let m12 = p1.add(p2).scale(half);
-            let m = m01.add(&m12).scale(half);
-
-            let b1 = Bezier2::new(p0.clone(), m01, m.clone());
-            let b2 = Bezier2::new(m, m12, p2.clone());
-
-            b1.estimate_length(precision) + b2.estimate_length(precision)
-        }
+        subdivided_length::<P, _>(
+            self.clone(),
+            precision,
+            |c| {
+                let min = c.p0.distance(&c.p2);
+                let max = c.p0.distance(&c.p1) + c.p1.distance(&c.p2);
+                (min, max)
+            },
+            |c| {
+                let m01 = c.p0.midpoint(&c.p1);
+                let m12 = c.p1.midpoint(&c.p2);
+                let m = m01.midpoint(&m12);
+                (
+                    Bezier2::new(c.p0.clone(), m01, m.clone()),
+                    Bezier2::new(m, m12, c.p2.clone()),
+                )
+            },
+        )
+    }
+
+    fn integrate(&self, t0: P::Scalar, t1: P::Scalar, _precision: P::Scalar) -> P
+    where
+        P: Distance,
+    {
+        simpson(self, t0, t1)
     }
 }
 
 impl<P: Point> Curve<P> for Bezier3<P> {
     fn value_at(&self, t: P::Scalar) -> P {
+        debug_check_parameter(t);
         let three = P::Scalar::one() + P::Scalar::one() + P::Scalar::one();
 
         let t2: P::Scalar = t * t;
@@ -259,6 +597,7 @@ impl<P: Point> Curve<P> for Bezier3<P> {
     }
 
     fn tangent_at(&self, t: P::Scalar) -> P {
+        debug_check_parameter(t);
         let p0 = &self.p0;
         let p1 = &self.p1;
         let p2 = &self.p2;
@@ -279,6 +618,16 @@ impl<P: Point> Curve<P> for Bezier3<P> {
         v1.add(&v2).add(&v3)
     }
 
+    fn value_at_many(&self, ts: &[P::Scalar], out: &mut [P]) {
+        debug_check_parameters(ts);
+        self.to_polynomial().value_at_many(ts, out)
+    }
+
+    fn tangent_at_many(&self, ts: &[P::Scalar], out: &mut [P]) {
+        debug_check_parameters(ts);
+        self.to_polynomial().tangent_at_many(ts, out)
+    }
+
     fn start_point(&self) -> P {
         self.p0.clone()
     }
@@ -287,49 +636,70 @@ impl<P: Point> Curve<P> for Bezier3<P> {
         self.p3.clone()
     }
 
+    #[cfg(feature = "alloc")]
+    fn control_points(&self) -> Vec<P> {
+        vec![
+            self.p0.clone(),
+            self.p1.clone(),
+            self.p2.clone(),
+            self.p3.clone(),
+        ]
+    }
+
     fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
     where
         P: Distance,
     {
-        let p0 = &self.p0;
-        let p1 = &self.p1;
-        let p2 = &self.p2;
-        let p3 = &self.p3;
-
-        let min = p0.distance(p3);
-        let max = p0.distance(p1) + p1.distance(p2) + p2.distance(p3);
-
-        let half: P::Scalar = P::Scalar::one() / (P::Scalar::one() + P::Scalar::one());
-
-        if max == P::Scalar::zero() {
-            P::Scalar::zero()
-        } else if (max - min) / max < precision {
-            (min + max) * half
-        } else {
-            let m01 = p0.add(p1).scale(half);
-            let m12 = p1.add(p2).scale(half);
-            let m23 = p2.add(p3).scale(half);
-            let m012 = m01.add(&m12).scale(half);
-            let m123 = m12.add(&m23).scale(half);
-            let m = m012.add(&m123).scale(half);
-
-            let b1 = Bezier3::new(p0.clone(), m01, m012, m.clone());
-            let b2 = Bezier3::new(m, m123, m23, p3.clone());
-
-            b1.estimate_length(precision) + b2.estimate_length(precision)
-        }
+        subdivided_length::<P, _>(
+            self.clone(),
+            precision,
+            |c| {
+                let min = c.p0.distance(&c.p3);
+                let max = c.p0.distance(&c.p1) + c.p1.distance(&c.p2) + c.p2.distance(&c.p3);
+                (min, max)
+            },
+            |c| {
+                let m01 = c.p0.midpoint(&c.p1);
+                let m12 = c.p1.midpoint(&c.p2);
+                let m23 = c.p2.midpoint(&c.p3);
+                let m012 = m01.midpoint(&m12);
+                let m123 = m12.midpoint(&m23);
+                let m = m012.midpoint(&m123);
+                (
+                    Bezier3::new(c.p0.clone(), m01, m012, m.clone()),
+                    Bezier3::new(m, m123, m23, c.p3.clone()),
+                )
+            },
+        )
+    }
+
+    fn integrate(&self, t0: P::Scalar, t1: P::Scalar, _precision: P::Scalar) -> P
+    where
+        P: Distance,
+    {
+        simpson(self, t0, t1)
     }
 }
 
 impl<P: Point> Curve<P> for Bezier<P> {
     fn value_at(&self, t: P::Scalar) -> P {
+        debug_check_parameter(t);
         for_every_level!(self, c, { c.value_at(t) })
     }
 
     fn tangent_at(&self, t: P::Scalar) -> P {
+        debug_check_parameter(t);
         for_every_level!(self, c, { c.tangent_at(t) })
     }
 
+    fn value_at_many(&self, ts: &[P::Scalar], out: &mut [P]) {
+        for_every_level!(self, c, { c.value_at_many(ts, out) })
+    }
+
+    fn tangent_at_many(&self, ts: &[P::Scalar], out: &mut [P]) {
+        for_every_level!(self, c, { c.tangent_at_many(ts, out) })
+    }
+
     fn start_point(&self) -> P {
         for_every_level!(self, c, { c.start_point() })
     }
@@ -338,17 +708,30 @@ impl<P: Point> Curve<P> for Bezier<P> {
         for_every_level!(self, c, { c.end_point() })
     }
 
+    #[cfg(feature = "alloc")]
+    fn control_points(&self) -> Vec<P> {
+        for_every_level!(self, c, { c.control_points() })
+    }
+
     fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
     where
         P: Distance,
     {
         for_every_level!(self, c, { c.estimate_length(precision) })
     }
+
+    fn integrate(&self, t0: P::Scalar, t1: P::Scalar, precision: P::Scalar) -> P
+    where
+        P: Distance,
+    {
+        for_every_level!(self, c, { c.integrate(t0, t1, precision) })
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use approx::assert_relative_eq;
 
     #[test]
     fn bezier_0() {
@@ -382,6 +765,81 @@ mod test {
         assert_eq!(curve.value_at(1.0), 4.0);
     }
 
+    #[test]
+    fn value_at_many() {
+        let ts = [0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0];
+
+        let curves: [Bezier<f64>; 4] = [
+            Bezier::C0(Bezier0::new(2.0)),
+            Bezier::C1(Bezier1::new(1.0, 3.0)),
+            Bezier::C2(Bezier2::new(1.0, 3.0, 2.0)),
+            Bezier::C3(Bezier3::new(1.0, 4.0, 2.0, 4.0)),
+        ];
+
+        for curve in curves.iter() {
+            let mut values = [0.0; 7];
+            let mut tangents = [0.0; 7];
+            curve.value_at_many(&ts, &mut values);
+            curve.tangent_at_many(&ts, &mut tangents);
+
+            for (i, t) in ts.iter().enumerate() {
+                assert_relative_eq!(values[i], curve.value_at(*t), epsilon = 1e-12);
+                assert_relative_eq!(tangents[i], curve.tangent_at(*t), epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn estimate_length_edge_cases() {
+        let curve = Bezier3::new([0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]);
+        let length = curve.estimate_length(1e-6);
+        assert_relative_eq!(length, 2.0, epsilon = 1e-4);
+        // Unreachable precision stops at the depth cap
+        assert_relative_eq!(curve.estimate_length(0.0), length, epsilon = 1e-6);
+        assert_relative_eq!(curve.estimate_length(-1.0), length, epsilon = 1e-6);
+
+        let quadratic = Bezier2::new([0.0, 0.0], [1.0, 1.0], [2.0, 0.0]);
+        assert_relative_eq!(
+            quadratic.estimate_length(0.0),
+            quadratic.estimate_length(1e-6),
+            epsilon = 1e-6
+        );
+
+        let nan = Bezier3::new([0.0, 0.0], [f64::NAN, 1.0], [1.0, 1.0], [1.0, 0.0]);
+        assert!(nan.estimate_length(1e-3).is_nan());
+        let infinite = Bezier2::new([0.0, 0.0], [f64::INFINITY, 0.0], [1.0, 0.0]);
+        assert!(infinite.estimate_length(1e-3).is_infinite());
+        assert_eq!(Bezier3::new(1.0, 1.0, 1.0, 1.0).estimate_length(0.0), 0.0);
+    }
+
+    #[test]
+    fn quadratic_length() {
+        // The chord is the lower bound, not the distance to the control point
+        let straight = Bezier2::new([0.0, 0.0], [1.0, 0.0], [10.0, 0.0]);
+        assert_relative_eq!(straight.estimate_length(1e-3), 10.0, epsilon = 1e-9);
+
+        let arch = Bezier2::new([0.0, 0.0], [0.0, 100.0], [1.0, 0.0]);
+        let polyline: f64 = (0..10000)
+            .map(|i| {
+                let (t0, t1) = (i as f64 / 10000.0, (i + 1) as f64 / 10000.0);
+                arch.value_at(t0).distance(&arch.value_at(t1))
+            })
+            .sum();
+        assert_relative_eq!(arch.estimate_length(1e-6), polyline, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn cast() {
+        let curve = Bezier3::new(1.0f64, 4.0, 2.0, 4.0);
+        assert_eq!(curve.cast::<f32>(), Bezier3::new(1.0f32, 4.0, 2.0, 4.0));
+
+        let curve = Bezier::C2(Bezier2::new(1.0f64, 3.0, 2.0));
+        assert_eq!(
+            curve.map_points(|p| p * 2.0),
+            Bezier::C2(Bezier2::new(2.0, 6.0, 4.0))
+        );
+    }
+
     #[derive(Clone, PartialEq, Debug)]
     struct Point2D {
         x: f64,
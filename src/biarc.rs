@@ -0,0 +1,228 @@
+use crate::arc::{Arc, ArcSegment};
+use crate::bezier::Bezier;
+use crate::{Bezier1, Bezier2, Bezier3, ComposedCurve, Curve, PlanarPoint, Scalar};
+use alloc::vec::Vec;
+use num_traits::{NumCast, One, Zero};
+
+/// Subdivision depth limit, every level halves the parameter range.
+const MAX_DEPTH: usize = 12;
+/// Number of curve points checked against the biarc on every level.
+const SAMPLES_COUNT: usize = 8;
+
+/// Approximate a curve with circular arcs, the distance between the curve and arcs
+/// is expected to be less than `tolerance`. Every piece of the curve is replaced
+/// by a biarc, a pair of arcs with matching tangents, so the result is tangent continuous.
+pub(crate) fn to_biarcs<P: PlanarPoint, C: Curve<P> + ?Sized>(
+    curve: &C,
+    tolerance: P::Scalar,
+) -> Vec<ArcSegment<P>> {
+    let mut result = Vec::new();
+    subdivide(
+        curve,
+        tolerance,
+        P::Scalar::zero(),
+        P::Scalar::one(),
+        0,
+        &mut result,
+    );
+    result
+}
+
+fn subdivide<P: PlanarPoint, C: Curve<P> + ?Sized>(
+    curve: &C,
+    tolerance: P::Scalar,
+    t0: P::Scalar,
+    t1: P::Scalar,
+    depth: usize,
+    result: &mut Vec<ArcSegment<P>>,
+) {
+    let p0 = curve.value_at(t0);
+    let p1 = curve.value_at(t1);
+
+    if p0 == p1 && depth > 0 {
+        return;
+    }
+
+    let biarc = biarc(
+        &p0,
+        &tangent(curve, t0, t1, t0),
+        &p1,
+        &tangent(curve, t0, t1, t1),
+    );
+
+    let steps: P::Scalar = NumCast::from(SAMPLES_COUNT + 1).unwrap();
+    let fits = (1..=SAMPLES_COUNT).all(|i| {
+        let i: P::Scalar = NumCast::from(i).unwrap();
+        let p = curve.value_at(t0 + (t1 - t0) * i / steps);
+        biarc
+            .iter()
+            .map(|segment| segment.distance_to(&p))
+            .fold(None, |acc: Option<P::Scalar>, d| {
+                Some(acc.map_or(d, |a| a.min(d)))
+            })
+            .is_some_and(|d| d <= tolerance)
+    });
+
+    if fits || depth >= MAX_DEPTH {
+        result.extend(biarc);
+    } else {
+        let tm = (t0 + t1) / (P::Scalar::one() + P::Scalar::one());
+        subdivide(curve, tolerance, t0, tm, depth + 1, result);
+        subdivide(curve, tolerance, tm, t1, depth + 1, result);
+    }
+}
+
+/// Unit tangent at `t`, if the derivative vanishes (e.g. at a cusp) it's taken a bit inside the range.
+fn tangent<P: PlanarPoint, C: Curve<P> + ?Sized>(
+    curve: &C,
+    t0: P::Scalar,
+    t1: P::Scalar,
+    t: P::Scalar,
+) -> P {
+    let mut tangent = curve.tangent_at(t);
+    if tangent.length_squared() == P::Scalar::zero() {
+        let shift: P::Scalar = NumCast::from(1e-3).unwrap();
+        let inside = if t == t0 {
+            t0 + (t1 - t0) * shift
+        } else {
+            t1 - (t1 - t0) * shift
+        };
+        tangent = curve.tangent_at(inside);
+    }
+    if tangent.length_squared() == P::Scalar::zero() {
+        tangent = curve.value_at(t1).sub(&curve.value_at(t0));
+    }
+
+    let length = tangent.length();
+    if length == P::Scalar::zero() {
+        tangent
+    } else {
+        tangent.scale(P::Scalar::one() / length)
+    }
+}
+
+/// A pair of arcs from `p0` to `p1` with the given unit tangents at the ends,
+/// the joint is chosen so both arcs have equal tangent lengths.
+fn biarc<P: PlanarPoint>(p0: &P, t0: &P, p1: &P, t1: &P) -> Vec<ArcSegment<P>> {
+    let one = P::Scalar::one();
+    let two = one + one;
+
+    let v = p1.sub(p0);
+    let t = t0.add(t1);
+    let vt = v.dot(&t);
+    let denominator = two * (one - t0.dot(t1));
+
+    let epsilon: P::Scalar = NumCast::from(1e-12).unwrap();
+    let d = if denominator.abs() <= epsilon {
+        let vt1 = v.dot(t1);
+        if vt1 == P::Scalar::zero() {
+            // Tangents are parallel and perpendicular to the chord, two half circles
+            let joint = p0.midpoint(p1);
+            return Vec::from([
+                segment(p0, t0, &joint),
+                segment(&joint, &t0.scale(-one), p1),
+            ]);
+        }
+        v.length_squared() / (two * two * vt1)
+    } else {
+        (-vt + (vt * vt + denominator * v.length_squared()).sqrt()) / denominator
+    };
+
+    let q0 = p0.add(&t0.scale(d));
+    let q1 = p1.sub(&t1.scale(d));
+    let joint = q0.midpoint(&q1);
+
+    Vec::from([segment(p0, t0, &joint), segment(&joint, &q1.sub(&q0), p1)])
+}
+
+fn segment<P: PlanarPoint>(start: &P, tangent: &P, end: &P) -> ArcSegment<P> {
+    match Arc::from_tangent(start.clone(), tangent, end.clone()) {
+        Some(arc) => ArcSegment::Arc(arc),
+        None => ArcSegment::Line(Bezier1::new(start.clone(), end.clone())),
+    }
+}
+
+impl<P: PlanarPoint> Bezier2<P> {
+    /// Approximate the curve with circular arcs and lines within `tolerance`.
+    pub fn to_biarcs(&self, tolerance: P::Scalar) -> Vec<ArcSegment<P>> {
+        to_biarcs(self, tolerance)
+    }
+}
+
+impl<P: PlanarPoint> Bezier3<P> {
+    /// Approximate the curve with circular arcs and lines within `tolerance`.
+    pub fn to_biarcs(&self, tolerance: P::Scalar) -> Vec<ArcSegment<P>> {
+        to_biarcs(self, tolerance)
+    }
+}
+
+impl<P: PlanarPoint> Bezier<P> {
+    /// Approximate the curve with circular arcs and lines within `tolerance`.
+    /// Lines are kept as they are.
+    pub fn to_biarcs(&self, tolerance: P::Scalar) -> Vec<ArcSegment<P>> {
+        match self {
+            Bezier::C0(_) => Vec::new(),
+            Bezier::C1(line) => Vec::from([ArcSegment::Line(line.clone())]),
+            _ => to_biarcs(self, tolerance),
+        }
+    }
+}
+
+impl<P: PlanarPoint> ComposedCurve<P> {
+    /// Approximate every segment with circular arcs and lines within `tolerance`.
+    pub fn to_biarcs(&self, tolerance: P::Scalar) -> Vec<ArcSegment<P>> {
+        self.segments()
+            .iter()
+            .flat_map(|segment| segment.to_biarcs(tolerance))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check<C: Curve<[f64; 2]>>(curve: &C, arcs: &[ArcSegment<[f64; 2]>], tolerance: f64) {
+        for i in 0..=100 {
+            let p = curve.value_at(i as f64 / 100.0);
+            let distance = arcs
+                .iter()
+                .map(|arc| arc.distance_to(&p))
+                .fold(f64::INFINITY, f64::min);
+            assert!(distance <= tolerance, "{} > {}", distance, tolerance);
+        }
+        for pair in arcs.windows(2) {
+            let (a, b) = (pair[0].end_point(), pair[1].start_point());
+            assert!((a[0] - b[0]).abs() < 1e-9 && (a[1] - b[1]).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn cubic_biarcs() {
+        let curve = Bezier3::new([0.0, 0.0], [1.0, 2.0], [3.0, -1.0], [4.0, 1.0]);
+        let arcs = curve.to_biarcs(0.01);
+        assert!(arcs.len() >= 2);
+        check(&curve, &arcs, 0.011);
+    }
+
+    #[test]
+    fn circle_is_exact() {
+        // A quarter of a unit circle approximated by a cubic is a single biarc
+        let k = 0.5522847498;
+        let curve = Bezier3::new([1.0, 0.0], [1.0, k], [k, 1.0], [0.0, 1.0]);
+        let arcs = curve.to_biarcs(0.001);
+        assert_eq!(arcs.len(), 2);
+        check(&curve, &arcs, 0.001);
+    }
+
+    #[test]
+    fn composed_biarcs() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([1.0, 0.0]);
+        curve.quadratic_to([2.0, 0.0], [2.0, 1.0]);
+
+        let arcs = curve.to_biarcs(0.001);
+        assert!(matches!(arcs[0], ArcSegment::Line(_)));
+        check(&curve, &arcs, 0.0011);
+    }
+}
@@ -0,0 +1,364 @@
+use crate::bezier::Bezier;
+use crate::linear_speed::LinearSpeed;
+use crate::lookup_table::LookupTable;
+use crate::{ComposedCurve, Curve, Distance, PlanarPoint, Point, Scalar};
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use core::mem::size_of;
+use num_traits::NumCast;
+
+/// Magic bytes of an encoded [`ComposedCurve`].
+const CURVE_MAGIC: [u8; 4] = *b"BZRC";
+/// Magic bytes of an encoded [`LinearSpeed`] table.
+const TABLE_MAGIC: [u8; 4] = *b"BZRT";
+/// The latest format version, decoders accept this and all the previous versions.
+const VERSION: u8 = 1;
+
+const LINE: u8 = 1;
+const QUADRATIC: u8 = 2;
+const CUBIC: u8 = 3;
+
+/// Error returned when decoding binary data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecodeError {
+    /// The data doesn't start with the expected magic bytes.
+    InvalidMagic,
+    /// The data is written by a newer version of the library.
+    UnsupportedVersion(u8),
+    /// Scalars are neither 4 nor 8 bytes wide.
+    UnsupportedScalar(u8),
+    /// Unknown segment type.
+    InvalidSegment(u8),
+    /// The data is truncated.
+    UnexpectedEnd,
+    /// The lookup table has fewer than two values.
+    InvalidTableSize(usize),
+    /// There is unused data after the end of the encoded value.
+    TrailingBytes,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidMagic => write!(f, "invalid magic bytes"),
+            DecodeError::UnsupportedVersion(v) => write!(f, "unsupported format version {}", v),
+            DecodeError::UnsupportedScalar(s) => write!(f, "unsupported scalar size {}", s),
+            DecodeError::InvalidSegment(s) => write!(f, "invalid segment type {}", s),
+            DecodeError::UnexpectedEnd => write!(f, "unexpected end of data"),
+            DecodeError::InvalidTableSize(s) => write!(f, "invalid lookup table size {}", s),
+            DecodeError::TrailingBytes => write!(f, "trailing bytes after the encoded data"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+/// Little-endian writer. Scalars are stored as `f32` if the scalar type is 4 bytes wide, and as `f64` otherwise.
+struct Encoder<F: Scalar> {
+    data: Vec<u8>,
+    wide: bool,
+    phantom_data: core::marker::PhantomData<F>,
+}
+
+impl<F: Scalar> Encoder<F> {
+    fn new(magic: [u8; 4]) -> Self {
+        let wide = size_of::<F>() != 4;
+        let mut data = Vec::new();
+        data.extend_from_slice(&magic);
+        data.push(VERSION);
+        data.push(if wide { 8 } else { 4 });
+        Self {
+            data,
+            wide,
+            phantom_data: Default::default(),
+        }
+    }
+
+    fn u8(&mut self, v: u8) {
+        self.data.push(v);
+    }
+
+    fn u32(&mut self, v: usize) {
+        let v = u32::try_from(v).expect("too many items to encode");
+        self.data.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn scalar(&mut self, v: F) {
+        if self.wide {
+            self.data
+                .extend_from_slice(&v.to_f64().unwrap().to_le_bytes());
+        } else {
+            self.data
+                .extend_from_slice(&v.to_f32().unwrap().to_le_bytes());
+        }
+    }
+
+    fn point<P: PlanarPoint<Scalar = F>>(&mut self, p: &P) {
+        self.scalar(p.x());
+        self.scalar(p.y());
+    }
+}
+
+struct Decoder<'a, F: Scalar> {
+    data: &'a [u8],
+    wide: bool,
+    phantom_data: core::marker::PhantomData<F>,
+}
+
+impl<'a, F: Scalar> Decoder<'a, F> {
+    fn new(data: &'a [u8], magic: [u8; 4]) -> Result<Self, DecodeError> {
+        let mut decoder = Self {
+            data,
+            wide: true,
+            phantom_data: Default::default(),
+        };
+
+        if decoder.bytes::<4>().ok() != Some(magic) {
+            return Err(DecodeError::InvalidMagic);
+        }
+
+        let version = decoder.u8()?;
+        if version == 0 || version > VERSION {
+            return Err(DecodeError::UnsupportedVersion(version));
+        }
+
+        decoder.wide = match decoder.u8()? {
+            4 => false,
+            8 => true,
+            size => return Err(DecodeError::UnsupportedScalar(size)),
+        };
+
+        Ok(decoder)
+    }
+
+    fn bytes<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        if self.data.len() < N {
+            return Err(DecodeError::UnexpectedEnd);
+        }
+        let (head, tail) = self.data.split_at(N);
+        self.data = tail;
+        Ok(head.try_into().unwrap())
+    }
+
+    fn u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.bytes::<1>()?[0])
+    }
+
+    fn u32(&mut self) -> Result<usize, DecodeError> {
+        Ok(u32::from_le_bytes(self.bytes()?) as usize)
+    }
+
+    fn scalar(&mut self) -> Result<F, DecodeError> {
+        let v = if self.wide {
+            f64::from_le_bytes(self.bytes()?)
+        } else {
+            f32::from_le_bytes(self.bytes()?) as f64
+        };
+        Ok(NumCast::from(v).unwrap())
+    }
+
+    fn point<P: PlanarPoint<Scalar = F>>(&mut self) -> Result<P, DecodeError> {
+        let x = self.scalar()?;
+        let y = self.scalar()?;
+        Ok(P::from_xy(x, y))
+    }
+
+    fn finish(&self) -> Result<(), DecodeError> {
+        if self.data.is_empty() {
+            Ok(())
+        } else {
+            Err(DecodeError::TrailingBytes)
+        }
+    }
+}
+
+impl<P: PlanarPoint> ComposedCurve<P> {
+    /// Encode the curve into a compact little-endian binary format.
+    ///
+    /// Layout: magic `BZRC`, format version, scalar size (4 or 8),
+    /// the start point, `u32` segments count, and segments,
+    /// every segment is its order (1, 2, or 3) followed by its control points except the first one.
+    /// Dot segments have no length and aren't encoded.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut encoder = Encoder::new(CURVE_MAGIC);
+        encoder.point(&self.start_point());
+        let dots = self
+            .segments()
+            .iter()
+            .filter(|segment| matches!(segment, Bezier::C0(_)))
+            .count();
+        encoder.u32(self.segments().len() - dots);
+        for segment in self.segments() {
+            match segment {
+                Bezier::C0(_) => {}
+                Bezier::C1(c) => {
+                    encoder.u8(LINE);
+                    encoder.point(&c.p1);
+                }
+                Bezier::C2(c) => {
+                    encoder.u8(QUADRATIC);
+                    encoder.point(&c.p1);
+                    encoder.point(&c.p2);
+                }
+                Bezier::C3(c) => {
+                    encoder.u8(CUBIC);
+                    encoder.point(&c.p1);
+                    encoder.point(&c.p2);
+                    encoder.point(&c.p3);
+                }
+            }
+        }
+        encoder.data
+    }
+
+    /// Decode a curve encoded with [`ComposedCurve::encode`].
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut decoder = Decoder::new(data, CURVE_MAGIC)?;
+        let mut curve = ComposedCurve::new(decoder.point()?);
+
+        for _ in 0..decoder.u32()? {
+            match decoder.u8()? {
+                LINE => curve.line_to(decoder.point()?),
+                QUADRATIC => curve.quadratic_to(decoder.point()?, decoder.point()?),
+                CUBIC => curve.cubic_to(decoder.point()?, decoder.point()?, decoder.point()?),
+                segment => return Err(DecodeError::InvalidSegment(segment)),
+            }
+        }
+
+        decoder.finish()?;
+        Ok(curve)
+    }
+}
+
+impl<P: Point + Distance, C: Curve<P>> LinearSpeed<P, C> {
+    /// Encode the prebuilt table, so it can be restored without sampling the curve again.
+    /// The curve itself is not included.
+    ///
+    /// Layout: magic `BZRT`, format version, scalar size (4 or 8),
+    /// the curve length, `u32` table size, and table values.
+    pub fn encode_table(&self) -> Vec<u8> {
+        let mut encoder = Encoder::new(TABLE_MAGIC);
+        encoder.scalar(self.length);
+        encoder.u32(self.table.values().len());
+        self.table.values().iter().for_each(|v| encoder.scalar(*v));
+        encoder.data
+    }
+
+    /// Restore the wrapper from the curve and a table encoded with [`LinearSpeed::encode_table`].
+    pub fn decode_table(curve: C, data: &[u8]) -> Result<Self, DecodeError> {
+        let mut decoder = Decoder::new(data, TABLE_MAGIC)?;
+        let length = decoder.scalar()?;
+        let size = decoder.u32()?;
+        if size < 2 {
+            return Err(DecodeError::InvalidTableSize(size));
+        }
+        if size > decoder.data.len() {
+            return Err(DecodeError::UnexpectedEnd);
+        }
+        let table = (0..size)
+            .map(|_| decoder.scalar())
+            .collect::<Result<Vec<_>, _>>()?;
+        decoder.finish()?;
+
+        Ok(Self::from_table(
+            curve,
+            length,
+            LookupTable::from_values(table),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bezier0, Bezier1};
+    use alloc::vec;
+
+    fn curve() -> ComposedCurve<[f64; 2]> {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([1.0, 0.0]);
+        curve.quadratic_to([2.0, 0.0], [2.0, 1.0]);
+        curve.cubic_to([2.0, 2.0], [1.0, 3.0], [0.5, 2.5]);
+        curve
+    }
+
+    #[test]
+    fn curve_round_trip() {
+        let curve = curve();
+        let data = curve.encode();
+        assert_eq!(&data[..6], b"BZRC\x01\x08");
+        assert_eq!(data.len(), 6 + 16 + 4 + (1 + 16) + (1 + 32) + (1 + 48));
+        assert_eq!(ComposedCurve::decode(&data), Ok(curve));
+
+        let narrow = ComposedCurve::<[f64; 2]>::new([1.5, 2.5]).cast::<[f32; 2]>();
+        assert_eq!(narrow.encode().len(), 6 + 8 + 4);
+        assert_eq!(ComposedCurve::decode(&narrow.encode()), Ok(narrow));
+    }
+
+    #[test]
+    fn dot_segments_are_skipped() {
+        let curve = ComposedCurve::try_from_segments(vec![
+            Bezier::C1(Bezier1::new([0.0, 0.0], [1.0, 0.0])),
+            Bezier::C0(Bezier0::new([1.0, 0.0])),
+            Bezier::C1(Bezier1::new([1.0, 0.0], [1.0, 1.0])),
+        ])
+        .unwrap();
+        let decoded = ComposedCurve::<[f64; 2]>::decode(&curve.encode()).unwrap();
+        assert_eq!(decoded.segments().len(), 2);
+        assert_eq!(decoded.end_point(), [1.0, 1.0]);
+    }
+
+    #[test]
+    fn table_round_trip() {
+        let linear = curve().linear_speed(32, 100);
+        let restored = LinearSpeed::decode_table(curve(), &linear.encode_table()).unwrap();
+
+        assert_eq!(restored.estimate_length(1.0), linear.estimate_length(1.0));
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            assert_eq!(restored.tangent_at(t), linear.tangent_at(t));
+        }
+    }
+
+    #[test]
+    fn decode_errors() {
+        let data = curve().encode();
+        type Curve = ComposedCurve<[f64; 2]>;
+
+        assert_eq!(
+            Curve::decode(b"BZRT\x01\x08"),
+            Err(DecodeError::InvalidMagic)
+        );
+        assert_eq!(
+            Curve::decode(b"BZRC\x02\x08"),
+            Err(DecodeError::UnsupportedVersion(2))
+        );
+        assert_eq!(
+            Curve::decode(b"BZRC\x01\x02"),
+            Err(DecodeError::UnsupportedScalar(2))
+        );
+        assert_eq!(
+            Curve::decode(&data[..data.len() - 1]),
+            Err(DecodeError::UnexpectedEnd)
+        );
+
+        let mut trailing = data.clone();
+        trailing.push(0);
+        assert_eq!(Curve::decode(&trailing), Err(DecodeError::TrailingBytes));
+
+        let mut invalid = data;
+        invalid[26] = 7;
+        assert_eq!(Curve::decode(&invalid), Err(DecodeError::InvalidSegment(7)));
+
+        let mut single = curve().linear_speed(32, 100).encode_table();
+        single.truncate(6 + 8);
+        single.extend_from_slice(&1u32.to_le_bytes());
+        single.extend_from_slice(&0.5f64.to_le_bytes());
+        assert_eq!(
+            LinearSpeed::decode_table(curve(), &single).err(),
+            Some(DecodeError::InvalidTableSize(1))
+        );
+    }
+}
@@ -0,0 +1,251 @@
+use crate::hull::convex_hull;
+use crate::{ComposedCurve, Curve, Path, PlanarPoint, Scalar};
+use alloc::vec::Vec;
+use num_traits::{One, Zero};
+
+/// Circle given by its center and radius, see [`ComposedCurve::bounding_circle`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Circle<P: PlanarPoint> {
+    pub center: P,
+    pub radius: P::Scalar,
+}
+
+impl<P: PlanarPoint> Circle<P> {
+    pub fn new(center: P, radius: P::Scalar) -> Self {
+        Self { center, radius }
+    }
+
+    /// Check if the point is inside the circle or on its border.
+    pub fn contains(&self, point: &P) -> bool {
+        point.sub(&self.center).length() <= self.radius
+    }
+
+    /// Circle with the segment between `a` and `b` as its diameter.
+    fn from_diameter(a: &P, b: &P) -> Self {
+        let center = a.midpoint(b);
+        let radius = center.sub(a).length();
+        Self { center, radius }
+    }
+
+    /// Circle going through three points, the diameter of the farthest two for collinear ones.
+    fn from_triangle(a: &P, b: &P, c: &P) -> Self {
+        let (ab, ac) = (b.sub(a), c.sub(a));
+        let denominator = ab.cross(&ac);
+        if denominator == P::Scalar::zero() {
+            let candidates = [
+                Self::from_diameter(a, b),
+                Self::from_diameter(a, c),
+                Self::from_diameter(b, c),
+            ];
+            return candidates
+                .into_iter()
+                .reduce(|x, y| if y.radius > x.radius { y } else { x })
+                .unwrap();
+        }
+        let two = P::Scalar::one() + P::Scalar::one();
+        let (ab2, ac2) = (ab.length_squared(), ac.length_squared());
+        let offset = P::from_xy(
+            (ac.y() * ab2 - ab.y() * ac2) / (two * denominator),
+            (ab.x() * ac2 - ac.x() * ab2) / (two * denominator),
+        );
+        Self {
+            center: a.add(&offset),
+            radius: offset.length(),
+        }
+    }
+}
+
+/// Rectangle rotated by an angle, see [`ComposedCurve::oriented_bbox`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OrientedRect<P: PlanarPoint> {
+    pub center: P,
+    /// Unit vector along the width of the rectangle, the height goes along its perpendicular.
+    pub axis: P,
+    pub half_width: P::Scalar,
+    pub half_height: P::Scalar,
+}
+
+impl<P: PlanarPoint> OrientedRect<P> {
+    pub fn area(&self) -> P::Scalar {
+        let two = P::Scalar::one() + P::Scalar::one();
+        self.half_width * self.half_height * two * two
+    }
+
+    /// Corners in counter-clockwise order.
+    pub fn corners(&self) -> [P; 4] {
+        let u = self.axis.scale(self.half_width);
+        let v = self.axis.perpendicular().scale(self.half_height);
+        [
+            self.center.sub(&u).sub(&v),
+            self.center.add(&u).sub(&v),
+            self.center.add(&u).add(&v),
+            self.center.sub(&u).add(&v),
+        ]
+    }
+
+    /// Check if the point is inside the rectangle or on its border.
+    pub fn contains(&self, point: &P) -> bool {
+        let d = point.sub(&self.center);
+        d.dot(&self.axis).abs() <= self.half_width
+            && d.dot(&self.axis.perpendicular()).abs() <= self.half_height
+    }
+}
+
+impl<P: PlanarPoint> ComposedCurve<P> {
+    /// The smallest circle containing the curve flattened with `tolerance`.
+    pub fn bounding_circle(&self, tolerance: P::Scalar) -> Circle<P> {
+        bounding_circle(&convex_hull(&self.flatten(tolerance)))
+    }
+
+    /// The smallest area rectangle of any rotation containing the curve flattened with `tolerance`.
+    pub fn oriented_bbox(&self, tolerance: P::Scalar) -> OrientedRect<P> {
+        oriented_bbox(&convex_hull(&self.flatten(tolerance)))
+    }
+}
+
+impl<P: PlanarPoint> Path<P> {
+    /// The smallest circle containing all the contours, `None` for a path without contours.
+    pub fn bounding_circle(&self, tolerance: P::Scalar) -> Option<Circle<P>> {
+        let hull = self.flattened_hull(tolerance);
+        (!hull.is_empty()).then(|| bounding_circle(&hull))
+    }
+
+    /// The smallest area rectangle containing all the contours, `None` for a path without contours.
+    pub fn oriented_bbox(&self, tolerance: P::Scalar) -> Option<OrientedRect<P>> {
+        let hull = self.flattened_hull(tolerance);
+        (!hull.is_empty()).then(|| oriented_bbox(&hull))
+    }
+}
+
+/// Welzl's algorithm in its iterative form, the points are shuffled with a fixed seed
+/// because points along a hull are the worst order for it.
+fn bounding_circle<P: PlanarPoint>(points: &[P]) -> Circle<P> {
+    let mut points: Vec<P> = points.to_vec();
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    for i in (1..points.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        points.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+
+    let mut circle = Circle::new(points[0].clone(), P::Scalar::zero());
+    for i in 1..points.len() {
+        if circle.contains(&points[i]) {
+            continue;
+        }
+        circle = Circle::new(points[i].clone(), P::Scalar::zero());
+        for j in 0..i {
+            if circle.contains(&points[j]) {
+                continue;
+            }
+            circle = Circle::from_diameter(&points[i], &points[j]);
+            for k in 0..j {
+                if !circle.contains(&points[k]) {
+                    circle = Circle::from_triangle(&points[i], &points[j], &points[k]);
+                }
+            }
+        }
+    }
+    circle
+}
+
+/// One side of the smallest rectangle lies on an edge of the convex `hull`,
+/// so all the edge directions are tried.
+fn oriented_bbox<P: PlanarPoint>(hull: &[P]) -> OrientedRect<P> {
+    let (zero, one) = (P::Scalar::zero(), P::Scalar::one());
+    let half = one / (one + one);
+    let mut best: Option<OrientedRect<P>> = None;
+    for i in 0..hull.len() {
+        let edge = hull[(i + 1) % hull.len()].sub(&hull[i]);
+        let length = edge.length();
+        let axis = if length > zero {
+            edge.scale(one / length)
+        } else {
+            P::from_xy(one, zero)
+        };
+        let normal = axis.perpendicular();
+        let (mut u_min, mut u_max) = (hull[i].dot(&axis), hull[i].dot(&axis));
+        let (mut v_min, mut v_max) = (hull[i].dot(&normal), hull[i].dot(&normal));
+        for p in hull {
+            let (u, v) = (p.dot(&axis), p.dot(&normal));
+            (u_min, u_max) = (u_min.min(u), u_max.max(u));
+            (v_min, v_max) = (v_min.min(v), v_max.max(v));
+        }
+        let rect = OrientedRect {
+            center: axis
+                .scale((u_min + u_max) * half)
+                .add(&normal.scale((v_min + v_max) * half)),
+            axis,
+            half_width: (u_max - u_min) * half,
+            half_height: (v_max - v_min) * half,
+        };
+        if best.as_ref().is_none_or(|best| rect.area() < best.area()) {
+            best = Some(rect);
+        }
+    }
+    best.unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InnerProduct, Point};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn circle() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([4.0, 0.0]);
+        curve.line_to([2.0, 1.0]);
+        curve.close();
+        let circle = curve.bounding_circle(1e-3);
+        assert_relative_eq!(circle.center[0], 2.0);
+        assert_relative_eq!(circle.center[1], 0.0);
+        assert_relative_eq!(circle.radius, 2.0);
+
+        let mut triangle = ComposedCurve::new([0.0, 0.0]);
+        triangle.line_to([2.0, 0.0]);
+        triangle.line_to([1.0, 3.0f64.sqrt()]);
+        triangle.close();
+        let circle = triangle.bounding_circle(1e-3);
+        assert_relative_eq!(circle.center[1], 1.0 / 3.0f64.sqrt(), epsilon = 1e-9);
+        assert_relative_eq!(circle.radius, 2.0 / 3.0f64.sqrt(), epsilon = 1e-9);
+        assert!(triangle
+            .flatten(1e-3)
+            .iter()
+            .all(|p| { circle.radius - p.sub(&circle.center).length() > -1e-9 }));
+
+        assert!(Path::<[f64; 2]>::default().bounding_circle(1e-3).is_none());
+    }
+
+    #[test]
+    fn oriented_box() {
+        // A thin diagonal stroke, much tighter than its axis aligned bounds
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.quadratic_to([5.5, 4.5], [10.0, 10.0]);
+        let rect = curve.oriented_bbox(1e-4);
+        assert!(rect.area() < 10.0);
+        assert_relative_eq!(
+            rect.half_width.max(rect.half_height),
+            200.0f64.sqrt() / 2.0,
+            epsilon = 1e-3
+        );
+        for p in curve.flatten(1e-4) {
+            let d = p.sub(&rect.center);
+            assert!(d.dot(&rect.axis).abs() <= rect.half_width + 1e-9);
+        }
+        assert_eq!(rect.corners().len(), 4);
+
+        let mut square = ComposedCurve::new([1.0, 1.0]);
+        square.line_to([3.0, 1.0]);
+        square.line_to([3.0, 2.0]);
+        square.line_to([1.0, 2.0]);
+        square.close();
+        let rect = square.oriented_bbox(1e-3);
+        assert_relative_eq!(rect.area(), 2.0);
+        assert_relative_eq!(rect.center[0], 2.0);
+        assert!(rect.contains(&[2.9, 1.9]));
+        assert!(!rect.contains(&[3.1, 1.9]));
+    }
+}
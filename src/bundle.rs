@@ -0,0 +1,192 @@
+use crate::{Curve, Point, Scalar};
+use core::marker::PhantomData;
+
+/// Tuple of curves with different point types sharing the parameter scalar,
+/// `Points` is the tuple of their point types.
+pub trait CurveTuple<S: Scalar, Points> {
+    /// Tuple of points, one per curve.
+    type Values;
+
+    fn values_at(&self, t: S) -> Self::Values;
+    fn tangents_at(&self, t: S) -> Self::Values;
+}
+
+macro_rules! impl_curve_tuple {
+    ($(($point:ident, $curve:ident, $index:tt)),+) => {
+        impl<S: Scalar, $($point: Point<Scalar = S>, $curve: Curve<$point>),+> CurveTuple<S, ($($point,)+)>
+            for ($($curve,)+)
+        {
+            type Values = ($($point,)+);
+
+            fn values_at(&self, t: S) -> Self::Values {
+                ($(self.$index.value_at(t),)+)
+            }
+
+            fn tangents_at(&self, t: S) -> Self::Values {
+                ($(self.$index.tangent_at(t),)+)
+            }
+        }
+    };
+}
+
+impl_curve_tuple!((P0, C0, 0));
+impl_curve_tuple!((P0, C0, 0), (P1, C1, 1));
+impl_curve_tuple!((P0, C0, 0), (P1, C1, 1), (P2, C2, 2));
+impl_curve_tuple!((P0, C0, 0), (P1, C1, 1), (P2, C2, 2), (P3, C3, 3));
+impl_curve_tuple!(
+    (P0, C0, 0),
+    (P1, C1, 1),
+    (P2, C2, 2),
+    (P3, C3, 3),
+    (P4, C4, 4)
+);
+impl_curve_tuple!(
+    (P0, C0, 0),
+    (P1, C1, 1),
+    (P2, C2, 2),
+    (P3, C3, 3),
+    (P4, C4, 4),
+    (P5, C5, 5)
+);
+impl_curve_tuple!(
+    (P0, C0, 0),
+    (P1, C1, 1),
+    (P2, C2, 2),
+    (P3, C3, 3),
+    (P4, C4, 4),
+    (P5, C5, 5),
+    (P6, C6, 6)
+);
+impl_curve_tuple!(
+    (P0, C0, 0),
+    (P1, C1, 1),
+    (P2, C2, 2),
+    (P3, C3, 3),
+    (P4, C4, 4),
+    (P5, C5, 5),
+    (P6, C6, 6),
+    (P7, C7, 7)
+);
+
+/// Several curves of different point types evaluated together, e.g. position, rotation,
+/// scale and color tracks of an animation. Values are combined into a user type with `map`:
+/// ```
+/// use baiser::{Bezier1, CurveBundle};
+///
+/// struct Sprite {
+///     position: [f64; 2],
+///     opacity: f64,
+/// }
+///
+/// let bundle = CurveBundle::new((
+///     Bezier1::new([0.0, 0.0], [10.0, 0.0]),
+///     Bezier1::new(0.0, 1.0),
+/// ))
+/// .map(|(position, opacity)| Sprite { position, opacity });
+///
+/// let sprite = bundle.value_at(0.5);
+/// assert_eq!(sprite.position, [5.0, 0.0]);
+/// assert_eq!(sprite.opacity, 0.5);
+/// ```
+///
+/// Up to eight curves are supported. To play all tracks with the same timing, evaluate the bundle
+/// at a parameter from a single easing or [`LinearSpeed`](crate::LinearSpeed) curve.
+pub struct CurveBundle<
+    S: Scalar,
+    Points,
+    T: CurveTuple<S, Points>,
+    F = fn(<T as CurveTuple<S, Points>>::Values) -> <T as CurveTuple<S, Points>>::Values,
+> {
+    curves: T,
+    map: F,
+    phantom_data: PhantomData<(S, Points)>,
+}
+
+impl<S: Scalar, Points, T: CurveTuple<S, Points>> CurveBundle<S, Points, T> {
+    pub fn new(curves: T) -> Self {
+        Self {
+            curves,
+            map: |values| values,
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+impl<S: Scalar, Points, T: CurveTuple<S, Points>, F> CurveBundle<S, Points, T, F> {
+    /// Combine values of the curves with `map`.
+    pub fn map<O, G: Fn(T::Values) -> O>(self, map: G) -> CurveBundle<S, Points, T, G> {
+        CurveBundle {
+            curves: self.curves,
+            map,
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub fn curves(&self) -> &T {
+        &self.curves
+    }
+
+    /// Values of all curves at `t`, before mapping.
+    pub fn values_at(&self, t: S) -> T::Values {
+        self.curves.values_at(t)
+    }
+
+    /// Derivatives of all curves at `t`.
+    pub fn tangents_at(&self, t: S) -> T::Values {
+        self.curves.tangents_at(t)
+    }
+
+    /// Mapped values of all curves at `t`.
+    pub fn value_at<O>(&self, t: S) -> O
+    where
+        F: Fn(T::Values) -> O,
+    {
+        (self.map)(self.curves.values_at(t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::easing::CubicBezierEasing;
+    use crate::{Bezier0, Bezier1, Bezier2};
+
+    #[test]
+    fn values_and_tangents() {
+        let bundle = CurveBundle::new((
+            Bezier1::new([0.0, 0.0], [2.0, 4.0]),
+            Bezier2::new(0.0, 1.0, 0.0),
+            Bezier0::new((1.0, 0.5, 0.25)),
+        ));
+        assert_eq!(bundle.values_at(0.5), ([1.0, 2.0], 0.5, (1.0, 0.5, 0.25)));
+        assert_eq!(bundle.tangents_at(0.5), ([2.0, 4.0], 0.0, (0.0, 0.0, 0.0)));
+        assert_eq!(bundle.value_at(1.0), bundle.values_at(1.0));
+    }
+
+    #[test]
+    fn mapping() {
+        #[derive(Debug, PartialEq)]
+        struct Transform {
+            x: f32,
+            angle: f32,
+            scale: f32,
+        }
+
+        let bundle = CurveBundle::new((
+            Bezier1::new(0.0f32, 10.0),
+            CubicBezierEasing::ease_in_out(),
+            Bezier1::new(1.0f32, 2.0),
+        ))
+        .map(|(x, angle, scale)| Transform { x, angle, scale });
+        assert_eq!(
+            bundle.value_at(0.0),
+            Transform {
+                x: 0.0,
+                angle: 0.0,
+                scale: 1.0
+            }
+        );
+        assert_eq!(bundle.value_at(1.0).scale, 2.0);
+        assert_eq!(bundle.curves().0.p1, 10.0);
+    }
+}
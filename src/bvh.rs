@@ -0,0 +1,367 @@
+use crate::intersection::{nearest_point, segment_intersections};
+use crate::{Bezier, ComposedCurve, Curve, PlanarPoint, Rect, Scalar};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// Maximum number of segments in a leaf node.
+const LEAF_SIZE: usize = 4;
+
+/// The point of a path nearest to a query point.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SegmentHit<P: PlanarPoint> {
+    /// Index of the segment in [`ComposedCurve::segments`].
+    pub segment: usize,
+    /// Parameter of the point on the segment.
+    pub t: P::Scalar,
+    pub point: P,
+    pub distance: P::Scalar,
+}
+
+/// An intersection point of two paths.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SegmentIntersection<P: PlanarPoint> {
+    /// Index of the segment of the first path.
+    pub segment: usize,
+    /// Parameter of the point on the segment of the first path.
+    pub t: P::Scalar,
+    /// Index of the segment of the second path.
+    pub other_segment: usize,
+    /// Parameter of the point on the segment of the second path.
+    pub other_t: P::Scalar,
+    pub point: P,
+}
+
+#[derive(Clone)]
+enum NodeContent {
+    /// Range in [`SegmentBvh::order`].
+    Leaf {
+        start: usize,
+        end: usize,
+    },
+    Branch {
+        left: usize,
+        right: usize,
+    },
+}
+
+#[derive(Clone)]
+struct Node<P: PlanarPoint> {
+    bounds: Rect<P>,
+    content: NodeContent,
+}
+
+/// Bounding volume hierarchy over the segments of a path, it answers nearest point,
+/// hit testing, intersection, and rectangle queries in logarithmic time.
+///
+/// Every node is bounded by the control points of its segments, so the bounds are conservative.
+#[derive(Clone)]
+pub struct SegmentBvh<P: PlanarPoint> {
+    segments: Vec<Bezier<P>>,
+    /// Segment indices in the order of the leaves.
+    order: Vec<usize>,
+    /// The root is the first node.
+    nodes: Vec<Node<P>>,
+}
+
+impl<P: PlanarPoint + Debug> Debug for SegmentBvh<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SegmentBvh")
+            .field("segments", &self.segments)
+            .field("nodes", &self.nodes.len())
+            .finish()
+    }
+}
+
+impl<P: PlanarPoint> SegmentBvh<P> {
+    pub fn new(curve: &ComposedCurve<P>) -> Self {
+        let segments = curve.segments().to_vec();
+        let bounds: Vec<Rect<P>> = segments.iter().map(Bezier::control_bounds).collect();
+        let mut order: Vec<usize> = (0..segments.len()).collect();
+        let mut nodes = Vec::with_capacity(2 * segments.len() / LEAF_SIZE + 1);
+        if !segments.is_empty() {
+            build(&bounds, &mut order, 0, &mut nodes);
+        }
+        Self {
+            segments,
+            order,
+            nodes,
+        }
+    }
+
+    pub fn segments(&self) -> &[Bezier<P>] {
+        &self.segments
+    }
+
+    /// Bounds of all the control points, `None` for an empty path.
+    pub fn bounds(&self) -> Option<Rect<P>> {
+        self.nodes.first().map(|node| node.bounds.clone())
+    }
+
+    /// Indices of the segments whose control points bounds intersect `rect`, in ascending order.
+    pub fn query_rect(&self, rect: &Rect<P>) -> Vec<usize> {
+        let mut result = Vec::new();
+        let mut stack = Vec::from_iter(self.root());
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if !node.bounds.intersects(rect) {
+                continue;
+            }
+            match node.content {
+                NodeContent::Leaf { start, end } => result.extend(
+                    self.order[start..end]
+                        .iter()
+                        .filter(|&&i| self.segments[i].control_bounds().intersects(rect)),
+                ),
+                NodeContent::Branch { left, right } => stack.extend([left, right]),
+            }
+        }
+        result.sort_unstable();
+        result
+    }
+
+    /// The point of the path nearest to `point`, `None` for an empty path.
+    pub fn project(&self, point: &P) -> Option<SegmentHit<P>> {
+        self.nearest(point, None)
+    }
+
+    /// The point of the path nearest to `point` if it's not further than `radius`.
+    pub fn hit_test(&self, point: &P, radius: P::Scalar) -> Option<SegmentHit<P>> {
+        self.nearest(point, Some(radius))
+    }
+
+    /// Intersection points with another path ordered by the segment index and the parameter.
+    /// Curved segments are subdivided until their bounds are smaller than `tolerance`.
+    pub fn intersections(&self, other: &Self, tolerance: P::Scalar) -> Vec<SegmentIntersection<P>> {
+        let mut result = Vec::new();
+        let mut stack = Vec::new();
+        if let (Some(a), Some(b)) = (self.root(), other.root()) {
+            stack.push((a, b));
+        }
+        while let Some((a, b)) = stack.pop() {
+            let (node_a, node_b) = (&self.nodes[a], &other.nodes[b]);
+            if !node_a.bounds.inflate(tolerance).intersects(&node_b.bounds) {
+                continue;
+            }
+            match (&node_a.content, &node_b.content) {
+                (
+                    &NodeContent::Leaf { start, end },
+                    &NodeContent::Leaf {
+                        start: other_start,
+                        end: other_end,
+                    },
+                ) => {
+                    for &i in &self.order[start..end] {
+                        for &j in &other.order[other_start..other_end] {
+                            let (a, b) = (&self.segments[i], &other.segments[j]);
+                            for (t, u) in segment_intersections(a, b, tolerance) {
+                                result.push(SegmentIntersection {
+                                    segment: i,
+                                    t,
+                                    other_segment: j,
+                                    other_t: u,
+                                    point: a.value_at(t),
+                                });
+                            }
+                        }
+                    }
+                }
+                (&NodeContent::Branch { left, right }, &NodeContent::Leaf { .. }) => {
+                    stack.extend([(left, b), (right, b)])
+                }
+                (&NodeContent::Leaf { .. }, &NodeContent::Branch { left, right }) => {
+                    stack.extend([(a, left), (a, right)])
+                }
+                (&NodeContent::Branch { left, right }, &NodeContent::Branch { .. }) => {
+                    // Descend into the larger node first
+                    if extent(&node_a.bounds) >= extent(&node_b.bounds) {
+                        stack.extend([(left, b), (right, b)]);
+                    } else if let NodeContent::Branch { left, right } = node_b.content {
+                        stack.extend([(a, left), (a, right)]);
+                    }
+                }
+            }
+        }
+        result.sort_by(|x, y| {
+            x.segment
+                .cmp(&y.segment)
+                .then(x.t.partial_cmp(&y.t).unwrap())
+        });
+        result
+    }
+
+    fn root(&self) -> Option<usize> {
+        (!self.nodes.is_empty()).then_some(0)
+    }
+
+    fn nearest(&self, point: &P, radius: Option<P::Scalar>) -> Option<SegmentHit<P>> {
+        let mut best: Option<SegmentHit<P>> = None;
+        let limit = |best: &Option<SegmentHit<P>>| best.as_ref().map(|hit| hit.distance).or(radius);
+
+        let mut stack = Vec::from_iter(self.root());
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if limit(&best).is_some_and(|limit| node.bounds.distance_to(point) > limit) {
+                continue;
+            }
+            match node.content {
+                NodeContent::Leaf { start, end } => {
+                    for &i in &self.order[start..end] {
+                        let segment = &self.segments[i];
+                        let lower_bound = segment.control_bounds().distance_to(point);
+                        if limit(&best).is_some_and(|limit| lower_bound > limit) {
+                            continue;
+                        }
+                        let (t, nearest, distance) = nearest_point(segment, point);
+                        if limit(&best).is_none_or(|limit| distance <= limit) {
+                            best = Some(SegmentHit {
+                                segment: i,
+                                t,
+                                point: nearest,
+                                distance,
+                            });
+                        }
+                    }
+                }
+                NodeContent::Branch { left, right } => {
+                    // Visit the closer child first, it's pushed last
+                    let left_distance = self.nodes[left].bounds.distance_to(point);
+                    let right_distance = self.nodes[right].bounds.distance_to(point);
+                    if left_distance < right_distance {
+                        stack.extend([right, left]);
+                    } else {
+                        stack.extend([left, right]);
+                    }
+                }
+            }
+        }
+        best
+    }
+}
+
+fn extent<P: PlanarPoint>(rect: &Rect<P>) -> P::Scalar {
+    rect.width().max(rect.height())
+}
+
+/// Build the subtree over `order`, which starts at `offset` in the whole order,
+/// and return the index of its root node.
+fn build<P: PlanarPoint>(
+    bounds: &[Rect<P>],
+    order: &mut [usize],
+    offset: usize,
+    nodes: &mut Vec<Node<P>>,
+) -> usize {
+    let node_bounds = order
+        .iter()
+        .skip(1)
+        .fold(bounds[order[0]].clone(), |acc, &i| acc.union(&bounds[i]));
+    let index = nodes.len();
+    nodes.push(Node {
+        bounds: node_bounds.clone(),
+        content: NodeContent::Leaf {
+            start: offset,
+            end: offset + order.len(),
+        },
+    });
+    if order.len() <= LEAF_SIZE {
+        return index;
+    }
+
+    // Split by the median of the centers along the longest side
+    let by_x = node_bounds.width() >= node_bounds.height();
+    let key = |i: &usize| {
+        let center = bounds[*i].center();
+        if by_x {
+            center.x()
+        } else {
+            center.y()
+        }
+    };
+    let middle = order.len() / 2;
+    order.select_nth_unstable_by(middle, |a, b| key(a).partial_cmp(&key(b)).unwrap());
+
+    let (head, tail) = order.split_at_mut(middle);
+    let left = build(bounds, head, offset, nodes);
+    let right = build(bounds, tail, offset + middle, nodes);
+    nodes[index].content = NodeContent::Branch { left, right };
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn zigzag(count: usize) -> ComposedCurve<[f64; 2]> {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        for i in 1..=count {
+            let point = [i as f64, (i % 2) as f64];
+            if i % 3 == 0 {
+                curve.quadratic_to([i as f64 - 0.8, 0.5], point);
+            } else {
+                curve.line_to(point);
+            }
+        }
+        curve
+    }
+
+    #[test]
+    fn project() {
+        let curve = zigzag(100);
+        let bvh = SegmentBvh::new(&curve);
+        for query in [[10.3, 3.0], [57.1, -2.0], [-5.0, 0.5], [33.3, 0.4]] {
+            let hit = bvh.project(&query).unwrap();
+            let brute = curve
+                .segments()
+                .iter()
+                .map(|s| nearest_point(s, &query).2)
+                .fold(f64::INFINITY, f64::min);
+            assert_relative_eq!(hit.distance, brute, epsilon = 1e-9);
+            assert_relative_eq!(
+                curve.segments()[hit.segment].value_at(hit.t)[0],
+                hit.point[0]
+            );
+        }
+
+        assert!(bvh.hit_test(&[10.0, 5.0], 1.0).is_none());
+        assert_eq!(bvh.hit_test(&[9.5, 0.4], 1.0).unwrap().segment, 9);
+    }
+
+    #[test]
+    fn query_rect() {
+        let bvh = SegmentBvh::new(&zigzag(100));
+        assert_eq!(
+            bvh.query_rect(&Rect::new([10.2, -1.0], [11.5, 3.0])),
+            [10, 11]
+        );
+        assert!(bvh
+            .query_rect(&Rect::new([10.2, 5.0], [11.5, 6.0]))
+            .is_empty());
+    }
+
+    #[test]
+    fn intersections() {
+        let bvh = SegmentBvh::new(&zigzag(100));
+        let mut line = ComposedCurve::new([-1.0, 0.5]);
+        line.line_to([101.0, 0.5]);
+        let other = SegmentBvh::new(&line);
+
+        let result = bvh.intersections(&other, 1e-9);
+        // Every segment goes between zero and one, so it crosses the line once
+        assert_eq!(result.len(), 100);
+        for hit in result {
+            assert_relative_eq!(hit.point[1], 0.5, epsilon = 1e-6);
+            assert_relative_eq!(
+                line.segments()[0].value_at(hit.other_t)[0],
+                hit.point[0],
+                epsilon = 1e-6
+            );
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let bvh = SegmentBvh::new(&ComposedCurve::new([0.0, 0.0]));
+        assert!(bvh.project(&[1.0, 1.0]).is_none());
+        assert!(bvh.bounds().is_none());
+    }
+}
@@ -0,0 +1,84 @@
+use crate::polynomial::Polynomial3;
+use crate::{Bezier3, Curve, Distance, Point};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// Cubic bezier curve that keeps its power basis form next to the control points.
+/// Use it for curves that are sampled many times, the evaluation is done with Horner's rule.
+#[derive(Clone, PartialEq)]
+pub struct CachedCurve<P: Point> {
+    bezier: Bezier3<P>,
+    polynomial: Polynomial3<P>,
+}
+
+impl<P: Point> Copy for CachedCurve<P> where P: Copy {}
+
+impl<P: Point> CachedCurve<P> {
+    pub fn new(bezier: Bezier3<P>) -> Self {
+        let polynomial = bezier.to_polynomial();
+        Self { bezier, polynomial }
+    }
+
+    pub fn bezier(&self) -> &Bezier3<P> {
+        &self.bezier
+    }
+
+    pub fn polynomial(&self) -> &Polynomial3<P> {
+        &self.polynomial
+    }
+
+    pub fn into_bezier(self) -> Bezier3<P> {
+        self.bezier
+    }
+}
+
+impl<P: Point> From<Bezier3<P>> for CachedCurve<P> {
+    fn from(bezier: Bezier3<P>) -> Self {
+        Self::new(bezier)
+    }
+}
+
+impl<P: Point + Debug> Debug for CachedCurve<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("CachedCurve").field(&self.bezier).finish()
+    }
+}
+
+impl<P: Point> Curve<P> for CachedCurve<P> {
+    fn value_at(&self, t: P::Scalar) -> P {
+        self.polynomial.value_at(t)
+    }
+
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        self.polynomial.tangent_at(t)
+    }
+
+    fn value_at_many(&self, ts: &[P::Scalar], out: &mut [P]) {
+        self.polynomial.value_at_many(ts, out)
+    }
+
+    fn tangent_at_many(&self, ts: &[P::Scalar], out: &mut [P]) {
+        self.polynomial.tangent_at_many(ts, out)
+    }
+
+    fn start_point(&self) -> P {
+        self.bezier.start_point()
+    }
+
+    fn end_point(&self) -> P {
+        self.bezier.end_point()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn control_points(&self) -> Vec<P> {
+        self.bezier.control_points()
+    }
+
+    fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        self.bezier.estimate_length(precision)
+    }
+}
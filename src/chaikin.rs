@@ -0,0 +1,276 @@
+use crate::{Bezier2, ComposedCurve, Curve, Distance, Point, Scalar};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use num_traits::{NumCast, One, ToPrimitive, Zero};
+
+/// Chaikin's corner cutting curve of a control polygon.
+///
+/// Cutting every corner of the polygon at 1/4 and 3/4 of its edges again and again converges
+/// to a quadratic B-spline, which is the curve evaluated by the [`Curve`] implementation.
+/// It consists of quadratic segments, one per corner, so [`Chaikin::to_composed_curve`] is exact.
+/// The refined polygon after a finite number of `iterations` is available with [`Chaikin::to_polyline`].
+///
+/// Open curves start at the first point and end at the last one,
+/// closed curves go around all the points and don't pass through them.
+#[derive(Clone, PartialEq)]
+pub struct Chaikin<P: Point> {
+    points: Vec<P>,
+    closed: bool,
+    iterations: usize,
+}
+
+impl<P: Point + Debug> Debug for Chaikin<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Chaikin")
+            .field("points", &self.points)
+            .field("closed", &self.closed)
+            .field("iterations", &self.iterations)
+            .finish()
+    }
+}
+
+impl<P: Point> Chaikin<P> {
+    /// # Panics
+    ///
+    /// Panics if `points` is empty.
+    pub fn new(points: Vec<P>, closed: bool) -> Self {
+        assert!(!points.is_empty(), "Chaikin curve needs at least one point");
+        Self {
+            points,
+            closed,
+            iterations: 4,
+        }
+    }
+
+    /// Number of corner cutting steps done by [`Chaikin::to_polyline`], 4 by default.
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    pub fn points(&self) -> &[P] {
+        &self.points
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// The control polygon after the configured number of corner cutting steps.
+    /// For closed curves the first point isn't repeated at the end.
+    pub fn to_polyline(&self) -> Vec<P> {
+        let one = P::Scalar::one();
+        let quarter = one / (one + one + one + one);
+
+        let mut points = self.points.clone();
+        for _ in 0..self.iterations {
+            if points.len() < 3 {
+                break;
+            }
+
+            let mut refined = Vec::with_capacity(points.len() * 2);
+            if self.closed {
+                for i in 0..points.len() {
+                    let (a, b) = (&points[i], &points[(i + 1) % points.len()]);
+                    refined.push(a.lerp(b, quarter));
+                    refined.push(b.lerp(a, quarter));
+                }
+            } else {
+                let last = points.len() - 2;
+                refined.push(points[0].clone());
+                for (i, edge) in points.windows(2).enumerate() {
+                    if i > 0 {
+                        refined.push(edge[0].lerp(&edge[1], quarter));
+                    }
+                    if i < last {
+                        refined.push(edge[1].lerp(&edge[0], quarter));
+                    }
+                }
+                refined.push(points[points.len() - 1].clone());
+            }
+            points = refined;
+        }
+        points
+    }
+
+    /// The limit curve as quadratic segments.
+    pub fn to_composed_curve(&self) -> ComposedCurve<P> {
+        let mut curve = ComposedCurve::with_capacity(self.start_point(), self.segments_count());
+        for i in 0..self.segments_count() {
+            let segment = self.segment(i);
+            curve.quadratic_to(segment.p1, segment.p2);
+        }
+        curve
+    }
+
+    fn is_cyclic(&self) -> bool {
+        self.closed && self.points.len() >= 3
+    }
+
+    fn segments_count(&self) -> usize {
+        match self.points.len() {
+            1 => 0,
+            2 => 1,
+            n if self.is_cyclic() => n,
+            n => n - 2,
+        }
+    }
+
+    fn segment(&self, i: usize) -> Bezier2<P> {
+        let points = &self.points;
+        let n = points.len();
+        if n == 2 {
+            return Bezier2::new(
+                points[0].clone(),
+                points[0].midpoint(&points[1]),
+                points[1].clone(),
+            );
+        }
+
+        if self.is_cyclic() {
+            let previous = &points[(i + n - 1) % n];
+            let next = &points[(i + 1) % n];
+            Bezier2::new(
+                previous.midpoint(&points[i]),
+                points[i].clone(),
+                points[i].midpoint(next),
+            )
+        } else {
+            let corner = i + 1;
+            let start = if corner == 1 {
+                points[0].clone()
+            } else {
+                points[corner - 1].midpoint(&points[corner])
+            };
+            let end = if corner == n - 2 {
+                points[n - 1].clone()
+            } else {
+                points[corner].midpoint(&points[corner + 1])
+            };
+            Bezier2::new(start, points[corner].clone(), end)
+        }
+    }
+
+    /// Index of the segment and the parameter inside it.
+    fn locate(&self, t: P::Scalar) -> (usize, P::Scalar) {
+        let count = self.segments_count();
+        let t = t.clamp(P::Scalar::zero(), P::Scalar::one());
+        let t: P::Scalar = t * NumCast::from(count).unwrap();
+        let i = t.floor().to_usize().unwrap();
+        if i == count {
+            (count - 1, P::Scalar::one())
+        } else {
+            (i, t.fract())
+        }
+    }
+}
+
+impl<P: Point> Curve<P> for Chaikin<P> {
+    fn value_at(&self, t: P::Scalar) -> P {
+        if self.segments_count() == 0 {
+            return self.points[0].clone();
+        }
+        let (i, t) = self.locate(t);
+        self.segment(i).value_at(t)
+    }
+
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        let count = self.segments_count();
+        if count == 0 {
+            return self.points[0].scale(P::Scalar::zero());
+        }
+        let (i, t) = self.locate(t);
+        self.segment(i)
+            .tangent_at(t)
+            .scale(NumCast::from(count).unwrap())
+    }
+
+    fn start_point(&self) -> P {
+        self.value_at(P::Scalar::zero())
+    }
+
+    fn end_point(&self) -> P {
+        self.value_at(P::Scalar::one())
+    }
+
+    fn control_points(&self) -> Vec<P> {
+        self.points.clone()
+    }
+
+    fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        (0..self.segments_count()).fold(P::Scalar::zero(), |acc, i| {
+            acc + self.segment(i).estimate_length(precision)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn open_curve() {
+        let chaikin = Chaikin::new(vec![[0.0, 0.0], [1.0, 1.0], [2.0, 0.0], [3.0, 1.0]], false);
+        assert_eq!(chaikin.start_point(), [0.0, 0.0]);
+        assert_eq!(chaikin.end_point(), [3.0, 1.0]);
+
+        let curve = chaikin.to_composed_curve();
+        assert_eq!(curve.segments().len(), 2);
+        for i in 0..=20 {
+            let t = i as f64 / 20.0;
+            assert_eq!(curve.value_at(t), chaikin.value_at(t));
+        }
+
+        let polyline = chaikin.with_iterations(1).to_polyline();
+        assert_eq!(
+            polyline,
+            [
+                [0.0, 0.0],
+                [0.75, 0.75],
+                [1.25, 0.75],
+                [1.75, 0.25],
+                [2.25, 0.25],
+                [3.0, 1.0]
+            ]
+        );
+    }
+
+    #[test]
+    fn closed_curve_converges() {
+        let square = vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+        let chaikin = Chaikin::new(square, true).with_iterations(8);
+        assert_eq!(chaikin.start_point(), chaikin.end_point());
+        assert_eq!(chaikin.to_composed_curve().segments().len(), 4);
+
+        // Every point of the refined polygon is close to the limit curve
+        let samples: Vec<_> = (0..=4000)
+            .map(|i| chaikin.value_at(i as f64 / 4000.0))
+            .collect();
+        for point in chaikin.to_polyline() {
+            let distance = samples
+                .iter()
+                .map(|q| q.distance(&point))
+                .fold(f64::INFINITY, f64::min);
+            assert!(distance < 1e-3, "{}", distance);
+        }
+    }
+
+    #[test]
+    fn short_polygons() {
+        let dot = Chaikin::new(vec![[1.0, 2.0]], false);
+        assert_eq!(dot.value_at(0.5), [1.0, 2.0]);
+
+        let line = Chaikin::new(vec![[0.0, 0.0], [2.0, 0.0]], true);
+        assert_relative_eq!(line.value_at(0.25)[0], 0.5);
+        assert_relative_eq!(line.estimate_length(0.01), 2.0);
+    }
+}
@@ -0,0 +1,117 @@
+use crate::bezier::Bezier;
+use crate::geometry::distance_to_segment;
+use crate::{Bezier1, ComposedCurve, Curve, Distance, InnerProduct};
+use alloc::vec::Vec;
+use num_traits::Zero;
+
+impl<P: InnerProduct> ComposedCurve<P> {
+    /// Copy of the curve without the noise typical for imported data:
+    ///
+    /// * segments shorter than `tolerance` are removed, the following segment starts where the previous one ends,
+    /// * quadratic and cubic segments with all control points closer than `tolerance` to the chord become lines,
+    /// * adjacent lines going in the same direction are merged, if the joint is closer than `tolerance` to the merged line,
+    /// * the end point is snapped to the start point if they are closer than `tolerance`.
+    pub fn cleanup(&self, tolerance: P::Scalar) -> ComposedCurve<P> {
+        let start = self.start_point();
+        let mut segments: Vec<Bezier<P>> = Vec::with_capacity(self.segments().len());
+        let mut last_point = start.clone();
+
+        for segment in self.segments() {
+            let end = segment.end_point();
+            if segment.is_point(tolerance) {
+                continue;
+            }
+
+            let segment = if segment.is_linear(tolerance) {
+                Bezier::C1(Bezier1::new(last_point.clone(), end.clone()))
+            } else {
+                with_start(segment, last_point.clone())
+            };
+
+            match (segments.last_mut(), &segment) {
+                (Some(Bezier::C1(previous)), Bezier::C1(line))
+                    if line.p1.sub(&line.p0).dot(&previous.p1.sub(&previous.p0))
+                        > P::Scalar::zero()
+                        && distance_to_segment(&line.p0, &previous.p0, &line.p1) <= tolerance =>
+                {
+                    previous.p1 = line.p1.clone();
+                }
+                _ => segments.push(segment),
+            }
+            last_point = end;
+        }
+
+        if segments.len() > 1 && last_point != start && last_point.distance(&start) <= tolerance {
+            let last = segments.pop().unwrap();
+            segments.push(with_end(&last, start.clone()));
+        }
+
+        let mut result = ComposedCurve::with_capacity(start, segments.len());
+        for segment in segments {
+            result.push_segment(segment);
+        }
+        result
+    }
+}
+
+/// Copy of the segment with the start point replaced by `point`.
+fn with_start<P: InnerProduct>(segment: &Bezier<P>, point: P) -> Bezier<P> {
+    let mut segment = segment.clone();
+    match &mut segment {
+        Bezier::C0(c) => c.point = point,
+        Bezier::C1(c) => c.p0 = point,
+        Bezier::C2(c) => c.p0 = point,
+        Bezier::C3(c) => c.p0 = point,
+    }
+    segment
+}
+
+/// Copy of the segment with the end point replaced by `point`.
+fn with_end<P: InnerProduct>(segment: &Bezier<P>, point: P) -> Bezier<P> {
+    let mut segment = segment.clone();
+    match &mut segment {
+        Bezier::C0(c) => c.point = point,
+        Bezier::C1(c) => c.p1 = point,
+        Bezier::C2(c) => c.p2 = point,
+        Bezier::C3(c) => c.p3 = point,
+    }
+    segment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Curve;
+
+    #[test]
+    fn removes_noise() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([1.0, 0.0]);
+        curve.line_to([1.0, 1e-9]);
+        curve.line_to([2.0, 0.0]);
+        curve.cubic_to([3.0, 0.0], [4.0, 0.0], [5.0, 1e-9]);
+        curve.quadratic_to([6.0, 1.0], [7.0, 0.0]);
+        curve.line_to([7.0, -1.0]);
+        curve.line_to([1e-9, 0.0]);
+
+        let clean = curve.cleanup(1e-6);
+        let segments = clean.segments();
+        assert_eq!(segments.len(), 4);
+        assert!(matches!(&segments[0], Bezier::C1(c) if c.p0 == [0.0, 0.0] && c.p1 == [5.0, 1e-9]));
+        assert!(matches!(segments[1], Bezier::C2(_)));
+        assert!(matches!(segments[2], Bezier::C1(_)));
+        assert_eq!(clean.end_point(), [0.0, 0.0]);
+    }
+
+    #[test]
+    fn keeps_turns() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([1.0, 0.0]);
+        curve.line_to([0.5, 0.0]);
+        curve.line_to([0.5, 1.0]);
+
+        let clean = curve.cleanup(1e-6);
+        assert_eq!(clean.segments().len(), 3);
+        assert_eq!(clean.control_points(), curve.control_points());
+    }
+}
@@ -0,0 +1,187 @@
+use crate::roots::{solve_cubic, solve_linear, solve_quadratic, Roots};
+use crate::{Bezier, ComposedCurve, Curve, PlanarPoint, Scalar};
+use alloc::vec::Vec;
+use num_traits::{One, Zero};
+
+/// Part of a curve kept by [`Bezier::clip_halfplane`] or [`ComposedCurve::clip_halfplane`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClippedPiece<P: PlanarPoint> {
+    pub curve: Bezier<P>,
+    /// Index of the segment of the composed curve the piece comes from, 0 for a single bezier curve.
+    pub segment: usize,
+    /// Parameters of the segment where the piece starts and ends.
+    pub start: P::Scalar,
+    pub end: P::Scalar,
+}
+
+impl<P: PlanarPoint> Bezier<P> {
+    /// Pieces of the curve on the side of the line through `point` that `normal` points to,
+    /// points on the line are kept. Crossing parameters are exact roots of the curve polynomial.
+    pub fn clip_halfplane(&self, point: &P, normal: &P) -> Vec<ClippedPiece<P>> {
+        let (zero, one) = (P::Scalar::zero(), P::Scalar::one());
+        let side = |p: &P| p.sub(point).dot(normal);
+        let distances: Vec<P::Scalar> = self.control_points().iter().map(side).collect();
+
+        // The curve is inside the hull of its control points
+        if distances.iter().all(|&d| d >= zero) {
+            return Vec::from([ClippedPiece {
+                curve: self.clone(),
+                segment: 0,
+                start: zero,
+                end: one,
+            }]);
+        }
+        if distances.iter().all(|&d| d < zero) {
+            return Vec::new();
+        }
+
+        let roots = crossings(&distances);
+        let mut bounds = Vec::from([zero]);
+        bounds.extend(roots.iter().copied().filter(|&t| t > zero && t < one));
+        bounds.push(one);
+
+        let half = one / (one + one);
+        let mut ranges: Vec<(P::Scalar, P::Scalar)> = Vec::new();
+        for pair in bounds.windows(2) {
+            let (start, end) = (pair[0], pair[1]);
+            if side(&self.value_at((start + end) * half)) < zero {
+                continue;
+            }
+            // A touching root doesn't split the kept part
+            match ranges.last_mut() {
+                Some(last) if last.1 == start => last.1 = end,
+                _ => ranges.push((start, end)),
+            }
+        }
+        ranges
+            .into_iter()
+            .map(|(start, end)| ClippedPiece {
+                curve: self.sub_curve(start, end),
+                segment: 0,
+                start,
+                end,
+            })
+            .collect()
+    }
+
+    /// The part of the curve between the parameters `start < end`.
+    fn sub_curve(&self, start: P::Scalar, end: P::Scalar) -> Self {
+        let (zero, one) = (P::Scalar::zero(), P::Scalar::one());
+        let tail = if start > zero {
+            self.split_at(start).1
+        } else {
+            self.clone()
+        };
+        if end < one {
+            tail.split_at((end - start) / (one - start)).0
+        } else {
+            tail
+        }
+    }
+}
+
+impl<P: PlanarPoint> ComposedCurve<P> {
+    /// Pieces of all the segments on the side of the line through `point` that `normal` points to,
+    /// in the order of the curve. See [`Bezier::clip_halfplane`].
+    pub fn clip_halfplane(&self, point: &P, normal: &P) -> Vec<ClippedPiece<P>> {
+        let mut pieces = Vec::new();
+        for (i, segment) in self.segments().iter().enumerate() {
+            pieces.extend(
+                segment
+                    .clip_halfplane(point, normal)
+                    .into_iter()
+                    .map(|piece| ClippedPiece {
+                        segment: i,
+                        ..piece
+                    }),
+            );
+        }
+        pieces
+    }
+}
+
+/// Roots of the polynomial with the bernstein coefficients `d`.
+fn crossings<F: Scalar>(d: &[F]) -> Roots<F> {
+    let two = F::one() + F::one();
+    let three = two + F::one();
+    match *d {
+        [d0, d1] => solve_linear(d1 - d0, d0),
+        [d0, d1, d2] => solve_quadratic(d0 - two * d1 + d2, two * (d1 - d0), d0),
+        [d0, d1, d2, d3] => solve_cubic(
+            d3 - d0 + three * (d1 - d2),
+            three * (d0 - (d1 + d1) + d2),
+            three * (d1 - d0),
+            d0,
+        ),
+        _ => solve_linear(F::zero(), F::zero()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bezier1, Bezier2, Bezier3};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn line() {
+        let line = Bezier::C1(Bezier1::new([0.0, 0.0], [4.0, 0.0]));
+        let pieces = line.clip_halfplane(&[1.0, 0.0], &[1.0, 0.0]);
+        assert_eq!(pieces.len(), 1);
+        assert_relative_eq!(pieces[0].start, 0.25);
+        assert_relative_eq!(pieces[0].end, 1.0);
+        assert_relative_eq!(pieces[0].curve.start_point()[0], 1.0);
+
+        assert!(line.clip_halfplane(&[5.0, 0.0], &[1.0, 0.0]).is_empty());
+        let whole = line.clip_halfplane(&[0.0, 0.0], &[1.0, 0.0]);
+        assert_eq!(whole[0].curve, line);
+    }
+
+    #[test]
+    fn curves() {
+        // An arch above the line y = 1 in the middle only
+        let arch = Bezier::C2(Bezier2::new([0.0, 0.0], [2.0, 4.0], [4.0, 0.0]));
+        let pieces = arch.clip_halfplane(&[0.0, 1.0], &[0.0, 1.0]);
+        assert_eq!(pieces.len(), 1);
+        // y(t) = 4 t (1 - t) = 1
+        let t = (1.0 - 0.5f64.sqrt()) / 2.0;
+        assert_relative_eq!(pieces[0].start, t, epsilon = 1e-12);
+        assert_relative_eq!(pieces[0].end, 1.0 - t, epsilon = 1e-12);
+        assert_relative_eq!(pieces[0].curve.end_point()[1], 1.0, epsilon = 1e-12);
+
+        // Below the line there are two pieces
+        let pieces = arch.clip_halfplane(&[0.0, 1.0], &[0.0, -1.0]);
+        assert_eq!(pieces.len(), 2);
+        assert_relative_eq!(pieces[0].start, 0.0);
+        assert_relative_eq!(pieces[1].end, 1.0);
+
+        // A wave touching the line stays in one piece
+        let touching = Bezier::C2(Bezier2::new([0.0, 0.0], [2.0, 2.0], [4.0, 0.0]));
+        assert_eq!(touching.clip_halfplane(&[0.0, 1.0], &[0.0, -1.0]).len(), 1);
+
+        let s_curve = Bezier::C3(Bezier3::new(
+            [0.0, 0.0],
+            [1.0, 3.0],
+            [2.0, -3.0],
+            [3.0, 0.0],
+        ));
+        let pieces = s_curve.clip_halfplane(&[0.0, 0.0], &[0.0, 1.0]);
+        assert_eq!(pieces.len(), 1);
+        assert_relative_eq!(pieces[0].end, 0.5, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn composed_curve() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([4.0, 0.0]);
+        curve.line_to([4.0, 4.0]);
+        curve.line_to([0.0, 4.0]);
+        let pieces = curve.clip_halfplane(&[2.0, 0.0], &[1.0, 0.0]);
+        assert_eq!(pieces.len(), 3);
+        assert_eq!(
+            pieces.iter().map(|piece| piece.segment).collect::<Vec<_>>(),
+            [0, 1, 2]
+        );
+        assert_relative_eq!(pieces[2].end, 0.5);
+    }
+}
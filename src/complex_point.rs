@@ -0,0 +1,68 @@
+use crate::{InnerProduct, PlanarPoint, Point, Scalar};
+use ::num_complex::Complex;
+
+/// Complex numbers are points on a plane, `re` is `x` and `im` is `y`.
+/// [`Point::multiply`] is component-wise like for other points, not the complex product.
+impl<F: Scalar> Point for Complex<F> {
+    type Scalar = F;
+
+    fn add(&self, other: &Self) -> Self {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+
+    fn multiply(&self, other: &Self) -> Self {
+        Complex::new(self.re * other.re, self.im * other.im)
+    }
+
+    fn scale(&self, s: F) -> Self {
+        Complex::new(self.re * s, self.im * s)
+    }
+}
+
+impl<F: Scalar> InnerProduct for Complex<F> {
+    fn dot(&self, other: &Self) -> F {
+        self.re * other.re + self.im * other.im
+    }
+}
+
+impl<F: Scalar> PlanarPoint for Complex<F> {
+    fn from_xy(x: F, y: F) -> Self {
+        Complex::new(x, y)
+    }
+
+    fn x(&self) -> F {
+        self.re
+    }
+
+    fn y(&self) -> F {
+        self.im
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bezier2, Curve, Distance, PlanarPoint};
+    use ::num_complex::Complex;
+
+    #[test]
+    fn complex_points() {
+        let curve = Bezier2::new(
+            Complex::new(0.0f32, 0.0),
+            Complex::new(1.0, 2.0),
+            Complex::new(2.0, 0.0),
+        );
+        assert_eq!(curve.value_at(0.5), Complex::new(1.0, 1.0));
+        assert_eq!(
+            Complex::new(0.0f64, 0.0).distance(&Complex::new(3.0, 4.0)),
+            5.0
+        );
+        assert_eq!(
+            Complex::new(1.0f64, 0.0).perpendicular(),
+            Complex::new(-0.0, 1.0)
+        );
+    }
+}
@@ -1,10 +1,19 @@
 use crate::bezier::Bezier;
-use crate::{Bezier1, Bezier2, Bezier3, Curve, Distance, Point};
-use num_traits::{Float, NumCast, One, ToPrimitive, Zero};
-use std::fmt::Debug;
-use std::ops::Deref;
+use crate::flatten::flatten_into;
+use crate::validate::debug_check_parameter;
+use crate::vertex_buffer::{VertexLayout, VertexWriter};
+use crate::{
+    Bezier1, Bezier2, Bezier3, Curve, CurveError, Distance, Error, PlanarPoint, Point, PointCast,
+    Scalar,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::ops::Deref;
+use num_traits::{NumCast, One, ToPrimitive, Zero};
 
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ComposedCurve<P: Point> {
     last_point: P,
     curves: Vec<Bezier<P>>,
@@ -21,8 +30,16 @@ where
     }
 }
 
+impl<P: Point> TryFrom<Vec<Bezier<P>>> for ComposedCurve<P> {
+    type Error = Error;
+
+    fn try_from(segments: Vec<Bezier<P>>) -> Result<Self, Self::Error> {
+        Self::try_from_segments(segments)
+    }
+}
+
 impl<P: Point + Debug> Debug for ComposedCurve<P> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("ComposedCurve")
             .field("last_point", &self.last_point)
             .field("curves", &self.curves)
@@ -45,6 +62,51 @@ impl<P: Point> ComposedCurve<P> {
         }
     }
 
+    /// Build a curve from connected segments, it fails if there are no segments,
+    /// a control point isn't finite, or a segment doesn't start where the previous one ends.
+    /// Dot segments are dropped like in [`ComposedCurve::push_segment`], so they must
+    /// be at the joints, and there must be at least one segment of a higher order.
+    pub fn try_from_segments(segments: Vec<Bezier<P>>) -> Result<Self, Error> {
+        let mut curve = Self {
+            last_point: match segments.last() {
+                Some(segment) => segment.end_point(),
+                None => return Err(CurveError::Empty.into()),
+            },
+            curves: segments,
+        };
+        curve.validate()?;
+        curve
+            .curves
+            .retain(|segment| !matches!(segment, Bezier::C0(_)));
+        curve.validate()?;
+        Ok(curve)
+    }
+
+    /// Convert every control point with `f`.
+    pub fn map_points<Q: Point>(&self, mut f: impl FnMut(&P) -> Q) -> ComposedCurve<Q> {
+        ComposedCurve {
+            last_point: f(&self.last_point),
+            curves: self
+                .curves
+                .iter()
+                .map(|curve| curve.map_points(&mut f))
+                .collect(),
+        }
+    }
+
+    /// Convert the curve to another point type, e.g. from `f64` to `f32`.
+    pub fn cast<Q: Point>(&self) -> ComposedCurve<Q>
+    where
+        P: PointCast<Q>,
+    {
+        self.map_points(PointCast::cast)
+    }
+
+    /// Segments of the curve in the order they were added.
+    pub fn segments(&self) -> &[Bezier<P>] {
+        &self.curves
+    }
+
     pub fn line_to(&mut self, point: P) {
         if point != self.last_point {
             let curve = Bezier::C1(Bezier1::new(self.last_point.clone(), point.clone()));
@@ -73,6 +135,83 @@ impl<P: Point> ComposedCurve<P> {
         self.last_point = p3;
     }
 
+    /// Append a segment of any order, it starts at the current end point of the curve,
+    /// so the start point of `segment` itself is ignored.
+    pub fn push_segment(&mut self, segment: Bezier<P>) {
+        match segment {
+            Bezier::C0(_) => {}
+            Bezier::C1(c) => self.line_to(c.p1),
+            Bezier::C2(c) => self.quadratic_to(c.p1, c.p2),
+            Bezier::C3(c) => self.cubic_to(c.p1, c.p2, c.p3),
+        }
+    }
+
+    /// Split the segment containing `t` with de Casteljau's algorithm, so the shape stays
+    /// the same but a new anchor appears at `value_at(t)`.
+    ///
+    /// Returns the index of the segment starting at the new anchor, or `None` if `t`
+    /// is already at an anchor or is NaN. The parameterization of the whole curve changes
+    /// as it has one more segment.
+    pub fn insert_point_at(&mut self, t: P::Scalar) -> Option<usize> {
+        if self.curves.is_empty() {
+            return None;
+        }
+        let t = t.clamp(P::Scalar::zero(), P::Scalar::one());
+        let t: P::Scalar = t * NumCast::from(self.curves.len()).unwrap();
+        // NaN has no integer part
+        let i = t.floor().to_usize()?;
+        let local = t.fract();
+        if i == self.curves.len() || local == P::Scalar::zero() {
+            return None;
+        }
+
+        let (first, second) = self.curves[i].split_at(local);
+        self.curves[i] = first;
+        self.curves.insert(i + 1, second);
+        Some(i + 1)
+    }
+
+    /// Derivative at `t` approached from below, at a joint it's the end derivative
+    /// of the segment ending there.
+    pub fn tangent_at_left(&self, t: P::Scalar) -> P {
+        debug_check_parameter(t);
+        let len: P::Scalar = NumCast::from(self.curves.len()).unwrap();
+        let t = t.clamp(Zero::zero(), One::one()) * len;
+        let i = t.ceil().to_usize().unwrap().max(1);
+        let offset: P::Scalar = NumCast::from(i - 1).unwrap();
+        self.curves[i - 1].tangent_at(t - offset).scale(len)
+    }
+
+    /// Derivative at `t` approached from above, at a joint it's the start derivative
+    /// of the segment starting there.
+    pub fn tangent_at_right(&self, t: P::Scalar) -> P {
+        debug_check_parameter(t);
+        let len: P::Scalar = NumCast::from(self.curves.len()).unwrap();
+        let t = t.clamp(Zero::zero(), One::one()) * len;
+        let i = t.floor().to_usize().unwrap();
+        if i == self.curves.len() {
+            self.curves[i - 1].tangent_at(One::one()).scale(len)
+        } else {
+            self.curves[i].tangent_at(t.fract()).scale(len)
+        }
+    }
+
+    /// Replace the segments in `range` with `segments` and return the removed ones,
+    /// the caller keeps the segments connected.
+    pub(crate) fn splice_segments(
+        &mut self,
+        range: core::ops::Range<usize>,
+        segments: Vec<Bezier<P>>,
+    ) -> Vec<Bezier<P>> {
+        let removed: Vec<_> = self.curves.splice(range, segments).collect();
+        if let Some(last) = self.curves.last() {
+            self.last_point = last.end_point();
+        } else if let Some(first) = removed.first() {
+            self.last_point = first.start_point();
+        }
+        removed
+    }
+
     pub fn close(&mut self) {
         if !self.curves.is_empty() {
             let first_point = self.curves[0].start_point();
@@ -81,8 +220,46 @@ impl<P: Point> ComposedCurve<P> {
     }
 }
 
+impl<P: Distance> ComposedCurve<P> {
+    /// Derivative at `t` scaled as if every segment took a part of `t` proportional
+    /// to its length, so its magnitude doesn't jump at smooth joints of segments
+    /// of different lengths. The direction is the same as of [`Curve::tangent_at`],
+    /// which scales by the segment count. Lengths are estimated with `precision`,
+    /// see [`Curve::estimate_length`].
+    pub fn tangent_at_by_length(&self, t: P::Scalar, precision: P::Scalar) -> P {
+        let count = self.curves.len();
+        let lengths: Vec<_> = self
+            .curves
+            .iter()
+            .map(|curve| curve.estimate_length(precision))
+            .collect();
+        let total = lengths
+            .iter()
+            .fold(P::Scalar::zero(), |sum, length| sum + *length);
+        let scaled = |i: usize, local: P::Scalar| {
+            let tangent = self.curves[i].tangent_at(local);
+            if lengths[i] > P::Scalar::zero() {
+                tangent.scale(total / lengths[i])
+            } else {
+                tangent.scale(NumCast::from(count).unwrap())
+            }
+        };
+
+        let x = t.clamp(P::Scalar::zero(), P::Scalar::one()) * NumCast::from(count).unwrap();
+        let i = x.floor().to_usize().unwrap();
+        if i == count {
+            scaled(i - 1, P::Scalar::one())
+        } else if i > 0 && x.fract() == P::Scalar::zero() {
+            scaled(i - 1, P::Scalar::one()).midpoint(&scaled(i, P::Scalar::zero()))
+        } else {
+            scaled(i, x.fract())
+        }
+    }
+}
+
 impl<P: Point> Curve<P> for ComposedCurve<P> {
     fn value_at(&self, t: P::Scalar) -> P {
+        debug_check_parameter(t);
         let t = t.clamp(P::Scalar::zero(), P::Scalar::one());
         let t: P::Scalar = t * NumCast::from(self.curves.len()).unwrap();
         let i = t.floor().to_usize().unwrap();
@@ -95,19 +272,84 @@ impl<P: Point> Curve<P> for ComposedCurve<P> {
         }
     }
 
+    fn start_point(&self) -> P {
+        self.curves
+            .first()
+            .map_or_else(|| self.last_point.clone(), |curve| curve.start_point())
+    }
+
+    fn end_point(&self) -> P {
+        self.last_point.clone()
+    }
+
+    fn control_points(&self) -> Vec<P> {
+        let mut points = vec![self.start_point()];
+        self.curves
+            .iter()
+            .for_each(|curve| points.extend(curve.control_points().into_iter().skip(1)));
+        points
+    }
+
+    /// At an exact joint of two segments it's the average of [`ComposedCurve::tangent_at_left`]
+    /// and [`ComposedCurve::tangent_at_right`], so it doesn't depend on rounding of `t`.
     fn tangent_at(&self, t: P::Scalar) -> P {
+        debug_check_parameter(t);
         let len: P::Scalar = NumCast::from(self.curves.len()).unwrap();
+        let x = t.clamp(Zero::zero(), One::one()) * len;
+        if x.fract() == P::Scalar::zero() && x > P::Scalar::zero() && x < len {
+            self.tangent_at_left(t).midpoint(&self.tangent_at_right(t))
+        } else {
+            self.tangent_at_right(t)
+        }
+    }
 
-        let t = t.clamp(Zero::zero(), One::one());
-        let t: P::Scalar = t * len;
-        let i = t.floor().to_usize().unwrap();
-        let t = t.fract();
+    fn flatten(&self, tolerance: P::Scalar) -> Vec<P>
+    where
+        P: Distance,
+    {
+        let mut points = vec![self.start_point()];
+        self.curves
+            .iter()
+            .for_each(|curve| flatten_into(curve, tolerance, &mut points));
+        points
+    }
 
-        if i == self.curves.len() {
-            self.curves[i - 1].tangent_at(One::one()).scale(len)
-        } else {
-            self.curves[i].tangent_at(t).scale(len)
+    fn flatten_into(
+        &self,
+        buffer: &mut Vec<f32>,
+        layout: &VertexLayout,
+        tolerance: P::Scalar,
+    ) -> usize
+    where
+        P: PlanarPoint,
+    {
+        let mut writer = VertexWriter::new(buffer, layout);
+        self.curves
+            .iter()
+            .for_each(|curve| writer.write(curve, tolerance));
+        writer.count()
+    }
+
+    /// Integrated exactly segment by segment.
+    fn integrate(&self, t0: P::Scalar, t1: P::Scalar, precision: P::Scalar) -> P
+    where
+        P: Distance,
+    {
+        let zero = self.last_point.scale(P::Scalar::zero());
+        if t1 < t0 {
+            return zero.sub(&self.integrate(t1, t0, precision));
         }
+        let count: P::Scalar = NumCast::from(self.curves.len()).unwrap();
+        let (x0, x1) = (t0 * count, t1 * count);
+        let first = x0.floor().max(P::Scalar::zero()).to_usize().unwrap();
+        let last = x1.ceil().to_usize().unwrap().min(self.curves.len());
+        (first..last).fold(zero, |acc, i| {
+            let offset: P::Scalar = NumCast::from(i).unwrap();
+            let a = (x0 - offset).max(P::Scalar::zero());
+            let b = (x1 - offset).min(P::Scalar::one());
+            let part = self.curves[i].integrate(a, b, precision);
+            acc.add(&part.scale(P::Scalar::one() / count))
+        })
     }
 
     fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
@@ -119,3 +361,174 @@ impl<P: Point> Curve<P> for ComposedCurve<P> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bezier0, Distance};
+
+    #[test]
+    fn insert_point_keeps_shape() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.cubic_to([1.0, 2.0], [3.0, 2.0], [4.0, 0.0]);
+        curve.quadratic_to([5.0, -2.0], [6.0, 0.0]);
+        let original = curve.clone();
+
+        let expected = original.value_at(0.3);
+        assert_eq!(curve.insert_point_at(0.3), Some(1));
+        assert_eq!(curve.segments().len(), 3);
+        assert!(curve.segments()[0].end_point().distance(&expected) < 1e-12);
+        assert_eq!(
+            curve.segments()[1].start_point(),
+            curve.segments()[0].end_point()
+        );
+        assert_eq!(curve.end_point(), [6.0, 0.0]);
+
+        for i in 0..=20 {
+            let t = i as f64 / 20.0;
+            let point = original.segments()[0].value_at(t);
+            let nearest = (0..=2000)
+                .map(|j| curve.value_at(j as f64 / 2000.0).distance(&point))
+                .fold(f64::INFINITY, f64::min);
+            assert!(nearest < 1e-2);
+        }
+    }
+
+    #[test]
+    fn hash_geometry() {
+        let mut a = ComposedCurve::new([0.0, 0.0]);
+        a.line_to([1.0, 0.0]);
+        a.cubic_to([1.5, 0.5], [2.0, 1.0], [2.0, 2.0]);
+        let b = a.map_points(|p| [p[0] + 1e-6, p[1]]);
+        assert_eq!(a.hash_geometry(10), b.hash_geometry(10));
+        assert_ne!(a.hash_geometry(30), b.hash_geometry(30));
+
+        // Same control points, different segments
+        let mut c = ComposedCurve::new([0.0, 0.0]);
+        c.quadratic_to([1.0, 0.0], [1.5, 0.5]);
+        c.quadratic_to([2.0, 1.0], [2.0, 2.0]);
+        assert_ne!(a.hash_geometry(10), c.hash_geometry(10));
+
+        // Stable across runs and platforms
+        let mut line = ComposedCurve::new([0.0, 0.0]);
+        line.line_to([1.0, 0.0]);
+        assert_eq!(line.hash_geometry(8), 0x0a79_6e18_246c_e761);
+    }
+
+    #[test]
+    fn try_from_segments() {
+        let segments = vec![
+            Bezier::C1(Bezier1::new([0.0, 0.0], [1.0, 0.0])),
+            Bezier::C2(Bezier2::new([1.0, 0.0], [2.0, 0.0], [2.0, 1.0])),
+        ];
+        let curve = ComposedCurve::try_from_segments(segments.clone()).unwrap();
+        assert_eq!(curve.segments(), &segments[..]);
+        assert_eq!(curve.end_point(), [2.0, 1.0]);
+
+        let mut broken = segments.clone();
+        broken[1] = Bezier::C1(Bezier1::new([1.0, 1.0], [2.0, 1.0]));
+        assert_eq!(
+            ComposedCurve::try_from(broken),
+            Err(Error::Curve(CurveError::Discontinuous { segment: 1 }))
+        );
+        assert_eq!(
+            ComposedCurve::<[f64; 2]>::try_from_segments(Vec::new()),
+            Err(Error::Curve(CurveError::Empty))
+        );
+        let dotted = vec![
+            Bezier::C0(Bezier0::new([0.0, 0.0])),
+            segments[0],
+            Bezier::C0(Bezier0::new([1.0, 0.0])),
+            segments[1],
+        ];
+        assert_eq!(
+            ComposedCurve::try_from_segments(dotted).unwrap().segments(),
+            &segments[..]
+        );
+        assert_eq!(
+            ComposedCurve::try_from_segments(vec![Bezier::C0(Bezier0::new([1.0, 0.0]))]),
+            Err(Error::Curve(CurveError::Empty))
+        );
+        assert!(Bezier3::try_new(0.0, 1.0, f64::NAN, 2.0).is_err());
+        assert_eq!(Bezier1::try_new(0.0, 1.0), Ok(Bezier1::new(0.0, 1.0)));
+    }
+
+    #[test]
+    fn tangents_at_joints() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([1.0, 0.0]);
+        curve.line_to([1.0, 1.0]);
+        assert_eq!(curve.tangent_at_left(0.5), [2.0, 0.0]);
+        assert_eq!(curve.tangent_at_right(0.5), [0.0, 2.0]);
+        assert_eq!(curve.tangent_at(0.5), [1.0, 1.0]);
+        assert_eq!(curve.tangent_at(0.25), [2.0, 0.0]);
+        assert_eq!(curve.tangent_at_left(0.0), [2.0, 0.0]);
+        assert_eq!(curve.tangent_at_right(1.0), [0.0, 2.0]);
+        assert_eq!(curve.tangent_at(1.0), [0.0, 2.0]);
+    }
+
+    #[test]
+    fn tangent_by_length() {
+        // Smooth joint of a short and a long line
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([1.0, 0.0]);
+        curve.line_to([4.0, 0.0]);
+        assert_eq!(curve.tangent_at_left(0.5), [2.0, 0.0]);
+        assert_eq!(curve.tangent_at_right(0.5), [6.0, 0.0]);
+        for t in [0.25, 0.5, 0.75, 1.0] {
+            let tangent = curve.tangent_at_by_length(t, 1e-6);
+            assert!(tangent.distance(&[4.0, 0.0]) < 1e-9);
+        }
+    }
+
+    #[test]
+    fn insert_point_at_anchor() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([1.0, 0.0]);
+        curve.line_to([1.0, 1.0]);
+        assert_eq!(curve.insert_point_at(0.5), None);
+        assert_eq!(curve.insert_point_at(1.0), None);
+        assert_eq!(curve.insert_point_at(0.0), None);
+        assert_eq!(curve.insert_point_at(f64::NAN), None);
+        assert_eq!(curve.segments().len(), 2);
+
+        assert_eq!(ComposedCurve::new([0.0, 0.0]).insert_point_at(0.5), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_test::{assert_tokens, Token};
+
+        let mut curve = ComposedCurve::new(0.0);
+        curve.line_to(1.0);
+        assert_tokens(
+            &curve,
+            &[
+                Token::Struct {
+                    name: "ComposedCurve",
+                    len: 2,
+                },
+                Token::Str("last_point"),
+                Token::F64(1.0),
+                Token::Str("curves"),
+                Token::Seq { len: Some(1) },
+                Token::NewtypeVariant {
+                    name: "Bezier",
+                    variant: "C1",
+                },
+                Token::Struct {
+                    name: "Bezier1",
+                    len: 2,
+                },
+                Token::Str("p0"),
+                Token::F64(0.0),
+                Token::Str("p1"),
+                Token::F64(1.0),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
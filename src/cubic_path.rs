@@ -0,0 +1,139 @@
+use crate::{Bezier3, ComposedCurve, Curve, Distance, Point};
+use num_traits::{NumCast, One, Zero};
+
+/// Limit of interval halving, a curve is split into at most `2^MAX_DEPTH` cubics.
+const MAX_DEPTH: usize = 16;
+
+/// Points inside every piece compared with the curve.
+const CHECKS: usize = 5;
+
+/// Converts the curve into cubics, see [`Curve::to_cubic_path`].
+pub(crate) fn to_cubic_path<P: Distance, C: Curve<P> + ?Sized>(
+    curve: &C,
+    tolerance: P::Scalar,
+) -> ComposedCurve<P> {
+    let mut path = ComposedCurve::new(curve.start_point());
+    let start = (
+        P::Scalar::zero(),
+        curve.value_at(P::Scalar::zero()),
+        curve.tangent_at(P::Scalar::zero()),
+    );
+    let end = (
+        P::Scalar::one(),
+        curve.value_at(P::Scalar::one()),
+        curve.tangent_at(P::Scalar::one()),
+    );
+    approximate(curve, &start, &end, tolerance, MAX_DEPTH, &mut path);
+    path
+}
+
+/// Parameter, point, and tangent.
+type Sample<P> = (<P as Point>::Scalar, P, P);
+
+fn approximate<P: Distance, C: Curve<P> + ?Sized>(
+    curve: &C,
+    start: &Sample<P>,
+    end: &Sample<P>,
+    tolerance: P::Scalar,
+    depth: usize,
+    path: &mut ComposedCurve<P>,
+) {
+    let cubic = hermite(start, end);
+    let width = end.0 - start.0;
+    let fits = (1..=CHECKS).all(|i| {
+        let s: P::Scalar = NumCast::from(i as f64 / (CHECKS + 1) as f64).unwrap();
+        curve
+            .value_at(start.0 + width * s)
+            .distance(&cubic.value_at(s))
+            <= tolerance
+    });
+
+    if fits || depth == 0 {
+        path.cubic_to(cubic.p1, cubic.p2, cubic.p3);
+    } else {
+        let t = start.0 + width / (P::Scalar::one() + P::Scalar::one());
+        let middle = (t, curve.value_at(t), curve.tangent_at(t));
+        approximate(curve, start, &middle, tolerance, depth - 1, path);
+        approximate(curve, &middle, end, tolerance, depth - 1, path);
+    }
+}
+
+/// The cubic with the same end points and derivatives as the curve between two samples,
+/// it follows the curve parametrization.
+fn hermite<P: Point>(start: &Sample<P>, end: &Sample<P>) -> Bezier3<P> {
+    let third = (end.0 - start.0) / NumCast::from(3).unwrap();
+    Bezier3::new(
+        start.1.clone(),
+        start.1.add(&start.2.scale(third)),
+        end.1.sub(&end.2.scale(third)),
+        end.1.clone(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Arc, Bezier, Bezier1, Bezier2, ComposedCurve, Curve, Distance, InnerProduct, Jitter, Point,
+    };
+    use approx::assert_relative_eq;
+
+    /// The largest distance from curve samples to the flattened path.
+    fn max_deviation<C: Curve<[f64; 2]>>(
+        curve: &C,
+        path: &ComposedCurve<[f64; 2]>,
+        tolerance: f64,
+    ) -> f64 {
+        let polyline = path.flatten(tolerance / 10.0);
+        (0..=200)
+            .map(|i| {
+                let p = curve.value_at(i as f64 / 200.0);
+                polyline
+                    .windows(2)
+                    .map(|w| {
+                        let d = w[1].sub(&w[0]);
+                        let s = (p.sub(&w[0]).dot(&d) / d.dot(&d)).clamp(0.0, 1.0);
+                        w[0].lerp(&w[1], s).distance(&p)
+                    })
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn cubic_stays_single() {
+        let quadratic = Bezier2::new([0.0, 0.0], [1.0, 2.0], [2.0, 0.0]);
+        let path = quadratic.to_cubic_path(1e-6);
+        assert_eq!(path.segments().len(), 1);
+        assert!(matches!(path.segments()[0], Bezier::C3(_)));
+        assert_relative_eq!(
+            path.value_at(0.3).distance(&quadratic.value_at(0.3)),
+            0.0,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn arc() {
+        let arc = Arc::new([0.0, 0.0], [10.0, 0.0], 3.0);
+        for tolerance in [0.1, 0.001] {
+            let path = arc.to_cubic_path(tolerance);
+            assert!(max_deviation(&arc, &path, tolerance) < tolerance);
+            assert_eq!(path.start_point(), arc.start_point());
+            assert_relative_eq!(
+                path.end_point().distance(&arc.end_point()),
+                0.0,
+                epsilon = 1e-12
+            );
+        }
+        assert!(
+            arc.to_cubic_path(0.001).segments().len() > arc.to_cubic_path(0.1).segments().len()
+        );
+    }
+
+    #[test]
+    fn jitter() {
+        let curve = Jitter::new(Bezier1::new([0.0, 0.0], [10.0, 0.0]), 0.5, 6.0, 3);
+        let path = curve.to_cubic_path(0.01);
+        assert!(max_deviation(&curve, &path, 0.01) < 0.01);
+    }
+}
@@ -1,18 +1,75 @@
+use crate::arithmetic::{Clamp, Maximum, Minimum, Negation, Product, Sum};
+#[cfg(feature = "bevy")]
+use crate::bevy_interop::BevyCurve;
 use crate::bezier::{Bezier0, Bezier1, Bezier2, Bezier3};
+#[cfg(feature = "alloc")]
 use crate::composed_curve::ComposedCurve;
+#[cfg(feature = "alloc")]
+use crate::cubic_path::to_cubic_path;
 use crate::curve_iterator::CurveIterator;
+use crate::extend::{extended_tangent_at, extended_value_at, ExtendMode, Extended};
+#[cfg(feature = "alloc")]
+use crate::flatten::flatten;
+use crate::integrate::adaptive_simpson;
+use crate::jitter::Jitter;
+#[cfg(feature = "alloc")]
 use crate::linear_speed::LinearSpeed;
+#[cfg(feature = "alloc")]
+use crate::memoized_curve::MemoizedCurve;
+use crate::offset_curve::OffsetCurve;
+use crate::playback::{Delay, PingPong, Repeat, TimeScale};
 use crate::point::Point;
-use crate::Distance;
-use num_traits::{One, Zero};
+#[cfg(feature = "alloc")]
+use crate::similarity::hausdorff_distance;
+#[cfg(feature = "alloc")]
+use crate::vertex_buffer::{VertexLayout, VertexWriter};
+#[cfg(feature = "alloc")]
+use crate::InnerProduct;
+use crate::{Distance, PlanarPoint, Scalar, Tolerance};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use num_traits::{NumCast, One, Zero};
+
+/// Precision of the length estimate that converts between relative and absolute tolerances.
+const ROUGH_PRECISION: f64 = 0.05;
 
 /// A curve is a parametric function that maps a value `t` in range from 0 to 1 to a point in space.
 pub trait Curve<P: Point> {
     /// Get the point at a given value `t` in range from 0 to 1.
+    /// Values outside of the range are handled differently by different curves,
+    /// use [`Curve::value_at_extended`] for a defined behavior.
     fn value_at(&self, t: P::Scalar) -> P;
     /// Get the derivative at a given value `t` in range from 0 to 1.
     fn tangent_at(&self, t: P::Scalar) -> P;
 
+    /// Get the points at every `t` from `ts` and write them into `out`.
+    /// Both slices must have the same length.
+    fn value_at_many(&self, ts: &[P::Scalar], out: &mut [P]) {
+        assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+        for (t, p) in ts.iter().zip(out.iter_mut()) {
+            *p = self.value_at(*t);
+        }
+    }
+
+    /// Get the derivatives at every `t` from `ts` and write them into `out`.
+    /// Both slices must have the same length.
+    fn tangent_at_many(&self, ts: &[P::Scalar], out: &mut [P]) {
+        assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+        for (t, p) in ts.iter().zip(out.iter_mut()) {
+            *p = self.tangent_at(*t);
+        }
+    }
+
+    /// Get the point at `t`, values outside of the `[0, 1]` range are handled with `mode`.
+    fn value_at_extended(&self, t: P::Scalar, mode: ExtendMode) -> P {
+        extended_value_at(self, t, mode)
+    }
+
+    /// Get the derivative at `t`, values outside of the `[0, 1]` range are handled with `mode`.
+    fn tangent_at_extended(&self, t: P::Scalar, mode: ExtendMode) -> P {
+        extended_tangent_at(self, t, mode)
+    }
+
     fn start_point(&self) -> P {
         self.value_at(P::Scalar::zero())
     }
@@ -30,27 +87,145 @@ pub trait Curve<P: Point> {
     ///   * **0.5** - the same as above, but the difference is `50%`,
     ///   * **0.1** - the same as above, but the difference is `10%`,
     ///   * and so on...
+    ///
+    /// Bezier curves are subdivided at most 16 levels deep, so an unreachable precision,
+    /// e.g. zero, still terminates. Non-finite control points give a non-finite length.
+    ///
+    /// See [`Curve::length_within`] for a length accurate to a given distance.
     fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
     where
         P: Distance;
 
+    /// Length of the curve within `tolerance`, unlike [`Curve::estimate_length`] it can also
+    /// be accurate to a given distance, e.g. `Tolerance::Absolute(0.01)`.
+    fn length_within(&self, tolerance: Tolerance<P::Scalar>) -> P::Scalar
+    where
+        P: Distance,
+    {
+        let precision = match tolerance {
+            Tolerance::Relative(fraction) => fraction,
+            Tolerance::Absolute(_) => {
+                let rough = self.estimate_length(NumCast::from(ROUGH_PRECISION).unwrap());
+                tolerance.relative_for(rough)
+            }
+        };
+        self.estimate_length(precision)
+    }
+
+    /// Definite integral of the curve values by `t` from `t0` to `t1`, e.g. the distance travelled
+    /// for a speed curve. Bezier curves are integrated exactly, other curves with adaptive quadrature
+    /// stopping when the absolute error estimate is below `precision`.
+    fn integrate(&self, t0: P::Scalar, t1: P::Scalar, precision: P::Scalar) -> P
+    where
+        P: Distance,
+    {
+        adaptive_simpson(self, t0, t1, precision)
+    }
+
+    /// Approximate the curve with a polyline.
+    /// The distance between the curve and the polyline is expected to be less than `tolerance`.
+    #[cfg(feature = "alloc")]
+    fn flatten(&self, tolerance: P::Scalar) -> Vec<P>
+    where
+        P: Distance,
+    {
+        flatten(self, tolerance)
+    }
+
+    /// Approximate the curve with a polyline within `tolerance`, a relative tolerance
+    /// is a fraction of the curve length.
+    #[cfg(feature = "alloc")]
+    fn flatten_within(&self, tolerance: Tolerance<P::Scalar>) -> Vec<P>
+    where
+        P: Distance,
+    {
+        let tolerance = match tolerance {
+            Tolerance::Absolute(tolerance) => tolerance,
+            Tolerance::Relative(_) => {
+                let rough = self.estimate_length(NumCast::from(ROUGH_PRECISION).unwrap());
+                tolerance.absolute_for(rough)
+            }
+        };
+        flatten(self, tolerance)
+    }
+
+    /// Approximate the curve with cubic bezier curves within `tolerance`, e.g. to export
+    /// a custom curve type to SVG. Every cubic matches the curve points and derivatives at its ends.
+    #[cfg(feature = "alloc")]
+    fn to_cubic_path(&self, tolerance: P::Scalar) -> ComposedCurve<P>
+    where
+        P: Distance,
+    {
+        to_cubic_path(self, tolerance)
+    }
+
+    /// Flatten the curve like [`Curve::flatten`] and append the vertices to an interleaved `f32` buffer
+    /// according to `layout`, returns the number of written vertices.
+    #[cfg(feature = "alloc")]
+    fn flatten_into(
+        &self,
+        buffer: &mut Vec<f32>,
+        layout: &VertexLayout,
+        tolerance: P::Scalar,
+    ) -> usize
+    where
+        P: PlanarPoint,
+    {
+        let mut writer = VertexWriter::new(buffer, layout);
+        writer.write(self, tolerance);
+        writer.count()
+    }
+
+    /// Check if two curves have the same shape within `tolerance`, regardless of parameterization.
+    /// Curves must start and end at the same points, and every point of one curve
+    /// must be closer than `tolerance` to the other curve.
+    #[cfg(feature = "alloc")]
+    fn is_close<C: Curve<P> + ?Sized>(&self, other: &C, tolerance: P::Scalar) -> bool
+    where
+        Self: Sized,
+        P: InnerProduct,
+    {
+        let quarter = P::Scalar::one() / NumCast::from(4).unwrap();
+        self.start_point().distance(&other.start_point()) <= tolerance
+            && self.end_point().distance(&other.end_point()) <= tolerance
+            && hausdorff_distance(self, other, tolerance * quarter) <= tolerance
+    }
+
+    /// Control points of the curve, empty for curves that aren't defined by control points.
+    #[cfg(feature = "alloc")]
+    fn control_points(&self) -> Vec<P> {
+        Vec::new()
+    }
+
     /// Create a dot, at any `t` it will return the same value
-    fn dot(p0: P) -> Bezier0<P> {
+    fn dot(p0: P) -> Bezier0<P>
+    where
+        Self: Sized,
+    {
         Bezier0::new(p0)
     }
 
     /// Create a line
-    fn line(p0: P, p1: P) -> Bezier1<P> {
+    fn line(p0: P, p1: P) -> Bezier1<P>
+    where
+        Self: Sized,
+    {
         Bezier1::new(p0, p1)
     }
 
     /// Create a quadratic bezier curve
-    fn quad_bezier(p0: P, p1: P, p2: P) -> Bezier2<P> {
+    fn quad_bezier(p0: P, p1: P, p2: P) -> Bezier2<P>
+    where
+        Self: Sized,
+    {
         Bezier2::new(p0, p1, p2)
     }
 
     /// Create a cubic bezier curve
-    fn cubic_bezier(p0: P, p1: P, p2: P, p3: P) -> Bezier3<P> {
+    fn cubic_bezier(p0: P, p1: P, p2: P, p3: P) -> Bezier3<P>
+    where
+        Self: Sized,
+    {
         Bezier3::new(p0, p1, p2, p3)
     }
 
@@ -73,7 +248,11 @@ pub trait Curve<P: Point> {
     /// Create a composed curve that will be a sequence of curves.
     /// Each segment of the curve will be represented by equal `t` range.
     /// For example, if you have three curves, they will take `t` ranges: `0 - 0.33`, `0.33 - 0.66` and `0.66 - 1.0`.
-    fn composed_curve(start_point: P) -> ComposedCurve<P> {
+    #[cfg(feature = "alloc")]
+    fn composed_curve(start_point: P) -> ComposedCurve<P>
+    where
+        Self: Sized,
+    {
         ComposedCurve::new(start_point)
     }
 
@@ -82,10 +261,15 @@ pub trait Curve<P: Point> {
     ///
     /// Arguments:
     /// * `table_size` - the size of the table that will be used to speed up the calculations,
-    ///     the bigger means the better the precision.
+    ///   the bigger means the better the precision.
     /// * `steps_count` - the number of steps that will be used to calculate the table,
-    ///     so if you have 3 steps then the curve points will be calculated at 0.0, 0.5 and 1.0.
-    ///     Intermediate points will be interpolated.
+    ///   so if you have 3 steps then the curve points will be calculated at 0.0, 0.5 and 1.0.
+    ///   Intermediate points will be interpolated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `table_size` is less than 2.
+    #[cfg(feature = "alloc")]
     fn linear_speed(self, table_size: usize, steps_count: usize) -> LinearSpeed<P, Self>
     where
         P: Distance,
@@ -93,4 +277,270 @@ pub trait Curve<P: Point> {
     {
         LinearSpeed::new(self, table_size, steps_count)
     }
+
+    /// Remember the values at `resolution + 1` parameters, see [`MemoizedCurve`].
+    #[cfg(feature = "alloc")]
+    fn memoized(self, resolution: usize) -> MemoizedCurve<P, Self>
+    where
+        Self: Sized,
+    {
+        MemoizedCurve::new(self, resolution)
+    }
+
+    /// Add smooth noise to the curve, see [`Jitter`].
+    fn jitter(self, amplitude: P::Scalar, frequency: P::Scalar, seed: u64) -> Jitter<P, Self>
+    where
+        P: PlanarPoint,
+        Self: Sized,
+    {
+        Jitter::new(self, amplitude, frequency, seed)
+    }
+
+    /// Use the curve as a `bevy_math` curve, see [`BevyCurve`].
+    #[cfg(feature = "bevy")]
+    fn into_bevy(self) -> BevyCurve<P, Self>
+    where
+        P: Point<Scalar = f32>,
+        Self: Sized,
+    {
+        BevyCurve::new(self)
+    }
+
+    /// Move the curve by `distance` along its normal, see [`OffsetCurve`].
+    fn offset_curve(self, distance: P::Scalar) -> OffsetCurve<P, Self>
+    where
+        P: PlanarPoint,
+        Self: Sized,
+    {
+        OffsetCurve::new(self, distance)
+    }
+
+    /// Evaluate the curve outside of the `[0, 1]` range with `mode`, see [`Extended`].
+    fn with_extend_mode(self, mode: ExtendMode) -> Extended<P, Self>
+    where
+        Self: Sized,
+    {
+        Extended::new(self, mode)
+    }
+
+    /// Play the curve `count` times, see [`Repeat`].
+    fn repeat(self, count: usize) -> Repeat<P, Self>
+    where
+        Self: Sized,
+    {
+        Repeat::new(self, count)
+    }
+
+    /// Play the curve forward and backward `count` times, see [`PingPong`].
+    fn ping_pong(self, count: usize) -> PingPong<P, Self>
+    where
+        Self: Sized,
+    {
+        PingPong::new(self, count)
+    }
+
+    /// Hold the start point for the first `delay` part of `t`, see [`Delay`].
+    fn delay(self, delay: P::Scalar) -> Delay<P, Self>
+    where
+        Self: Sized,
+    {
+        Delay::new(self, delay)
+    }
+
+    /// Play the curve `scale` times faster, see [`TimeScale`].
+    fn time_scale(self, scale: P::Scalar) -> TimeScale<P, Self>
+    where
+        Self: Sized,
+    {
+        TimeScale::new(self, scale)
+    }
+
+    /// Add values of another curve, see [`Sum`].
+    fn sum<C: Curve<P>>(self, other: C) -> Sum<P, Self, C>
+    where
+        Self: Sized,
+    {
+        Sum::new(self, other)
+    }
+
+    /// Multiply by values of another curve, see [`Product`].
+    fn product<C: Curve<P>>(self, other: C) -> Product<P, Self, C>
+    where
+        Self: Sized,
+    {
+        Product::new(self, other)
+    }
+
+    /// The smaller of this and another scalar curve, see [`Minimum`].
+    fn minimum<C: Curve<P>>(self, other: C) -> Minimum<P, Self, C>
+    where
+        P: Scalar + Point<Scalar = P>,
+        Self: Sized,
+    {
+        Minimum::new(self, other)
+    }
+
+    /// The larger of this and another scalar curve, see [`Maximum`].
+    fn maximum<C: Curve<P>>(self, other: C) -> Maximum<P, Self, C>
+    where
+        P: Scalar + Point<Scalar = P>,
+        Self: Sized,
+    {
+        Maximum::new(self, other)
+    }
+
+    /// Keep values of a scalar curve between two bound curves, see [`Clamp`].
+    fn clamp_between<L: Curve<P>, H: Curve<P>>(self, min: L, max: H) -> Clamp<P, Self, L, H>
+    where
+        P: Scalar + Point<Scalar = P>,
+        Self: Sized,
+    {
+        Clamp::new(self, min, max)
+    }
+
+    /// Negate values of the curve, see [`Negation`].
+    fn negate(self) -> Negation<P, Self>
+    where
+        Self: Sized,
+    {
+        Negation::new(self)
+    }
+}
+
+/// Curves are also curves by reference, so adaptors like [`LinearSpeed`] can borrow
+/// a large curve instead of taking it, e.g. `LinearSpeed::new(&path, 256, 1000)`.
+impl<P: Point, C: Curve<P> + ?Sized> Curve<P> for &C {
+    fn value_at(&self, t: P::Scalar) -> P {
+        (**self).value_at(t)
+    }
+
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        (**self).tangent_at(t)
+    }
+
+    fn value_at_many(&self, ts: &[P::Scalar], out: &mut [P]) {
+        (**self).value_at_many(ts, out)
+    }
+
+    fn tangent_at_many(&self, ts: &[P::Scalar], out: &mut [P]) {
+        (**self).tangent_at_many(ts, out)
+    }
+
+    fn value_at_extended(&self, t: P::Scalar, mode: ExtendMode) -> P {
+        (**self).value_at_extended(t, mode)
+    }
+
+    fn tangent_at_extended(&self, t: P::Scalar, mode: ExtendMode) -> P {
+        (**self).tangent_at_extended(t, mode)
+    }
+
+    fn start_point(&self) -> P {
+        (**self).start_point()
+    }
+
+    fn end_point(&self) -> P {
+        (**self).end_point()
+    }
+
+    fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        (**self).estimate_length(precision)
+    }
+
+    fn length_within(&self, tolerance: Tolerance<P::Scalar>) -> P::Scalar
+    where
+        P: Distance,
+    {
+        (**self).length_within(tolerance)
+    }
+
+    fn integrate(&self, t0: P::Scalar, t1: P::Scalar, precision: P::Scalar) -> P
+    where
+        P: Distance,
+    {
+        (**self).integrate(t0, t1, precision)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn flatten(&self, tolerance: P::Scalar) -> Vec<P>
+    where
+        P: Distance,
+    {
+        (**self).flatten(tolerance)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn flatten_within(&self, tolerance: Tolerance<P::Scalar>) -> Vec<P>
+    where
+        P: Distance,
+    {
+        (**self).flatten_within(tolerance)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_cubic_path(&self, tolerance: P::Scalar) -> ComposedCurve<P>
+    where
+        P: Distance,
+    {
+        (**self).to_cubic_path(tolerance)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn flatten_into(
+        &self,
+        buffer: &mut Vec<f32>,
+        layout: &VertexLayout,
+        tolerance: P::Scalar,
+    ) -> usize
+    where
+        P: PlanarPoint,
+    {
+        (**self).flatten_into(buffer, layout, tolerance)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn control_points(&self) -> Vec<P> {
+        (**self).control_points()
+    }
+}
+
+/// Upper limit for the number of steps in [`polyline_length`].
+const MAX_POLYLINE_STEPS: usize = 1 << 16;
+
+/// Length of the part of `curve` between `t0` and `t1`, measured by polylines with twice as many
+/// steps every time (starting from `min_steps`) until the relative change is below `precision`.
+pub(crate) fn polyline_length<P: Distance, C: Curve<P> + ?Sized>(
+    curve: &C,
+    t0: P::Scalar,
+    t1: P::Scalar,
+    min_steps: usize,
+    precision: P::Scalar,
+) -> P::Scalar {
+    let measure = |steps: usize| {
+        let step: P::Scalar = (t1 - t0) / NumCast::from(steps).unwrap();
+        let mut previous = curve.value_at(t0);
+        let mut length = P::Scalar::zero();
+        for i in 1..=steps {
+            let i: P::Scalar = NumCast::from(i).unwrap();
+            let point = curve.value_at(t0 + step * i);
+            length = length + previous.distance(&point);
+            previous = point;
+        }
+        length
+    };
+
+    let mut steps = min_steps.max(1);
+    let mut length = measure(steps);
+    while steps < MAX_POLYLINE_STEPS {
+        steps *= 2;
+        let refined = measure(steps);
+        let converged = refined - length <= precision * refined;
+        length = refined;
+        if converged {
+            break;
+        }
+    }
+    length
 }
@@ -1,7 +1,7 @@
 use crate::{Curve, Point};
+use core::fmt::Debug;
+use core::marker::PhantomData;
 use num_traits::{NumCast, One, Zero};
-use std::fmt::Debug;
-use std::marker::PhantomData;
 
 #[derive(Clone, PartialEq)]
 pub struct CurveIterator<P: Point, C: Curve<P>> {
@@ -16,7 +16,7 @@ impl<P: Point + Debug, C: Curve<P> + Debug> Debug for CurveIterator<P, C>
 where
     P::Scalar: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("CurveIterator")
             .field("curve", &self.curve)
             .field("steps_count", &self.steps_count)
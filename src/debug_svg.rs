@@ -0,0 +1,197 @@
+use crate::{Curve, PlanarPoint};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use num_traits::{NumCast, ToPrimitive};
+
+/// Colors used for curves, the palette is repeated if there are more curves.
+const PALETTE: [&str; 6] = [
+    "#1f77b4", "#d62728", "#2ca02c", "#9467bd", "#ff7f0e", "#17becf",
+];
+
+/// Options of [`debug_svg`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DebugSvgOptions {
+    /// Size of the output image in pixels, curves are scaled to fit it.
+    pub width: f64,
+    pub height: f64,
+    pub padding: f64,
+    pub stroke_width: f64,
+    /// Number of steps used to draw every curve.
+    pub steps_count: usize,
+    /// Number of sample points marked on every curve, `0` disables them.
+    pub samples_count: usize,
+    /// Draw polygons connecting control points.
+    pub control_polygons: bool,
+}
+
+impl Default for DebugSvgOptions {
+    fn default() -> Self {
+        Self {
+            width: 512.0,
+            height: 512.0,
+            padding: 16.0,
+            stroke_width: 1.5,
+            steps_count: 256,
+            samples_count: 11,
+            control_polygons: true,
+        }
+    }
+}
+
+/// Render curves into a standalone SVG document, useful for visual debugging of geometric algorithms.
+/// Curves, their control polygons, and sample points at uniformly distributed `t` values are drawn.
+/// The image uses SVG orientation of axes, `y` grows down.
+pub fn debug_svg<P: PlanarPoint>(curves: &[&dyn Curve<P>], options: &DebugSvgOptions) -> String {
+    let sample = |curve: &dyn Curve<P>, steps_count: usize| -> Vec<(f64, f64)> {
+        let steps: P::Scalar = NumCast::from(steps_count.max(1)).unwrap();
+        (0..=steps_count.max(1))
+            .map(|i| {
+                let t = <P::Scalar as NumCast>::from(i).unwrap() / steps;
+                to_f64(&curve.value_at(t))
+            })
+            .collect()
+    };
+
+    let lines: Vec<Vec<(f64, f64)>> = curves
+        .iter()
+        .map(|curve| sample(*curve, options.steps_count))
+        .collect();
+    let polygons: Vec<Vec<(f64, f64)>> = curves
+        .iter()
+        .map(|curve| curve.control_points().iter().map(to_f64).collect())
+        .collect();
+
+    let (mut min_x, mut min_y) = (f64::INFINITY, f64::INFINITY);
+    let (mut max_x, mut max_y) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+    lines
+        .iter()
+        .chain(polygons.iter().filter(|_| options.control_polygons))
+        .flatten()
+        .for_each(|&(x, y)| {
+            min_x = min_x.min(x);
+            min_y = min_y.min(y);
+            max_x = max_x.max(x);
+            max_y = max_y.max(y);
+        });
+    if min_x > max_x {
+        (min_x, min_y, max_x, max_y) = (0.0, 0.0, 1.0, 1.0);
+    }
+
+    let inner_width = (options.width - options.padding * 2.0).max(1.0);
+    let inner_height = (options.height - options.padding * 2.0).max(1.0);
+    let scale = (inner_width / (max_x - min_x).max(f64::EPSILON))
+        .min(inner_height / (max_y - min_y).max(f64::EPSILON));
+    let offset_x = options.padding + (inner_width - (max_x - min_x) * scale) / 2.0;
+    let offset_y = options.padding + (inner_height - (max_y - min_y) * scale) / 2.0;
+    let transform = |&(x, y): &(f64, f64)| {
+        (
+            offset_x + (x - min_x) * scale,
+            offset_y + (y - min_y) * scale,
+        )
+    };
+
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+        w = options.width,
+        h = options.height,
+    )
+    .unwrap();
+    writeln!(svg, r#"<rect width="100%" height="100%" fill="white"/>"#).unwrap();
+
+    for (i, line) in lines.iter().enumerate() {
+        let color = PALETTE[i % PALETTE.len()];
+
+        if options.control_polygons && polygons[i].len() > 1 {
+            writeln!(
+                svg,
+                r#"<polyline points="{}" fill="none" stroke="{}" stroke-opacity="0.5" stroke-width="{}" stroke-dasharray="4 3"/>"#,
+                points_attribute(polygons[i].iter().map(transform)),
+                color,
+                options.stroke_width / 2.0,
+            )
+            .unwrap();
+            for p in polygons[i].iter().map(transform) {
+                writeln!(
+                    svg,
+                    r#"<rect x="{:.3}" y="{:.3}" width="{s}" height="{s}" fill="none" stroke="{}" stroke-width="{}"/>"#,
+                    p.0 - options.stroke_width * 1.5,
+                    p.1 - options.stroke_width * 1.5,
+                    color,
+                    options.stroke_width / 2.0,
+                    s = options.stroke_width * 3.0,
+                )
+                .unwrap();
+            }
+        }
+
+        writeln!(
+            svg,
+            r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{}"/>"#,
+            points_attribute(line.iter().map(transform)),
+            color,
+            options.stroke_width,
+        )
+        .unwrap();
+
+        if options.samples_count > 0 {
+            let samples = sample(curves[i], options.samples_count.max(2) - 1);
+            for p in samples.iter().map(transform) {
+                writeln!(
+                    svg,
+                    r#"<circle cx="{:.3}" cy="{:.3}" r="{}" fill="{}"/>"#,
+                    p.0,
+                    p.1,
+                    options.stroke_width * 1.5,
+                    color,
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn to_f64<P: PlanarPoint>(p: &P) -> (f64, f64) {
+    (p.x().to_f64().unwrap(), p.y().to_f64().unwrap())
+}
+
+fn points_attribute(points: impl Iterator<Item = (f64, f64)>) -> String {
+    let mut result = String::new();
+    for (i, (x, y)) in points.enumerate() {
+        if i > 0 {
+            result.push(' ');
+        }
+        write!(result, "{:.3},{:.3}", x, y).unwrap();
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bezier1, Bezier3};
+
+    #[test]
+    fn render() {
+        let cubic = Bezier3::new([0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]);
+        let line = Bezier1::new([0.0, 0.0], [1.0, 0.0]);
+
+        let options = DebugSvgOptions {
+            samples_count: 3,
+            ..Default::default()
+        };
+        let svg = debug_svg(&[&cubic, &line], &options);
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<circle").count(), 6);
+        // two curves and two control polygons, the line polygon is drawn as well
+        assert_eq!(svg.matches("<polyline").count(), 4);
+        assert!(svg.contains(r#"cx="16.000" cy="16.000""#));
+    }
+}
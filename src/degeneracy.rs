@@ -0,0 +1,154 @@
+use crate::geometry::distance_to_segment;
+use crate::{Bezier, Bezier0, Bezier1, Bezier2, Bezier3, Distance, InnerProduct};
+use num_traits::{NumCast, One};
+
+impl<P: InnerProduct> Bezier0<P> {
+    /// Always `true`, a single point.
+    pub fn is_point(&self, _tolerance: P::Scalar) -> bool {
+        true
+    }
+
+    /// Always `true`, a point is a line of zero length.
+    pub fn is_linear(&self, _tolerance: P::Scalar) -> bool {
+        true
+    }
+
+    /// Always `true`, a point can be represented by a quadratic.
+    pub fn is_effectively_quadratic(&self, _tolerance: P::Scalar) -> bool {
+        true
+    }
+}
+
+impl<P: InnerProduct> Bezier1<P> {
+    /// Check if the end points are closer than `tolerance`.
+    pub fn is_point(&self, tolerance: P::Scalar) -> bool {
+        self.p0.distance(&self.p1) <= tolerance
+    }
+
+    /// Always `true`.
+    pub fn is_linear(&self, _tolerance: P::Scalar) -> bool {
+        true
+    }
+
+    /// Always `true`, a line can be represented by a quadratic.
+    pub fn is_effectively_quadratic(&self, _tolerance: P::Scalar) -> bool {
+        true
+    }
+}
+
+impl<P: InnerProduct> Bezier2<P> {
+    /// Check if all control points are closer than `tolerance` to the start point.
+    pub fn is_point(&self, tolerance: P::Scalar) -> bool {
+        self.p0.distance(&self.p1) <= tolerance && self.p0.distance(&self.p2) <= tolerance
+    }
+
+    /// Check if the control point is closer than `tolerance` to the line segment between
+    /// the end points, so the whole curve is within `tolerance` from it.
+    pub fn is_linear(&self, tolerance: P::Scalar) -> bool {
+        distance_to_segment(&self.p1, &self.p0, &self.p2) <= tolerance
+    }
+
+    /// Always `true`.
+    pub fn is_effectively_quadratic(&self, _tolerance: P::Scalar) -> bool {
+        true
+    }
+}
+
+impl<P: InnerProduct> Bezier3<P> {
+    /// Check if all control points are closer than `tolerance` to the start point.
+    pub fn is_point(&self, tolerance: P::Scalar) -> bool {
+        [&self.p1, &self.p2, &self.p3]
+            .into_iter()
+            .all(|p| self.p0.distance(p) <= tolerance)
+    }
+
+    /// Check if both middle control points are closer than `tolerance` to the line segment
+    /// between the end points, so the whole curve is within `tolerance` from it.
+    pub fn is_linear(&self, tolerance: P::Scalar) -> bool {
+        distance_to_segment(&self.p1, &self.p0, &self.p3) <= tolerance
+            && distance_to_segment(&self.p2, &self.p0, &self.p3) <= tolerance
+    }
+
+    /// Check if the curve is closer than `tolerance` to the quadratic with the same end points.
+    pub fn is_effectively_quadratic(&self, tolerance: P::Scalar) -> bool {
+        self.quadratic_error() <= tolerance
+    }
+
+    /// Upper bound of the distance to the quadratic with the same end points and the control point
+    /// `(3 (p1 + p2) - p0 - p3) / 4`, it's `sqrt(3) / 36 * |p3 - 3 p2 + 3 p1 - p0|`.
+    pub(crate) fn quadratic_error(&self) -> P::Scalar {
+        let three = P::Scalar::one() + P::Scalar::one() + P::Scalar::one();
+        let third_difference = self
+            .p3
+            .sub(&self.p0)
+            .add(&self.p1.sub(&self.p2).scale(three))
+            .length();
+        let factor: P::Scalar = NumCast::from(0.048_112_522_432_468_81f64).unwrap(); // sqrt(3) / 36
+        third_difference * factor
+    }
+}
+
+impl<P: InnerProduct> Bezier<P> {
+    /// Check if all control points are closer than `tolerance` to the start point.
+    pub fn is_point(&self, tolerance: P::Scalar) -> bool {
+        match self {
+            Bezier::C0(c) => c.is_point(tolerance),
+            Bezier::C1(c) => c.is_point(tolerance),
+            Bezier::C2(c) => c.is_point(tolerance),
+            Bezier::C3(c) => c.is_point(tolerance),
+        }
+    }
+
+    /// Check if the curve is closer than `tolerance` to the line segment between its end points.
+    pub fn is_linear(&self, tolerance: P::Scalar) -> bool {
+        match self {
+            Bezier::C0(c) => c.is_linear(tolerance),
+            Bezier::C1(c) => c.is_linear(tolerance),
+            Bezier::C2(c) => c.is_linear(tolerance),
+            Bezier::C3(c) => c.is_linear(tolerance),
+        }
+    }
+
+    /// Check if the curve is closer than `tolerance` to a quadratic with the same end points.
+    pub fn is_effectively_quadratic(&self, tolerance: P::Scalar) -> bool {
+        match self {
+            Bezier::C0(c) => c.is_effectively_quadratic(tolerance),
+            Bezier::C1(c) => c.is_effectively_quadratic(tolerance),
+            Bezier::C2(c) => c.is_effectively_quadratic(tolerance),
+            Bezier::C3(c) => c.is_effectively_quadratic(tolerance),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point() {
+        let dot = Bezier3::new([1.0, 1.0], [1.0, 1.0 + 1e-9], [1.0, 1.0], [1.0 + 1e-9, 1.0]);
+        assert!(dot.is_point(1e-6));
+        assert!(dot.is_linear(1e-6));
+        assert!(!Bezier1::new([0.0, 0.0], [1.0, 0.0]).is_point(1e-6));
+    }
+
+    #[test]
+    fn linear() {
+        let line = Bezier3::new([0.0, 0.0], [1.0, 1e-9], [1.5, 0.0], [2.0, 0.0]);
+        assert!(line.is_linear(1e-6));
+        assert!(!line.is_point(1e-6));
+
+        let arch = Bezier2::new([0.0, 0.0], [1.0, 1.0], [2.0, 0.0]);
+        assert!(!arch.is_linear(1e-6));
+        assert!(Bezier::C2(arch).is_effectively_quadratic(0.0));
+    }
+
+    #[test]
+    fn quadratic() {
+        let elevated = Bezier2::new([0.0, 0.0], [1.0, 2.0], [3.0, 0.0]).to_cubic();
+        assert!(elevated.is_effectively_quadratic(1e-9));
+
+        let s_curve = Bezier3::new([0.0, 0.0], [1.0, 1.0], [2.0, -1.0], [3.0, 0.0]);
+        assert!(!s_curve.is_effectively_quadratic(1e-2));
+    }
+}
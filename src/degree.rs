@@ -0,0 +1,183 @@
+use crate::bezier::Bezier;
+use crate::{Bezier2, Bezier3, ComposedCurve, Curve, InnerProduct, Point};
+use alloc::vec::Vec;
+use num_traits::{NumCast, One};
+
+/// Upper limit for the number of quadratics a single cubic is split into.
+const MAX_QUADRATICS: usize = 1024;
+
+impl<P: InnerProduct> Bezier3<P> {
+    /// Approximate the curve with quadratic curves, the distance between the cubic and the
+    /// result is at most `tolerance`. The cubic is split into equal parameter ranges and every
+    /// piece is replaced by a quadratic with the same end points.
+    ///
+    /// The cubic is split into at most 1024 pieces, so an unreachable tolerance, e.g. zero,
+    /// still terminates, but then the error of the result can be above `tolerance`.
+    pub fn to_quadratics(&self, tolerance: P::Scalar) -> Vec<Bezier2<P>> {
+        let error = self.quadratic_error();
+
+        let mut count = 1;
+        while count < MAX_QUADRATICS {
+            let n: P::Scalar = NumCast::from(count).unwrap();
+            if error <= tolerance * n * n * n {
+                break;
+            }
+            count += 1;
+        }
+
+        let mut result = Vec::with_capacity(count);
+        let mut rest = self.clone();
+        for i in 0..count {
+            let piece = if i + 1 == count {
+                rest.clone()
+            } else {
+                let t = P::Scalar::one() / NumCast::from(count - i).unwrap();
+                let (piece, tail) = rest.split_at(t);
+                rest = tail;
+                piece
+            };
+            result.push(piece.to_quadratic());
+        }
+        result
+    }
+
+    /// The quadratic with the same end points, its control point is the average of the
+    /// points the end tangents of the cubic point to.
+    fn to_quadratic(&self) -> Bezier2<P> {
+        let three = P::Scalar::one() + P::Scalar::one() + P::Scalar::one();
+        let quarter = P::Scalar::one() / (three + P::Scalar::one());
+        let p1 = self
+            .p1
+            .add(&self.p2)
+            .scale(three)
+            .sub(&self.p0)
+            .sub(&self.p3)
+            .scale(quarter);
+        Bezier2::new(self.p0.clone(), p1, self.p3.clone())
+    }
+}
+
+impl<P: InnerProduct> ComposedCurve<P> {
+    /// Copy of the curve where every cubic segment is approximated with quadratics within
+    /// `tolerance`, as required by TrueType outlines. Lines and quadratics are kept as they are.
+    /// See [`Bezier3::to_quadratics`] for the limit of pieces per cubic.
+    pub fn to_quadratics(&self, tolerance: P::Scalar) -> ComposedCurve<P> {
+        let mut result = ComposedCurve::new(self.start_point());
+        for segment in self.segments() {
+            match segment {
+                Bezier::C0(_) => {}
+                Bezier::C1(c) => result.line_to(c.p1.clone()),
+                Bezier::C2(c) => result.quadratic_to(c.p1.clone(), c.p2.clone()),
+                Bezier::C3(c) => {
+                    for q in c.to_quadratics(tolerance) {
+                        result.quadratic_to(q.p1, q.p2);
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+impl<P: Point> ComposedCurve<P> {
+    /// Copy of the curve where every line and quadratic segment is converted to a cubic,
+    /// the conversion is exact.
+    pub fn to_cubics(&self) -> ComposedCurve<P> {
+        let mut result = ComposedCurve::new(self.start_point());
+        for segment in self.segments() {
+            let cubic = match segment {
+                Bezier::C0(_) => continue,
+                Bezier::C1(c) => c.to_quadratic().to_cubic(),
+                Bezier::C2(c) => c.to_cubic(),
+                Bezier::C3(c) => c.clone(),
+            };
+            result.cubic_to(cubic.p1, cubic.p2, cubic.p3);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Distance;
+    use approx::assert_relative_eq;
+
+    fn max_distance(a: &impl Curve<[f64; 2]>, b: &impl Curve<[f64; 2]>) -> f64 {
+        let points: Vec<_> = (0..=2000).map(|i| b.value_at(i as f64 / 2000.0)).collect();
+        (0..=200)
+            .map(|i| {
+                let p = a.value_at(i as f64 / 200.0);
+                points
+                    .iter()
+                    .map(|q| q.distance(&p))
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn cubic_to_quadratics() {
+        let cubic = Bezier3::new([0.0, 0.0], [10.0, 20.0], [30.0, -10.0], [40.0, 10.0]);
+        for tolerance in [1.0, 0.1, 0.01] {
+            let quadratics = cubic.to_quadratics(tolerance);
+            let mut curve = ComposedCurve::new([0.0, 0.0]);
+            for q in &quadratics {
+                curve.quadratic_to(q.p1, q.p2);
+            }
+            assert_eq!(curve.end_point(), [40.0, 10.0]);
+            assert!(max_distance(&cubic, &curve) <= tolerance + 0.02);
+        }
+        assert!(cubic.to_quadratics(0.01).len() > cubic.to_quadratics(1.0).len());
+        assert_eq!(cubic.to_quadratics(0.0).len(), MAX_QUADRATICS);
+    }
+
+    #[test]
+    fn elevated_quadratic_is_single() {
+        let quadratic = Bezier2::new([0.0, 0.0], [1.0, 2.0], [3.0, 0.0]);
+        let quadratics = quadratic.to_cubic().to_quadratics(1e-9);
+        assert_eq!(quadratics.len(), 1);
+        assert_relative_eq!(quadratics[0].p1[0], 1.0);
+        assert_relative_eq!(quadratics[0].p1[1], 2.0);
+    }
+
+    #[test]
+    fn composed_round_trip() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([1.0, 0.0]);
+        curve.quadratic_to([2.0, 0.0], [2.0, 1.0]);
+        curve.cubic_to([2.0, 3.0], [0.0, 3.0], [0.0, 1.0]);
+
+        let cubics = curve.to_cubics();
+        assert_eq!(cubics.segments().len(), 3);
+        assert!(cubics.segments().iter().all(|s| matches!(s, Bezier::C3(_))));
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            let (a, b) = (curve.value_at(t), cubics.value_at(t));
+            assert_relative_eq!(a[0], b[0], epsilon = 1e-12);
+            assert_relative_eq!(a[1], b[1], epsilon = 1e-12);
+        }
+
+        let quadratics = curve.to_quadratics(0.001);
+        assert!(matches!(quadratics.segments()[0], Bezier::C1(_)));
+        assert!(quadratics.segments()[1..]
+            .iter()
+            .all(|s| matches!(s, Bezier::C2(_))));
+        assert_eq!(quadratics.end_point(), [0.0, 1.0]);
+    }
+
+    #[test]
+    fn split() {
+        let cubic = Bezier3::new([0.0, 0.0], [1.0, 2.0], [3.0, -1.0], [4.0, 1.0]);
+        let (a, b) = cubic.split_at(0.3);
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            let (p, q) = (a.value_at(t), cubic.value_at(t * 0.3));
+            assert_relative_eq!(p[0], q[0], epsilon = 1e-12);
+            assert_relative_eq!(p[1], q[1], epsilon = 1e-12);
+            let (p, q) = (b.value_at(t), cubic.value_at(0.3 + t * 0.7));
+            assert_relative_eq!(p[0], q[0], epsilon = 1e-12);
+            assert_relative_eq!(p[1], q[1], epsilon = 1e-12);
+        }
+    }
+}
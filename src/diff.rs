@@ -0,0 +1,235 @@
+use crate::{Bezier, ComposedCurve, Point};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Edit distance above which the differing middle part is reported as a single change,
+/// it bounds the memory of the diff to `O(MAX_EDIT_DISTANCE²)`.
+const MAX_EDIT_DISTANCE: usize = 256;
+
+/// What happened to a range of segments, see [`SegmentChange::kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChangeKind {
+    Changed,
+    Added,
+    Removed,
+}
+
+/// Segments `old` of the old curve were replaced by the segments `new` of the new curve,
+/// one of the ranges may be empty.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SegmentChange {
+    pub old: Range<usize>,
+    pub new: Range<usize>,
+}
+
+impl SegmentChange {
+    pub fn kind(&self) -> ChangeKind {
+        if self.old.is_empty() {
+            ChangeKind::Added
+        } else if self.new.is_empty() {
+            ChangeKind::Removed
+        } else {
+            ChangeKind::Changed
+        }
+    }
+}
+
+impl<P: Point> ComposedCurve<P> {
+    /// Ranges of segments that differ between `self` and `other`, in ascending order.
+    ///
+    /// Segments are compared exactly and matched with Myers' diff algorithm, so an edit
+    /// in the middle of a long path only reports the edited segments and the renderer can
+    /// re-tessellate just them. Segments outside the changes are equal, the segment `i`
+    /// of `self` is the segment `i + shift` of `other`, where `shift` is the difference
+    /// of the lengths of all the preceding changes.
+    pub fn diff(&self, other: &Self) -> Vec<SegmentChange> {
+        let (a, b) = (self.segments(), other.segments());
+        let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+        let suffix = a[prefix..]
+            .iter()
+            .rev()
+            .zip(b[prefix..].iter().rev())
+            .take_while(|(x, y)| x == y)
+            .count();
+        let (a_middle, b_middle) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+        if a_middle.is_empty() && b_middle.is_empty() {
+            return Vec::new();
+        }
+
+        let matches = common_pairs(a_middle, b_middle).unwrap_or_default();
+        let mut result = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        for (x, y) in matches
+            .into_iter()
+            .chain([(a_middle.len(), b_middle.len())])
+        {
+            if x > i || y > j {
+                result.push(SegmentChange {
+                    old: prefix + i..prefix + x,
+                    new: prefix + j..prefix + y,
+                });
+            }
+            (i, j) = (x + 1, y + 1);
+        }
+        result
+    }
+}
+
+/// Indices of the equal segments of the longest common subsequence, `None` if the edit
+/// distance is above [`MAX_EDIT_DISTANCE`].
+fn common_pairs<P: Point>(a: &[Bezier<P>], b: &[Bezier<P>]) -> Option<Vec<(usize, usize)>> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max = (a.len() + b.len()).min(MAX_EDIT_DISTANCE) as isize;
+    let offset = max + 1;
+    let mut v = alloc::vec![0isize; 2 * offset as usize + 1];
+    let mut trace = Vec::new();
+
+    // The furthest reaching path on every diagonal `k = x - y` for growing edit distance `d`
+    let mut distance = None;
+    'search: for d in 0..=max {
+        trace.push(v.clone());
+        for k in (-d..=d).step_by(2) {
+            let index = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && v[index - 1] < v[index + 1]) {
+                v[index + 1]
+            } else {
+                v[index - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[index] = x;
+            if x >= n && y >= m {
+                distance = Some(d);
+                break 'search;
+            }
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for d in (0..=distance?).rev() {
+        let v = &trace[d as usize];
+        let k = x - y;
+        let index = (k + offset) as usize;
+        let previous_k = if k == -d || (k != d && v[index - 1] < v[index + 1]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let previous_x = if d == 0 {
+            0
+        } else {
+            v[(previous_k + offset) as usize]
+        };
+        let previous_y = if d == 0 { 0 } else { previous_x - previous_k };
+        while x > previous_x && y > previous_y {
+            x -= 1;
+            y -= 1;
+            pairs.push((x as usize, y as usize));
+        }
+        (x, y) = (previous_x, previous_y);
+    }
+    pairs.reverse();
+    Some(pairs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Curve;
+
+    fn polyline(points: &[[f64; 2]]) -> ComposedCurve<[f64; 2]> {
+        let mut curve = ComposedCurve::new(points[0]);
+        points[1..].iter().for_each(|point| curve.line_to(*point));
+        curve
+    }
+
+    fn staircase(count: usize) -> Vec<[f64; 2]> {
+        (0..count)
+            .map(|i| [(i / 2 + i % 2) as f64, (i / 2) as f64])
+            .collect()
+    }
+
+    #[test]
+    fn equal_curves() {
+        let curve = polyline(&staircase(10));
+        assert!(curve.diff(&curve.clone()).is_empty());
+    }
+
+    #[test]
+    fn moved_point_changes_two_segments() {
+        let points = staircase(20);
+        let mut moved = points.clone();
+        moved[7] = [10.0, 10.0];
+        let diff = polyline(&points).diff(&polyline(&moved));
+        assert_eq!(
+            diff,
+            [SegmentChange {
+                old: 6..8,
+                new: 6..8
+            }]
+        );
+        assert_eq!(diff[0].kind(), ChangeKind::Changed);
+    }
+
+    #[test]
+    fn insertions_and_removals() {
+        let mut old = polyline(&staircase(10));
+        old.cubic_to([5.0, 5.0], [6.0, 5.0], [6.0, 6.0]);
+        let mut new = ComposedCurve::new([0.0, 0.0]);
+        new.line_to([-1.0, 0.0]);
+        new.line_to([0.0, 0.0]);
+        for segment in &old.segments()[..4] {
+            new.push_segment(*segment);
+        }
+        for segment in &old.segments()[6..] {
+            new.line_to(segment.start_point());
+            new.push_segment(*segment);
+        }
+        let diff = old.diff(&new);
+        let kinds: Vec<_> = diff.iter().map(SegmentChange::kind).collect();
+        assert_eq!(
+            diff[0],
+            SegmentChange {
+                old: 0..0,
+                new: 0..2
+            }
+        );
+        assert_eq!(
+            diff[1],
+            SegmentChange {
+                old: 4..6,
+                new: 6..7
+            }
+        );
+        assert_eq!(kinds[..2], [ChangeKind::Added, ChangeKind::Changed]);
+
+        let mut truncated = old.clone();
+        truncated.splice_segments(3..5, Vec::new());
+        let diff = old.diff(&truncated);
+        assert_eq!(
+            diff,
+            [SegmentChange {
+                old: 3..5,
+                new: 3..3
+            }]
+        );
+        assert_eq!(diff[0].kind(), ChangeKind::Removed);
+    }
+
+    #[test]
+    fn unrelated_curves() {
+        let a = polyline(&staircase(600));
+        let b = a.map_points(|p| [p[0], p[1] + 0.5]);
+        assert_eq!(
+            a.diff(&b),
+            [SegmentChange {
+                old: 0..599,
+                new: 0..599
+            }]
+        );
+    }
+}
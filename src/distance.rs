@@ -1,17 +1,7 @@
 use crate::Point;
 
+/// Points with a distance between them.
+/// It's implemented automatically for every [`InnerProduct`](crate::InnerProduct) point.
 pub trait Distance: Point {
     fn distance(&self, other: &Self) -> Self::Scalar;
 }
-
-impl Distance for f32 {
-    fn distance(&self, other: &Self) -> Self::Scalar {
-        (self - other).abs()
-    }
-}
-
-impl Distance for f64 {
-    fn distance(&self, other: &Self) -> Self::Scalar {
-        (self - other).abs()
-    }
-}
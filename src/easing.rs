@@ -0,0 +1,597 @@
+//! Easing functions, 1D curves mapping the animation progress to the output progress.
+
+use crate::scalar::is_nan;
+use crate::{Bezier3, Curve, Distance, Point, Scalar};
+use core::marker::PhantomData;
+use num_traits::NumCast;
+
+/// Newton's method iterations before falling back to bisection.
+const NEWTON_ITERATIONS: usize = 8;
+/// Bisection iterations limit, enough for the full `f64` precision.
+const BISECTION_ITERATIONS: usize = 64;
+
+/// Easing with the semantics of the CSS `cubic-bezier(x1, y1, x2, y2)` function.
+///
+/// It's a cubic curve from `(0, 0)` to `(1, 1)` with two control points, evaluated
+/// at `x`: the curve parameter for `x` is found first, and then `y` is calculated for it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CubicBezierEasing<F: Scalar> {
+    pub x1: F,
+    pub y1: F,
+    pub x2: F,
+    pub y2: F,
+}
+
+impl<F: Scalar> CubicBezierEasing<F> {
+    /// # Panics
+    ///
+    /// Panics if `x1` or `x2` is outside of the `0..=1` range,
+    /// such easings aren't functions of `x` (and are invalid in CSS).
+    pub fn new(x1: F, y1: F, x2: F, y2: F) -> Self {
+        let range = F::zero()..=F::one();
+        assert!(
+            range.contains(&x1) && range.contains(&x2),
+            "x coordinates of the easing control points must be in 0..=1"
+        );
+        Self { x1, y1, x2, y2 }
+    }
+
+    /// `linear`, `cubic-bezier(0, 0, 1, 1)`.
+    pub fn linear() -> Self {
+        Self::from_f64(0.0, 0.0, 1.0, 1.0)
+    }
+
+    /// `ease`, `cubic-bezier(0.25, 0.1, 0.25, 1)`.
+    pub fn ease() -> Self {
+        Self::from_f64(0.25, 0.1, 0.25, 1.0)
+    }
+
+    /// `ease-in`, `cubic-bezier(0.42, 0, 1, 1)`.
+    pub fn ease_in() -> Self {
+        Self::from_f64(0.42, 0.0, 1.0, 1.0)
+    }
+
+    /// `ease-out`, `cubic-bezier(0, 0, 0.58, 1)`.
+    pub fn ease_out() -> Self {
+        Self::from_f64(0.0, 0.0, 0.58, 1.0)
+    }
+
+    /// `ease-in-out`, `cubic-bezier(0.42, 0, 0.58, 1)`.
+    pub fn ease_in_out() -> Self {
+        Self::from_f64(0.42, 0.0, 0.58, 1.0)
+    }
+
+    fn from_f64(x1: f64, y1: f64, x2: f64, y2: f64) -> Self {
+        let cast = |v: f64| -> F { NumCast::from(v).unwrap() };
+        Self::new(cast(x1), cast(y1), cast(x2), cast(y2))
+    }
+
+    /// Output progress `y` for the input progress `x`.
+    ///
+    /// Outside of `0..=1` the curve is extended linearly along the tangent at the nearest end,
+    /// as the CSS specification requires for an input progress coming from an easing that overshoots.
+    pub fn ease_at(&self, x: F) -> F {
+        if x < F::zero() {
+            self.start_slope() * x
+        } else if x > F::one() {
+            F::one() + self.end_slope() * (x - F::one())
+        } else {
+            component(self.y1, self.y2, self.solve(x))
+        }
+    }
+
+    /// The slope at `x = 0`, the first control point that isn't at the start defines it.
+    fn start_slope(&self) -> F {
+        if self.x1 > F::zero() {
+            self.y1 / self.x1
+        } else if self.y1 == F::zero() && self.x2 > F::zero() {
+            self.y2 / self.x2
+        } else {
+            F::zero()
+        }
+    }
+
+    /// The slope at `x = 1`, the last control point that isn't at the end defines it.
+    fn end_slope(&self) -> F {
+        if self.x2 < F::one() {
+            (self.y2 - F::one()) / (self.x2 - F::one())
+        } else if self.y2 == F::one() && self.x1 < F::one() {
+            (self.y1 - F::one()) / (self.x1 - F::one())
+        } else {
+            F::zero()
+        }
+    }
+
+    /// Curve parameter where the x coordinate is equal to `x`.
+    fn solve(&self, x: F) -> F {
+        let epsilon: F = NumCast::from(1e-7).unwrap();
+
+        let mut t = x;
+        for _ in 0..NEWTON_ITERATIONS {
+            let error = component(self.x1, self.x2, t) - x;
+            if error.abs() < epsilon {
+                return t;
+            }
+            let derivative = component_derivative(self.x1, self.x2, t);
+            if derivative.abs() < epsilon {
+                break;
+            }
+            t = t - error / derivative;
+        }
+
+        // x(t) is monotonic, so bisection always converges
+        let (mut low, mut high) = (F::zero(), F::one());
+        t = x;
+        for _ in 0..BISECTION_ITERATIONS {
+            let value = component(self.x1, self.x2, t);
+            if (value - x).abs() < epsilon {
+                break;
+            }
+            if value < x {
+                low = t;
+            } else {
+                high = t;
+            }
+            t = (low + high) / (F::one() + F::one());
+        }
+        t
+    }
+}
+
+impl<F: Scalar + Point<Scalar = F>> Curve<F> for CubicBezierEasing<F> {
+    fn value_at(&self, x: F) -> F {
+        self.ease_at(x)
+    }
+
+    /// Derivative of the output by the input progress, constant outside of `0..=1`.
+    fn tangent_at(&self, x: F) -> F {
+        if x < F::zero() {
+            return self.start_slope();
+        } else if x > F::one() {
+            return self.end_slope();
+        }
+        let t = self.solve(x);
+        let dx = component_derivative(self.x1, self.x2, t);
+        let dy = component_derivative(self.y1, self.y2, t);
+        if dx == F::zero() {
+            F::zero()
+        } else {
+            dy / dx
+        }
+    }
+
+    fn estimate_length(&self, precision: F) -> F
+    where
+        F: Distance,
+    {
+        // The length of a 1D curve is its total variation, y can overshoot with control points outside 0..=1
+        Bezier3::new(F::zero(), self.y1, self.y2, F::one()).estimate_length(precision)
+    }
+}
+
+/// Where the jumps of a [`Steps`] easing are, like in the CSS `steps()` function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JumpTerm {
+    /// The first jump happens at the start, `jump-start`.
+    Start,
+    /// The last jump happens at the end, `jump-end`.
+    End,
+    /// No jumps at the start and at the end, `jump-none`.
+    None,
+    /// Jumps both at the start and at the end, `jump-both`.
+    Both,
+}
+
+/// Easing with the semantics of the CSS `steps(n, jump_term)` function,
+/// the output stays constant between `n` equally spaced steps.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Steps<F: Scalar> {
+    steps: usize,
+    jump: JumpTerm,
+    phantom_data: PhantomData<F>,
+}
+
+impl<F: Scalar> Steps<F> {
+    /// # Panics
+    ///
+    /// Panics if `steps` is zero, or less than 2 for [`JumpTerm::None`].
+    pub fn new(steps: usize, jump: JumpTerm) -> Self {
+        let min = if jump == JumpTerm::None { 2 } else { 1 };
+        assert!(steps >= min, "steps easing needs at least {} steps", min);
+        Self {
+            steps,
+            jump,
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn jump(&self) -> JumpTerm {
+        self.jump
+    }
+
+    /// Output progress for the input progress `x`, `x` is clamped to `0..=1`, NaN gives NaN.
+    pub fn ease_at(&self, x: F) -> F {
+        if is_nan(x) {
+            return x;
+        }
+        let x = x.clamp(F::zero(), F::one());
+        let steps: F = NumCast::from(self.steps).unwrap();
+        let mut step = (x * steps).floor().to_usize().unwrap();
+        if matches!(self.jump, JumpTerm::Start | JumpTerm::Both) {
+            step += 1;
+        }
+
+        let jumps = match self.jump {
+            JumpTerm::Start | JumpTerm::End => self.steps,
+            JumpTerm::None => self.steps - 1,
+            JumpTerm::Both => self.steps + 1,
+        };
+        let step: F = NumCast::from(step.min(jumps)).unwrap();
+        step / NumCast::from(jumps).unwrap()
+    }
+}
+
+impl<F: Scalar + Point<Scalar = F>> Curve<F> for Steps<F> {
+    fn value_at(&self, x: F) -> F {
+        self.ease_at(x)
+    }
+
+    /// Steps are flat, the derivative is zero everywhere except the jumps.
+    fn tangent_at(&self, _x: F) -> F {
+        F::zero()
+    }
+
+    fn estimate_length(&self, _precision: F) -> F
+    where
+        F: Distance,
+    {
+        self.ease_at(F::one()) - self.ease_at(F::zero())
+    }
+}
+
+/// Easing following a damped spring released from the start towards the end value.
+///
+/// The input progress `0..=1` is mapped to the time from `0` to [`Spring::duration`] in seconds,
+/// which is by default the time the spring needs to settle within 0.1% of the end value.
+/// Underdamped springs overshoot the end value and oscillate around it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Spring<F: Scalar> {
+    mass: F,
+    stiffness: F,
+    damping: F,
+    duration: F,
+}
+
+impl<F: Scalar> Spring<F> {
+    /// Spring with physical parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mass`, `stiffness` or `damping` isn't positive,
+    /// an undamped spring oscillates forever and never settles.
+    pub fn new(mass: F, stiffness: F, damping: F) -> Self {
+        assert!(mass > F::zero() && stiffness > F::zero() && damping > F::zero());
+        let mut spring = Self {
+            mass,
+            stiffness,
+            damping,
+            duration: F::one(),
+        };
+        spring.duration = spring.settling_duration();
+        spring
+    }
+
+    /// Spring with perceptual parameters, as in SwiftUI and other animation libraries.
+    /// `duration` is the period of the oscillation in seconds, and `bounce` goes from `-1`
+    /// (very damped) through `0` (critically damped) to `1` (not damped).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `duration` isn't positive or `bounce` isn't in `-1..1`.
+    pub fn with_bounce(duration: F, bounce: F) -> Self {
+        let one = F::one();
+        assert!(duration > F::zero() && bounce > -one && bounce < one);
+        let two_pi = F::pi() + F::pi();
+        let stiffness = (two_pi / duration) * (two_pi / duration);
+        let damping = if bounce >= F::zero() {
+            (two_pi + two_pi) * (one - bounce) / duration
+        } else {
+            (two_pi + two_pi) / (duration * (one + bounce))
+        };
+        Self::new(one, stiffness, damping)
+    }
+
+    /// Set the time in seconds mapped to the input progress of `1`.
+    pub fn with_duration(mut self, duration: F) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn mass(&self) -> F {
+        self.mass
+    }
+
+    pub fn stiffness(&self) -> F {
+        self.stiffness
+    }
+
+    pub fn damping(&self) -> F {
+        self.damping
+    }
+
+    pub fn duration(&self) -> F {
+        self.duration
+    }
+
+    /// Output progress for the input progress `x`, `x` is clamped to `0..=1`.
+    pub fn ease_at(&self, x: F) -> F {
+        let time = x.clamp(F::zero(), F::one()) * self.duration;
+        F::one() - self.displacement(time).0
+    }
+
+    /// Time when the distance to the end value stays below 0.1%.
+    fn settling_duration(&self) -> F {
+        let threshold: F = NumCast::from(1e-3).unwrap();
+        let growth: F = NumCast::from(1.1).unwrap();
+        let (rate, amplitude) = self.envelope();
+
+        // The envelope bounds the displacement, start from its estimate and go on until it's small enough
+        let mut time = (amplitude / threshold).ln() / rate;
+        while amplitude * (F::one() + self.critical_term(time)) * (-rate * time).exp() > threshold {
+            time = time * growth;
+        }
+        time
+    }
+
+    fn natural_frequency(&self) -> F {
+        (self.stiffness / self.mass).sqrt()
+    }
+
+    fn damping_ratio(&self) -> F {
+        let two = F::one() + F::one();
+        self.damping / (two * (self.stiffness * self.mass).sqrt())
+    }
+
+    /// Decay rate of the slowest exponent and the amplitude of the displacement.
+    fn envelope(&self) -> (F, F) {
+        let omega = self.natural_frequency();
+        let zeta = self.damping_ratio();
+        let one = F::one();
+        if zeta < one {
+            let damped = omega * (one - zeta * zeta).sqrt();
+            let ratio = zeta * omega / damped;
+            (zeta * omega, (one + ratio * ratio).sqrt())
+        } else if zeta == one {
+            (omega, one)
+        } else {
+            let root = (zeta * zeta - one).sqrt();
+            let (r1, r2) = (-omega * (zeta - root), -omega * (zeta + root));
+            (-r1, (r2.abs() + r1.abs()) / (r1 - r2))
+        }
+    }
+
+    /// The polynomial factor of a critically damped spring, zero for other springs.
+    fn critical_term(&self, time: F) -> F {
+        if self.damping_ratio() == F::one() {
+            self.natural_frequency() * time
+        } else {
+            F::zero()
+        }
+    }
+
+    /// Distance to the end value and its derivative by time, it starts at `1` with zero velocity.
+    fn displacement(&self, time: F) -> (F, F) {
+        let omega = self.natural_frequency();
+        let zeta = self.damping_ratio();
+        let one = F::one();
+
+        if zeta < one {
+            let damped = omega * (one - zeta * zeta).sqrt();
+            let decay = (-zeta * omega * time).exp();
+            let (sin, cos) = ((damped * time).sin(), (damped * time).cos());
+            let ratio = zeta * omega / damped;
+            let value = decay * (cos + ratio * sin);
+            let derivative = -decay * sin * (omega * omega / damped);
+            (value, derivative)
+        } else if zeta == one {
+            let decay = (-omega * time).exp();
+            (decay * (one + omega * time), -decay * omega * omega * time)
+        } else {
+            let root = (zeta * zeta - one).sqrt();
+            let (r1, r2) = (-omega * (zeta - root), -omega * (zeta + root));
+            let (e1, e2) = ((r1 * time).exp(), (r2 * time).exp());
+            let value = (r2 * e1 - r1 * e2) / (r2 - r1);
+            let derivative = r1 * r2 * (e1 - e2) / (r2 - r1);
+            (value, derivative)
+        }
+    }
+}
+
+impl<F: Scalar + Point<Scalar = F>> Curve<F> for Spring<F> {
+    fn value_at(&self, x: F) -> F {
+        self.ease_at(x)
+    }
+
+    fn tangent_at(&self, x: F) -> F {
+        let time = x.clamp(F::zero(), F::one()) * self.duration;
+        -self.displacement(time).1 * self.duration
+    }
+
+    fn estimate_length(&self, precision: F) -> F
+    where
+        F: Distance,
+    {
+        // Total variation of the output, measured with a polyline fine enough for the oscillations
+        let omega = self.natural_frequency();
+        let periods = (omega * self.duration / (F::pi() + F::pi()))
+            .ceil()
+            .to_usize()
+            .unwrap_or(1);
+        let steps = periods.max(1)
+            * (F::one() / precision)
+                .to_usize()
+                .unwrap_or(100)
+                .clamp(16, 4096);
+        let inverted: F = F::one() / NumCast::from(steps).unwrap();
+        let mut previous = self.ease_at(F::zero());
+        let mut length = F::zero();
+        for i in 1..=steps {
+            let i: F = NumCast::from(i).unwrap();
+            let value = self.ease_at(i * inverted);
+            length = length + (value - previous).abs();
+            previous = value;
+        }
+        length
+    }
+}
+
+/// One coordinate of a cubic from 0 to 1 with the inner control points `p1` and `p2`.
+fn component<F: Scalar>(p1: F, p2: F, t: F) -> F {
+    let three: F = NumCast::from(3).unwrap();
+    let c = three * p1;
+    let b = three * (p2 - p1) - c;
+    let a = F::one() - c - b;
+    ((a * t + b) * t + c) * t
+}
+
+fn component_derivative<F: Scalar>(p1: F, p2: F, t: F) -> F {
+    let three: F = NumCast::from(3).unwrap();
+    let c = three * p1;
+    let b = three * (p2 - p1) - c;
+    let a = F::one() - c - b;
+    (three * a * t + (b + b)) * t + c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn presets() {
+        let linear = CubicBezierEasing::<f64>::linear();
+        for i in 0..=10 {
+            let x = i as f64 / 10.0;
+            assert_relative_eq!(linear.ease_at(x), x, epsilon = 1e-6);
+        }
+
+        // Reference values found by bisection with full precision
+        let ease = CubicBezierEasing::<f64>::ease();
+        assert_relative_eq!(ease.ease_at(0.25), 0.4085106, epsilon = 1e-6);
+        assert_relative_eq!(ease.ease_at(0.5), 0.8024034, epsilon = 1e-6);
+
+        let ease_in_out = CubicBezierEasing::<f64>::ease_in_out();
+        assert_relative_eq!(ease_in_out.ease_at(0.5), 0.5, epsilon = 1e-6);
+        assert_relative_eq!(ease_in_out.ease_at(0.0), 0.0);
+        assert_relative_eq!(ease_in_out.ease_at(1.0), 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn overshoot() {
+        let back = CubicBezierEasing::new(0.68f64, -0.6, 0.32, 1.6);
+        let values = (0..=100).map(|i| back.value_at(i as f64 / 100.0));
+        assert!(values.clone().any(|y| y < 0.0));
+        assert!(values.clone().any(|y| y > 1.0));
+    }
+
+    #[test]
+    fn extrapolation() {
+        let ease = CubicBezierEasing::<f64>::ease();
+        assert_relative_eq!(ease.ease_at(-0.5), -0.2);
+        assert_relative_eq!(ease.ease_at(1.5), 1.0);
+        assert_relative_eq!(ease.tangent_at(-0.5), 0.4);
+
+        let ease_in = CubicBezierEasing::<f64>::ease_in();
+        assert_relative_eq!(ease_in.ease_at(-1.0), 0.0);
+        // The first control point is at the start, so the second one gives the slope
+        let easing = CubicBezierEasing::new(0.0f64, 0.0, 0.5, 1.0);
+        assert_relative_eq!(easing.ease_at(-1.0), -2.0);
+
+        let back = CubicBezierEasing::new(0.68f64, -0.6, 0.32, 1.6);
+        assert_relative_eq!(back.ease_at(2.0), 1.0 - 0.6 / 0.68, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn steep_start_needs_bisection() {
+        let easing = CubicBezierEasing::new(0.0f64, 1.0, 0.0, 1.0);
+        for i in 0..=100 {
+            let x = i as f64 / 100.0;
+            let t = easing.solve(x);
+            assert_relative_eq!(component(0.0, 0.0, t), x, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn derivative() {
+        let ease = CubicBezierEasing::<f64>::ease();
+        let h = 1e-6;
+        let x = 0.3;
+        let numeric = (ease.value_at(x + h) - ease.value_at(x - h)) / (2.0 * h);
+        assert_relative_eq!(ease.tangent_at(x), numeric, epsilon = 1e-3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_x() {
+        CubicBezierEasing::new(1.5, 0.0, 0.5, 1.0);
+    }
+
+    #[test]
+    fn steps() {
+        let values = |jump| {
+            let steps = Steps::<f64>::new(4, jump);
+            [0.0, 0.1, 0.25, 0.6, 0.99, 1.0].map(|x| steps.value_at(x))
+        };
+        assert_eq!(values(JumpTerm::End), [0.0, 0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(values(JumpTerm::Start), [0.25, 0.25, 0.5, 0.75, 1.0, 1.0]);
+        assert_eq!(values(JumpTerm::Both), [0.2, 0.2, 0.4, 0.6, 0.8, 1.0]);
+        assert_eq!(
+            values(JumpTerm::None),
+            [0.0, 0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn steps_nan() {
+        assert!(Steps::<f64>::new(4, JumpTerm::End)
+            .ease_at(f64::NAN)
+            .is_nan());
+    }
+
+    #[test]
+    fn springs_settle() {
+        let springs = [
+            Spring::new(1.0f64, 100.0, 5.0),
+            Spring::new(1.0, 100.0, 20.0),
+            Spring::new(1.0, 100.0, 50.0),
+            Spring::with_bounce(0.5, 0.3),
+            Spring::with_bounce(0.5, -0.5),
+        ];
+        for spring in springs {
+            assert_eq!(spring.value_at(0.0), 0.0);
+            assert_relative_eq!(spring.value_at(1.0), 1.0, epsilon = 1e-3);
+            assert_relative_eq!(spring.tangent_at(0.0), 0.0, epsilon = 1e-9);
+
+            let h = 1e-6;
+            let x = 0.2;
+            let numeric = (spring.value_at(x + h) - spring.value_at(x - h)) / (2.0 * h);
+            assert_relative_eq!(spring.tangent_at(x), numeric, epsilon = 1e-3);
+        }
+
+        let bouncy = Spring::with_bounce(0.5f64, 0.5);
+        assert!((0..=100).any(|i| bouncy.value_at(i as f64 / 100.0) > 1.01));
+        let damped = Spring::new(1.0f64, 100.0, 50.0);
+        assert!((0..=100).all(|i| damped.value_at(i as f64 / 100.0) <= 1.0));
+        assert!(bouncy.estimate_length(0.01) > 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn undamped_spring() {
+        Spring::new(1.0f64, 100.0, 0.0);
+    }
+}
@@ -0,0 +1,655 @@
+use crate::fit::unit;
+use crate::{Bezier, ComposedCurve, Curve, Distance, InnerProduct, Point};
+use alloc::vec::Vec;
+use num_traits::{NumCast, One, Zero};
+
+/// How the two handles of an anchor are tied together.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HandleMode {
+    /// The handles move independently, the anchor is a corner.
+    #[default]
+    Free,
+    /// The handles stay collinear but keep their own lengths, the path is tangent continuous.
+    Aligned,
+    /// The handles stay collinear with equal lengths, so the joint of two cubics
+    /// is C1 continuous.
+    Mirrored,
+}
+
+/// An editing operation of a [`PathEditor`].
+///
+/// Anchors are the points where segments meet, anchor `i` is the start of segment `i`
+/// and the end of segment `i - 1`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PathEdit<P: Point> {
+    /// Move control point `index` of `segment`.
+    ///
+    /// Moving an end point moves the anchor with the neighbouring segment's end and both
+    /// adjacent cubic handles. Moving a cubic handle updates the opposite handle according
+    /// to the [`HandleMode`] of its anchor.
+    MovePoint {
+        segment: usize,
+        index: usize,
+        position: P,
+    },
+    /// Split `segment` at `t`, the new anchor is aligned.
+    InsertKnot { segment: usize, t: P::Scalar },
+    /// Remove `segment`, the following segment then starts at its start point.
+    DeleteSegment { segment: usize },
+    /// Switch the anchor between [`HandleMode::Free`] and [`HandleMode::Aligned`],
+    /// a mirrored anchor becomes free.
+    ToggleSmooth { anchor: usize },
+    /// Change the mode of the anchor, the handles are adjusted to satisfy it.
+    SetHandleMode { anchor: usize, mode: HandleMode },
+}
+
+/// Replacement of `removed` segments from `start`, with the modes of the anchors
+/// `start..=start + removed`.
+#[derive(Clone)]
+struct Change<P: Point> {
+    start: usize,
+    removed: usize,
+    segments: Vec<Bezier<P>>,
+    modes: Vec<HandleMode>,
+}
+
+/// A [`ComposedCurve`] with editing operations that can be undone and redone.
+///
+/// Every anchor has a [`HandleMode`] and edits keep its handles consistent with it.
+/// The modes are initialized from the handles of the curve.
+/// Closed curves stay closed, their first and last anchors are edited together.
+#[derive(Clone)]
+pub struct PathEditor<P: InnerProduct> {
+    curve: ComposedCurve<P>,
+    modes: Vec<HandleMode>,
+    undo: Vec<Change<P>>,
+    redo: Vec<Change<P>>,
+}
+
+impl<P: InnerProduct> PathEditor<P> {
+    pub fn new(curve: ComposedCurve<P>) -> Self {
+        let mut editor = Self {
+            modes: Vec::new(),
+            curve,
+            undo: Vec::new(),
+            redo: Vec::new(),
+        };
+        editor.modes = (0..editor.anchor_count())
+            .map(|anchor| editor.detect_mode(anchor))
+            .collect();
+        editor
+    }
+
+    pub fn curve(&self) -> &ComposedCurve<P> {
+        &self.curve
+    }
+
+    pub fn into_curve(self) -> ComposedCurve<P> {
+        self.curve
+    }
+
+    /// Number of anchors, one more than the number of segments.
+    pub fn anchor_count(&self) -> usize {
+        self.curve.segments().len() + 1
+    }
+
+    /// # Panics
+    /// If `anchor` is out of range.
+    pub fn handle_mode(&self, anchor: usize) -> HandleMode {
+        self.modes[anchor]
+    }
+
+    /// Whether the anchor isn't a corner.
+    ///
+    /// # Panics
+    /// If `anchor` is out of range.
+    pub fn is_smooth(&self, anchor: usize) -> bool {
+        self.modes[anchor] != HandleMode::Free
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Apply `edit` and record it for [`PathEditor::undo`], the redo history is cleared.
+    ///
+    /// # Panics
+    /// If the segment, control point, or anchor index is out of range.
+    pub fn apply(&mut self, edit: PathEdit<P>) {
+        let count = self.curve.segments().len();
+        let change = match edit {
+            PathEdit::MovePoint {
+                segment,
+                index,
+                position,
+            } => {
+                assert!(segment < count, "segment index out of range");
+                let degree = degree(&self.curve.segments()[segment]);
+                assert!(index <= degree, "control point index out of range");
+                if index == 0 || index == degree {
+                    let anchor = if index == 0 { segment } else { segment + 1 };
+                    self.move_anchor(anchor, position)
+                } else {
+                    self.move_handle(segment, index, position)
+                }
+            }
+            PathEdit::InsertKnot { segment, t } => {
+                assert!(segment < count, "segment index out of range");
+                let (first, second) = self.curve.segments()[segment].split_at(t);
+                Change {
+                    start: segment,
+                    removed: 1,
+                    segments: Vec::from([first, second]),
+                    modes: Vec::from([
+                        self.modes[segment],
+                        HandleMode::Aligned,
+                        self.modes[segment + 1],
+                    ]),
+                }
+            }
+            PathEdit::DeleteSegment { segment } => {
+                assert!(segment < count, "segment index out of range");
+                let segments = self.curve.segments();
+                if segment + 1 < count {
+                    let mut next = segments[segment + 1].clone();
+                    *point_mut(&mut next, 0) = segments[segment].start_point();
+                    Change {
+                        start: segment,
+                        removed: 2,
+                        segments: Vec::from([next]),
+                        modes: Vec::from([self.modes[segment], self.modes[segment + 2]]),
+                    }
+                } else {
+                    Change {
+                        start: segment,
+                        removed: 1,
+                        segments: Vec::new(),
+                        modes: Vec::from([self.modes[segment]]),
+                    }
+                }
+            }
+            PathEdit::ToggleSmooth { anchor } => {
+                assert!(anchor <= count, "anchor index out of range");
+                let mode = match self.modes[anchor] {
+                    HandleMode::Free => HandleMode::Aligned,
+                    HandleMode::Aligned | HandleMode::Mirrored => HandleMode::Free,
+                };
+                self.set_mode(anchor, mode)
+            }
+            PathEdit::SetHandleMode { anchor, mode } => {
+                assert!(anchor <= count, "anchor index out of range");
+                self.set_mode(anchor, mode)
+            }
+        };
+        let inverse = self.perform(change);
+        self.undo.push(inverse);
+        self.redo.clear();
+    }
+
+    /// Revert the last applied edit, returns `false` if there is nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        match self.undo.pop() {
+            Some(change) => {
+                let inverse = self.perform(change);
+                self.redo.push(inverse);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Apply the last undone edit again, returns `false` if there is nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        match self.redo.pop() {
+            Some(change) => {
+                let inverse = self.perform(change);
+                self.undo.push(inverse);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Apply `change` and return the change reverting it.
+    fn perform(&mut self, change: Change<P>) -> Change<P> {
+        let Change {
+            start,
+            removed,
+            segments,
+            modes,
+        } = change;
+        let inserted = segments.len();
+        let segments = self.curve.splice_segments(start..start + removed, segments);
+        let modes = self.modes.splice(start..=start + removed, modes).collect();
+        Change {
+            start,
+            removed: inserted,
+            segments,
+            modes,
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        !self.curve.segments().is_empty() && self.curve.start_point() == self.curve.end_point()
+    }
+
+    /// Segments ending and starting at `anchor`.
+    fn neighbours(&self, anchor: usize) -> (Option<usize>, Option<usize>) {
+        let count = self.curve.segments().len();
+        let closed = self.is_closed();
+        let incoming = match anchor {
+            0 if closed => Some(count - 1),
+            0 => None,
+            _ => Some(anchor - 1),
+        };
+        let outgoing = if anchor < count {
+            Some(anchor)
+        } else if closed {
+            Some(0)
+        } else {
+            None
+        };
+        (incoming, outgoing)
+    }
+
+    /// The mode the handles of `anchor` already satisfy.
+    fn detect_mode(&self, anchor: usize) -> HandleMode {
+        let (Some(incoming), Some(outgoing)) = self.neighbours(anchor) else {
+            return HandleMode::Free;
+        };
+        let one = P::Scalar::one();
+        let tolerance: P::Scalar = NumCast::from(1e-6).unwrap();
+        let segments = self.curve.segments();
+        let a = segments[incoming].tangent_at(one);
+        let b = segments[outgoing].tangent_at(P::Scalar::zero());
+        let lengths = a.length() * b.length();
+        if lengths == P::Scalar::zero() || a.dot(&b) < (one - tolerance) * lengths {
+            HandleMode::Free
+        } else if degree(&segments[incoming]) == 3
+            && degree(&segments[outgoing]) == 3
+            && a.distance(&b) <= tolerance * b.length()
+        {
+            HandleMode::Mirrored
+        } else {
+            HandleMode::Aligned
+        }
+    }
+
+    /// A change of the segments between `first` and `last` inclusive, and the anchors around them.
+    fn edit_range(
+        &self,
+        first: usize,
+        last: usize,
+        edit: impl FnOnce(&mut [Bezier<P>], &mut [HandleMode]),
+    ) -> Change<P> {
+        let mut segments = self.curve.segments()[first..=last].to_vec();
+        let mut modes = self.modes[first..=last + 1].to_vec();
+        edit(&mut segments, &mut modes);
+        Change {
+            start: first,
+            removed: segments.len(),
+            segments,
+            modes,
+        }
+    }
+
+    fn move_anchor(&self, anchor: usize, position: P) -> Change<P> {
+        let (incoming, outgoing) = self.neighbours(anchor);
+        let (first, last) = span(incoming, outgoing);
+        self.edit_range(first, last, |segments, _| {
+            if let Some(i) = incoming {
+                let segment = &mut segments[i - first];
+                let degree = degree(segment);
+                let delta = position.sub(point_mut(segment, degree));
+                *point_mut(segment, degree) = position.clone();
+                if degree == 3 {
+                    let handle = point_mut(segment, 2);
+                    *handle = handle.add(&delta);
+                }
+            }
+            if let Some(i) = outgoing {
+                let segment = &mut segments[i - first];
+                let delta = position.sub(point_mut(segment, 0));
+                *point_mut(segment, 0) = position.clone();
+                if degree(segment) == 3 {
+                    let handle = point_mut(segment, 1);
+                    *handle = handle.add(&delta);
+                }
+            }
+        })
+    }
+
+    fn move_handle(&self, segment: usize, index: usize, position: P) -> Change<P> {
+        let segments = self.curve.segments();
+        // The anchor of the handle and the segment with the opposite handle, cubics only
+        let opposite = match (degree(&segments[segment]), index) {
+            (3, 1) => self.neighbours(segment).0.map(|s| (segment, s, 2)),
+            (3, 2) => self.neighbours(segment + 1).1.map(|s| (segment + 1, s, 1)),
+            _ => None,
+        };
+        let opposite = opposite.filter(|&(anchor, other, other_index)| {
+            self.modes[anchor] != HandleMode::Free
+                && other != segment
+                && degree(&segments[other]) > other_index
+        });
+
+        let (first, last) = span(Some(segment), opposite.map(|(_, other, _)| other));
+        self.edit_range(first, last, |segments, _| {
+            *point_mut(&mut segments[segment - first], index) = position.clone();
+            if let Some((anchor, other, other_index)) = opposite {
+                let anchor_point = if anchor == segment {
+                    segments[segment - first].start_point()
+                } else {
+                    segments[segment - first].end_point()
+                };
+                let offset = position.sub(&anchor_point);
+                let handle = point_mut(&mut segments[other - first], other_index);
+                if self.modes[anchor] == HandleMode::Mirrored {
+                    *handle = anchor_point.sub(&offset);
+                } else if offset.length() > P::Scalar::zero() {
+                    let length = handle.distance(&anchor_point);
+                    *handle = anchor_point.sub(&unit(&offset).scale(length));
+                }
+            }
+        })
+    }
+
+    fn set_mode(&self, anchor: usize, mode: HandleMode) -> Change<P> {
+        let (incoming, outgoing) = self.neighbours(anchor);
+        let count = self.curve.segments().len();
+        if incoming.is_none() && outgoing.is_none() {
+            return Change {
+                start: anchor,
+                removed: 0,
+                segments: Vec::new(),
+                modes: Vec::from([mode]),
+            };
+        }
+
+        let closed = self.is_closed();
+        let (first, last) = span(incoming, outgoing);
+        self.edit_range(first, last, |segments, modes| {
+            modes[anchor - first] = mode;
+            if closed && (anchor == 0 || anchor == count) {
+                modes[0] = mode;
+                modes[modes.len() - 1] = mode;
+            }
+            if mode == HandleMode::Free {
+                return;
+            }
+
+            let (Some(i), Some(o)) = (incoming, outgoing) else {
+                return;
+            };
+            let in_index = degree(&segments[i - first])
+                .checked_sub(1)
+                .filter(|&d| d > 0);
+            let out_index = Some(1).filter(|_| degree(&segments[o - first]) > 1);
+            let (Some(in_index), Some(out_index)) = (in_index, out_index) else {
+                return;
+            };
+            if i == o {
+                return;
+            }
+
+            let anchor_point = segments[o - first].start_point();
+            let in_handle = point_mut(&mut segments[i - first], in_index).clone();
+            let out_handle = point_mut(&mut segments[o - first], out_index).clone();
+            let direction = unit(&out_handle.sub(&in_handle));
+            if direction.length() == P::Scalar::zero() {
+                return;
+            }
+            let mut in_length = in_handle.distance(&anchor_point);
+            let mut out_length = out_handle.distance(&anchor_point);
+            if mode == HandleMode::Mirrored {
+                let two = P::Scalar::one() + P::Scalar::one();
+                in_length = (in_length + out_length) / two;
+                out_length = in_length;
+            }
+            *point_mut(&mut segments[i - first], in_index) =
+                anchor_point.sub(&direction.scale(in_length));
+            *point_mut(&mut segments[o - first], out_index) =
+                anchor_point.add(&direction.scale(out_length));
+        })
+    }
+}
+
+/// The smallest and the largest of the given segment indices.
+fn span(a: Option<usize>, b: Option<usize>) -> (usize, usize) {
+    match (a, b) {
+        (Some(a), Some(b)) => (a.min(b), a.max(b)),
+        (Some(a), None) | (None, Some(a)) => (a, a),
+        (None, None) => unreachable!("an anchor of a non-empty curve has a segment"),
+    }
+}
+
+fn degree<P: Point>(segment: &Bezier<P>) -> usize {
+    match segment {
+        Bezier::C0(_) => 0,
+        Bezier::C1(_) => 1,
+        Bezier::C2(_) => 2,
+        Bezier::C3(_) => 3,
+    }
+}
+
+fn point_mut<P: Point>(segment: &mut Bezier<P>, index: usize) -> &mut P {
+    match (segment, index) {
+        (Bezier::C0(c), 0) => &mut c.point,
+        (Bezier::C1(c), 0) => &mut c.p0,
+        (Bezier::C1(c), 1) => &mut c.p1,
+        (Bezier::C2(c), 0) => &mut c.p0,
+        (Bezier::C2(c), 1) => &mut c.p1,
+        (Bezier::C2(c), 2) => &mut c.p2,
+        (Bezier::C3(c), 0) => &mut c.p0,
+        (Bezier::C3(c), 1) => &mut c.p1,
+        (Bezier::C3(c), 2) => &mut c.p2,
+        (Bezier::C3(c), 3) => &mut c.p3,
+        _ => panic!("control point index out of range"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path() -> ComposedCurve<[f64; 2]> {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.cubic_to([1.0, 1.0], [2.0, 1.0], [3.0, 0.0]);
+        curve.cubic_to([4.0, -1.0], [5.0, -1.0], [6.0, 0.0]);
+        curve.line_to([6.0, 3.0]);
+        curve
+    }
+
+    #[test]
+    fn detects_smooth_anchors() {
+        let editor = PathEditor::new(path());
+        assert_eq!(editor.anchor_count(), 4);
+        assert!(!editor.is_smooth(0));
+        assert!(editor.is_smooth(1));
+        assert!(!editor.is_smooth(2));
+        assert!(!editor.is_smooth(3));
+    }
+
+    #[test]
+    fn moving_anchor_keeps_connectivity() {
+        let mut editor = PathEditor::new(path());
+        editor.apply(PathEdit::MovePoint {
+            segment: 0,
+            index: 3,
+            position: [3.0, 2.0],
+        });
+        let segments = editor.curve().segments();
+        assert_eq!(segments[0].end_point(), [3.0, 2.0]);
+        assert_eq!(segments[1].start_point(), [3.0, 2.0]);
+        assert_eq!(segments[0].control_points()[2], [2.0, 3.0]);
+        assert_eq!(segments[1].control_points()[1], [4.0, 1.0]);
+    }
+
+    #[test]
+    fn detects_handle_modes() {
+        let editor = PathEditor::new(path());
+        assert_eq!(editor.handle_mode(0), HandleMode::Free);
+        assert_eq!(editor.handle_mode(1), HandleMode::Mirrored);
+        assert_eq!(editor.handle_mode(2), HandleMode::Free);
+
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.cubic_to([1.0, 1.0], [2.0, 1.0], [3.0, 0.0]);
+        curve.cubic_to([5.0, -2.0], [5.0, -1.0], [6.0, 0.0]);
+        let editor = PathEditor::new(curve);
+        assert_eq!(editor.handle_mode(1), HandleMode::Aligned);
+    }
+
+    #[test]
+    fn moving_handle_of_mirrored_anchor() {
+        let mut editor = PathEditor::new(path());
+        editor.apply(PathEdit::MovePoint {
+            segment: 0,
+            index: 2,
+            position: [3.0, 2.0],
+        });
+        let segments = editor.curve().segments();
+        assert_eq!(segments[1].control_points()[1], [3.0, -2.0]);
+    }
+
+    #[test]
+    fn set_mirrored_equalizes_handles() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.cubic_to([0.0, 1.0], [1.0, 2.0], [2.0, 2.0]);
+        curve.cubic_to([2.0, 5.0], [3.0, 3.0], [4.0, 3.0]);
+        let mut editor = PathEditor::new(curve.clone());
+        editor.apply(PathEdit::SetHandleMode {
+            anchor: 1,
+            mode: HandleMode::Mirrored,
+        });
+        assert_eq!(editor.handle_mode(1), HandleMode::Mirrored);
+        let segments = editor.curve().segments();
+        let a: [f64; 2] = segments[0].tangent_at(1.0);
+        let b = segments[1].tangent_at(0.0);
+        assert!(a.distance(&b) < 1e-9);
+
+        assert!(editor.undo());
+        assert!(*editor.curve() == curve);
+        assert_eq!(editor.handle_mode(1), HandleMode::Free);
+    }
+
+    #[test]
+    fn moving_handle_of_aligned_anchor_keeps_length() {
+        let mut editor = PathEditor::new(path());
+        editor.apply(PathEdit::SetHandleMode {
+            anchor: 1,
+            mode: HandleMode::Aligned,
+        });
+        editor.apply(PathEdit::MovePoint {
+            segment: 0,
+            index: 2,
+            position: [3.0, 2.0],
+        });
+        let segments = editor.curve().segments();
+        let opposite = segments[1].control_points()[1];
+        assert!((opposite[0] - 3.0).abs() < 1e-12);
+        assert!((opposite[1] + 2.0f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn undo_and_redo_restore_the_curve() {
+        let original = path();
+        let mut editor = PathEditor::new(original.clone());
+        editor.apply(PathEdit::InsertKnot { segment: 1, t: 0.5 });
+        editor.apply(PathEdit::DeleteSegment { segment: 0 });
+        editor.apply(PathEdit::ToggleSmooth { anchor: 2 });
+        editor.apply(PathEdit::MovePoint {
+            segment: 1,
+            index: 0,
+            position: [10.0, 10.0],
+        });
+        let edited = editor.curve().clone();
+        let flags: Vec<_> = (0..editor.anchor_count())
+            .map(|a| editor.is_smooth(a))
+            .collect();
+
+        while editor.undo() {}
+        assert!(*editor.curve() == original);
+        assert_eq!(editor.anchor_count(), 4);
+        assert!(editor.is_smooth(1));
+        assert!(!editor.can_undo());
+
+        while editor.redo() {}
+        assert!(*editor.curve() == edited);
+        let redone: Vec<_> = (0..editor.anchor_count())
+            .map(|a| editor.is_smooth(a))
+            .collect();
+        assert_eq!(redone, flags);
+    }
+
+    #[test]
+    fn insert_knot_keeps_shape() {
+        let mut editor = PathEditor::new(path());
+        editor.apply(PathEdit::InsertKnot {
+            segment: 0,
+            t: 0.25,
+        });
+        assert_eq!(editor.anchor_count(), 5);
+        assert!(editor.is_smooth(1));
+        let original = path();
+        let split = editor.curve().segments()[0].end_point();
+        let expected = original.segments()[0].value_at(0.25);
+        assert!((split[0] - expected[0]).abs() < 1e-12);
+        assert!((split[1] - expected[1]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn delete_segment_joins_neighbours() {
+        let mut editor = PathEditor::new(path());
+        editor.apply(PathEdit::DeleteSegment { segment: 1 });
+        let segments = editor.curve().segments();
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[1].start_point(), [3.0, 0.0]);
+        assert_eq!(editor.curve().end_point(), [6.0, 3.0]);
+
+        editor.apply(PathEdit::DeleteSegment { segment: 1 });
+        editor.apply(PathEdit::DeleteSegment { segment: 0 });
+        assert!(editor.curve().segments().is_empty());
+        assert_eq!(editor.curve().end_point(), [0.0, 0.0]);
+        assert!(editor.undo());
+        assert_eq!(editor.curve().segments().len(), 1);
+    }
+
+    #[test]
+    fn toggle_smooth_aligns_handles() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.cubic_to([0.0, 1.0], [1.0, 2.0], [2.0, 2.0]);
+        curve.cubic_to([2.0, 3.0], [3.0, 3.0], [4.0, 3.0]);
+        let mut editor = PathEditor::new(curve);
+        assert!(!editor.is_smooth(1));
+
+        editor.apply(PathEdit::ToggleSmooth { anchor: 1 });
+        assert!(editor.is_smooth(1));
+        let segments = editor.curve().segments();
+        let a: [f64; 2] = segments[0].tangent_at(1.0);
+        let b = segments[1].tangent_at(0.0);
+        assert!((a.dot(&b) - a.length() * b.length()).abs() < 1e-9);
+
+        editor.apply(PathEdit::ToggleSmooth { anchor: 1 });
+        assert!(!editor.is_smooth(1));
+    }
+
+    #[test]
+    fn closed_curve_stays_closed() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([2.0, 0.0]);
+        curve.line_to([1.0, 2.0]);
+        curve.close();
+        let mut editor = PathEditor::new(curve);
+        editor.apply(PathEdit::MovePoint {
+            segment: 0,
+            index: 0,
+            position: [-1.0, -1.0],
+        });
+        assert_eq!(editor.curve().start_point(), [-1.0, -1.0]);
+        assert_eq!(editor.curve().end_point(), [-1.0, -1.0]);
+    }
+}
@@ -0,0 +1,190 @@
+use crate::curve::polyline_length;
+use crate::easing::CubicBezierEasing;
+use crate::{Curve, Distance, Point, Scalar};
+use alloc::vec::Vec;
+use num_traits::NumCast;
+
+/// A stage of an [`Envelope`], moving from the previous level to `level`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EnvelopeStage<F: Scalar> {
+    pub duration: F,
+    pub level: F,
+    /// Shape of the transition, linear by default.
+    pub easing: CubicBezierEasing<F>,
+}
+
+/// Piecewise curve of levels changing over time, like an ADSR envelope of a synthesizer
+/// or a parameter automation lane.
+///
+/// Stages are added with a builder, `t` from 0 to 1 covers the total duration:
+/// ```
+/// use baiser::{Curve, Envelope};
+///
+/// let envelope = Envelope::new(0.0)
+///     .ramp_to(0.1, 1.0) // attack
+///     .ramp_to(0.2, 0.6) // decay
+///     .hold(0.5) // sustain
+///     .ramp_to(0.2, 0.0); // release
+/// assert_eq!(envelope.duration(), 1.0);
+/// assert_eq!(envelope.value_at(0.1), 1.0);
+/// assert_eq!(envelope.value_at(0.5), 0.6);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Envelope<F: Scalar> {
+    start: F,
+    stages: Vec<EnvelopeStage<F>>,
+    duration: F,
+}
+
+impl<F: Scalar + Point<Scalar = F>> Envelope<F> {
+    /// Empty envelope starting at `level`.
+    pub fn new(level: F) -> Self {
+        Self {
+            start: level,
+            stages: Vec::new(),
+            duration: F::zero(),
+        }
+    }
+
+    /// The classic attack, decay, sustain, release envelope going from zero to one, down to `sustain`,
+    /// and back to zero.
+    pub fn adsr(attack: F, decay: F, sustain: F, sustain_duration: F, release: F) -> Self {
+        Self::new(F::zero())
+            .ramp_to(attack, F::one())
+            .ramp_to(decay, sustain)
+            .hold(sustain_duration)
+            .ramp_to(release, F::zero())
+    }
+
+    /// Linear transition to `level` during `duration`.
+    pub fn ramp_to(self, duration: F, level: F) -> Self {
+        self.ease_to(duration, level, CubicBezierEasing::linear())
+    }
+
+    /// Transition to `level` during `duration` shaped by `easing`.
+    ///
+    /// # Panics
+    /// If `duration` is negative.
+    pub fn ease_to(mut self, duration: F, level: F, easing: CubicBezierEasing<F>) -> Self {
+        assert!(duration >= F::zero(), "stage duration must not be negative");
+        self.duration = self.duration + duration;
+        self.stages.push(EnvelopeStage {
+            duration,
+            level,
+            easing,
+        });
+        self
+    }
+
+    /// Keep the current level during `duration`.
+    pub fn hold(self, duration: F) -> Self {
+        let level = self.end_level();
+        self.ramp_to(duration, level)
+    }
+
+    pub fn stages(&self) -> &[EnvelopeStage<F>] {
+        &self.stages
+    }
+
+    /// Sum of stage durations.
+    pub fn duration(&self) -> F {
+        self.duration
+    }
+
+    fn end_level(&self) -> F {
+        self.stages.last().map_or(self.start, |stage| stage.level)
+    }
+
+    /// Level at `time` from the start, clamped to the envelope duration.
+    pub fn value_at_time(&self, time: F) -> F {
+        self.locate(time)
+            .map_or(self.end_level(), |(from, stage, x)| {
+                from + (stage.level - from) * stage.easing.ease_at(x)
+            })
+    }
+
+    /// The stage at `time`, the level it starts from, and the progress in it.
+    fn locate(&self, time: F) -> Option<(F, &EnvelopeStage<F>, F)> {
+        let mut from = self.start;
+        let mut start = F::zero();
+        for stage in &self.stages {
+            if time < start + stage.duration {
+                let x = ((time - start) / stage.duration).max(F::zero());
+                return Some((from, stage, x));
+            }
+            start = start + stage.duration;
+            from = stage.level;
+        }
+        None
+    }
+}
+
+impl<F: Scalar + Point<Scalar = F>> Curve<F> for Envelope<F> {
+    fn value_at(&self, t: F) -> F {
+        self.value_at_time(t * self.duration)
+    }
+
+    fn tangent_at(&self, t: F) -> F {
+        let time = t.clamp(F::zero(), F::one()) * self.duration;
+        // The last stage is extended to the end
+        let time = if t >= F::one() {
+            time - self.duration * NumCast::from(1e-9).unwrap()
+        } else {
+            time
+        };
+        self.locate(time).map_or(F::zero(), |(from, stage, x)| {
+            (stage.level - from) * stage.easing.tangent_at(x) * self.duration / stage.duration
+        })
+    }
+
+    fn estimate_length(&self, precision: F) -> F
+    where
+        F: Distance,
+    {
+        polyline_length(self, F::zero(), F::one(), 16 * self.stages.len(), precision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn adsr() {
+        let envelope = Envelope::adsr(1.0, 1.0, 0.5, 2.0, 4.0);
+        assert_eq!(envelope.duration(), 8.0);
+        assert_eq!(envelope.stages().len(), 4);
+        assert_relative_eq!(envelope.value_at_time(0.5), 0.5);
+        assert_relative_eq!(envelope.value_at_time(1.5), 0.75);
+        assert_relative_eq!(envelope.value_at_time(3.0), 0.5);
+        assert_relative_eq!(envelope.value_at_time(6.0), 0.25);
+        assert_relative_eq!(envelope.value_at_time(9.0), 0.0);
+        assert_relative_eq!(envelope.value_at(0.5), 0.5);
+        // Total variation, up by one and down by one
+        assert_relative_eq!(envelope.estimate_length(0.001), 2.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn tangents() {
+        let envelope = Envelope::adsr(1.0, 1.0, 0.5, 2.0, 4.0);
+        // The attack rises by one in 1/8 of t
+        assert_relative_eq!(envelope.tangent_at(0.05), 8.0, epsilon = 1e-9);
+        assert_relative_eq!(envelope.tangent_at(0.3), 0.0, epsilon = 1e-9);
+        assert_relative_eq!(envelope.tangent_at(1.0), -1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn eased_stage() {
+        let envelope = Envelope::new(0.0).ease_to(2.0, 10.0, CubicBezierEasing::ease_in());
+        let expected = 10.0 * CubicBezierEasing::ease_in().ease_at(0.25);
+        assert_relative_eq!(envelope.value_at_time(0.5), expected);
+    }
+
+    #[test]
+    fn zero_duration_stage() {
+        let envelope = Envelope::new(0.0).ramp_to(0.0, 1.0).hold(1.0);
+        assert_eq!(envelope.value_at(0.0), 1.0);
+        assert_eq!(Envelope::new(3.0).value_at(0.5), 3.0);
+    }
+}
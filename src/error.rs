@@ -0,0 +1,90 @@
+use crate::CurveError;
+#[cfg(feature = "alloc")]
+use crate::{DecodeError, ParseCurveError};
+use core::fmt::{self, Display, Formatter};
+
+/// Any error returned by the library, every specific error converts into it,
+/// so `?` works across the APIs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// Invalid geometry, see [`CurveError`].
+    Curve(CurveError),
+    /// Invalid binary data, see [`DecodeError`].
+    #[cfg(feature = "alloc")]
+    Decode(DecodeError),
+    /// Invalid textual representation, see [`ParseCurveError`].
+    #[cfg(feature = "alloc")]
+    Parse(ParseCurveError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Curve(e) => write!(f, "invalid curve: {}", e),
+            #[cfg(feature = "alloc")]
+            Error::Decode(e) => write!(f, "decoding failed: {}", e),
+            #[cfg(feature = "alloc")]
+            Error::Parse(e) => write!(f, "parsing failed: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Curve(e) => Some(e),
+            Error::Decode(e) => Some(e),
+            Error::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<CurveError> for Error {
+    fn from(error: CurveError) -> Self {
+        Error::Curve(error)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<DecodeError> for Error {
+    fn from(error: DecodeError) -> Self {
+        Error::Decode(error)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<ParseCurveError> for Error {
+    fn from(error: ParseCurveError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::ComposedCurve;
+    use alloc::string::ToString;
+
+    fn decode_and_check(data: &[u8]) -> Result<ComposedCurve<[f64; 2]>, Error> {
+        let curve = ComposedCurve::decode(data)?;
+        curve.validate()?;
+        Ok(curve)
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(
+            decode_and_check(b"nope"),
+            Err(Error::Decode(DecodeError::InvalidMagic))
+        );
+        let empty = ComposedCurve::new([0.0, 0.0]).encode();
+        let error = decode_and_check(&empty).unwrap_err();
+        assert_eq!(error, Error::Curve(CurveError::Empty));
+        assert_eq!(
+            error.to_string(),
+            "invalid curve: the curve has no segments"
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{InnerProduct, PlanarPoint, Point, Scalar};
+use ::euclid::{Point2D, Vector2D};
+
+macro_rules! impl_euclid_point {
+    ($point:ident) => {
+        impl<F: Scalar, U> Point for $point<F, U> {
+            type Scalar = F;
+
+            fn add(&self, other: &Self) -> Self {
+                $point::new(self.x + other.x, self.y + other.y)
+            }
+
+            fn sub(&self, other: &Self) -> Self {
+                $point::new(self.x - other.x, self.y - other.y)
+            }
+
+            fn multiply(&self, other: &Self) -> Self {
+                $point::new(self.x * other.x, self.y * other.y)
+            }
+
+            fn scale(&self, s: F) -> Self {
+                $point::new(self.x * s, self.y * s)
+            }
+        }
+
+        impl<F: Scalar, U> InnerProduct for $point<F, U> {
+            fn dot(&self, other: &Self) -> F {
+                self.x * other.x + self.y * other.y
+            }
+        }
+
+        impl<F: Scalar, U> PlanarPoint for $point<F, U> {
+            fn from_xy(x: F, y: F) -> Self {
+                $point::new(x, y)
+            }
+
+            fn x(&self) -> F {
+                self.x
+            }
+
+            fn y(&self) -> F {
+                self.y
+            }
+        }
+    };
+}
+
+impl_euclid_point!(Point2D);
+impl_euclid_point!(Vector2D);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bezier2, Curve, Distance, PlanarPoint};
+    use ::euclid::default::{Point2D, Vector2D};
+
+    #[test]
+    fn euclid_points() {
+        let curve = Bezier2::new(
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 2.0),
+            Point2D::new(2.0, 0.0),
+        );
+        assert_eq!(curve.value_at(0.5), Point2D::new(1.0, 1.0));
+        assert_eq!(
+            Point2D::new(0.0, 0.0).distance(&Point2D::new(3.0, 4.0)),
+            5.0
+        );
+        assert_eq!(
+            Vector2D::new(1.0, 0.0).perpendicular(),
+            Vector2D::new(-0.0, 1.0)
+        );
+    }
+}
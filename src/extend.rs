@@ -0,0 +1,183 @@
+use crate::{Curve, Distance, Point, Scalar};
+use core::marker::PhantomData;
+use num_traits::{One, Zero};
+
+/// How a curve is evaluated for `t` outside of the `[0, 1]` range.
+///
+/// Without it the behavior depends on the curve type, e.g. bezier curves extrapolate
+/// their polynomials and [`ComposedCurve`](crate::ComposedCurve) clamps `t`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ExtendMode {
+    /// Hold the start or the end point.
+    #[default]
+    Clamp,
+    /// Continue along the tangent at the start or the end point.
+    Extrapolate,
+    /// Repeat the curve, `t = 1.25` is the same as `t = 0.25`.
+    Wrap,
+    /// Play the curve backward and forward, `t = 1.25` is the same as `t = 0.75`.
+    Mirror,
+}
+
+impl ExtendMode {
+    /// Parameter inside `[0, 1]` and whether the curve is played backward there,
+    /// extrapolation uses the clamped parameter.
+    fn inner<F: Scalar>(self, t: F) -> (F, bool) {
+        let (zero, one) = (F::zero(), F::one());
+        if t >= zero && t <= one {
+            return (t, false);
+        }
+        match self {
+            ExtendMode::Clamp | ExtendMode::Extrapolate => (t.clamp(zero, one), false),
+            ExtendMode::Wrap => (t - t.floor(), false),
+            ExtendMode::Mirror => {
+                let two = one + one;
+                let t = t - (t / two).floor() * two;
+                if t > one {
+                    (two - t, true)
+                } else {
+                    (t, false)
+                }
+            }
+        }
+    }
+}
+
+/// Value of `curve` at `t` extended outside of `[0, 1]` with `mode`.
+pub(crate) fn extended_value_at<P: Point, C: Curve<P> + ?Sized>(
+    curve: &C,
+    t: P::Scalar,
+    mode: ExtendMode,
+) -> P {
+    let (inner, _) = mode.inner(t);
+    let point = curve.value_at(inner);
+    if mode == ExtendMode::Extrapolate && inner != t {
+        point.add(&curve.tangent_at(inner).scale(t - inner))
+    } else {
+        point
+    }
+}
+
+/// Derivative of `curve` at `t` extended outside of `[0, 1]` with `mode`.
+pub(crate) fn extended_tangent_at<P: Point, C: Curve<P> + ?Sized>(
+    curve: &C,
+    t: P::Scalar,
+    mode: ExtendMode,
+) -> P {
+    let (inner, backward) = mode.inner(t);
+    let tangent = curve.tangent_at(inner);
+    if mode == ExtendMode::Clamp && inner != t {
+        tangent.scale(P::Scalar::zero())
+    } else if backward {
+        tangent.scale(-P::Scalar::one())
+    } else {
+        tangent
+    }
+}
+
+/// Evaluates the passed curve outside of the `[0, 1]` range with the given [`ExtendMode`].
+pub struct Extended<P: Point, C: Curve<P>> {
+    curve: C,
+    mode: ExtendMode,
+    phantom_data: PhantomData<P>,
+}
+
+impl<P: Point, C: Curve<P>> Extended<P, C> {
+    pub fn new(curve: C, mode: ExtendMode) -> Self {
+        Self {
+            curve,
+            mode,
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+
+    pub fn mode(&self) -> ExtendMode {
+        self.mode
+    }
+}
+
+impl<P: Point, C: Curve<P>> Curve<P> for Extended<P, C> {
+    fn value_at(&self, t: P::Scalar) -> P {
+        extended_value_at(&self.curve, t, self.mode)
+    }
+
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        extended_tangent_at(&self.curve, t, self.mode)
+    }
+
+    fn start_point(&self) -> P {
+        self.curve.start_point()
+    }
+
+    fn end_point(&self) -> P {
+        self.curve.end_point()
+    }
+
+    fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        self.curve.estimate_length(precision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bezier2;
+    use approx::assert_relative_eq;
+
+    fn curve() -> Bezier2<f64> {
+        Bezier2::new(0.0, 10.0, 10.0)
+    }
+
+    #[test]
+    fn clamp_and_extrapolate() {
+        let clamped = curve().with_extend_mode(ExtendMode::Clamp);
+        assert_relative_eq!(clamped.value_at(-0.5), 0.0);
+        assert_relative_eq!(clamped.value_at(1.5), 10.0);
+        assert_relative_eq!(clamped.tangent_at(1.5), 0.0);
+        assert_relative_eq!(clamped.value_at(0.5), curve().value_at(0.5));
+
+        let extrapolated = curve().with_extend_mode(ExtendMode::Extrapolate);
+        assert_relative_eq!(extrapolated.value_at(-0.5), -10.0);
+        assert_relative_eq!(extrapolated.tangent_at(-0.5), 20.0);
+        // The end tangent is zero, unlike the polynomial that goes back to 0 at `t = 2`
+        assert_relative_eq!(extrapolated.value_at(2.0), 10.0);
+    }
+
+    #[test]
+    fn wrap_and_mirror() {
+        let c = curve();
+        assert_relative_eq!(
+            c.value_at_extended(1.25, ExtendMode::Wrap),
+            c.value_at(0.25)
+        );
+        assert_relative_eq!(
+            c.value_at_extended(-0.25, ExtendMode::Wrap),
+            c.value_at(0.75)
+        );
+        assert_relative_eq!(c.value_at_extended(1.0, ExtendMode::Wrap), 10.0);
+
+        assert_relative_eq!(
+            c.value_at_extended(1.25, ExtendMode::Mirror),
+            c.value_at(0.75)
+        );
+        assert_relative_eq!(
+            c.value_at_extended(-0.25, ExtendMode::Mirror),
+            c.value_at(0.25)
+        );
+        assert_relative_eq!(
+            c.value_at_extended(2.25, ExtendMode::Mirror),
+            c.value_at(0.25)
+        );
+        assert_relative_eq!(
+            c.tangent_at_extended(1.25, ExtendMode::Mirror),
+            -c.tangent_at(0.75)
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use crate::bezier::Bezier;
+use crate::{ComposedCurve, Curve, Point, Scalar};
+use alloc::vec::Vec;
+use num_traits::{One, Zero};
+
+impl<P: Point> ComposedCurve<P> {
+    /// Smooth the curve by reducing the bending energy of its control polygon.
+    ///
+    /// Every iteration moves each control point half way towards the average of its neighbours,
+    /// and then pulls it back towards its original position by `stiffness` (clamped to `0..=1`).
+    /// With a stiffness of `1` nothing changes, lower values give smoother but less faithful curves.
+    /// The end points and the segment types are kept.
+    pub fn fair(&self, iterations: usize, stiffness: P::Scalar) -> ComposedCurve<P> {
+        let one = P::Scalar::one();
+        let half = one / (one + one);
+        let stiffness = stiffness.clamp(P::Scalar::zero(), one);
+
+        let mut points = Vec::from([self.start_point()]);
+        for segment in self.segments() {
+            points.extend(segment.control_points().into_iter().skip(1));
+        }
+        let original = points.clone();
+
+        let mut smoothed = points.clone();
+        for _ in 0..iterations {
+            for i in 1..points.len().saturating_sub(1) {
+                let average = points[i - 1].midpoint(&points[i + 1]);
+                let relaxed = points[i].lerp(&average, half);
+                smoothed[i] = relaxed.lerp(&original[i], stiffness);
+            }
+            core::mem::swap(&mut points, &mut smoothed);
+        }
+
+        let mut result = ComposedCurve::new(points[0].clone());
+        let mut rest = &points[1..];
+        for segment in self.segments() {
+            match segment {
+                Bezier::C0(_) => {}
+                Bezier::C1(_) => {
+                    result.line_to(rest[0].clone());
+                    rest = &rest[1..];
+                }
+                Bezier::C2(_) => {
+                    result.quadratic_to(rest[0].clone(), rest[1].clone());
+                    rest = &rest[2..];
+                }
+                Bezier::C3(_) => {
+                    result.cubic_to(rest[0].clone(), rest[1].clone(), rest[2].clone());
+                    rest = &rest[3..];
+                }
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bumpy() -> ComposedCurve<[f64; 2]> {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        for i in 0..10 {
+            let x = i as f64 * 3.0;
+            let y = if i % 2 == 0 { 0.3 } else { -0.3 };
+            curve.cubic_to([x + 1.0, y], [x + 2.0, -y], [x + 3.0, 0.0]);
+        }
+        curve.line_to([31.0, 0.0]);
+        curve
+    }
+
+    fn bending(curve: &ComposedCurve<[f64; 2]>) -> f64 {
+        let points: Vec<_> = (0..=1000)
+            .map(|i| curve.value_at(i as f64 / 1000.0))
+            .collect();
+        points
+            .windows(3)
+            .map(|w| {
+                let x = w[0][0] - 2.0 * w[1][0] + w[2][0];
+                let y = w[0][1] - 2.0 * w[1][1] + w[2][1];
+                x * x + y * y
+            })
+            .sum()
+    }
+
+    #[test]
+    fn fairing_smooths() {
+        let curve = bumpy();
+        let faired = curve.fair(20, 0.1);
+
+        assert_eq!(faired.segments().len(), curve.segments().len());
+        assert!(matches!(faired.segments()[10], Bezier::C1(_)));
+        assert_eq!(faired.start_point(), curve.start_point());
+        assert_eq!(faired.end_point(), curve.end_point());
+        assert!(bending(&faired) < bending(&curve) / 2.0);
+    }
+
+    #[test]
+    fn full_stiffness_keeps_curve() {
+        let curve = bumpy();
+        assert_eq!(curve.fair(10, 1.0), curve);
+        assert_eq!(curve.fair(0, 0.0), curve);
+    }
+}
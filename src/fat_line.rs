@@ -0,0 +1,138 @@
+use crate::{Bezier, Bezier1, Bezier2, Bezier3, PlanarPoint, Scalar};
+use num_traits::{NumCast, One, Zero};
+
+/// A strip around the line through the end points of a curve, the curve lies inside it.
+///
+/// Distances are signed, they are positive to the left of the line, see [`PlanarPoint::perpendicular`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FatLine<P: PlanarPoint> {
+    /// A point on the base line, the start point of the curve.
+    pub origin: P,
+    /// Unit direction of the base line.
+    pub direction: P,
+    /// The smallest signed distance from the base line to the curve.
+    pub min: P::Scalar,
+    /// The largest signed distance from the base line to the curve.
+    pub max: P::Scalar,
+}
+
+impl<P: PlanarPoint> FatLine<P> {
+    /// Base line from `start` to `end` with zero width, `None` if the points are equal.
+    fn through(start: &P, end: &P) -> Option<Self> {
+        let direction = end.sub(start);
+        let length = direction.length();
+        if length == P::Scalar::zero() {
+            return None;
+        }
+        Some(Self {
+            origin: start.clone(),
+            direction: direction.scale(P::Scalar::one() / length),
+            min: P::Scalar::zero(),
+            max: P::Scalar::zero(),
+        })
+    }
+
+    /// Signed distance from the base line to `point`.
+    pub fn distance(&self, point: &P) -> P::Scalar {
+        self.direction.cross(&point.sub(&self.origin))
+    }
+
+    /// Check if `point` is inside the strip.
+    pub fn contains(&self, point: &P) -> bool {
+        let distance = self.distance(point);
+        distance >= self.min && distance <= self.max
+    }
+
+    /// Extend the range of distances by `[min(0, a, b), max(0, a, b)]` scaled by `factor`.
+    fn with_range(mut self, a: P::Scalar, b: P::Scalar, factor: P::Scalar) -> Self {
+        let zero = P::Scalar::zero();
+        self.min = zero.min(a).min(b) * factor;
+        self.max = zero.max(a).max(b) * factor;
+        self
+    }
+}
+
+impl<P: PlanarPoint> Bezier1<P> {
+    /// Fat line of zero width, `None` if the end points are equal.
+    pub fn fat_line(&self) -> Option<FatLine<P>> {
+        FatLine::through(&self.p0, &self.p1)
+    }
+}
+
+impl<P: PlanarPoint> Bezier2<P> {
+    /// The tightest fat line based on the end points, `None` if they are equal.
+    pub fn fat_line(&self) -> Option<FatLine<P>> {
+        let line = FatLine::through(&self.p0, &self.p2)?;
+        let d = line.distance(&self.p1);
+        let half = P::Scalar::one() / (P::Scalar::one() + P::Scalar::one());
+        Some(line.with_range(d, d, half))
+    }
+}
+
+impl<P: PlanarPoint> Bezier3<P> {
+    /// The tightest fat line based on the end points, `None` if they are equal.
+    ///
+    /// The width follows Sederberg and Nishita, the control points distances are scaled
+    /// by `3/4` if they are on the same side of the line, and by `4/9` otherwise.
+    pub fn fat_line(&self) -> Option<FatLine<P>> {
+        let line = FatLine::through(&self.p0, &self.p3)?;
+        let d1 = line.distance(&self.p1);
+        let d2 = line.distance(&self.p2);
+        let factor: P::Scalar = if d1 * d2 > P::Scalar::zero() {
+            NumCast::from(3.0 / 4.0).unwrap()
+        } else {
+            NumCast::from(4.0 / 9.0).unwrap()
+        };
+        Some(line.with_range(d1, d2, factor))
+    }
+}
+
+impl<P: PlanarPoint> Bezier<P> {
+    /// A strip around the line through the end points containing the curve,
+    /// `None` if the end points are equal.
+    pub fn fat_line(&self) -> Option<FatLine<P>> {
+        match self {
+            Bezier::C0(_) => None,
+            Bezier::C1(c) => c.fat_line(),
+            Bezier::C2(c) => c.fat_line(),
+            Bezier::C3(c) => c.fat_line(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Curve;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn contains_curve() {
+        let curves = [
+            Bezier3::new([0.0, 0.0], [1.0, 2.0], [3.0, 1.0], [4.0, 0.0]),
+            Bezier3::new([0.0, 0.0], [1.0, 2.0], [3.0, -1.0], [4.0, 1.0]),
+        ];
+        for curve in curves {
+            let line = curve.fat_line().unwrap();
+            let (mut min, mut max) = (0.0f64, 0.0f64);
+            for i in 0..=1000 {
+                let d = line.distance(&curve.value_at(i as f64 / 1000.0));
+                min = min.min(d);
+                max = max.max(d);
+            }
+            assert!(line.min <= min + 1e-9 && line.max >= max - 1e-9);
+        }
+    }
+
+    #[test]
+    fn quadratic() {
+        let line = Bezier2::new([0.0, 0.0], [1.0, 2.0], [2.0, 0.0])
+            .fat_line()
+            .unwrap();
+        assert_relative_eq!(line.min, 0.0);
+        assert_relative_eq!(line.max, 1.0);
+        assert!(line.contains(&[1.0, 1.0]));
+        assert!(!line.contains(&[1.0, -0.5]));
+        assert!(Bezier1::new([1.0, 1.0], [1.0, 1.0]).fat_line().is_none());
+    }
+}
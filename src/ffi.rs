@@ -0,0 +1,263 @@
+//! C ABI for embedding the library into C and C++ applications.
+//!
+//! Paths are exposed as opaque [`BaiserPath`] handles with `f64` coordinates, a handle
+//! created with [`baiser_path_new`] must be released with [`baiser_path_free`].
+//! Build the library with `cargo rustc --release --features ffi --crate-type staticlib`
+//! (or `cdylib`) to link it.
+
+use crate::{ComposedCurve, Curve};
+use alloc::boxed::Box;
+use core::ptr;
+
+/// Opaque path handle.
+pub struct BaiserPath(ComposedCurve<[f64; 2]>);
+
+/// Creates an empty path starting at `(x, y)`.
+#[no_mangle]
+pub extern "C" fn baiser_path_new(x: f64, y: f64) -> *mut BaiserPath {
+    Box::into_raw(Box::new(BaiserPath(ComposedCurve::new([x, y]))))
+}
+
+/// Releases the path, null is ignored.
+///
+/// # Safety
+///
+/// `path` must be null or a handle returned by [`baiser_path_new`] that wasn't released yet.
+#[no_mangle]
+pub unsafe extern "C" fn baiser_path_free(path: *mut BaiserPath) {
+    if !path.is_null() {
+        drop(Box::from_raw(path));
+    }
+}
+
+/// Appends a line to `(x, y)`.
+///
+/// # Safety
+///
+/// `path` must be null or a valid handle, nothing happens for null.
+#[no_mangle]
+pub unsafe extern "C" fn baiser_path_line_to(path: *mut BaiserPath, x: f64, y: f64) {
+    if let Some(path) = path.as_mut() {
+        path.0.line_to([x, y]);
+    }
+}
+
+/// Appends a quadratic curve with the control point `(x1, y1)` ending at `(x2, y2)`.
+///
+/// # Safety
+///
+/// `path` must be null or a valid handle, nothing happens for null.
+#[no_mangle]
+pub unsafe extern "C" fn baiser_path_quadratic_to(
+    path: *mut BaiserPath,
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+) {
+    if let Some(path) = path.as_mut() {
+        path.0.quadratic_to([x1, y1], [x2, y2]);
+    }
+}
+
+/// Appends a cubic curve with the control points `(x1, y1)`, `(x2, y2)` ending at `(x3, y3)`.
+///
+/// # Safety
+///
+/// `path` must be null or a valid handle, nothing happens for null.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn baiser_path_cubic_to(
+    path: *mut BaiserPath,
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+    x3: f64,
+    y3: f64,
+) {
+    if let Some(path) = path.as_mut() {
+        path.0.cubic_to([x1, y1], [x2, y2], [x3, y3]);
+    }
+}
+
+/// Closes the path with a line to its start point.
+///
+/// # Safety
+///
+/// `path` must be null or a valid handle, nothing happens for null.
+#[no_mangle]
+pub unsafe extern "C" fn baiser_path_close(path: *mut BaiserPath) {
+    if let Some(path) = path.as_mut() {
+        path.0.close();
+    }
+}
+
+/// Number of segments in the path, `0` for null.
+///
+/// # Safety
+///
+/// `path` must be null or a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn baiser_path_segment_count(path: *const BaiserPath) -> usize {
+    path.as_ref().map_or(0, |path| path.0.segments().len())
+}
+
+/// Writes the point at `t` in `0..=1` into `out[0]`, `out[1]`, returns `false` for null arguments,
+/// `t` outside of `0..=1` (or NaN), or a path without segments.
+///
+/// # Safety
+///
+/// `path` must be null or a valid handle, `out` must be null or point to two writable `double`s.
+#[no_mangle]
+pub unsafe extern "C" fn baiser_path_value_at(
+    path: *const BaiserPath,
+    t: f64,
+    out: *mut f64,
+) -> bool {
+    write_point(path, t, out, |curve, t| curve.value_at(t))
+}
+
+/// Writes the tangent (the derivative by `t`) at `t` into `out[0]`, `out[1]`,
+/// returns `false` for null arguments, `t` outside of `0..=1` (or NaN), or a path without segments.
+///
+/// # Safety
+///
+/// `path` must be null or a valid handle, `out` must be null or point to two writable `double`s.
+#[no_mangle]
+pub unsafe extern "C" fn baiser_path_tangent_at(
+    path: *const BaiserPath,
+    t: f64,
+    out: *mut f64,
+) -> bool {
+    write_point(path, t, out, |curve, t| curve.tangent_at(t))
+}
+
+/// Flattens the path into a polyline and writes up to `capacity` points as `x, y` pairs into `out`.
+///
+/// Returns the total number of points, so the required capacity can be queried by passing
+/// a null `out`. Returns `0` for a null path.
+///
+/// # Safety
+///
+/// `path` must be null or a valid handle, `out` must be null or point to `2 * capacity` writable `double`s.
+#[no_mangle]
+pub unsafe extern "C" fn baiser_path_flatten(
+    path: *const BaiserPath,
+    tolerance: f64,
+    out: *mut f64,
+    capacity: usize,
+) -> usize {
+    let Some(path) = path.as_ref() else {
+        return 0;
+    };
+    let points = path.0.flatten(tolerance);
+    if !out.is_null() {
+        let count = points.len().min(capacity);
+        ptr::copy_nonoverlapping(points.as_ptr() as *const f64, out, 2 * count);
+    }
+    points.len()
+}
+
+/// Curves panic on empty paths and NaN parameters, and on any parameter outside of `0..=1` with
+/// the `debug-checks` feature. A panic can't unwind through `extern "C"` functions, so these are
+/// rejected before evaluation.
+unsafe fn write_point(
+    path: *const BaiserPath,
+    t: f64,
+    out: *mut f64,
+    f: impl FnOnce(&ComposedCurve<[f64; 2]>, f64) -> [f64; 2],
+) -> bool {
+    match path.as_ref() {
+        Some(path)
+            if !out.is_null() && !path.0.segments().is_empty() && (0.0..=1.0).contains(&t) =>
+        {
+            let [x, y] = f(&path.0, t);
+            out.write(x);
+            out.add(1).write(y);
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn path_lifecycle() {
+        unsafe {
+            let path = baiser_path_new(0.0, 0.0);
+            baiser_path_line_to(path, 4.0, 0.0);
+            baiser_path_quadratic_to(path, 4.0, 4.0, 0.0, 4.0);
+            baiser_path_close(path);
+            assert_eq!(baiser_path_segment_count(path), 3);
+
+            let mut point = [0.0; 2];
+            assert!(baiser_path_value_at(path, 0.0, point.as_mut_ptr()));
+            assert_eq!(point, [0.0, 0.0]);
+            assert!(baiser_path_tangent_at(path, 0.1, point.as_mut_ptr()));
+            assert!(point[0] > 0.0 && point[1] == 0.0);
+
+            let count = baiser_path_flatten(path, 0.1, ptr::null_mut(), 0);
+            let mut buffer = vec![0.0; 2 * count];
+            assert_eq!(
+                baiser_path_flatten(path, 0.1, buffer.as_mut_ptr(), count),
+                count
+            );
+            assert_eq!(&buffer[..2], [0.0, 0.0]);
+            assert_eq!(&buffer[2 * count - 2..], [0.0, 0.0]);
+
+            baiser_path_free(path);
+        }
+    }
+
+    #[test]
+    fn null_handles() {
+        unsafe {
+            let mut point = [0.0; 2];
+            baiser_path_line_to(ptr::null_mut(), 1.0, 1.0);
+            assert_eq!(baiser_path_segment_count(ptr::null()), 0);
+            assert!(!baiser_path_value_at(ptr::null(), 0.5, point.as_mut_ptr()));
+            assert_eq!(
+                baiser_path_flatten(ptr::null(), 0.1, point.as_mut_ptr(), 1),
+                0
+            );
+            baiser_path_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn empty_path() {
+        unsafe {
+            let path = baiser_path_new(1.0, 2.0);
+            let mut point = [-1.0; 2];
+            assert!(!baiser_path_value_at(path, 0.5, point.as_mut_ptr()));
+            assert!(!baiser_path_tangent_at(path, 0.5, point.as_mut_ptr()));
+            assert_eq!(point, [-1.0, -1.0]);
+            assert_eq!(baiser_path_flatten(path, 0.1, ptr::null_mut(), 0), 1);
+
+            baiser_path_line_to(path, 2.0, 2.0);
+            assert!(!baiser_path_value_at(path, f64::NAN, point.as_mut_ptr()));
+            assert!(!baiser_path_value_at(path, 1.5, point.as_mut_ptr()));
+            assert!(!baiser_path_tangent_at(path, -0.5, point.as_mut_ptr()));
+            assert!(baiser_path_value_at(path, 1.0, point.as_mut_ptr()));
+            assert_eq!(point, [2.0, 2.0]);
+            baiser_path_free(path);
+        }
+    }
+
+    #[test]
+    fn partial_flatten() {
+        unsafe {
+            let path = baiser_path_new(0.0, 0.0);
+            baiser_path_line_to(path, 4.0, 0.0);
+            let mut buffer = [-1.0; 4];
+            assert_eq!(baiser_path_flatten(path, 0.1, buffer.as_mut_ptr(), 2), 5);
+            assert_eq!(buffer, [0.0, 0.0, 1.0, 0.0]);
+            baiser_path_free(path);
+        }
+    }
+}
@@ -0,0 +1,600 @@
+use crate::{
+    Bezier, Bezier3, ComposedCurve, Curve, Distance, InnerProduct, Point, Scalar, Tolerance,
+};
+use alloc::vec::Vec;
+use num_traits::{NumCast, One, Zero};
+
+/// Number of parameter re-estimation rounds after the initial fit.
+const REPARAMETERIZATION_ITERATIONS: usize = 8;
+/// Number of re-estimation rounds for a piece in [`ComposedCurve::fit_points`] before it's split.
+const PIECE_ITERATIONS: usize = 4;
+/// Number of points sampled from each segment in [`ComposedCurve::remove_anchor`].
+const REMOVAL_SAMPLES: usize = 16;
+
+impl<P: InnerProduct> Bezier3<P> {
+    /// Fit a cubic curve to `points` with least squares, the curve starts at the first
+    /// point and ends at the last one.
+    ///
+    /// `params` are the curve parameters for every point, when they are not known
+    /// chord length parameterization is used. The parameters are then improved with
+    /// Newton's method a few times and the best fit is returned.
+    ///
+    /// Returns `None` if there are less than two points.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `params` and `points` have different lengths.
+    pub fn fit(points: &[P], params: Option<&[P::Scalar]>) -> Option<Self> {
+        if points.len() < 2 {
+            return None;
+        }
+
+        let mut params = match params {
+            Some(params) => {
+                assert_eq!(params.len(), points.len());
+                params.to_vec()
+            }
+            None => chord_length_params(points),
+        };
+
+        let mut best = fit_with_params(points, &params);
+        let mut best_error = squared_error(&best, points, &params);
+        for _ in 0..REPARAMETERIZATION_ITERATIONS {
+            reparameterize(&best, points, &mut params);
+            let curve = fit_with_params(points, &params);
+            let error = squared_error(&curve, points, &params);
+            if error < best_error {
+                best = curve;
+                best_error = error;
+            }
+        }
+        Some(best)
+    }
+}
+
+impl<P: InnerProduct> ComposedCurve<P> {
+    /// Fit a tangent continuous sequence of cubic curves to `points` with Schneider's algorithm
+    /// (Graphics Gems, "An Algorithm for Automatically Fitting Digitized Curves").
+    ///
+    /// The points are first split at corners, where the direction of the polyline turns by more
+    /// than `corner_angle` radians. Every part is then fitted with a single cubic, and recursively
+    /// split at the worst point while the distance between the points and the curve is above `max_error`.
+    ///
+    /// Returns `None` if there are no points.
+    pub fn fit_points(points: &[P], max_error: P::Scalar, corner_angle: P::Scalar) -> Option<Self> {
+        let mut points = points.to_vec();
+        points.dedup();
+
+        let mut result = ComposedCurve::new(points.first()?.clone());
+        fit_points_into(&points, max_error, corner_angle, None, &mut result);
+        Some(result)
+    }
+
+    /// [`ComposedCurve::fit_points`] with the error given as a [`Tolerance`], a relative tolerance
+    /// is a fraction of the length of the polyline through `points`.
+    pub fn fit_points_within(
+        points: &[P],
+        tolerance: Tolerance<P::Scalar>,
+        corner_angle: P::Scalar,
+    ) -> Option<Self> {
+        let length = points.windows(2).fold(P::Scalar::zero(), |acc, pair| {
+            acc + pair[0].distance(&pair[1])
+        });
+        Self::fit_points(points, tolerance.absolute_for(length), corner_angle)
+    }
+
+    /// Remove an interior anchor and replace the two segments around it with a single cubic,
+    /// the inverse of [`ComposedCurve::insert_point_at`].
+    ///
+    /// The cubic keeps the outer end points and tangent directions. It starts as the reverse
+    /// of a split, which is exact for anchors added by splitting, and the lengths of its handles
+    /// are then refitted to points sampled from the removed segments. Returns the largest distance
+    /// of the samples from the new cubic, or `None` if `anchor` isn't interior.
+    pub fn remove_anchor(&mut self, anchor: usize) -> Option<P::Scalar> {
+        let segments = self.segments();
+        if anchor == 0 || anchor >= segments.len() {
+            return None;
+        }
+        let before = as_cubic(&segments[anchor - 1]);
+        let after = as_cubic(&segments[anchor]);
+        let zero = P::Scalar::zero();
+        let one = P::Scalar::one();
+
+        // Where the anchor would be on the merged cubic if the segments came from a split,
+        // the handles around a split point are proportional to the lengths of the parts
+        let before_handle = before.p3.distance(&before.p2);
+        let after_handle = after.p1.distance(&after.p0);
+        let split = if before_handle + after_handle > zero {
+            before_handle / (before_handle + after_handle)
+        } else {
+            one / (one + one)
+        };
+
+        let count: P::Scalar = NumCast::from(REMOVAL_SAMPLES).unwrap();
+        let mut points = Vec::with_capacity(2 * REMOVAL_SAMPLES + 1);
+        let mut params = Vec::with_capacity(2 * REMOVAL_SAMPLES + 1);
+        for i in 0..=REMOVAL_SAMPLES {
+            let t = <P::Scalar as NumCast>::from(i).unwrap() / count;
+            points.push(before.value_at(t));
+            params.push(split * t);
+        }
+        for i in 1..=REMOVAL_SAMPLES {
+            let t = <P::Scalar as NumCast>::from(i).unwrap() / count;
+            points.push(after.value_at(t));
+            params.push(split + (one - split) * t);
+        }
+
+        // Undo the split, exact when the segments came from one
+        let mut best = Bezier3::new(
+            before.p0.clone(),
+            if split > zero {
+                before.p0.add(&before.p1.sub(&before.p0).scale(one / split))
+            } else {
+                before.p0.clone()
+            },
+            if split < one {
+                after
+                    .p3
+                    .add(&after.p2.sub(&after.p3).scale(one / (one - split)))
+            } else {
+                after.p3.clone()
+            },
+            after.p3.clone(),
+        );
+        let mut best_error = max_distance(&best, &points, &params).0;
+
+        let last = points.len() - 1;
+        let start_tangent = match unit(&before.p1.sub(&before.p0)) {
+            tangent if tangent.length() == zero => unit(&points[1].sub(&points[0])),
+            tangent => tangent,
+        };
+        let end_tangent = match unit(&after.p2.sub(&after.p3)) {
+            tangent if tangent.length() == zero => unit(&points[last - 1].sub(&points[last])),
+            tangent => tangent,
+        };
+        let mut curve = best.clone();
+        for _ in 0..REPARAMETERIZATION_ITERATIONS {
+            reparameterize(&curve, &points, &mut params);
+            curve = fit_with_tangents(&points, &params, &start_tangent, &end_tangent);
+            let error = max_distance(&curve, &points, &params).0;
+            if error < best_error {
+                best = curve.clone();
+                best_error = error;
+            }
+        }
+
+        self.splice_segments(anchor - 1..anchor + 1, Vec::from([Bezier::C3(best)]));
+        Some(best_error)
+    }
+}
+
+/// The segment as a cubic with the same shape.
+fn as_cubic<P: Point>(segment: &Bezier<P>) -> Bezier3<P> {
+    match segment {
+        Bezier::C0(c) => Bezier3::new(
+            c.point.clone(),
+            c.point.clone(),
+            c.point.clone(),
+            c.point.clone(),
+        ),
+        Bezier::C1(c) => c.to_quadratic().to_cubic(),
+        Bezier::C2(c) => c.to_cubic(),
+        Bezier::C3(c) => c.clone(),
+    }
+}
+
+/// Append the fit of `points` to `result`, which must end at the first point.
+///
+/// `start_tangent` is the unit direction the first piece leaves in, when it's `None`
+/// the direction of the first polyline edge is used.
+pub(crate) fn fit_points_into<P: InnerProduct>(
+    points: &[P],
+    max_error: P::Scalar,
+    corner_angle: P::Scalar,
+    start_tangent: Option<&P>,
+    result: &mut ComposedCurve<P>,
+) {
+    let corner_cos = corner_angle.cos();
+
+    let mut first = 0;
+    for i in 1..points.len() {
+        let is_corner = i + 1 < points.len() && {
+            let d1 = points[i].sub(&points[i - 1]);
+            let d2 = points[i + 1].sub(&points[i]);
+            d1.dot(&d2) < corner_cos * d1.length() * d2.length()
+        };
+        if is_corner || i + 1 == points.len() {
+            let part = &points[first..=i];
+            let start_tangent = match start_tangent {
+                Some(tangent) if first == 0 => tangent.clone(),
+                _ => unit(&part[1].sub(&part[0])),
+            };
+            let end_tangent = unit(&part[part.len() - 2].sub(&part[part.len() - 1]));
+            fit_piece(part, &start_tangent, &end_tangent, max_error, result);
+            first = i;
+        }
+    }
+}
+
+pub(crate) fn unit<P: InnerProduct>(vector: &P) -> P {
+    let length = vector.length();
+    if length == P::Scalar::zero() {
+        vector.clone()
+    } else {
+        vector.scale(P::Scalar::one() / length)
+    }
+}
+
+fn fit_piece<P: InnerProduct>(
+    points: &[P],
+    start_tangent: &P,
+    end_tangent: &P,
+    max_error: P::Scalar,
+    result: &mut ComposedCurve<P>,
+) {
+    let one = P::Scalar::one();
+    let three = one + one + one;
+    let first = &points[0];
+    let last = &points[points.len() - 1];
+
+    if points.len() == 2 {
+        let distance = first.distance(last) / three;
+        result.cubic_to(
+            first.add(&start_tangent.scale(distance)),
+            last.add(&end_tangent.scale(distance)),
+            last.clone(),
+        );
+        return;
+    }
+
+    let mut params = chord_length_params(points);
+    let mut curve = fit_with_tangents(points, &params, start_tangent, end_tangent);
+    let (mut error, mut split) = max_distance(&curve, points, &params);
+    if error <= max_error {
+        result.cubic_to(curve.p1, curve.p2, curve.p3);
+        return;
+    }
+
+    // Re-estimating the parameters only helps when the fit is already close
+    if error <= max_error * (one + one + one + one) {
+        for _ in 0..PIECE_ITERATIONS {
+            reparameterize(&curve, points, &mut params);
+            curve = fit_with_tangents(points, &params, start_tangent, end_tangent);
+            (error, split) = max_distance(&curve, points, &params);
+            if error <= max_error {
+                result.cubic_to(curve.p1, curve.p2, curve.p3);
+                return;
+            }
+        }
+    }
+
+    let center_tangent = unit(&points[split - 1].sub(&points[split + 1]));
+    fit_piece(
+        &points[..=split],
+        start_tangent,
+        &center_tangent,
+        max_error,
+        result,
+    );
+    fit_piece(
+        &points[split..],
+        &center_tangent.scale(-one),
+        end_tangent,
+        max_error,
+        result,
+    );
+}
+
+/// Least squares fit where the handles keep the given directions, only their lengths are found.
+fn fit_with_tangents<P: InnerProduct>(
+    points: &[P],
+    params: &[P::Scalar],
+    start_tangent: &P,
+    end_tangent: &P,
+) -> Bezier3<P> {
+    let one = P::Scalar::one();
+    let three = one + one + one;
+    let zero = P::Scalar::zero();
+
+    let p0 = points[0].clone();
+    let p3 = points[points.len() - 1].clone();
+
+    let (mut c11, mut c12, mut c22, mut x1, mut x2) = (zero, zero, zero, zero, zero);
+    for (point, &t) in points.iter().zip(params) {
+        let nt = one - t;
+        let b0 = nt * nt * nt;
+        let b1 = three * nt * nt * t;
+        let b2 = three * nt * t * t;
+        let b3 = t * t * t;
+
+        let a1 = start_tangent.scale(b1);
+        let a2 = end_tangent.scale(b2);
+        c11 = c11 + a1.dot(&a1);
+        c12 = c12 + a1.dot(&a2);
+        c22 = c22 + a2.dot(&a2);
+
+        let residual = point.sub(&p0.scale(b0 + b1)).sub(&p3.scale(b2 + b3));
+        x1 = x1 + a1.dot(&residual);
+        x2 = x2 + a2.dot(&residual);
+    }
+
+    let determinant = c11 * c22 - c12 * c12;
+    let (alpha1, alpha2) = if determinant == zero {
+        (zero, zero)
+    } else {
+        (
+            (x1 * c22 - x2 * c12) / determinant,
+            (c11 * x2 - c12 * x1) / determinant,
+        )
+    };
+
+    // Negative or tiny handles make loops, fall back to the Wu/Barsky heuristic
+    let chord = p0.distance(&p3);
+    let epsilon: P::Scalar = NumCast::from(1e-6).unwrap();
+    let (alpha1, alpha2) = if alpha1 < chord * epsilon || alpha2 < chord * epsilon {
+        (chord / three, chord / three)
+    } else {
+        (alpha1, alpha2)
+    };
+
+    Bezier3::new(
+        p0.clone(),
+        p0.add(&start_tangent.scale(alpha1)),
+        p3.add(&end_tangent.scale(alpha2)),
+        p3,
+    )
+}
+
+/// The largest distance between a point and the curve, with the index of that point.
+/// The first and the last point are skipped, they always lie on the curve.
+fn max_distance<P: InnerProduct>(
+    curve: &Bezier3<P>,
+    points: &[P],
+    params: &[P::Scalar],
+) -> (P::Scalar, usize) {
+    let mut result = (P::Scalar::zero(), points.len() / 2);
+    for i in 1..points.len() - 1 {
+        let distance = curve.value_at(params[i]).distance(&points[i]);
+        if distance > result.0 {
+            result = (distance, i);
+        }
+    }
+    result
+}
+
+/// Parameters proportional to the distance along the polyline through `points`.
+pub(crate) fn chord_length_params<P: Distance>(points: &[P]) -> Vec<P::Scalar> {
+    let mut params = Vec::with_capacity(points.len());
+    let mut length = P::Scalar::zero();
+    params.push(length);
+    for pair in points.windows(2) {
+        length = length + pair[0].distance(&pair[1]);
+        params.push(length);
+    }
+
+    let last = params.len() - 1;
+    if length > P::Scalar::zero() {
+        for param in params.iter_mut() {
+            *param = *param / length;
+        }
+    } else {
+        let count: P::Scalar = NumCast::from(last).unwrap();
+        for (i, param) in params.iter_mut().enumerate() {
+            let i: P::Scalar = NumCast::from(i).unwrap();
+            *param = i / count;
+        }
+    }
+    params
+}
+
+/// Least squares fit of the inner control points, end points are fixed.
+pub(crate) fn fit_with_params<P: InnerProduct>(points: &[P], params: &[P::Scalar]) -> Bezier3<P> {
+    let one = P::Scalar::one();
+    let three = one + one + one;
+    let zero = P::Scalar::zero();
+
+    let p0 = points[0].clone();
+    let p3 = points[points.len() - 1].clone();
+
+    let (mut a11, mut a12, mut a22) = (zero, zero, zero);
+    let mut r1 = p0.scale(zero);
+    let mut r2 = p0.scale(zero);
+    for (point, &t) in points.iter().zip(params) {
+        let nt = one - t;
+        let b0 = nt * nt * nt;
+        let b1 = three * nt * nt * t;
+        let b2 = three * nt * t * t;
+        let b3 = t * t * t;
+
+        a11 = a11 + b1 * b1;
+        a12 = a12 + b1 * b2;
+        a22 = a22 + b2 * b2;
+
+        let residual = point.sub(&p0.scale(b0)).sub(&p3.scale(b3));
+        r1 = r1.add(&residual.scale(b1));
+        r2 = r2.add(&residual.scale(b2));
+    }
+
+    let determinant = a11 * a22 - a12 * a12;
+    let epsilon: P::Scalar = NumCast::from(1e-12).unwrap();
+    if determinant.abs() <= epsilon * (a11 * a22).abs() {
+        // Not enough points to determine the handles, use a straight line
+        let third = one / three;
+        return Bezier3::new(p0.clone(), p0.lerp(&p3, third), p3.lerp(&p0, third), p3);
+    }
+
+    let p1 = r1.scale(a22).sub(&r2.scale(a12)).scale(one / determinant);
+    let p2 = r2.scale(a11).sub(&r1.scale(a12)).scale(one / determinant);
+    Bezier3::new(p0, p1, p2, p3)
+}
+
+/// One Newton step for every parameter towards the closest point on `curve`.
+pub(crate) fn reparameterize<P: InnerProduct>(
+    curve: &Bezier3<P>,
+    points: &[P],
+    params: &mut [P::Scalar],
+) {
+    let one = P::Scalar::one();
+    let six = (one + one + one) * (one + one);
+    let second_a = curve.p2.sub(&curve.p1.scale(one + one)).add(&curve.p0);
+    let second_b = curve.p3.sub(&curve.p2.scale(one + one)).add(&curve.p1);
+
+    for (point, t) in points.iter().zip(params.iter_mut()) {
+        let difference = curve.value_at(*t).sub(point);
+        let first = curve.tangent_at(*t);
+        let second = second_a.lerp(&second_b, *t).scale(six);
+
+        let numerator = difference.dot(&first);
+        let denominator = first.dot(&first) + difference.dot(&second);
+        if denominator != P::Scalar::zero() {
+            *t = (*t - numerator / denominator).clamp(P::Scalar::zero(), one);
+        }
+    }
+}
+
+pub(crate) fn squared_error<P: InnerProduct>(
+    curve: &Bezier3<P>,
+    points: &[P],
+    params: &[P::Scalar],
+) -> P::Scalar {
+    points
+        .iter()
+        .zip(params)
+        .fold(P::Scalar::zero(), |acc, (point, &t)| {
+            acc + curve.value_at(t).sub(point).length_squared()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn remove_inserted_anchor() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([-1.0, 0.0]);
+        curve.cubic_to([1.0, 2.0], [3.0, 2.0], [4.0, 0.0]);
+        curve.line_to([4.0, -1.0]);
+        let original = curve.clone();
+        curve.insert_point_at(0.5);
+        assert_eq!(curve.segments().len(), 4);
+
+        let error = curve.remove_anchor(2).unwrap();
+        assert!(error < 1e-6, "{error}");
+        assert_eq!(curve.segments().len(), 3);
+        for (a, b) in curve.control_points().iter().zip(original.control_points()) {
+            assert_relative_eq!(a[0], b[0], epsilon = 1e-4);
+            assert_relative_eq!(a[1], b[1], epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    fn remove_corner_anchor() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([1.0, 1.0]);
+        curve.line_to([2.0, 0.0]);
+        assert!(curve.remove_anchor(0).is_none());
+        assert!(curve.remove_anchor(2).is_none());
+
+        let error = curve.remove_anchor(1).unwrap();
+        assert!(error > 0.01, "{error}");
+        assert_eq!(curve.segments().len(), 1);
+        assert_eq!(curve.start_point(), [0.0, 0.0]);
+        assert_eq!(curve.end_point(), [2.0, 0.0]);
+    }
+
+    #[test]
+    fn exact_fit() {
+        let curve = Bezier3::new([0.0, 0.0], [1.0, 2.0], [3.0, 2.0], [4.0, 0.0]);
+        let params: Vec<f64> = (0..=10).map(|i| i as f64 / 10.0).collect();
+        let points: Vec<_> = params.iter().map(|&t| curve.value_at(t)).collect();
+
+        let fitted = Bezier3::fit(&points, Some(&params)).unwrap();
+        for (a, b) in fitted.control_points().iter().zip(curve.control_points()) {
+            assert_relative_eq!(a[0], b[0], epsilon = 1e-9);
+            assert_relative_eq!(a[1], b[1], epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn estimated_params() {
+        let curve = Bezier3::new([0.0, 0.0], [1.0, 2.0], [3.0, 2.0], [4.0, 0.0]);
+        let points: Vec<_> = (0..=20).map(|i| curve.value_at(i as f64 / 20.0)).collect();
+
+        let fitted = Bezier3::fit(&points, None).unwrap();
+        for point in &points {
+            let distance = (0..=1000)
+                .map(|i| fitted.value_at(i as f64 / 1000.0).distance(point))
+                .fold(f64::INFINITY, f64::min);
+            assert!(distance < 0.01, "{}", distance);
+        }
+    }
+
+    #[test]
+    fn degenerate_input() {
+        assert!(Bezier3::<[f64; 2]>::fit(&[], None).is_none());
+        assert!(Bezier3::fit(&[[0.0, 0.0]], None).is_none());
+
+        let line = Bezier3::fit(&[[0.0, 0.0], [3.0, 0.0]], None).unwrap();
+        assert_relative_eq!(line.p1[0], 1.0);
+        assert_relative_eq!(line.p2[0], 2.0);
+    }
+
+    fn check_fit(curve: &ComposedCurve<[f64; 2]>, points: &[[f64; 2]], max_error: f64) {
+        let samples: Vec<_> = (0..=5000)
+            .map(|i| curve.value_at(i as f64 / 5000.0))
+            .collect();
+        for point in points {
+            let distance = samples
+                .iter()
+                .map(|q| q.distance(point))
+                .fold(f64::INFINITY, f64::min);
+            assert!(distance <= max_error * 1.1, "{}", distance);
+        }
+    }
+
+    #[test]
+    fn fit_smooth_points() {
+        let points: Vec<_> = (0..=100)
+            .map(|i| {
+                let a = i as f64 / 100.0 * core::f64::consts::PI * 3.0;
+                [a, a.sin()]
+            })
+            .collect();
+
+        let curve = ComposedCurve::fit_points(&points, 0.01, 1.0).unwrap();
+        assert!(curve.segments().len() < 20);
+        assert_eq!(curve.start_point(), [0.0, 0.0]);
+        assert_eq!(curve.end_point(), points[100]);
+        check_fit(&curve, &points, 0.01);
+    }
+
+    #[test]
+    fn fit_corners() {
+        let mut points = Vec::new();
+        for i in 0..10 {
+            points.push([i as f64, 0.0]);
+        }
+        for i in 0..=10 {
+            points.push([10.0, i as f64]);
+        }
+
+        let curve = ComposedCurve::fit_points(&points, 0.001, 0.5).unwrap();
+        assert_eq!(curve.segments().len(), 2);
+        assert_eq!(curve.segments()[0].end_point(), [10.0, 0.0]);
+        check_fit(&curve, &points, 0.001);
+    }
+
+    #[test]
+    fn fit_few_points() {
+        assert!(ComposedCurve::<[f64; 2]>::fit_points(&[], 0.1, 1.0).is_none());
+
+        let single = ComposedCurve::fit_points(&[[1.0, 1.0], [1.0, 1.0]], 0.1, 1.0).unwrap();
+        assert!(single.segments().is_empty());
+
+        let pair = ComposedCurve::fit_points(&[[0.0, 0.0], [3.0, 0.0]], 0.1, 1.0).unwrap();
+        assert_eq!(pair.segments().len(), 1);
+        assert_eq!(pair.end_point(), [3.0, 0.0]);
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{Curve, Distance};
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::{One, Zero};
+
+/// Subdivision depth that is always performed, so symmetric curves don't look straight.
+const MIN_DEPTH: usize = 2;
+/// Subdivision depth limit, it protects from infinite recursion on degenerate input.
+const MAX_DEPTH: usize = 16;
+
+/// Approximate the curve with a polyline, the distance between the curve and the polyline
+/// is expected to be less than `tolerance`. The first and the last points are always included.
+pub(crate) fn flatten<P: Distance, C: Curve<P> + ?Sized>(
+    curve: &C,
+    tolerance: P::Scalar,
+) -> Vec<P> {
+    let mut points = vec![curve.start_point()];
+    flatten_into(curve, tolerance, &mut points);
+    points
+}
+
+/// The same as [`flatten`], but appends points to `points` skipping the starting one.
+pub(crate) fn flatten_into<P: Distance, C: Curve<P> + ?Sized>(
+    curve: &C,
+    tolerance: P::Scalar,
+    points: &mut Vec<P>,
+) {
+    flatten_with(curve, tolerance, &mut |_, point| points.push(point));
+}
+
+/// Call `emit` with the parameter and the position of every point of the polyline
+/// approximating the curve, except the starting one.
+pub(crate) fn flatten_with<P: Distance, C: Curve<P> + ?Sized>(
+    curve: &C,
+    tolerance: P::Scalar,
+    emit: &mut impl FnMut(P::Scalar, P),
+) {
+    let t0 = P::Scalar::zero();
+    let t1 = P::Scalar::one();
+    subdivide(
+        curve,
+        tolerance,
+        (t0, curve.start_point()),
+        (t1, curve.end_point()),
+        0,
+        emit,
+    );
+}
+
+fn subdivide<P: Distance, C: Curve<P> + ?Sized>(
+    curve: &C,
+    tolerance: P::Scalar,
+    (t0, p0): (P::Scalar, P),
+    (t1, p1): (P::Scalar, P),
+    depth: usize,
+    emit: &mut impl FnMut(P::Scalar, P),
+) {
+    let half = P::Scalar::one() / (P::Scalar::one() + P::Scalar::one());
+    let tm = (t0 + t1) * half;
+    let pm = curve.value_at(tm);
+
+    let flat = depth >= MIN_DEPTH && p0.midpoint(&p1).distance(&pm) <= tolerance;
+
+    if flat || depth >= MAX_DEPTH {
+        emit(t1, p1);
+    } else {
+        subdivide(
+            curve,
+            tolerance,
+            (t0, p0),
+            (tm, pm.clone()),
+            depth + 1,
+            emit,
+        );
+        subdivide(curve, tolerance, (tm, pm), (t1, p1), depth + 1, emit);
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{Curve, Scalar, SpatialPoint};
+use alloc::vec::Vec;
+use num_traits::{NumCast, One, Zero};
+
+/// Orthonormal frame at a point of a curve.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frame<P: SpatialPoint> {
+    pub origin: P,
+    /// Unit tangent of the curve.
+    pub tangent: P,
+    pub normal: P,
+    /// `tangent × normal`.
+    pub binormal: P,
+}
+
+impl<P: SpatialPoint> Frame<P> {
+    /// Point at `x` along the normal and `y` along the binormal from the origin.
+    pub fn to_world(&self, x: P::Scalar, y: P::Scalar) -> P {
+        self.origin
+            .add(&self.normal.scale(x))
+            .add(&self.binormal.scale(y))
+    }
+}
+
+/// Unit vector or `None` for a zero vector.
+pub(crate) fn normalize<P: SpatialPoint>(v: &P) -> Option<P> {
+    let length = v.length();
+    (length > P::Scalar::zero()).then(|| v.scale(P::Scalar::one() / length))
+}
+
+/// Any unit vector perpendicular to the unit vector `v`.
+pub(crate) fn any_perpendicular<P: SpatialPoint>(v: &P) -> P {
+    let (zero, one) = (P::Scalar::zero(), P::Scalar::one());
+    let axis = if v.x().abs() <= v.y().abs() && v.x().abs() <= v.z().abs() {
+        P::from_xyz(one, zero, zero)
+    } else if v.y().abs() <= v.z().abs() {
+        P::from_xyz(zero, one, zero)
+    } else {
+        P::from_xyz(zero, zero, one)
+    };
+    normalize(&v.cross(&axis)).unwrap()
+}
+
+/// Reflects `v` in the plane with the normal `n`, `c` is `n · n`.
+fn reflect<P: SpatialPoint>(v: &P, n: &P, c: P::Scalar) -> P {
+    let two = P::Scalar::one() + P::Scalar::one();
+    v.sub(&n.scale(two * n.dot(v) / c))
+}
+
+/// `count` rotation minimizing frames at evenly spaced `t` from `0` to `1`, computed with the
+/// double reflection method. Unlike Frenet frames they don't flip at inflections and don't twist
+/// around the curve more than necessary.
+///
+/// # Panics
+/// If `count` is less than 2.
+pub fn rotation_minimizing_frames<P: SpatialPoint, C: Curve<P> + ?Sized>(
+    curve: &C,
+    count: usize,
+) -> Vec<Frame<P>> {
+    assert!(count >= 2, "at least two frames are required");
+    let last: P::Scalar = NumCast::from(count - 1).unwrap();
+    let samples: Vec<(P, Option<P>)> = (0..count)
+        .map(|i| {
+            let t = <P::Scalar as NumCast>::from(i).unwrap() / last;
+            (curve.value_at(t), normalize(&curve.tangent_at(t)))
+        })
+        .collect();
+
+    // Tangents at cusps are taken from neighbours, straight up for a curve collapsed into a point.
+    let fallback = samples
+        .iter()
+        .find_map(|(_, tangent)| tangent.clone())
+        .unwrap_or_else(|| P::from_xyz(P::Scalar::zero(), P::Scalar::zero(), P::Scalar::one()));
+
+    let mut frames: Vec<Frame<P>> = Vec::with_capacity(count);
+    for (origin, tangent) in samples {
+        let frame = match frames.last() {
+            None => {
+                let tangent = tangent.unwrap_or(fallback.clone());
+                let normal = any_perpendicular(&tangent);
+                Frame {
+                    binormal: tangent.cross(&normal),
+                    origin,
+                    tangent,
+                    normal,
+                }
+            }
+            Some(previous) => {
+                let tangent = tangent.unwrap_or(previous.tangent.clone());
+                let v1 = origin.sub(&previous.origin);
+                let c1 = v1.length_squared();
+                let (normal, reflected_tangent) = if c1 > P::Scalar::zero() {
+                    (
+                        reflect(&previous.normal, &v1, c1),
+                        reflect(&previous.tangent, &v1, c1),
+                    )
+                } else {
+                    (previous.normal.clone(), previous.tangent.clone())
+                };
+                let v2 = tangent.sub(&reflected_tangent);
+                let c2 = v2.length_squared();
+                let normal = if c2 > P::Scalar::zero() {
+                    reflect(&normal, &v2, c2)
+                } else {
+                    normal
+                };
+                // Keep the frame orthonormal despite accumulated errors.
+                let normal = normalize(&normal.sub(&tangent.scale(tangent.dot(&normal))))
+                    .unwrap_or_else(|| any_perpendicular(&tangent));
+                Frame {
+                    binormal: tangent.cross(&normal),
+                    origin,
+                    tangent,
+                    normal,
+                }
+            }
+        };
+        frames.push(frame);
+    }
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bezier1, Bezier3, InnerProduct, Point};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn straight_line() {
+        let line = Bezier1::new([0.0, 0.0, 0.0], [0.0, 0.0, 5.0]);
+        let frames = rotation_minimizing_frames(&line, 5);
+        assert_eq!(frames.len(), 5);
+        for frame in &frames {
+            assert_eq!(frame.tangent, [0.0, 0.0, 1.0]);
+            assert_eq!(frame.normal, frames[0].normal);
+        }
+        assert_eq!(frames[2].origin, [0.0, 0.0, 2.5]);
+    }
+
+    #[test]
+    fn orthonormal() {
+        let curve = Bezier3::new(
+            [0.0, 0.0, 0.0],
+            [4.0, 0.0, 1.0],
+            [4.0, 4.0, -2.0],
+            [0.0, 4.0, 3.0],
+        );
+        for frame in rotation_minimizing_frames(&curve, 64) {
+            assert_relative_eq!(frame.normal.length(), 1.0, epsilon = 1e-12);
+            assert_relative_eq!(frame.binormal.length(), 1.0, epsilon = 1e-12);
+            assert_relative_eq!(frame.normal.dot(&frame.tangent), 0.0, epsilon = 1e-12);
+            assert_relative_eq!(frame.binormal.dot(&frame.normal), 0.0, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn planar_curve_keeps_normal_out_of_plane() {
+        // For a planar curve the rotation minimizing frame keeps the plane normal unchanged.
+        let curve = Bezier3::new(
+            [0.0, 0.0, 0.0],
+            [4.0, 0.0, 0.0],
+            [4.0, 4.0, 0.0],
+            [0.0, 4.0, 0.0],
+        );
+        let frames = rotation_minimizing_frames(&curve, 32);
+        let axis = if frames[0].normal[2].abs() > 0.5 {
+            |f: &Frame<[f64; 3]>| f.normal[2]
+        } else {
+            |f: &Frame<[f64; 3]>| f.binormal[2]
+        };
+        let first = axis(&frames[0]);
+        assert_relative_eq!(first.abs(), 1.0, epsilon = 1e-12);
+        for frame in &frames {
+            assert_relative_eq!(axis(frame), first, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn to_world() {
+        let line = Bezier1::new([0.0, 0.0, 0.0], [1.0, 0.0, 0.0]);
+        let frame = &rotation_minimizing_frames(&line, 2)[1];
+        let point = frame.to_world(2.0, 3.0);
+        assert_relative_eq!(
+            point.sub(&frame.origin).length(),
+            13f64.sqrt(),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(point[0], 1.0, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,122 @@
+#[cfg(feature = "alloc")]
+use crate::{ComposedCurve, Curve, LinearSpeed, PlanarPoint};
+use crate::{InnerProduct, Scalar};
+#[cfg(feature = "alloc")]
+use num_traits::NumCast;
+use num_traits::{One, Zero};
+
+/// Relative precision of the length measured by [`Walker`].
+#[cfg(feature = "alloc")]
+const LENGTH_PRECISION: f64 = 1e-4;
+
+/// Center and radius of the circle passing through three points,
+/// `None` if the points are collinear.
+#[cfg(feature = "alloc")]
+pub(crate) fn circle_through<P: PlanarPoint>(a: &P, b: &P, c: &P) -> Option<(P, P::Scalar)> {
+    let ab = b.sub(a);
+    let ac = c.sub(a);
+    let d = ab.cross(&ac);
+    if d == P::Scalar::zero() {
+        return None;
+    }
+
+    let two = P::Scalar::one() + P::Scalar::one();
+    let ab2 = ab.length_squared();
+    let ac2 = ac.length_squared();
+    let offset = P::from_xy(
+        (ac.y() * ab2 - ab.y() * ac2) / (two * d),
+        (ab.x() * ac2 - ac.x() * ab2) / (two * d),
+    );
+
+    Some((a.add(&offset), offset.length()))
+}
+
+/// Distance between `p` and the line segment from `a` to `b`.
+pub(crate) fn distance_to_segment<P: InnerProduct>(p: &P, a: &P, b: &P) -> P::Scalar {
+    let d = b.sub(a);
+    let length_squared = d.length_squared();
+    let t = if length_squared == P::Scalar::zero() {
+        P::Scalar::zero()
+    } else {
+        (p.sub(a).dot(&d) / length_squared).clamp(P::Scalar::zero(), P::Scalar::one())
+    };
+    p.sub(&a.lerp(b, t)).length()
+}
+
+/// Walks along a curve by distance, positions are relative to the length of the curve.
+#[cfg(feature = "alloc")]
+pub(crate) struct Walker<P: PlanarPoint> {
+    curve: LinearSpeed<P, ComposedCurve<P>>,
+    length: P::Scalar,
+}
+
+#[cfg(feature = "alloc")]
+impl<P: PlanarPoint> Walker<P> {
+    /// `None` for curves of zero length.
+    pub(crate) fn new(curve: &ComposedCurve<P>) -> Option<Self> {
+        let length = curve.estimate_length(NumCast::from(LENGTH_PRECISION).unwrap());
+        if length <= P::Scalar::zero() {
+            return None;
+        }
+
+        let steps = curve.segments().len().max(4) * 64;
+        Some(Self {
+            curve: LinearSpeed::new(curve.clone(), steps, steps * 4),
+            length,
+        })
+    }
+
+    pub(crate) fn length(&self) -> P::Scalar {
+        self.length
+    }
+
+    /// Point at the relative distance `s` and the unit tangent there.
+    pub(crate) fn frame(&self, s: P::Scalar) -> (P, P) {
+        let point = self.curve.value_at(s);
+        let tangent = self.curve.tangent_at(s);
+        let length = tangent.length();
+        if length == P::Scalar::zero() {
+            (point, tangent)
+        } else {
+            (point, tangent.scale(P::Scalar::one() / length))
+        }
+    }
+
+    /// Point at the relative distance `s`, moved by `offset` to the left of the curve.
+    pub(crate) fn offset_point(&self, s: P::Scalar, offset: P::Scalar) -> P {
+        let (point, tangent) = self.frame(s);
+        point.add(&tangent.perpendicular().scale(offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn circle() {
+        let (center, radius) = circle_through(&[1.0, 0.0], &[0.0, 1.0], &[-1.0, 0.0]).unwrap();
+        assert_relative_eq!(center[0], 0.0);
+        assert_relative_eq!(center[1], 0.0);
+        assert_relative_eq!(radius, 1.0);
+
+        assert!(circle_through(&[0.0, 0.0], &[1.0, 1.0], &[2.0, 2.0]).is_none());
+    }
+
+    #[test]
+    fn segment_distance() {
+        assert_relative_eq!(
+            distance_to_segment(&[1.0, 1.0], &[0.0, 0.0], &[2.0, 0.0]),
+            1.0
+        );
+        assert_relative_eq!(
+            distance_to_segment(&[3.0, 0.0], &[0.0, 0.0], &[2.0, 0.0]),
+            1.0
+        );
+        assert_relative_eq!(
+            distance_to_segment(&[0.0, 2.0], &[0.0, 0.0], &[0.0, 0.0]),
+            2.0
+        );
+    }
+}
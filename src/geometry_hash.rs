@@ -0,0 +1,115 @@
+use crate::{
+    Bezier, Bezier0, Bezier1, Bezier2, Bezier3, ComposedCurve, Curve, PlanarPoint, Scalar,
+};
+use num_traits::ToPrimitive;
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 64-bit FNV-1a hash of quantized coordinates, the result only depends on the input values,
+/// not on the platform, the Rust version, or the process.
+pub(crate) struct GeometryHasher {
+    state: u64,
+    scale: f64,
+}
+
+impl GeometryHasher {
+    /// Coordinates are rounded to multiples of `2^-tolerance_bits`.
+    pub(crate) fn new(tolerance_bits: u32) -> Self {
+        Self {
+            state: FNV_OFFSET,
+            scale: (0..tolerance_bits).fold(1.0, |scale, _| scale * 2.0),
+        }
+    }
+
+    pub(crate) fn write_u64(&mut self, value: u64) {
+        for byte in value.to_le_bytes() {
+            self.state ^= byte as u64;
+            self.state = self.state.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    pub(crate) fn write_scalar<F: Scalar>(&mut self, value: F) {
+        let quantized = value
+            .to_f64()
+            .map(|value| (value * self.scale + 0.5).floor())
+            .and_then(|value| value.to_i64())
+            // NaN and values out of the `i64` range
+            .unwrap_or(i64::MIN);
+        self.write_u64(quantized as u64);
+    }
+
+    pub(crate) fn write_point<P: PlanarPoint>(&mut self, point: &P) {
+        self.write_scalar(point.x());
+        self.write_scalar(point.y());
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.state
+    }
+}
+
+macro_rules! impl_hash_geometry {
+    ($($curve:ident),+) => {
+        $(
+            impl<P: PlanarPoint> $curve<P> {
+                /// Stable hash of the control points rounded to multiples of `2^-tolerance_bits`,
+                /// e.g. 10 bits is about a thousandth of a unit.
+                ///
+                /// Curves with nearly equal control points usually get the same hash, and the hash
+                /// doesn't change between runs, platforms, or versions of the crate, so it can be used
+                /// as a cache key for tessellations and arc length tables, also in persistent caches.
+                pub fn hash_geometry(&self, tolerance_bits: u32) -> u64 {
+                    let points = self.control_points();
+                    let mut hasher = GeometryHasher::new(tolerance_bits);
+                    hasher.write_u64(points.len() as u64);
+                    points.iter().for_each(|point| hasher.write_point(point));
+                    hasher.finish()
+                }
+            }
+        )+
+    };
+}
+
+impl_hash_geometry!(Bezier0, Bezier1, Bezier2, Bezier3, Bezier);
+
+impl<P: PlanarPoint> ComposedCurve<P> {
+    /// Stable hash of the control points, see [`Bezier3::hash_geometry`].
+    /// It's computed segment by segment, so the segment boundaries count.
+    pub fn hash_geometry(&self, tolerance_bits: u32) -> u64 {
+        let mut hasher = GeometryHasher::new(tolerance_bits);
+        hasher.write_point(&self.start_point());
+        hasher.write_u64(self.segments().len() as u64);
+        for curve in self.segments() {
+            let points = curve.control_points();
+            hasher.write_u64(points.len() as u64);
+            points[1..]
+                .iter()
+                .for_each(|point| hasher.write_point(point));
+        }
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bezier, Bezier1, Bezier2};
+
+    #[test]
+    fn bezier_hash() {
+        let line = Bezier1::new([0.0, 0.0], [1.0, 0.0]);
+        assert_eq!(line.hash_geometry(10), Bezier::C1(line).hash_geometry(10));
+        assert_eq!(
+            line.hash_geometry(10),
+            Bezier1::new([1e-6, 0.0], [1.0, 0.0]).hash_geometry(10)
+        );
+        assert_ne!(
+            line.hash_geometry(10),
+            Bezier1::new([5.0, 5.0], [9.0, 3.0]).hash_geometry(10)
+        );
+        assert_ne!(
+            line.hash_geometry(10),
+            Bezier2::new([0.0, 0.0], [0.5, 0.0], [1.0, 0.0]).hash_geometry(10)
+        );
+    }
+}
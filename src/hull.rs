@@ -0,0 +1,186 @@
+use crate::{Bezier, Bezier1, Bezier2, Bezier3, ComposedCurve, Curve, Path, PlanarPoint};
+use alloc::vec::Vec;
+use num_traits::Zero;
+
+impl<P: PlanarPoint> Bezier1<P> {
+    /// Convex hull of the control points, see [`Bezier::control_hull`].
+    pub fn control_hull(&self) -> Vec<P> {
+        convex_hull(&[self.p0.clone(), self.p1.clone()])
+    }
+}
+
+impl<P: PlanarPoint> Bezier2<P> {
+    /// Convex hull of the control points, see [`Bezier::control_hull`].
+    pub fn control_hull(&self) -> Vec<P> {
+        convex_hull(&[self.p0.clone(), self.p1.clone(), self.p2.clone()])
+    }
+}
+
+impl<P: PlanarPoint> Bezier3<P> {
+    /// Convex hull of the control points, see [`Bezier::control_hull`].
+    pub fn control_hull(&self) -> Vec<P> {
+        convex_hull(&[
+            self.p0.clone(),
+            self.p1.clone(),
+            self.p2.clone(),
+            self.p3.clone(),
+        ])
+    }
+}
+
+impl<P: PlanarPoint> Bezier<P> {
+    /// Convex hull of the control points in counter-clockwise order, the curve lies inside it.
+    /// Points on the hull edges are skipped, so a straight curve gives just its two extreme points.
+    pub fn control_hull(&self) -> Vec<P> {
+        match self {
+            Bezier::C0(c) => Vec::from([c.point.clone()]),
+            Bezier::C1(c) => c.control_hull(),
+            Bezier::C2(c) => c.control_hull(),
+            Bezier::C3(c) => c.control_hull(),
+        }
+    }
+}
+
+impl<P: PlanarPoint> ComposedCurve<P> {
+    /// Convex hull of the control points of all the segments in counter-clockwise order,
+    /// it contains the curve exactly, see [`Bezier::control_hull`].
+    pub fn convex_hull(&self) -> Vec<P> {
+        let mut points = Vec::from([self.start_point()]);
+        for segment in self.segments() {
+            points.extend(segment.control_points());
+        }
+        convex_hull(&points)
+    }
+
+    /// Convex hull of the curve flattened with `tolerance`, tighter than
+    /// [`convex_hull`](Self::convex_hull) but the curve may stick out of it by `tolerance`.
+    pub fn flattened_hull(&self, tolerance: P::Scalar) -> Vec<P> {
+        convex_hull(&self.flatten(tolerance))
+    }
+}
+
+impl<P: PlanarPoint> Path<P> {
+    /// Convex hull of the control points of all the contours, see [`ComposedCurve::convex_hull`].
+    pub fn convex_hull(&self) -> Vec<P> {
+        let hulls: Vec<P> = self
+            .contours()
+            .iter()
+            .flat_map(|c| c.convex_hull())
+            .collect();
+        convex_hull(&hulls)
+    }
+
+    /// Convex hull of all the contours flattened with `tolerance`,
+    /// see [`ComposedCurve::flattened_hull`].
+    pub fn flattened_hull(&self, tolerance: P::Scalar) -> Vec<P> {
+        let points: Vec<P> = self
+            .contours()
+            .iter()
+            .flat_map(|c| c.flatten(tolerance))
+            .collect();
+        convex_hull(&points)
+    }
+}
+
+/// Convex hull of the points in counter-clockwise order, starting from the point
+/// with the smallest x (and y for equal x). Points on the hull edges are skipped.
+pub(crate) fn convex_hull<P: PlanarPoint>(points: &[P]) -> Vec<P> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| {
+        a.x()
+            .partial_cmp(&b.x())
+            .unwrap()
+            .then(a.y().partial_cmp(&b.y()).unwrap())
+    });
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    // Andrew's monotone chain, the lower half goes left to right and the upper one back
+    let mut hull: Vec<P> = Vec::with_capacity(sorted.len() + 1);
+    let push = |hull: &mut Vec<P>, p: &P, floor: usize| {
+        while hull.len() >= floor + 2 {
+            let n = hull.len();
+            if hull[n - 1].sub(&hull[n - 2]).cross(&p.sub(&hull[n - 2])) > P::Scalar::zero() {
+                break;
+            }
+            hull.pop();
+        }
+        hull.push(p.clone());
+    };
+    for p in sorted.iter() {
+        push(&mut hull, p, 0);
+    }
+    let floor = hull.len() - 1;
+    for p in sorted.iter().rev().skip(1) {
+        push(&mut hull, p, floor);
+    }
+    hull.pop();
+    hull
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hull() {
+        let points = [
+            [0.0, 0.0],
+            [2.0, 0.0],
+            [1.0, 1.0],
+            [2.0, 2.0],
+            [0.0, 2.0],
+            [1.0, 0.0],
+        ];
+        assert_eq!(
+            convex_hull(&points),
+            [[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]
+        );
+    }
+
+    #[test]
+    fn control_hull() {
+        let s_curve = Bezier3::new([0.0, 0.0], [1.0, 1.0], [2.0, -1.0], [3.0, 0.0]);
+        assert_eq!(
+            s_curve.control_hull(),
+            [[0.0, 0.0], [2.0, -1.0], [3.0, 0.0], [1.0, 1.0]]
+        );
+
+        let straight = Bezier3::new([0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [3.0, 3.0]);
+        assert_eq!(straight.control_hull(), [[0.0, 0.0], [3.0, 3.0]]);
+    }
+
+    #[test]
+    fn curve_hull() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([2.0, 0.0]);
+        curve.quadratic_to([3.0, 2.0], [0.0, 2.0]);
+        assert_eq!(
+            curve.convex_hull(),
+            [[0.0, 0.0], [2.0, 0.0], [3.0, 2.0], [0.0, 2.0]]
+        );
+
+        // The curve stays away from its control point
+        let flattened = curve.flattened_hull(1e-3);
+        assert!(flattened.iter().all(|p| p[0] < 2.5));
+        assert_eq!(flattened[0], [0.0, 0.0]);
+
+        let mut other = ComposedCurve::new([-1.0, 1.0]);
+        other.line_to([-1.0, 1.5]);
+        let path: Path<_> = [curve, other].into_iter().collect();
+        assert_eq!(
+            path.convex_hull(),
+            [
+                [-1.0, 1.0],
+                [0.0, 0.0],
+                [2.0, 0.0],
+                [3.0, 2.0],
+                [0.0, 2.0],
+                [-1.0, 1.5]
+            ]
+        );
+        assert_eq!(path.flattened_hull(1e-3)[0], [-1.0, 1.0]);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{Distance, Point, Scalar};
+
+/// Points with a dot product, like vectors in euclidean space.
+/// Every such point type gets [`Distance`] for free.
+pub trait InnerProduct: Point {
+    fn dot(&self, other: &Self) -> Self::Scalar;
+
+    fn length_squared(&self) -> Self::Scalar {
+        self.dot(self)
+    }
+
+    fn length(&self) -> Self::Scalar {
+        self.length_squared().sqrt()
+    }
+}
+
+impl<P: InnerProduct> Distance for P {
+    fn distance(&self, other: &Self) -> Self::Scalar {
+        self.sub(other).length()
+    }
+}
+
+impl InnerProduct for f32 {
+    fn dot(&self, other: &Self) -> f32 {
+        self * other
+    }
+
+    fn length(&self) -> f32 {
+        self.abs()
+    }
+}
+
+impl InnerProduct for f64 {
+    fn dot(&self, other: &Self) -> f64 {
+        self * other
+    }
+
+    fn length(&self) -> f64 {
+        self.abs()
+    }
+}
+
+impl<F: Scalar, const N: usize> InnerProduct for [F; N] {
+    fn dot(&self, other: &Self) -> F {
+        self.iter()
+            .zip(other.iter())
+            .fold(F::zero(), |acc, (a, b)| acc + *a * *b)
+    }
+}
+
+impl<F: Scalar> InnerProduct for (F, F) {
+    fn dot(&self, other: &Self) -> F {
+        self.0 * other.0 + self.1 * other.1
+    }
+}
+
+impl<F: Scalar> InnerProduct for (F, F, F) {
+    fn dot(&self, other: &Self) -> F {
+        self.0 * other.0 + self.1 * other.1 + self.2 * other.2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dot_product() {
+        assert_eq!([1.0, 2.0, 3.0].dot(&[4.0, 5.0, 6.0]), 32.0);
+        assert_eq!((3.0, 4.0).length_squared(), 25.0);
+        assert_eq!((3.0, 4.0).length(), 5.0);
+        assert_eq!((-2.0f32).length(), 2.0);
+        assert_eq!(1.0f64.distance(&-2.0), 3.0);
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{Curve, Distance, Point};
+use num_traits::{NumCast, One};
+
+/// Recursion limit of the adaptive quadrature, the interval is split into at most `2^MAX_DEPTH` parts.
+const MAX_DEPTH: usize = 24;
+
+/// Simpson's rule over `[t0, t1]`, exact for polynomials up to the third degree,
+/// so for every bezier curve.
+pub(crate) fn simpson<P: Point, C: Curve<P> + ?Sized>(
+    curve: &C,
+    t0: P::Scalar,
+    t1: P::Scalar,
+) -> P {
+    let m = (t0 + t1) / two::<P::Scalar>();
+    simpson_values(
+        &curve.value_at(t0),
+        &curve.value_at(m),
+        &curve.value_at(t1),
+        t1 - t0,
+    )
+}
+
+/// Adaptive Simpson's quadrature, intervals are split until the error estimate is below `precision`.
+pub(crate) fn adaptive_simpson<P: Distance, C: Curve<P> + ?Sized>(
+    curve: &C,
+    t0: P::Scalar,
+    t1: P::Scalar,
+    precision: P::Scalar,
+) -> P {
+    let (a, m, b) = (
+        curve.value_at(t0),
+        curve.value_at((t0 + t1) / two()),
+        curve.value_at(t1),
+    );
+    let whole = simpson_values(&a, &m, &b, t1 - t0);
+    Interval { curve, precision }.refine(t0, t1, &a, &m, &b, whole, MAX_DEPTH)
+}
+
+struct Interval<'a, P: Distance, C: Curve<P> + ?Sized> {
+    curve: &'a C,
+    precision: P::Scalar,
+}
+
+impl<P: Distance, C: Curve<P> + ?Sized> Interval<'_, P, C> {
+    #[allow(clippy::too_many_arguments)]
+    fn refine(
+        &self,
+        t0: P::Scalar,
+        t1: P::Scalar,
+        a: &P,
+        m: &P,
+        b: &P,
+        whole: P,
+        depth: usize,
+    ) -> P {
+        let tm = (t0 + t1) / two();
+        let left_m = self.curve.value_at((t0 + tm) / two());
+        let right_m = self.curve.value_at((tm + t1) / two());
+        let left = simpson_values(a, &left_m, m, tm - t0);
+        let right = simpson_values(m, &right_m, b, t1 - tm);
+        let halves = left.add(&right);
+
+        // The error of the refined estimate is about 1/15 of the difference
+        let fifteen: P::Scalar = NumCast::from(15).unwrap();
+        if depth == 0 || halves.distance(&whole) <= fifteen * self.precision {
+            let correction = halves.sub(&whole).scale(P::Scalar::one() / fifteen);
+            halves.add(&correction)
+        } else {
+            let half_precision = self.precision / two();
+            let nested = Interval {
+                curve: self.curve,
+                precision: half_precision,
+            };
+            nested
+                .refine(t0, tm, a, &left_m, m, left, depth - 1)
+                .add(&nested.refine(tm, t1, m, &right_m, b, right, depth - 1))
+        }
+    }
+}
+
+fn simpson_values<P: Point>(a: &P, m: &P, b: &P, width: P::Scalar) -> P {
+    let four: P::Scalar = NumCast::from(4).unwrap();
+    let six: P::Scalar = NumCast::from(6).unwrap();
+    a.add(&m.scale(four)).add(b).scale(width / six)
+}
+
+fn two<S: NumCast>() -> S {
+    NumCast::from(2).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bezier1, Bezier3, ComposedCurve, Curve, Jitter};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn exact_for_beziers() {
+        let curve = Bezier3::new(1.0, 2.0, -1.0, 3.0);
+        let n = 100_000;
+        let riemann = (0..n)
+            .map(|i| curve.value_at((i as f64 + 0.5) / n as f64) / n as f64)
+            .sum::<f64>();
+        assert_relative_eq!(curve.integrate(0.0, 1.0, 1e-9), riemann, epsilon = 1e-9);
+        assert_relative_eq!(Bezier1::new(0.0, 2.0).integrate(0.0, 0.5, 1e-9), 0.25);
+        assert_relative_eq!(Bezier1::new(0.0, 2.0).integrate(0.5, 0.0, 1e-9), -0.25);
+    }
+
+    #[test]
+    fn composed_curve() {
+        let mut curve = ComposedCurve::new(0.0);
+        curve.line_to(1.0);
+        curve.quadratic_to(0.0, 0.0);
+        // (1/2 + 1/3) / 2
+        assert_relative_eq!(curve.integrate(0.0, 1.0, 1e-9), 5.0 / 12.0, epsilon = 1e-12);
+        // (3/8 + 7/24) / 2
+        assert_relative_eq!(
+            curve.integrate(0.25, 0.75, 1e-9),
+            1.0 / 3.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            curve.integrate(0.75, 0.25, 1e-9),
+            -1.0 / 3.0,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn adaptive() {
+        let curve = Jitter::new(Bezier1::new([0.0, 0.0], [1.0, 0.0]), 0.1, 8.0, 7);
+        let n = 100_000;
+        let riemann = (0..n)
+            .map(|i| curve.value_at((i as f64 + 0.5) / n as f64)[1] / n as f64)
+            .sum::<f64>();
+        assert_relative_eq!(curve.integrate(0.0, 1.0, 1e-8)[1], riemann, epsilon = 1e-7);
+    }
+}
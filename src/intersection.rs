@@ -0,0 +1,221 @@
+use crate::{Bezier, Bezier1, Curve, Distance, PlanarPoint, Scalar};
+use alloc::vec::Vec;
+use num_traits::{NumCast, One, Zero};
+
+/// Subdivision depth limit, it's enough to reach the precision of `f64` from any sane extent.
+const MAX_DEPTH: usize = 64;
+/// Number of samples used to find the neighbourhood of the nearest point.
+const NEAREST_SAMPLES: usize = 16;
+/// Number of interval narrowing steps after sampling.
+const NEAREST_ITERATIONS: usize = 48;
+
+/// Parameters of the intersection points of two segments, `(t, u)` pairs with `t` on `a`
+/// and `u` on `b`. Curved segments are subdivided until their control bounds are smaller than `tolerance`,
+/// lines are intersected analytically, for overlapping lines only the ends of the overlap are returned.
+pub(crate) fn segment_intersections<P: PlanarPoint>(
+    a: &Bezier<P>,
+    b: &Bezier<P>,
+    tolerance: P::Scalar,
+) -> Vec<(P::Scalar, P::Scalar)> {
+    let (zero, one) = (P::Scalar::zero(), P::Scalar::one());
+    let mut candidates = Vec::new();
+    subdivide(
+        (a.clone(), zero, one),
+        (b.clone(), zero, one),
+        tolerance,
+        0,
+        &mut candidates,
+    );
+
+    // Neighbouring pieces report chains of close points, keep the middle one of every chain
+    candidates.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap());
+    let gap = tolerance * NumCast::from(4).unwrap();
+    let mut result = Vec::new();
+    let mut chain_start = 0;
+    for i in 1..=candidates.len() {
+        let chain_ends = i == candidates.len()
+            || a.value_at(candidates[i].0)
+                .distance(&a.value_at(candidates[i - 1].0))
+                > gap;
+        if chain_ends {
+            result.push(candidates[(chain_start + i - 1) / 2]);
+            chain_start = i;
+        }
+    }
+    result
+}
+
+fn subdivide<P: PlanarPoint>(
+    (a, t0, t1): (Bezier<P>, P::Scalar, P::Scalar),
+    (b, u0, u1): (Bezier<P>, P::Scalar, P::Scalar),
+    tolerance: P::Scalar,
+    depth: usize,
+    result: &mut Vec<(P::Scalar, P::Scalar)>,
+) {
+    let bounds_a = a.control_bounds();
+    let bounds_b = b.control_bounds();
+    if !bounds_a
+        .inflate(tolerance)
+        .intersects(&bounds_b.inflate(tolerance))
+    {
+        return;
+    }
+
+    if let (Bezier::C1(line_a), Bezier::C1(line_b)) = (&a, &b) {
+        for (s, v) in line_intersections(line_a, line_b, tolerance) {
+            result.push((t0 + (t1 - t0) * s, u0 + (u1 - u0) * v));
+        }
+        return;
+    }
+
+    let half = P::Scalar::one() / (P::Scalar::one() + P::Scalar::one());
+    let extent_a = bounds_a.width().max(bounds_a.height());
+    let extent_b = bounds_b.width().max(bounds_b.height());
+    if depth >= MAX_DEPTH || (extent_a <= tolerance && extent_b <= tolerance) {
+        result.push(((t0 + t1) * half, (u0 + u1) * half));
+        return;
+    }
+
+    if extent_a >= extent_b {
+        let tm = (t0 + t1) * half;
+        let (left, right) = a.split_at(half);
+        let b = (b, u0, u1);
+        subdivide((left, t0, tm), b.clone(), tolerance, depth + 1, result);
+        subdivide((right, tm, t1), b, tolerance, depth + 1, result);
+    } else {
+        let um = (u0 + u1) * half;
+        let (left, right) = b.split_at(half);
+        let a = (a, t0, t1);
+        subdivide(a.clone(), (left, u0, um), tolerance, depth + 1, result);
+        subdivide(a, (right, um, u1), tolerance, depth + 1, result);
+    }
+}
+
+/// Intersections of two line segments, the ends of the overlap for collinear ones.
+fn line_intersections<P: PlanarPoint>(
+    a: &Bezier1<P>,
+    b: &Bezier1<P>,
+    tolerance: P::Scalar,
+) -> Vec<(P::Scalar, P::Scalar)> {
+    let (zero, one) = (P::Scalar::zero(), P::Scalar::one());
+    let r = a.p1.sub(&a.p0);
+    let v = b.p1.sub(&b.p0);
+    let w = b.p0.sub(&a.p0);
+    let denominator = r.cross(&v);
+    let epsilon: P::Scalar = NumCast::from(1e-12).unwrap();
+
+    let mut result = Vec::new();
+    if denominator.abs() > epsilon * r.length() * v.length() {
+        let t = w.cross(&v) / denominator;
+        let u = w.cross(&r) / denominator;
+        // Allow the ends to miss by a rounding error
+        let margin_t = tolerance / r.length();
+        let margin_u = tolerance / v.length();
+        if t >= -margin_t && t <= one + margin_t && u >= -margin_u && u <= one + margin_u {
+            result.push((t.clamp(zero, one), u.clamp(zero, one)));
+        }
+        return result;
+    }
+
+    // Parallel lines, they intersect only if they are on the same line
+    let r2 = r.length_squared();
+    let v2 = v.length_squared();
+    if r2 == zero || v2 == zero {
+        return result;
+    }
+    if w.cross(&r).abs() > tolerance * r2.sqrt() {
+        return result;
+    }
+
+    let project = |p: &P| p.sub(&a.p0).dot(&r) / r2;
+    let unproject = |t: P::Scalar| a.p0.add(&r.scale(t)).sub(&b.p0).dot(&v) / v2;
+    let (s0, s1) = (project(&b.p0), project(&b.p1));
+    let start = s0.min(s1).max(zero);
+    let end = s0.max(s1).min(one);
+    if start <= end {
+        result.push((start, unproject(start).clamp(zero, one)));
+        if end > start {
+            result.push((end, unproject(end).clamp(zero, one)));
+        }
+    }
+    result
+}
+
+/// Parameter of the point of `curve` nearest to `point`, the point and the distance to it.
+pub(crate) fn nearest_point<P: PlanarPoint, C: Curve<P> + ?Sized>(
+    curve: &C,
+    point: &P,
+) -> (P::Scalar, P, P::Scalar) {
+    let (zero, one) = (P::Scalar::zero(), P::Scalar::one());
+    let samples: P::Scalar = NumCast::from(NEAREST_SAMPLES).unwrap();
+    let distance = |t: P::Scalar| curve.value_at(t).distance(point);
+
+    let mut best = (zero, distance(zero));
+    for i in 1..=NEAREST_SAMPLES {
+        let t = NumCast::from(i).map(|i: P::Scalar| i / samples).unwrap();
+        let d = distance(t);
+        if d < best.1 {
+            best = (t, d);
+        }
+    }
+
+    // Pattern search around the best sample with a halving step
+    let half = one / (one + one);
+    let mut step = one / samples;
+    for _ in 0..NEAREST_ITERATIONS {
+        for t in [best.0 - step, best.0 + step] {
+            let t = t.clamp(zero, one);
+            let d = distance(t);
+            if d < best.1 {
+                best = (t, d);
+            }
+        }
+        step = step * half;
+    }
+
+    (best.0, curve.value_at(best.0), best.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bezier3;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn curves() {
+        let a = Bezier::C3(Bezier3::new(
+            [0.0, 0.0],
+            [1.0, 3.0],
+            [2.0, -3.0],
+            [3.0, 0.0],
+        ));
+        let b = Bezier::C1(Bezier1::new([0.0, 0.0], [3.0, 0.0]));
+        let result = segment_intersections(&a, &b, 1e-9);
+        assert_eq!(result.len(), 3);
+        for (t, u) in result {
+            let (p, q) = (a.value_at(t), b.value_at(u));
+            assert_relative_eq!(p[0], q[0], epsilon = 1e-6);
+            assert_relative_eq!(p[1], q[1], epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn overlapping_lines() {
+        let a = Bezier::C1(Bezier1::new([0.0, 0.0], [4.0, 0.0]));
+        let b = Bezier::C1(Bezier1::new([6.0, 0.0], [2.0, 0.0]));
+        assert_eq!(
+            segment_intersections(&a, &b, 1e-9),
+            [(0.5, 1.0), (1.0, 0.5)]
+        );
+    }
+
+    #[test]
+    fn nearest() {
+        let curve = Bezier3::new([0.0, 0.0], [1.0, 2.0], [2.0, 2.0], [3.0, 0.0]);
+        let (t, point, distance) = nearest_point(&curve, &[1.5, 3.0]);
+        assert_relative_eq!(t, 0.5, epsilon = 1e-9);
+        assert_relative_eq!(point[1], 1.5, epsilon = 1e-9);
+        assert_relative_eq!(distance, 1.5, epsilon = 1e-9);
+    }
+}
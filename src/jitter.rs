@@ -0,0 +1,195 @@
+use crate::curve::polyline_length;
+#[cfg(feature = "alloc")]
+use crate::ComposedCurve;
+use crate::{Curve, Distance, PlanarPoint, Scalar};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use num_traits::{NumCast, One, ToPrimitive, Zero};
+
+/// The passed curve with smooth random noise added to its points, gives a hand drawn look.
+///
+/// Both coordinates are displaced by 1D value noise of the curve parameter, so the offset
+/// is never larger than `amplitude`. `frequency` is the number of noise periods along the whole curve
+/// and `seed` selects the noise pattern, the same seed always gives the same curve.
+pub struct Jitter<P: PlanarPoint, C: Curve<P>> {
+    curve: C,
+    amplitude: P::Scalar,
+    frequency: P::Scalar,
+    seed: u64,
+    phantom_data: PhantomData<P>,
+}
+
+impl<P: PlanarPoint, C: Curve<P>> Jitter<P, C> {
+    pub fn new(curve: C, amplitude: P::Scalar, frequency: P::Scalar, seed: u64) -> Self {
+        Self {
+            curve,
+            amplitude,
+            frequency,
+            seed,
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+
+    /// Displacement of the point at `t` and its derivative by `t`.
+    fn offset(&self, t: P::Scalar) -> (P, P) {
+        let frequency = self.frequency.to_f64().unwrap();
+        let amplitude = self.amplitude.to_f64().unwrap();
+        let u = t.to_f64().unwrap() * frequency;
+
+        let (x, dx) = noise(self.seed, u);
+        let (y, dy) = noise(self.seed ^ 0x9e37_79b9_7f4a_7c15, u);
+
+        let point = |x: f64, y: f64| {
+            P::from_xy(
+                NumCast::from(x * amplitude).unwrap(),
+                NumCast::from(y * amplitude).unwrap(),
+            )
+        };
+        (point(x, y), point(dx * frequency, dy * frequency))
+    }
+
+    /// Number of noise periods, at least one.
+    fn periods(&self) -> usize {
+        self.frequency.ceil().to_usize().unwrap_or(0).max(1)
+    }
+}
+
+impl<P: PlanarPoint, C: Curve<P>> Curve<P> for Jitter<P, C> {
+    fn value_at(&self, t: P::Scalar) -> P {
+        self.curve.value_at(t).add(&self.offset(t).0)
+    }
+
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        self.curve.tangent_at(t).add(&self.offset(t).1)
+    }
+
+    fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        // Every noise period bends the curve, so the polylines follow them
+        polyline_length(
+            self,
+            P::Scalar::zero(),
+            P::Scalar::one(),
+            self.periods() * 16,
+            precision,
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: PlanarPoint, C: Curve<P>> Jitter<P, C> {
+    /// Sample the jittered curve and fit cubic curves to it, the result stays within `tolerance`
+    /// of the samples.
+    pub fn to_composed_curve(&self, tolerance: P::Scalar) -> ComposedCurve<P> {
+        let steps = self.curve.flatten(tolerance).len().max(self.periods() * 16);
+
+        let inverted: P::Scalar = P::Scalar::one() / NumCast::from(steps).unwrap();
+        let points: Vec<P> = (0..=steps)
+            .map(|i| {
+                let i: P::Scalar = NumCast::from(i).unwrap();
+                self.value_at(i * inverted)
+            })
+            .collect();
+
+        ComposedCurve::fit_points(&points, tolerance, P::Scalar::pi()).unwrap()
+    }
+}
+
+/// Smooth value noise in `-1..=1` and its derivative.
+fn noise(seed: u64, u: f64) -> (f64, f64) {
+    let i = floor(u);
+    let f = u - i;
+    let a = hash(seed, i as i64);
+    let b = hash(seed, i as i64 + 1);
+
+    let s = f * f * (3.0 - 2.0 * f);
+    let ds = 6.0 * f * (1.0 - f);
+    (a + (b - a) * s, (b - a) * ds)
+}
+
+fn floor(u: f64) -> f64 {
+    let truncated = u as i64 as f64;
+    if truncated > u {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
+/// Pseudo random value in `-1..=1` for a lattice point, SplitMix64 finalizer.
+fn hash(seed: u64, i: i64) -> f64 {
+    let mut z = seed ^ (i as u64).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+    (z >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bezier3;
+    use alloc::vec::Vec;
+    use approx::assert_relative_eq;
+
+    fn curve() -> Bezier3<[f64; 2]> {
+        Bezier3::new([0.0, 0.0], [10.0, 20.0], [30.0, 20.0], [40.0, 0.0])
+    }
+
+    #[test]
+    fn bounded_and_deterministic() {
+        let jitter = curve().jitter(0.5, 7.0, 42);
+        let other = curve().jitter(0.5, 7.0, 43);
+
+        let mut differs = false;
+        for i in 0..=100 {
+            let t = i as f64 / 100.0;
+            let (a, b) = (curve().value_at(t), jitter.value_at(t));
+            assert!((a[0] - b[0]).abs() <= 0.5 && (a[1] - b[1]).abs() <= 0.5);
+            assert_eq!(b, curve().jitter(0.5, 7.0, 42).value_at(t));
+            differs |= b != other.value_at(t);
+        }
+        assert!(differs);
+
+        let still = curve().jitter(0.0, 7.0, 42);
+        assert_eq!(still.value_at(0.3), curve().value_at(0.3));
+    }
+
+    #[test]
+    fn tangent() {
+        let jitter = curve().jitter(0.5, 7.0, 1);
+        for i in 1..20 {
+            let t = i as f64 / 20.0 + 0.01;
+            let h = 1e-6;
+            let (a, b) = (jitter.value_at(t - h), jitter.value_at(t + h));
+            let tangent = jitter.tangent_at(t);
+            assert_relative_eq!(tangent[0], (b[0] - a[0]) / (2.0 * h), epsilon = 1e-3);
+            assert_relative_eq!(tangent[1], (b[1] - a[1]) / (2.0 * h), epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn eager() {
+        let jitter = curve().jitter(0.5, 7.0, 3);
+        let cubics = jitter.to_composed_curve(0.01);
+        let samples: Vec<_> = (0..=4000)
+            .map(|i| cubics.value_at(i as f64 / 4000.0))
+            .collect();
+        for i in 0..=100 {
+            let p = jitter.value_at(i as f64 / 100.0);
+            let distance = samples
+                .iter()
+                .map(|q| q.distance(&p))
+                .fold(f64::INFINITY, f64::min);
+            assert!(distance < 0.05, "{}", distance);
+        }
+        assert!(jitter.estimate_length(0.001) > curve().estimate_length(0.001));
+    }
+}
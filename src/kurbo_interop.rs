@@ -0,0 +1,213 @@
+use crate::bezier::Bezier;
+use crate::{Bezier3, ComposedCurve, Curve, Error, InnerProduct, Path, PlanarPoint, Point};
+use ::kurbo::{BezPath, CubicBez, PathEl, Vec2};
+
+type KurboPoint = ::kurbo::Point;
+
+macro_rules! impl_kurbo_point {
+    ($point:ident) => {
+        impl Point for $point {
+            type Scalar = f64;
+
+            fn add(&self, other: &Self) -> Self {
+                $point::new(self.x + other.x, self.y + other.y)
+            }
+
+            fn sub(&self, other: &Self) -> Self {
+                $point::new(self.x - other.x, self.y - other.y)
+            }
+
+            fn multiply(&self, other: &Self) -> Self {
+                $point::new(self.x * other.x, self.y * other.y)
+            }
+
+            fn scale(&self, s: f64) -> Self {
+                $point::new(self.x * s, self.y * s)
+            }
+        }
+
+        impl InnerProduct for $point {
+            fn dot(&self, other: &Self) -> f64 {
+                self.x * other.x + self.y * other.y
+            }
+        }
+
+        impl PlanarPoint for $point {
+            fn from_xy(x: f64, y: f64) -> Self {
+                $point::new(x, y)
+            }
+
+            fn x(&self) -> f64 {
+                self.x
+            }
+
+            fn y(&self) -> f64 {
+                self.y
+            }
+        }
+    };
+}
+
+impl_kurbo_point!(KurboPoint);
+impl_kurbo_point!(Vec2);
+
+impl From<Bezier3<KurboPoint>> for CubicBez {
+    fn from(curve: Bezier3<KurboPoint>) -> Self {
+        CubicBez::new(curve.p0, curve.p1, curve.p2, curve.p3)
+    }
+}
+
+impl From<CubicBez> for Bezier3<KurboPoint> {
+    fn from(curve: CubicBez) -> Self {
+        Bezier3::new(curve.p0, curve.p1, curve.p2, curve.p3)
+    }
+}
+
+fn push_contour(path: &mut BezPath, contour: &ComposedCurve<KurboPoint>) {
+    path.move_to(contour.start_point());
+    for segment in contour.segments() {
+        match segment {
+            Bezier::C0(_) => {}
+            Bezier::C1(c) => path.line_to(c.p1),
+            Bezier::C2(c) => path.quad_to(c.p1, c.p2),
+            Bezier::C3(c) => path.curve_to(c.p1, c.p2, c.p3),
+        }
+    }
+    // A closed contour already ends with the closing segment, so the implied line is empty
+    if contour.segments().len() > 1 && contour.end_point() == contour.start_point() {
+        path.close_path();
+    }
+}
+
+impl From<&ComposedCurve<KurboPoint>> for BezPath {
+    fn from(curve: &ComposedCurve<KurboPoint>) -> Self {
+        let mut path = BezPath::new();
+        push_contour(&mut path, curve);
+        path
+    }
+}
+
+impl From<&Path<KurboPoint>> for BezPath {
+    fn from(path: &Path<KurboPoint>) -> Self {
+        let mut result = BezPath::new();
+        for contour in path.contours() {
+            push_contour(&mut result, contour);
+        }
+        result
+    }
+}
+
+/// Every `MoveTo` starts a new contour, segments before the first `MoveTo`
+/// start at the origin like in SVG.
+impl From<&BezPath> for Path<KurboPoint> {
+    fn from(path: &BezPath) -> Self {
+        let mut result = Path::new();
+        for (i, element) in path.elements().iter().enumerate() {
+            if i == 0 && !matches!(element, PathEl::MoveTo(_)) {
+                result.move_to(KurboPoint::ORIGIN);
+            }
+            match *element {
+                PathEl::MoveTo(p) => result.move_to(p),
+                PathEl::LineTo(p) => result.line_to(p),
+                PathEl::QuadTo(p1, p2) => result.quadratic_to(p1, p2),
+                PathEl::CurveTo(p1, p2, p3) => result.cubic_to(p1, p2, p3),
+                PathEl::ClosePath => result.close(),
+            }
+        }
+        result
+    }
+}
+
+/// A composed curve is a single contour, so the conversion fails with
+/// [`CurveError::Discontinuous`](crate::CurveError::Discontinuous) if the path has several
+/// subpaths, and with [`CurveError::Empty`](crate::CurveError::Empty) if it has no segments.
+impl TryFrom<&BezPath> for ComposedCurve<KurboPoint> {
+    type Error = Error;
+
+    fn try_from(path: &BezPath) -> Result<Self, Self::Error> {
+        Path::from(path).into_single_contour()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CurveError;
+    use crate::LinearSpeed;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn cubic() {
+        let curve = Bezier3::new(
+            KurboPoint::new(0.0, 0.0),
+            KurboPoint::new(0.0, 1.0),
+            KurboPoint::new(1.0, 1.0),
+            KurboPoint::new(1.0, 0.0),
+        );
+        let kurbo = CubicBez::from(curve);
+        assert_eq!(Bezier3::from(kurbo), curve);
+
+        use ::kurbo::ParamCurve;
+        assert_relative_eq!(curve.value_at(0.3).distance(kurbo.eval(0.3)), 0.0);
+    }
+
+    #[test]
+    fn bez_path() {
+        let mut curve = ComposedCurve::new(KurboPoint::new(0.0, 0.0));
+        curve.line_to(KurboPoint::new(10.0, 0.0));
+        curve.quadratic_to(KurboPoint::new(10.0, 10.0), KurboPoint::new(0.0, 10.0));
+        curve.cubic_to(
+            KurboPoint::new(-5.0, 10.0),
+            KurboPoint::new(-5.0, 0.0),
+            KurboPoint::new(0.0, 0.0),
+        );
+
+        let path = BezPath::from(&curve);
+        assert_eq!(path.elements().len(), 5);
+        assert_eq!(path.elements()[4], PathEl::ClosePath);
+        assert_eq!(ComposedCurve::try_from(&path), Ok(curve.clone()));
+
+        let speed = LinearSpeed::new(ComposedCurve::try_from(&path).unwrap(), 16, 64);
+        assert_relative_eq!(speed.value_at(0.0).x, 0.0);
+
+        let mut two = path.clone();
+        two.move_to(KurboPoint::new(20.0, 0.0));
+        two.line_to(KurboPoint::new(30.0, 0.0));
+        assert_eq!(
+            ComposedCurve::try_from(&two),
+            Err(CurveError::Discontinuous { segment: 3 }.into())
+        );
+        assert_eq!(Path::from(&two).contours().len(), 2);
+        assert_eq!(BezPath::from(&Path::from(&two)), two);
+
+        assert_eq!(
+            ComposedCurve::try_from(&BezPath::new()),
+            Err(CurveError::Empty.into())
+        );
+    }
+
+    #[test]
+    fn closed_round_trip() {
+        let mut triangle = BezPath::new();
+        triangle.move_to(KurboPoint::new(0.0, 0.0));
+        triangle.line_to(KurboPoint::new(4.0, 0.0));
+        triangle.line_to(KurboPoint::new(0.0, 3.0));
+        triangle.close_path();
+        triangle.move_to(KurboPoint::new(10.0, 0.0));
+        triangle.line_to(KurboPoint::new(14.0, 0.0));
+
+        let path = Path::from(&triangle);
+        assert_eq!(path.contours()[0].segments().len(), 3);
+        assert_eq!(path.contours()[1].segments().len(), 1);
+
+        let mut expected = triangle.clone();
+        // The closing line is explicit after the round trip
+        expected.truncate(3);
+        expected.line_to(KurboPoint::new(0.0, 0.0));
+        expected.close_path();
+        expected.move_to(KurboPoint::new(10.0, 0.0));
+        expected.line_to(KurboPoint::new(14.0, 0.0));
+        assert_eq!(BezPath::from(&path), expected);
+        assert_eq!(Path::from(&BezPath::from(&path)), path);
+    }
+}
@@ -1,15 +1,278 @@
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+mod analyze;
+#[cfg(feature = "alloc")]
+mod approach;
+#[cfg(feature = "approx")]
+mod approx_eq;
+#[cfg(feature = "alloc")]
+mod arc;
+mod arithmetic;
+#[cfg(feature = "alloc")]
+mod arrangement;
+#[cfg(feature = "bevy")]
+mod bevy_interop;
 mod bezier;
+#[cfg(feature = "alloc")]
+mod biarc;
+#[cfg(feature = "alloc")]
+mod binary;
+#[cfg(feature = "alloc")]
+mod bounding;
+mod bundle;
+#[cfg(feature = "alloc")]
+mod bvh;
+mod cached_curve;
+#[cfg(feature = "alloc")]
+mod chaikin;
+#[cfg(feature = "alloc")]
+mod cleanup;
+#[cfg(feature = "alloc")]
+mod clip;
+#[cfg(feature = "num-complex")]
+mod complex_point;
+#[cfg(feature = "alloc")]
 mod composed_curve;
+#[cfg(feature = "alloc")]
+mod cubic_path;
 mod curve;
 mod curve_iterator;
+#[cfg(feature = "debug-svg")]
+mod debug_svg;
+mod degeneracy;
+#[cfg(feature = "alloc")]
+mod degree;
+#[cfg(feature = "alloc")]
+mod diff;
 mod distance;
+pub mod easing;
+#[cfg(feature = "alloc")]
+mod edit;
+#[cfg(feature = "alloc")]
+mod envelope;
+mod error;
+#[cfg(feature = "euclid")]
+mod euclid_point;
+mod extend;
+#[cfg(feature = "alloc")]
+mod fair;
+mod fat_line;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "alloc")]
+mod fit;
+#[cfg(feature = "alloc")]
+mod flatten;
+#[cfg(feature = "alloc")]
+mod frames;
+mod geometry;
+#[cfg(feature = "alloc")]
+mod geometry_hash;
+#[cfg(feature = "alloc")]
+mod hull;
+mod inner_product;
+mod integrate;
+#[cfg(feature = "alloc")]
+mod intersection;
+mod jitter;
+#[cfg(feature = "kurbo")]
+mod kurbo_interop;
+#[cfg(feature = "alloc")]
 mod linear_speed;
+#[cfg(feature = "alloc")]
+mod loft;
+#[cfg(feature = "alloc")]
+mod lookup_table;
+#[cfg(feature = "lyon")]
+mod lyon_interop;
+#[cfg(feature = "alloc")]
+mod medial_axis;
+#[cfg(feature = "alloc")]
+mod memoized_curve;
+#[cfg(feature = "mint")]
+mod mint_point;
+#[cfg(feature = "alloc")]
+mod monotone;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_point;
+mod offset_curve;
+mod on_frame;
+#[cfg(feature = "palette")]
+mod palette_point;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "alloc")]
+mod path;
+mod planar_point;
+mod playback;
 mod point;
-mod smooth_array;
+mod polynomial;
+#[cfg(feature = "alloc")]
+mod profiled_speed;
+mod quaternion;
+#[cfg(feature = "raster")]
+mod raster;
+mod rect;
+#[cfg(feature = "alloc")]
+mod repeat;
+#[cfg(feature = "alloc")]
+mod retime;
+#[cfg(feature = "alloc")]
+mod revolve;
+pub mod roots;
+mod scalar;
+#[cfg(feature = "alloc")]
+mod sdf;
+#[cfg(feature = "alloc")]
+mod shader;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "alloc")]
+mod similarity;
+#[cfg(feature = "alloc")]
+mod simplify;
+#[cfg(feature = "alloc")]
+pub mod snapping;
+mod spatial_point;
+#[cfg(feature = "alloc")]
+mod streaming_path;
+#[cfg(feature = "alloc")]
+mod stroke;
+mod surface;
+#[cfg(feature = "alloc")]
+mod svg;
+#[cfg(feature = "alloc")]
+mod sweep;
+#[cfg(feature = "alloc")]
+mod tessellate;
+#[cfg(feature = "alloc")]
+mod text_format;
+mod timed_curve;
+#[cfg(feature = "alloc")]
+mod timeline;
+mod tolerance;
+#[cfg(feature = "alloc")]
+mod toolpath;
+#[cfg(feature = "ttf-parser")]
+mod ttf_outline;
+mod validate;
+#[cfg(feature = "alloc")]
+mod vertex_buffer;
+#[cfg(feature = "alloc")]
+mod wave;
+#[cfg(feature = "alloc")]
+mod winding;
 
-pub use bezier::{Bezier0, Bezier1, Bezier2, Bezier3};
+#[cfg(feature = "alloc")]
+pub use analyze::CurveStats;
+#[cfg(feature = "alloc")]
+pub use approach::closest_approach;
+#[cfg(feature = "alloc")]
+pub use arc::{Arc, ArcSegment};
+pub use arithmetic::{Clamp, Maximum, Minimum, Negation, Product, Sum};
+#[cfg(feature = "alloc")]
+pub use arrangement::{Arrangement, ArrangementEdge, ArrangementFace};
+#[cfg(feature = "bevy")]
+pub use bevy_interop::BevyCurve;
+pub use bezier::{Bezier, Bezier0, Bezier1, Bezier2, Bezier3};
+#[cfg(feature = "alloc")]
+pub use binary::DecodeError;
+#[cfg(feature = "alloc")]
+pub use bounding::{Circle, OrientedRect};
+pub use bundle::{CurveBundle, CurveTuple};
+#[cfg(feature = "alloc")]
+pub use bvh::{SegmentBvh, SegmentHit, SegmentIntersection};
+pub use cached_curve::CachedCurve;
+#[cfg(feature = "alloc")]
+pub use chaikin::Chaikin;
+#[cfg(feature = "alloc")]
+pub use clip::ClippedPiece;
+#[cfg(feature = "alloc")]
 pub use composed_curve::ComposedCurve;
 pub use curve::Curve;
+#[cfg(feature = "debug-svg")]
+pub use debug_svg::{debug_svg, DebugSvgOptions};
+#[cfg(feature = "alloc")]
+pub use diff::{ChangeKind, SegmentChange};
 pub use distance::Distance;
+#[cfg(feature = "alloc")]
+pub use edit::{HandleMode, PathEdit, PathEditor};
+#[cfg(feature = "alloc")]
+pub use envelope::{Envelope, EnvelopeStage};
+pub use error::Error;
+pub use extend::{ExtendMode, Extended};
+pub use fat_line::FatLine;
+#[cfg(feature = "alloc")]
+pub use frames::{rotation_minimizing_frames, Frame};
+pub use inner_product::InnerProduct;
+pub use jitter::Jitter;
+#[cfg(feature = "alloc")]
 pub use linear_speed::LinearSpeed;
-pub use point::Point;
+#[cfg(feature = "alloc")]
+pub use loft::{loft, Loft, LoftInterpolation};
+#[cfg(feature = "alloc")]
+pub use lookup_table::{LookupTable, TableInterpolation};
+#[cfg(feature = "alloc")]
+pub use medial_axis::MedialPoint;
+#[cfg(feature = "alloc")]
+pub use memoized_curve::MemoizedCurve;
+pub use offset_curve::{OffsetCurve, StrokeSide};
+pub use on_frame::{OnFrame, Perpendicular, SideDirection, UpVector};
+#[cfg(feature = "alloc")]
+pub use path::Path;
+pub use planar_point::PlanarPoint;
+pub use playback::{Delay, PingPong, Repeat, TimeScale};
+pub use point::{Point, PointCast};
+pub use polynomial::Polynomial3;
+#[cfg(feature = "alloc")]
+pub use profiled_speed::ProfiledSpeed;
+#[cfg(feature = "alloc")]
+pub use quaternion::Squad;
+pub use quaternion::{Quaternion, Slerp};
+#[cfg(feature = "raster")]
+pub use raster::{rasterize, Antialiasing, RasterOptions};
+pub use rect::Rect;
+#[cfg(feature = "alloc")]
+pub use repeat::AlignMode;
+#[cfg(feature = "alloc")]
+pub use retime::TrapezoidProfile;
+#[cfg(feature = "alloc")]
+pub use revolve::{revolve, Revolution};
+pub use scalar::Scalar;
+#[cfg(feature = "alloc")]
+pub use shader::ShaderLanguage;
+#[cfg(feature = "alloc")]
+pub use similarity::{discrete_frechet_distance, frechet_distance, hausdorff_distance};
+#[cfg(feature = "alloc")]
+pub use simplify::{simplify_rdp, simplify_visvalingam};
+pub use spatial_point::SpatialPoint;
+#[cfg(feature = "alloc")]
+pub use streaming_path::StreamingPath;
+#[cfg(feature = "alloc")]
+pub use stroke::{LineCap, LineJoin, StrokeStrip};
+#[cfg(feature = "alloc")]
+pub use surface::SurfaceMesh;
+pub use surface::{BezierPatch3, Surface};
+#[cfg(feature = "alloc")]
+pub use sweep::{extrude, sweep, Extrusion, Sweep};
+#[cfg(feature = "alloc")]
+pub use tessellate::{FillRule, Mesh};
+#[cfg(feature = "alloc")]
+pub use text_format::ParseCurveError;
+pub use timed_curve::TimedCurve;
+#[cfg(feature = "alloc")]
+pub use timeline::{Timeline, Track};
+pub use tolerance::Tolerance;
+#[cfg(feature = "alloc")]
+pub use toolpath::{Move, Toolpath};
+pub use validate::{validate_parameter, CurveError};
+#[cfg(feature = "alloc")]
+pub use vertex_buffer::VertexLayout;
+#[cfg(feature = "alloc")]
+pub use winding::RayHit;
@@ -1,51 +1,117 @@
-use crate::smooth_array::SmoothArray;
-use crate::{Curve, Distance, Point};
-use num_traits::{Float, NumCast, One, Zero};
-use std::marker::PhantomData;
+use crate::lookup_table::LookupTable;
+use crate::{Curve, Distance, Point, Scalar};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use num_traits::{NumCast, One, Zero};
 
 /// The same curve as a passed one, but with a linear dependency between the time and the distance.
+/// Curves of zero length, as far as the samples can tell, keep their own parameterization.
 pub struct LinearSpeed<P: Point + Distance, C: Curve<P>> {
     curve: C,
-    length: P::Scalar,
-    table: SmoothArray<P::Scalar>,
+    pub(crate) length: P::Scalar,
+    pub(crate) table: LookupTable<P::Scalar>,
     phantom_data: PhantomData<P>,
 }
 
 impl<P: Point + Distance, C: Curve<P>> LinearSpeed<P, C> {
+    /// See [`Curve::linear_speed`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `table_size` is less than 2.
     pub fn new(curve: C, table_size: usize, steps_count: usize) -> Self {
-        let mut table = SmoothArray::with_steps_count(table_size);
+        let inverted_steps: P::Scalar = P::Scalar::one() / NumCast::from(steps_count).unwrap();
+        let points: Vec<P> = (0..=steps_count)
+            .map(|i| {
+                let i: P::Scalar = NumCast::from(i).unwrap();
+                curve.value_at(i * inverted_steps)
+            })
+            .collect();
+
+        Self::from_samples(curve, table_size, &points)
+    }
+
+    /// Build the table from curve points sampled at uniformly distributed `t` values.
+    pub(crate) fn from_samples(curve: C, table_size: usize, points: &[P]) -> Self {
+        let mut table = LookupTable::with_steps_count(table_size);
+
+        let steps_count = points.len() - 1;
+        let inverted_steps: P::Scalar = P::Scalar::one() / NumCast::from(steps_count).unwrap();
 
-        let mut last_point = curve.value_at(P::Scalar::zero());
         let mut total_length = P::Scalar::zero();
 
         let mut t_by_offset: Vec<(P::Scalar, P::Scalar)> = Vec::with_capacity(steps_count + 1);
         t_by_offset.push((P::Scalar::zero(), P::Scalar::zero()));
 
-        let inverted_steps: P::Scalar = P::Scalar::one() / NumCast::from(steps_count).unwrap();
-
-        for i in 1..=steps_count {
-            let i: P::Scalar = NumCast::from(i).unwrap();
+        for (i, segment) in points.windows(2).enumerate() {
+            let i: P::Scalar = NumCast::from(i + 1).unwrap();
             let t: P::Scalar = i * inverted_steps;
-            let point = curve.value_at(t);
-            let segment_length = last_point.distance(&point);
-            total_length = total_length + segment_length;
+            total_length = total_length + segment[0].distance(&segment[1]);
             t_by_offset.push((total_length, t));
-            last_point = point;
         }
 
-        let inverted_length: P::Scalar = P::Scalar::one() / total_length;
+        if total_length <= P::Scalar::zero() {
+            // A single point, or samples that don't see the movement
+            table.line(
+                (P::Scalar::zero(), P::Scalar::zero()),
+                (One::one(), One::one()),
+            );
+            return Self::from_table(curve, P::Scalar::zero(), table);
+        }
+
+        // Division keeps the last offset exactly 1
         t_by_offset.windows(2).for_each(|window| {
             let (offset1, t1) = window[0];
             let (offset2, t2) = window[1];
-            table.line(
-                (offset1 * inverted_length, t1),
-                (offset2 * inverted_length, t2),
-            );
+            table.line((offset1 / total_length, t1), (offset2 / total_length, t2));
         });
 
+        Self::from_table(curve, total_length, table)
+    }
+
+    /// The wrapped curve.
+    pub fn inner(&self) -> &C {
+        &self.curve
+    }
+
+    /// Unwrap the original curve, e.g. to rebuild the table with another size.
+    pub fn into_inner(self) -> C {
+        self.curve
+    }
+
+    /// The table mapping the distance part to the curve `t`.
+    pub fn table(&self) -> &LookupTable<P::Scalar> {
+        &self.table
+    }
+
+    /// Number of entries of the table.
+    pub fn table_len(&self) -> usize {
+        self.table.values().len()
+    }
+
+    /// The largest difference between the distance travelled along the curve and the distance
+    /// expected with constant speed, measured at twice the table resolution. The distance
+    /// is measured along a polyline, so it's an estimate in the units of the points.
+    pub fn sample_error_estimate(&self) -> P::Scalar {
+        let steps = (self.table_len().max(2) - 1) * 2;
+        let inverted_steps: P::Scalar = P::Scalar::one() / NumCast::from(steps).unwrap();
+        let mut previous = self.value_at(P::Scalar::zero());
+        let mut travelled = P::Scalar::zero();
+        let mut error = P::Scalar::zero();
+        for i in 1..=steps {
+            let t = <P::Scalar as NumCast>::from(i).unwrap() * inverted_steps;
+            let point = self.value_at(t);
+            travelled = travelled + previous.distance(&point);
+            error = error.max((travelled - self.length * t).abs());
+            previous = point;
+        }
+        error
+    }
+
+    pub(crate) fn from_table(curve: C, length: P::Scalar, table: LookupTable<P::Scalar>) -> Self {
         Self {
             curve,
-            length: total_length,
+            length,
             table,
             phantom_data: Default::default(),
         }
@@ -53,14 +119,19 @@ impl<P: Point + Distance, C: Curve<P>> LinearSpeed<P, C> {
 }
 
 impl<P: Point + Distance, C: Curve<P>> Curve<P> for LinearSpeed<P, C> {
+    /// The point at the distance part `t` of the curve length, the table maps it to the curve `t`.
+    /// NaN goes through the table unchanged, so it gives the same value as the wrapped curve.
     fn value_at(&self, t: P::Scalar) -> P {
-        self.curve.value_at(t)
+        let t = t.clamp(P::Scalar::zero(), P::Scalar::one());
+        self.curve.value_at(self.table.value_at(t))
     }
 
     fn tangent_at(&self, t: P::Scalar) -> P {
         let t = t.clamp(P::Scalar::zero(), P::Scalar::one());
-        let t = self.table.value_at(t);
-        self.curve.tangent_at(t).scale(self.table.tangent_at(t))
+        let curve_t = self.table.value_at(t);
+        self.curve
+            .tangent_at(curve_t)
+            .scale(self.table.tangent_at(t))
     }
 
     fn start_point(&self) -> P {
@@ -71,7 +142,102 @@ impl<P: Point + Distance, C: Curve<P>> Curve<P> for LinearSpeed<P, C> {
         self.curve.end_point()
     }
 
+    fn control_points(&self) -> Vec<P> {
+        self.curve.control_points()
+    }
+
     fn estimate_length(&self, _precision: P::Scalar) -> P::Scalar {
         self.length
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bezier1, Bezier3, ComposedCurve};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn uniform_distance() {
+        let curve = Bezier3::new([0.0, 0.0], [0.0, 10.0], [1.0, 10.0], [10.0, 0.0]);
+        let linear = LinearSpeed::new(curve, 256, 2000);
+        let length = linear.estimate_length(0.0);
+
+        let points: Vec<_> = (0..=50).map(|i| linear.value_at(i as f64 / 50.0)).collect();
+        for pair in points.windows(2) {
+            assert_relative_eq!(
+                pair[0].distance(&pair[1]),
+                length / 50.0,
+                max_relative = 0.02
+            );
+        }
+        assert_eq!(linear.value_at(0.0), curve.start_point());
+        assert_relative_eq!(linear.value_at(1.0)[0], 10.0, epsilon = 1e-9);
+
+        let speed = linear.tangent_at(0.37);
+        assert_relative_eq!(speed[0].hypot(speed[1]), length, max_relative = 0.02);
+    }
+
+    #[test]
+    #[cfg(not(feature = "debug-checks"))]
+    fn nan_parameter() {
+        let curve = Bezier3::new([0.0, 0.0], [0.0, 10.0], [1.0, 10.0], [10.0, 0.0]);
+        let linear = LinearSpeed::new(curve, 64, 100);
+        assert!(linear.value_at(f64::NAN).iter().all(|v| v.is_nan()));
+        assert!(linear.tangent_at(f64::NAN).iter().all(|v| v.is_nan()));
+    }
+
+    #[test]
+    fn coincident_samples() {
+        // The first half of the delayed line is a single point
+        let curve = Bezier1::new([0.0, 0.0], [10.0, 0.0]).delay(0.5);
+        let linear = LinearSpeed::new(curve, 64, 100);
+        assert_relative_eq!(linear.estimate_length(0.0), 10.0, epsilon = 1e-9);
+        for i in 0..=10 {
+            let point = linear.value_at(i as f64 / 10.0);
+            assert_relative_eq!(point[0], i as f64, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn zero_length() {
+        let linear = LinearSpeed::new(Bezier1::new([1.0, 2.0], [1.0, 2.0]), 16, 10);
+        assert_eq!(linear.estimate_length(0.0), 0.0);
+        assert_eq!(linear.value_at(0.3), [1.0, 2.0]);
+        assert_eq!(linear.table.value_at(0.25), 0.25);
+
+        // Closed curve sampled only at its end points
+        let loop_curve = Bezier3::new([0.0, 0.0], [1.0, 1.0], [-1.0, 1.0], [0.0, 0.0]);
+        let linear = LinearSpeed::new(loop_curve, 16, 1);
+        assert_eq!(linear.estimate_length(0.0), 0.0);
+        let point = linear.value_at(0.5);
+        assert_eq!(point, loop_curve.value_at(0.5));
+    }
+
+    #[test]
+    fn introspection() {
+        let curve = Bezier3::new([0.0, 0.0], [0.0, 10.0], [1.0, 10.0], [10.0, 0.0]);
+        let coarse = LinearSpeed::new(curve, 8, 2000);
+        let fine = LinearSpeed::new(curve, 512, 2000);
+        assert_eq!(coarse.table_len(), 8);
+        assert_eq!(coarse.table().values().len(), 8);
+        assert_eq!(coarse.inner(), &curve);
+        assert!(fine.sample_error_estimate() < coarse.sample_error_estimate());
+        assert!(fine.sample_error_estimate() < 0.01 * fine.estimate_length(0.0));
+
+        let rebuilt = LinearSpeed::new(coarse.into_inner(), 64, 100);
+        assert_eq!(rebuilt.table_len(), 64);
+    }
+
+    #[test]
+    fn borrowed_curve() {
+        let mut path = ComposedCurve::new([0.0, 0.0]);
+        path.line_to([3.0, 0.0]);
+        path.cubic_to([4.0, 0.0], [4.0, 1.0], [4.0, 3.0]);
+        let borrowed = LinearSpeed::new(&path, 128, 1000);
+        let owned = LinearSpeed::new(path.clone(), 128, 1000);
+        assert_eq!(borrowed.value_at(0.4), owned.value_at(0.4));
+        assert_eq!(borrowed.estimate_length(0.0), owned.estimate_length(0.0));
+        assert_eq!(borrowed.inner().segments().len(), 2);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::{Curve, Point, Scalar, Surface};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use num_traits::{NumCast, One, ToPrimitive, Zero};
+
+/// Interpolation between sections of a [`Loft`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum LoftInterpolation {
+    /// Straight lines between consecutive sections, a ruled surface for two sections.
+    #[default]
+    Linear,
+    /// Smooth Catmull-Rom spline through all sections.
+    Cubic,
+}
+
+/// Surface interpolating a sequence of section curves, see [`loft`].
+#[derive(Clone, Debug)]
+pub struct Loft<P: Point, C: Curve<P>> {
+    sections: Vec<C>,
+    interpolation: LoftInterpolation,
+    _point: PhantomData<P>,
+}
+
+/// Surface going through the `sections`: the `u` parameter goes along sections,
+/// sections are evenly spaced along `v`, the first one is at `v = 0`, the last one at `v = 1`.
+///
+/// # Panics
+/// If there are less than two sections.
+pub fn loft<P: Point, C: Curve<P> + Clone>(
+    sections: &[C],
+    interpolation: LoftInterpolation,
+) -> Loft<P, C> {
+    assert!(sections.len() >= 2, "at least two sections are required");
+    Loft {
+        sections: sections.to_vec(),
+        interpolation,
+        _point: PhantomData,
+    }
+}
+
+impl<P: Point, C: Curve<P>> Loft<P, C> {
+    pub fn sections(&self) -> &[C] {
+        &self.sections
+    }
+
+    pub fn interpolation(&self) -> LoftInterpolation {
+        self.interpolation
+    }
+
+    /// Weights of sections at `v`, or of their derivatives by `v` if `derivative` is set.
+    fn weights(&self, v: P::Scalar, derivative: bool) -> Vec<(usize, P::Scalar)> {
+        let last = self.sections.len() - 1;
+        let scale: P::Scalar = NumCast::from(last).unwrap();
+        let x = v.clamp(P::Scalar::zero(), P::Scalar::one()) * scale;
+        let i = x.floor().to_usize().unwrap().min(last - 1);
+        let s = x - NumCast::from(i).unwrap();
+        let c = |v: f64| -> P::Scalar { NumCast::from(v).unwrap() };
+
+        match self.interpolation {
+            LoftInterpolation::Linear if derivative => Vec::from([(i, -scale), (i + 1, scale)]),
+            LoftInterpolation::Linear => Vec::from([(i, P::Scalar::one() - s), (i + 1, s)]),
+            LoftInterpolation::Cubic => {
+                let (s2, s3) = (s * s, s * s * s);
+                // Hermite basis for p(i), m(i), p(i + 1), m(i + 1)
+                let h = if derivative {
+                    [
+                        c(6.0) * s2 - c(6.0) * s,
+                        c(3.0) * s2 - c(4.0) * s + P::Scalar::one(),
+                        c(6.0) * s - c(6.0) * s2,
+                        c(3.0) * s2 - c(2.0) * s,
+                    ]
+                    .map(|h| h * scale)
+                } else {
+                    [
+                        c(2.0) * s3 - c(3.0) * s2 + P::Scalar::one(),
+                        s3 - c(2.0) * s2 + s,
+                        c(3.0) * s2 - c(2.0) * s3,
+                        s3 - s2,
+                    ]
+                };
+                let mut weights = Vec::with_capacity(6);
+                for (k, (hp, hm)) in [(i, (h[0], h[1])), (i + 1, (h[2], h[3]))] {
+                    // Catmull-Rom tangent, one-sided at the ends
+                    let (a, b) = (k.saturating_sub(1), (k + 1).min(last));
+                    let m = hm / NumCast::from(b - a).unwrap();
+                    weights.extend([(k, hp), (b, m), (a, -m)]);
+                }
+                weights
+            }
+        }
+    }
+
+    fn combine(&self, weights: Vec<(usize, P::Scalar)>, f: impl Fn(&C) -> P) -> P {
+        weights
+            .into_iter()
+            .map(|(k, w)| f(&self.sections[k]).scale(w))
+            .reduce(|a, b| a.add(&b))
+            .unwrap()
+    }
+}
+
+impl<P: Point, C: Curve<P>> Surface<P> for Loft<P, C> {
+    fn value_at(&self, u: P::Scalar, v: P::Scalar) -> P {
+        self.combine(self.weights(v, false), |c| c.value_at(u))
+    }
+
+    fn derivative_u(&self, u: P::Scalar, v: P::Scalar) -> P {
+        self.combine(self.weights(v, false), |c| c.tangent_at(u))
+    }
+
+    fn derivative_v(&self, u: P::Scalar, v: P::Scalar) -> P {
+        self.combine(self.weights(v, true), |c| c.value_at(u))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bezier1, Bezier3, Distance};
+    use approx::assert_relative_eq;
+
+    fn sections() -> Vec<Bezier3<[f64; 3]>> {
+        (0..4)
+            .map(|i| {
+                let z = i as f64;
+                let w = 1.0 + (z * 1.3).sin();
+                Bezier3::new(
+                    [0.0, 0.0, z],
+                    [w, 1.0, z],
+                    [2.0 * w, -1.0, z],
+                    [3.0, 0.0, z + 0.5 * w],
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn ruled_surface() {
+        let a = Bezier1::new([0.0, 0.0, 0.0], [2.0, 0.0, 0.0]);
+        let b = Bezier1::new([0.0, 2.0, 1.0], [2.0, 2.0, 3.0]);
+        let surface = loft(&[a, b], LoftInterpolation::Linear);
+        assert_relative_eq!(
+            surface.value_at(0.5, 0.5).distance(&[1.0, 1.0, 1.0]),
+            0.0,
+            epsilon = 1e-12
+        );
+        assert_eq!(surface.derivative_v(0.0, 0.3), [0.0, 2.0, 1.0]);
+        assert_eq!(surface.derivative_u(1.0, 0.5), [2.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn passes_through_sections() {
+        let sections = sections();
+        for interpolation in [LoftInterpolation::Linear, LoftInterpolation::Cubic] {
+            let surface = loft(&sections, interpolation);
+            for (i, section) in sections.iter().enumerate() {
+                let v = i as f64 / 3.0;
+                for u in [0.0, 0.3, 1.0] {
+                    let d = surface.value_at(u, v).distance(&section.value_at(u));
+                    assert_relative_eq!(d, 0.0, epsilon = 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cubic_derivatives() {
+        let surface = loft(&sections(), LoftInterpolation::Cubic);
+        let h = 1e-6;
+        for (u, v) in [(0.2, 0.1), (0.5, 0.5), (0.8, 0.95)] {
+            let du = surface
+                .value_at(u + h, v)
+                .sub(&surface.value_at(u - h, v))
+                .scale(0.5 / h);
+            let dv = surface
+                .value_at(u, v + h)
+                .sub(&surface.value_at(u, v - h))
+                .scale(0.5 / h);
+            assert_relative_eq!(
+                surface.derivative_u(u, v).distance(&du),
+                0.0,
+                epsilon = 1e-6
+            );
+            assert_relative_eq!(
+                surface.derivative_v(u, v).distance(&dv),
+                0.0,
+                epsilon = 1e-6
+            );
+        }
+    }
+
+    #[test]
+    fn cubic_is_smooth_at_sections() {
+        let surface = loft(&sections(), LoftInterpolation::Cubic);
+        let v = 1.0 / 3.0;
+        let before = surface.derivative_v(0.4, v - 1e-9);
+        let after = surface.derivative_v(0.4, v + 1e-9);
+        assert_relative_eq!(before.distance(&after), 0.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn single_section() {
+        loft(
+            &[Bezier1::new([0.0; 3], [1.0; 3])],
+            LoftInterpolation::Linear,
+        );
+    }
+}
@@ -0,0 +1,336 @@
+use crate::scalar::is_nan;
+use crate::Scalar;
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::NumCast;
+
+/// How [`LookupTable`] interpolates between its entries.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TableInterpolation {
+    #[default]
+    Linear,
+    /// Monotone cubic Hermite interpolation, it's smooth and doesn't overshoot,
+    /// so monotonic tables stay monotonic between the entries.
+    Cubic,
+}
+
+/// Values baked at uniformly distributed indexes, interpolated between the entries.
+/// Indexes are in range 0.0..=1.0, e.g. gamma curves, audio envelopes, or the arc length
+/// parameterization of [`LinearSpeed`](crate::LinearSpeed).
+#[derive(Clone, Debug, PartialEq)]
+pub struct LookupTable<F: Scalar> {
+    data: Vec<F>,
+    interpolation: TableInterpolation,
+}
+
+impl<F: Scalar> LookupTable<F> {
+    pub(crate) fn with_steps_count(steps_count: usize) -> Self {
+        Self::from_values(vec![F::zero(); steps_count])
+    }
+
+    /// The table with the given values at uniformly distributed indexes,
+    /// the first value is at index 0 and the last one at index 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less than 2 values.
+    pub fn from_values(values: Vec<F>) -> Self {
+        assert!(values.len() >= 2, "table size must be at least 2");
+        Self {
+            data: values,
+            interpolation: TableInterpolation::Linear,
+        }
+    }
+
+    /// Resample `(index, value)` pairs sorted by index into a table of `size` entries,
+    /// values between the samples are interpolated linearly, and outside of them
+    /// the first or the last value is held.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is empty or `size` is less than 2.
+    pub fn from_samples(samples: &[(F, F)], size: usize) -> Self {
+        assert!(!samples.is_empty(), "samples must not be empty");
+        assert!(size >= 2, "table size must be at least 2");
+
+        let (first, last) = (samples[0], samples[samples.len() - 1]);
+        let mut table = Self::from_values(vec![first.1; size]);
+        samples
+            .windows(2)
+            .for_each(|window| table.line(window[0], window[1]));
+        let last_index = table.to_array_index(last.0).max(F::zero());
+        for (i, value) in table.data.iter_mut().enumerate() {
+            if <F as NumCast>::from(i).unwrap() > last_index {
+                *value = last.1;
+            }
+        }
+        table
+    }
+
+    /// Use `interpolation` between the entries.
+    pub fn with_interpolation(mut self, interpolation: TableInterpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    pub fn interpolation(&self) -> TableInterpolation {
+        self.interpolation
+    }
+
+    /// The table entries.
+    pub fn values(&self) -> &[F] {
+        &self.data
+    }
+
+    /// Check if the values never decrease or never increase.
+    pub fn is_monotonic(&self) -> bool {
+        let pairs = || self.data.windows(2);
+        pairs().all(|w| w[0] <= w[1]) || pairs().all(|w| w[0] >= w[1])
+    }
+
+    /// Replace every value that goes against the overall direction, from the first
+    /// to the last value, by the previous one, so the table becomes monotonic.
+    pub fn make_monotonic(&mut self) {
+        let increasing = match (self.data.first(), self.data.last()) {
+            (Some(first), Some(last)) => first <= last,
+            _ => return,
+        };
+        for i in 1..self.data.len() {
+            let previous = self.data[i - 1];
+            self.data[i] = if increasing {
+                self.data[i].max(previous)
+            } else {
+                self.data[i].min(previous)
+            };
+        }
+    }
+
+    /// The interpolated value at `index`, NaN for a NaN index.
+    pub fn value_at(&self, index: F) -> F {
+        if is_nan(index) {
+            return index;
+        }
+        let i = self.to_array_index(index);
+        match self.interpolation {
+            TableInterpolation::Linear => self.value_at_scaled_index(i),
+            TableInterpolation::Cubic => self.hermite_at_scaled_index(i).0,
+        }
+    }
+
+    /// The derivative by the index, NaN for a NaN index.
+    pub fn tangent_at(&self, index: F) -> F {
+        if is_nan(index) {
+            return index;
+        }
+        let i = self.to_array_index(index);
+        if self.interpolation == TableInterpolation::Cubic {
+            return self.hermite_at_scaled_index(i).1 * self.last_index();
+        }
+
+        let i1 = (i - F::one()).max(F::zero());
+        let i2 = (i + F::one()).min(self.last_index());
+
+        let v1 = self.value_at_scaled_index(i1);
+        let v2 = self.value_at_scaled_index(i2);
+
+        let dl = (i2 - i1) / self.last_index();
+
+        (v2 - v1) / dl
+    }
+
+    /// Fill the entries between the indexes `i1` and `i2` with the values linearly interpolated
+    /// between `v1` and `v2`. The indexes may come in any order, entries outside of the table
+    /// are skipped, and a zero length span sets the entry at its index to `v2`.
+    pub(crate) fn line(&mut self, (i1, v1): (F, F), (i2, v2): (F, F)) {
+        let ((i1, v1), (i2, v2)) = if i2 < i1 {
+            ((i2, v2), (i1, v1))
+        } else {
+            ((i1, v1), (i2, v2))
+        };
+        let i1 = self.to_array_index(i1);
+        let i2 = self.to_array_index(i2);
+        // NaN indexes
+        if i1.partial_cmp(&i2).is_none() {
+            return;
+        }
+
+        let mut i = i1.ceil().max(F::zero());
+        let max_i = i2.min(self.last_index());
+        while i <= max_i {
+            let v = if i2 == i1 {
+                v2
+            } else {
+                let f = (i - i1) / (i2 - i1);
+                v1 * (F::one() - f) + v2 * f
+            };
+            self.data[i.to_usize().unwrap()] = v;
+            i = i + F::one();
+        }
+    }
+
+    fn to_array_index(&self, index: F) -> F {
+        index * self.last_index()
+    }
+
+    fn value_at_scaled_index(&self, i: F) -> F {
+        let i = i.clamp(F::zero(), self.last_index());
+
+        let f = i.fract();
+        let i1 = i.floor().to_usize().unwrap();
+        let i2 = i.ceil().to_usize().unwrap();
+
+        let v1 = self.data[i1];
+        let v2 = self.data[i2];
+
+        v1 + (v2 - v1) * f
+    }
+
+    /// Value and derivative by the array index, Fritsch-Butland tangents keep
+    /// the interpolation monotonic.
+    fn hermite_at_scaled_index(&self, i: F) -> (F, F) {
+        let i = i.clamp(F::zero(), self.last_index());
+        let k = i.floor().to_usize().unwrap().min(self.data.len() - 2);
+        let s = i - <F as NumCast>::from(k).unwrap();
+
+        let (y0, y1) = (self.data[k], self.data[k + 1]);
+        let (m0, m1) = (self.hermite_tangent(k), self.hermite_tangent(k + 1));
+
+        let one = F::one();
+        let two = one + one;
+        let three = two + one;
+        let s2 = s * s;
+        let s3 = s2 * s;
+        let value = (two * s3 - three * s2 + one) * y0
+            + (s3 - two * s2 + s) * m0
+            + (three * s2 - two * s3) * y1
+            + (s3 - s2) * m1;
+        let derivative = (s2 - s) * (three + three) * (y0 - y1)
+            + (three * s2 - (two + two) * s + one) * m0
+            + (three * s2 - two * s) * m1;
+        (value, derivative)
+    }
+
+    fn hermite_tangent(&self, k: usize) -> F {
+        let last = self.data.len() - 1;
+        if k == 0 {
+            return self.data[1] - self.data[0];
+        }
+        if k == last {
+            return self.data[last] - self.data[last - 1];
+        }
+        let d0 = self.data[k] - self.data[k - 1];
+        let d1 = self.data[k + 1] - self.data[k];
+        if d0 * d1 <= F::zero() {
+            F::zero()
+        } else {
+            (d0 * d1 + d0 * d1) / (d0 + d1)
+        }
+    }
+
+    fn len(&self) -> F {
+        <F as NumCast>::from(self.data.len()).unwrap()
+    }
+
+    fn last_index(&self) -> F {
+        self.len() - F::one()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn linear_table() {
+        let mut array = LookupTable::with_steps_count(10);
+        array.line((0.0, 0.0), (1.0, 1.0));
+
+        assert_eq!(array.value_at(0.0), 0.0);
+        assert_eq!(array.value_at(0.5), 0.5);
+        assert_eq!(array.value_at(1.0), 1.0);
+
+        assert_relative_eq!(array.tangent_at(0.0), 1.0, epsilon = 1e-6);
+        assert_relative_eq!(array.tangent_at(0.5), 1.0, epsilon = 1e-6);
+        assert_relative_eq!(array.tangent_at(1.0), 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn degenerate_lines() {
+        let mut table = LookupTable::with_steps_count(5);
+        table.line((1.0, 1.0), (0.0, 0.0));
+        assert_eq!(table.values(), &[0.0, 0.25, 0.5, 0.75, 1.0]);
+
+        table.line((0.5, 3.0), (0.5, 4.0));
+        assert_eq!(table.values(), &[0.0, 0.25, 4.0, 0.75, 1.0]);
+        table.line((0.6, 3.0), (0.6, 4.0));
+        assert_eq!(table.values(), &[0.0, 0.25, 4.0, 0.75, 1.0]);
+
+        table.line((-1.0, 0.0), (2.0, 3.0));
+        assert_eq!(table.values(), &[1.0, 1.25, 1.5, 1.75, 2.0]);
+        table.line((0.0, 1.0), (f64::NAN, 0.0));
+        assert_eq!(table.values(), &[1.0, 1.25, 1.5, 1.75, 2.0]);
+    }
+
+    #[test]
+    fn from_samples() {
+        let table = LookupTable::from_samples(&[(0.25, 1.0), (0.5, 2.0), (0.75, 2.0)], 5);
+        assert_eq!(table.values(), &[1.0, 1.0, 2.0, 2.0, 2.0]);
+
+        // Gamma 2 curve
+        let samples: Vec<_> = (0..=16)
+            .map(|i| (i as f64 / 16.0, (i as f64 / 16.0).powi(2)))
+            .collect();
+        let table = LookupTable::from_samples(&samples, 17);
+        assert_relative_eq!(table.value_at(0.5), 0.25, epsilon = 1e-12);
+        assert!(table.is_monotonic());
+    }
+
+    #[test]
+    fn monotonic() {
+        let mut table = LookupTable::from_values(vec![0.0, 0.5, 0.4, 1.0]);
+        assert!(!table.is_monotonic());
+        table.make_monotonic();
+        assert_eq!(table.values(), &[0.0, 0.5, 0.5, 1.0]);
+        assert!(table.is_monotonic());
+
+        let mut falling = LookupTable::from_values(vec![1.0, 0.2, 0.3, 0.0]);
+        falling.make_monotonic();
+        assert_eq!(falling.values(), &[1.0, 0.2, 0.2, 0.0]);
+    }
+
+    #[test]
+    fn cubic_interpolation() {
+        let values: Vec<_> = (0..=8).map(|i| (i as f64 / 8.0).powi(3)).collect();
+        let table = LookupTable::from_values(values).with_interpolation(TableInterpolation::Cubic);
+        assert_eq!(table.value_at(0.0), 0.0);
+        assert_eq!(table.value_at(0.25), 0.25f64.powi(3));
+        assert_eq!(table.value_at(1.0), 1.0);
+        assert_relative_eq!(table.value_at(0.6), 0.216, epsilon = 2e-3);
+        assert_relative_eq!(table.tangent_at(0.6), 3.0 * 0.36, epsilon = 5e-2);
+
+        // Stays monotonic and doesn't overshoot a step
+        let step = LookupTable::from_values(vec![0.0, 0.0, 1.0, 1.0])
+            .with_interpolation(TableInterpolation::Cubic);
+        let values: Vec<_> = (0..=30).map(|i| step.value_at(i as f64 / 30.0)).collect();
+        assert!(values.windows(2).all(|w| w[0] <= w[1]));
+        assert!(values.iter().all(|v| (0.0..=1.0).contains(v)));
+    }
+
+    #[test]
+    fn nan_index() {
+        let table = LookupTable::from_values(vec![0.0, 1.0]);
+        assert!(table.value_at(f64::NAN).is_nan());
+        assert!(table.tangent_at(f64::NAN).is_nan());
+
+        let cubic = table.with_interpolation(TableInterpolation::Cubic);
+        assert!(cubic.value_at(f64::NAN).is_nan());
+        assert_eq!(cubic.value_at(0.5), 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "table size must be at least 2")]
+    fn single_entry() {
+        LookupTable::from_values(vec![1.0]);
+    }
+}
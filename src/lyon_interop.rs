@@ -0,0 +1,118 @@
+use crate::bezier::Bezier;
+use crate::{ComposedCurve, Curve, Error, Path};
+use ::lyon_path::math::Point as LyonPoint;
+use ::lyon_path::{Event, Path as LyonPath};
+
+fn push_contour(builder: &mut ::lyon_path::path::Builder, contour: &ComposedCurve<LyonPoint>) {
+    builder.begin(contour.start_point());
+    for segment in contour.segments() {
+        match segment {
+            Bezier::C0(_) => {}
+            Bezier::C1(c) => {
+                builder.line_to(c.p1);
+            }
+            Bezier::C2(c) => {
+                builder.quadratic_bezier_to(c.p1, c.p2);
+            }
+            Bezier::C3(c) => {
+                builder.cubic_bezier_to(c.p1, c.p2, c.p3);
+            }
+        }
+    }
+    // A closed contour already ends with the closing segment, so lyon only has to join it
+    builder.end(contour.segments().len() > 1 && contour.end_point() == contour.start_point());
+}
+
+impl From<&ComposedCurve<LyonPoint>> for LyonPath {
+    fn from(curve: &ComposedCurve<LyonPoint>) -> Self {
+        let mut builder = LyonPath::builder();
+        push_contour(&mut builder, curve);
+        builder.build()
+    }
+}
+
+impl From<&Path<LyonPoint>> for LyonPath {
+    fn from(path: &Path<LyonPoint>) -> Self {
+        let mut builder = LyonPath::builder();
+        for contour in path.contours() {
+            push_contour(&mut builder, contour);
+        }
+        builder.build()
+    }
+}
+
+/// Every lyon sub-path becomes a contour, closed sub-paths get the closing line.
+impl From<&LyonPath> for Path<LyonPoint> {
+    fn from(path: &LyonPath) -> Self {
+        let mut result = Path::new();
+        for event in path.iter() {
+            match event {
+                Event::Begin { at } => result.move_to(at),
+                Event::Line { to, .. } => result.line_to(to),
+                Event::Quadratic { ctrl, to, .. } => result.quadratic_to(ctrl, to),
+                Event::Cubic {
+                    ctrl1, ctrl2, to, ..
+                } => result.cubic_to(ctrl1, ctrl2, to),
+                Event::End { close, .. } => {
+                    if close {
+                        result.close();
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+/// A composed curve is a single contour, so the conversion fails with
+/// [`CurveError::Discontinuous`](crate::CurveError::Discontinuous) if the path has several
+/// sub-paths, and with [`CurveError::Empty`](crate::CurveError::Empty) if it has no segments.
+impl TryFrom<&LyonPath> for ComposedCurve<LyonPoint> {
+    type Error = Error;
+
+    fn try_from(path: &LyonPath) -> Result<Self, Self::Error> {
+        Path::from(path).into_single_contour()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CurveError;
+    use ::lyon_path::math::point;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn lyon_path() {
+        let mut curve = ComposedCurve::new(point(0.0, 0.0));
+        curve.line_to(point(10.0, 0.0));
+        curve.quadratic_to(point(10.0, 10.0), point(0.0, 10.0));
+        curve.cubic_to(point(-5.0, 10.0), point(-5.0, 5.0), point(0.0, 5.0));
+        curve.close();
+
+        let path = LyonPath::from(&curve);
+        let events: Vec<_> = path.iter().collect();
+        assert_eq!(events.len(), 6);
+        assert!(matches!(events[5], Event::End { close: true, .. }));
+        assert_eq!(ComposedCurve::try_from(&path), Ok(curve.clone()));
+
+        let mut builder = LyonPath::builder();
+        builder.begin(point(0.0, 0.0));
+        builder.line_to(point(1.0, 0.0));
+        builder.line_to(point(1.0, 1.0));
+        builder.end(true);
+        builder.begin(point(5.0, 5.0));
+        builder.line_to(point(6.0, 5.0));
+        builder.end(false);
+        let two = builder.build();
+
+        let contours = Path::from(&two);
+        assert_eq!(contours.contours().len(), 2);
+        assert_eq!(contours.contours()[0].segments().len(), 3);
+        assert_eq!(
+            ComposedCurve::try_from(&two),
+            Err(CurveError::Discontinuous { segment: 3 }.into())
+        );
+        assert_eq!(Path::from(&LyonPath::from(&contours)), contours);
+    }
+}
@@ -0,0 +1,309 @@
+use crate::{ComposedCurve, Curve, Path, PlanarPoint, Scalar};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::{NumCast, One, ToPrimitive, Zero};
+
+/// A point of the medial axis with the radius of the largest circle around it inside the shape.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MedialPoint<P: PlanarPoint> {
+    pub point: P,
+    pub radius: P::Scalar,
+}
+
+impl<P: PlanarPoint> Path<P> {
+    /// Approximate medial axis of the shape filled by the non-zero rule: the centers of circles
+    /// inside the shape touching its outline at two or more points, as polylines going between
+    /// the ends and the forks of the skeleton. Open contours are closed by lines.
+    ///
+    /// The outline is sampled every `spacing` and the medial axis is taken from the Voronoi diagram
+    /// of the samples. Its edges between neighbouring samples only follow the sampling noise,
+    /// so edges between samples closer than twice the spacing are dropped. Smaller details
+    /// of the shape don't get branches, and the points are within about `spacing` from the exact axis.
+    /// The result is empty if `spacing` isn't positive and finite.
+    pub fn medial_axis(&self, spacing: P::Scalar) -> Vec<Vec<MedialPoint<P>>> {
+        let valid = spacing
+            .to_f64()
+            .is_some_and(|spacing| spacing > 0.0 && spacing.is_finite());
+        if !valid {
+            return Vec::new();
+        }
+        let samples: Vec<P> = self
+            .contours()
+            .iter()
+            .flat_map(|contour| sample_outline(contour, spacing))
+            .collect();
+        if samples.len() < 3 {
+            return Vec::new();
+        }
+        let triangles = delaunay(&samples);
+        let circles: Vec<(P, P::Scalar)> = triangles
+            .iter()
+            .map(|&[a, b, c]| circumcircle(&samples[a], &samples[b], &samples[c]))
+            .collect();
+        let inside: Vec<bool> = circles
+            .iter()
+            .map(|(center, _)| self.winding_at(center) != 0)
+            .collect();
+
+        // Voronoi edges are dual to the edges shared by two triangles
+        let mut shared: BTreeMap<(usize, usize), Vec<usize>> = BTreeMap::new();
+        for (i, &[a, b, c]) in triangles.iter().enumerate() {
+            for (u, v) in [(a, b), (b, c), (c, a)] {
+                shared.entry((u.min(v), u.max(v))).or_default().push(i);
+            }
+        }
+        let min_gap = spacing + spacing;
+        let mut neighbours: Vec<Vec<usize>> = vec![Vec::new(); triangles.len()];
+        for ((u, v), owners) in shared {
+            if let [t0, t1] = owners[..] {
+                if inside[t0] && inside[t1] && samples[u].sub(&samples[v]).length() >= min_gap {
+                    neighbours[t0].push(t1);
+                    neighbours[t1].push(t0);
+                }
+            }
+        }
+
+        let point = |t: usize| MedialPoint {
+            point: circles[t].0.clone(),
+            radius: circles[t].1,
+        };
+        trace_polylines(&neighbours)
+            .into_iter()
+            .map(|chain| {
+                let mut polyline: Vec<MedialPoint<P>> = Vec::with_capacity(chain.len());
+                for t in chain {
+                    let p = point(t);
+                    // Cocircular samples give several triangles with the same center
+                    if polyline.last().is_none_or(|last| last.point != p.point) {
+                        polyline.push(p);
+                    }
+                }
+                polyline
+            })
+            .filter(|polyline| polyline.len() > 1)
+            .collect()
+    }
+}
+
+impl<P: PlanarPoint> ComposedCurve<P> {
+    /// Approximate medial axis of the closed curve, see [`Path::medial_axis`].
+    pub fn medial_axis(&self, spacing: P::Scalar) -> Vec<Vec<MedialPoint<P>>> {
+        Path::from(self.clone()).medial_axis(spacing)
+    }
+}
+
+/// Points along the closed contour not further than `spacing` apart, the start point isn't repeated.
+fn sample_outline<P: PlanarPoint>(contour: &ComposedCurve<P>, spacing: P::Scalar) -> Vec<P> {
+    let mut points = contour.flatten(spacing / NumCast::from(4).unwrap());
+    points.push(contour.start_point());
+    let mut samples = Vec::from([points[0].clone()]);
+    let mut travelled = P::Scalar::zero();
+    for pair in points.windows(2) {
+        let length = pair[1].sub(&pair[0]).length();
+        if length == P::Scalar::zero() {
+            continue;
+        }
+        let mut position = spacing - travelled;
+        while position < length {
+            samples.push(pair[0].lerp(&pair[1], position / length));
+            position = position + spacing;
+        }
+        travelled = length - (position - spacing);
+    }
+    samples.dedup();
+    if samples.len() > 1 && samples[samples.len() - 1] == samples[0] {
+        samples.pop();
+    }
+    samples
+}
+
+/// Center and radius of the circle through three points, the center is far away for collinear ones.
+fn circumcircle<P: PlanarPoint>(a: &P, b: &P, c: &P) -> (P, P::Scalar) {
+    let (ab, ac) = (b.sub(a), c.sub(a));
+    let two = P::Scalar::one() + P::Scalar::one();
+    let denominator = two * ab.cross(&ac);
+    let (ab2, ac2) = (ab.length_squared(), ac.length_squared());
+    let offset = P::from_xy(
+        (ac.y() * ab2 - ab.y() * ac2) / denominator,
+        (ab.x() * ac2 - ac.x() * ab2) / denominator,
+    );
+    let radius = offset.length();
+    (a.add(&offset), radius)
+}
+
+/// Delaunay triangulation with the Bowyer-Watson algorithm, every point is inserted by removing
+/// the triangles whose circumcircles contain it and connecting it to the border of the hole.
+fn delaunay<P: PlanarPoint>(points: &[P]) -> Vec<[usize; 3]> {
+    let (zero, one) = (P::Scalar::zero(), P::Scalar::one());
+    let (mut min, mut max) = (points[0].clone(), points[0].clone());
+    for p in points {
+        min = P::from_xy(min.x().min(p.x()), min.y().min(p.y()));
+        max = P::from_xy(max.x().max(p.x()), max.y().max(p.y()));
+    }
+    let size = (max.x() - min.x()).max(max.y() - min.y()).max(one);
+    let center = min.midpoint(&max);
+    let far: P::Scalar = size * NumCast::from(64).unwrap();
+
+    // A triangle around all the points
+    let mut vertices = points.to_vec();
+    let n = vertices.len();
+    vertices.push(center.add(&P::from_xy(-far, -far)));
+    vertices.push(center.add(&P::from_xy(far, -far)));
+    vertices.push(center.add(&P::from_xy(zero, far)));
+
+    struct Triangle<F> {
+        vertices: [usize; 3],
+        center: (F, F),
+        radius_squared: F,
+    }
+    let triangle = |vertices: [usize; 3], points: &[P]| {
+        let [a, b, c] = vertices.map(|i| &points[i]);
+        let (center, radius) = circumcircle(a, b, c);
+        Triangle {
+            vertices,
+            center: (center.x(), center.y()),
+            radius_squared: radius * radius,
+        }
+    };
+
+    let mut triangles = Vec::from([triangle([n, n + 1, n + 2], &vertices)]);
+    for i in 0..n {
+        let p = &vertices[i];
+        let (bad, good): (Vec<_>, Vec<_>) = triangles.into_iter().partition(|t| {
+            let (dx, dy) = (p.x() - t.center.0, p.y() - t.center.1);
+            dx * dx + dy * dy < t.radius_squared
+        });
+        triangles = good;
+
+        // Edges of the hole belong to a single removed triangle
+        let mut edges: Vec<(usize, usize)> = bad
+            .iter()
+            .flat_map(|t| {
+                let [a, b, c] = t.vertices;
+                [
+                    (a.min(b), a.max(b)),
+                    (b.min(c), b.max(c)),
+                    (c.min(a), c.max(a)),
+                ]
+            })
+            .collect();
+        edges.sort_unstable();
+        let mut k = 0;
+        while k < edges.len() {
+            if k + 1 < edges.len() && edges[k] == edges[k + 1] {
+                k += 2;
+                continue;
+            }
+            let (a, b) = edges[k];
+            triangles.push(triangle([a, b, i], &vertices));
+            k += 1;
+        }
+    }
+    triangles
+        .into_iter()
+        .map(|t| t.vertices)
+        .filter(|vertices| vertices.iter().all(|&v| v < n))
+        .collect()
+}
+
+/// Chains of nodes of the graph going between nodes that don't have exactly two neighbours,
+/// and closed loops of the remaining nodes.
+fn trace_polylines(neighbours: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let mut visited = BTreeSet::new();
+    let mut visit = |a: usize, b: usize| visited.insert((a.min(b), a.max(b)));
+    let mut chains = Vec::new();
+    let starts = (0..neighbours.len())
+        .filter(|&i| !neighbours[i].is_empty() && neighbours[i].len() != 2)
+        .chain((0..neighbours.len()).filter(|&i| neighbours[i].len() == 2));
+    for start in starts {
+        for &first in &neighbours[start] {
+            if !visit(start, first) {
+                continue;
+            }
+            let mut chain = vec![start, first];
+            let (mut previous, mut current) = (start, first);
+            while neighbours[current].len() == 2 && current != start {
+                let next = if neighbours[current][0] == previous {
+                    neighbours[current][1]
+                } else {
+                    neighbours[current][0]
+                };
+                if !visit(current, next) {
+                    break;
+                }
+                chain.push(next);
+                (previous, current) = (current, next);
+            }
+            chains.push(chain);
+        }
+    }
+    chains
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn rectangle() {
+        // A long rectangle, the axis goes along its middle and forks to the corners
+        let mut rect = ComposedCurve::new([0.0, 0.0]);
+        rect.line_to([10.0, 0.0]);
+        rect.line_to([10.0, 2.0]);
+        rect.line_to([0.0, 2.0]);
+        rect.close();
+        let axis = rect.medial_axis(0.1);
+        assert!(!axis.is_empty());
+
+        let points: Vec<&MedialPoint<[f64; 2]>> = axis.iter().flatten().collect();
+        for p in &points {
+            assert!(p.point[0] >= 0.0 && p.point[0] <= 10.0);
+            assert!(p.point[1] >= 0.0 && p.point[1] <= 2.0);
+            assert!(p.radius <= 1.0 + 0.1);
+        }
+        // The middle part is on the center line with the half width as the radius
+        let middle: Vec<_> = points
+            .iter()
+            .filter(|p| p.point[0] > 2.0 && p.point[0] < 8.0)
+            .collect();
+        assert!(middle.len() > 10);
+        for p in middle {
+            assert_relative_eq!(p.point[1], 1.0, epsilon = 0.05);
+            assert_relative_eq!(p.radius, 1.0, epsilon = 0.05);
+        }
+        // Branches reach close to the corners
+        let near_corner = |x: f64, y: f64| {
+            points
+                .iter()
+                .any(|p| (p.point[0] - x).abs() < 0.3 && (p.point[1] - y).abs() < 0.3)
+        };
+        assert!(near_corner(0.0, 0.0) && near_corner(10.0, 2.0));
+    }
+
+    #[test]
+    fn disk_and_degenerate() {
+        // Circles of a disk touch its outline, and the largest one is around the center
+        let mut circle = ComposedCurve::new([2.0, 0.0]);
+        let k = 0.5522847498 * 2.0;
+        circle.cubic_to([2.0, k], [k, 2.0], [0.0, 2.0]);
+        circle.cubic_to([-k, 2.0], [-2.0, k], [-2.0, 0.0]);
+        circle.cubic_to([-2.0, -k], [-k, -2.0], [0.0, -2.0]);
+        circle.cubic_to([k, -2.0], [2.0, -k], [2.0, 0.0]);
+        let axis = circle.medial_axis(0.1);
+        for p in axis.iter().flatten() {
+            let to_outline = 2.0 - (p.point[0] * p.point[0] + p.point[1] * p.point[1]).sqrt();
+            assert_relative_eq!(p.radius, to_outline, epsilon = 0.05);
+        }
+        let largest = axis.iter().flatten().map(|p| p.radius).fold(0.0, f64::max);
+        assert_relative_eq!(largest, 2.0, epsilon = 0.05);
+
+        let line = ComposedCurve::new([0.0, 0.0]);
+        assert!(line.medial_axis(0.1).is_empty());
+
+        for spacing in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(circle.medial_axis(spacing).is_empty());
+        }
+    }
+}
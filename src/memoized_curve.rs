@@ -0,0 +1,161 @@
+use crate::{Curve, Distance, Point, Scalar};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::marker::PhantomData;
+use num_traits::{NumCast, One, ToPrimitive, Zero};
+
+/// Remembers values and tangents of the passed curve at `resolution + 1` uniformly distributed
+/// parameters, `t` is rounded to the nearest of them, and `t` outside of `[0, 1]` is clamped.
+///
+/// Use it for curves that are expensive to evaluate, e.g. offsets or deep adaptor chains,
+/// but sampled repeatedly with similar `t`, like when scrubbing a timeline. The cache is filled
+/// lazily on the first access of every parameter, so the wrapper is not `Sync`.
+pub struct MemoizedCurve<P: Point, C: Curve<P>> {
+    curve: C,
+    resolution: usize,
+    values: RefCell<Vec<Option<P>>>,
+    tangents: RefCell<Vec<Option<P>>>,
+    phantom_data: PhantomData<P>,
+}
+
+impl<P: Point, C: Curve<P>> MemoizedCurve<P, C> {
+    /// # Panics
+    ///
+    /// Panics if `resolution` is zero.
+    pub fn new(curve: C, resolution: usize) -> Self {
+        assert!(resolution > 0, "resolution must be positive");
+        Self {
+            curve,
+            resolution,
+            values: RefCell::new(vec![None; resolution + 1]),
+            tangents: RefCell::new(vec![None; resolution + 1]),
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+
+    pub fn into_inner(self) -> C {
+        self.curve
+    }
+
+    pub fn resolution(&self) -> usize {
+        self.resolution
+    }
+
+    /// Forget all the cached values, e.g. after the wrapped curve was changed through interior mutability.
+    pub fn clear(&self) {
+        self.values.borrow_mut().fill(None);
+        self.tangents.borrow_mut().fill(None);
+    }
+
+    /// Index of the nearest cached parameter and the parameter itself.
+    fn quantize(&self, t: P::Scalar) -> (usize, P::Scalar) {
+        let resolution: P::Scalar = NumCast::from(self.resolution).unwrap();
+        let half = P::Scalar::one() / (P::Scalar::one() + P::Scalar::one());
+        let t = t.clamp(P::Scalar::zero(), P::Scalar::one());
+        let i = (t * resolution + half).floor().to_usize().unwrap_or(0);
+        let i = i.min(self.resolution);
+        (i, <P::Scalar as NumCast>::from(i).unwrap() / resolution)
+    }
+
+    fn cached(
+        &self,
+        cache: &RefCell<Vec<Option<P>>>,
+        t: P::Scalar,
+        f: impl Fn(P::Scalar) -> P,
+    ) -> P {
+        let (i, t) = self.quantize(t);
+        if let Some(value) = &cache.borrow()[i] {
+            return value.clone();
+        }
+        let value = f(t);
+        cache.borrow_mut()[i] = Some(value.clone());
+        value
+    }
+}
+
+impl<P: Point, C: Curve<P>> Curve<P> for MemoizedCurve<P, C> {
+    fn value_at(&self, t: P::Scalar) -> P {
+        self.cached(&self.values, t, |t| self.curve.value_at(t))
+    }
+
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        self.cached(&self.tangents, t, |t| self.curve.tangent_at(t))
+    }
+
+    fn start_point(&self) -> P {
+        self.value_at(P::Scalar::zero())
+    }
+
+    fn end_point(&self) -> P {
+        self.value_at(P::Scalar::one())
+    }
+
+    fn control_points(&self) -> Vec<P> {
+        self.curve.control_points()
+    }
+
+    fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        self.curve.estimate_length(precision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bezier3;
+    use core::cell::Cell;
+
+    /// Counts the evaluations of the wrapped curve.
+    struct Counting<'a> {
+        curve: Bezier3<f64>,
+        calls: &'a Cell<usize>,
+    }
+
+    impl Curve<f64> for Counting<'_> {
+        fn value_at(&self, t: f64) -> f64 {
+            self.calls.set(self.calls.get() + 1);
+            self.curve.value_at(t)
+        }
+
+        fn tangent_at(&self, t: f64) -> f64 {
+            self.curve.tangent_at(t)
+        }
+
+        fn estimate_length(&self, precision: f64) -> f64 {
+            self.curve.estimate_length(precision)
+        }
+    }
+
+    #[test]
+    fn memoized_values() {
+        let calls = Cell::new(0);
+        let bezier = Bezier3::new(0.0, 1.0, 3.0, 2.0);
+        let curve = Counting {
+            curve: bezier,
+            calls: &calls,
+        }
+        .memoized(100);
+
+        assert_eq!(curve.value_at(0.5), bezier.value_at(0.5));
+        assert_eq!(curve.value_at(0.501), bezier.value_at(0.5));
+        assert_eq!(curve.value_at(0.499), bezier.value_at(0.5));
+        assert_eq!(calls.get(), 1);
+
+        assert_eq!(curve.value_at(0.257), bezier.value_at(0.26));
+        assert_eq!(curve.value_at(2.0), bezier.value_at(1.0));
+        assert_eq!(curve.tangent_at(0.5), bezier.tangent_at(0.5));
+        assert_eq!(calls.get(), 3);
+
+        curve.clear();
+        curve.value_at(0.5);
+        assert_eq!(calls.get(), 4);
+    }
+}
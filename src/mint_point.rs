@@ -0,0 +1,117 @@
+use crate::{InnerProduct, PlanarPoint, Point, Scalar, SpatialPoint};
+use ::mint::{Point2, Point3, Vector2, Vector3};
+
+macro_rules! impl_mint_point {
+    ($point:ident, $($field:ident),+) => {
+        impl<F: Scalar> Point for $point<F> {
+            type Scalar = F;
+
+            fn add(&self, other: &Self) -> Self {
+                $point { $($field: self.$field + other.$field),+ }
+            }
+
+            fn sub(&self, other: &Self) -> Self {
+                $point { $($field: self.$field - other.$field),+ }
+            }
+
+            fn multiply(&self, other: &Self) -> Self {
+                $point { $($field: self.$field * other.$field),+ }
+            }
+
+            fn scale(&self, s: F) -> Self {
+                $point { $($field: self.$field * s),+ }
+            }
+        }
+
+        impl<F: Scalar> InnerProduct for $point<F> {
+            fn dot(&self, other: &Self) -> F {
+                F::zero() $(+ self.$field * other.$field)+
+            }
+        }
+    };
+}
+
+macro_rules! impl_mint_planar {
+    ($point:ident) => {
+        impl<F: Scalar> PlanarPoint for $point<F> {
+            fn from_xy(x: F, y: F) -> Self {
+                $point { x, y }
+            }
+
+            fn x(&self) -> F {
+                self.x
+            }
+
+            fn y(&self) -> F {
+                self.y
+            }
+        }
+    };
+}
+
+macro_rules! impl_mint_spatial {
+    ($point:ident) => {
+        impl<F: Scalar> SpatialPoint for $point<F> {
+            fn from_xyz(x: F, y: F, z: F) -> Self {
+                $point { x, y, z }
+            }
+
+            fn x(&self) -> F {
+                self.x
+            }
+
+            fn y(&self) -> F {
+                self.y
+            }
+
+            fn z(&self) -> F {
+                self.z
+            }
+        }
+    };
+}
+
+impl_mint_point!(Point2, x, y);
+impl_mint_point!(Vector2, x, y);
+impl_mint_point!(Point3, x, y, z);
+impl_mint_point!(Vector3, x, y, z);
+
+impl_mint_planar!(Point2);
+impl_mint_planar!(Vector2);
+impl_mint_spatial!(Point3);
+impl_mint_spatial!(Vector3);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bezier1, Curve, Distance, SpatialPoint};
+    use ::mint::{Point2, Vector3};
+
+    #[test]
+    fn mint_points() {
+        let line = Bezier1::new(Point2 { x: 0.0, y: 0.0 }, Point2 { x: 2.0, y: 4.0 });
+        assert_eq!(line.value_at(0.5), Point2 { x: 1.0, y: 2.0 });
+        assert_eq!(
+            Point2 { x: 0.0, y: 0.0 }.distance(&Point2 { x: 3.0, y: 4.0 }),
+            5.0
+        );
+
+        let x = Vector3 {
+            x: 1.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        let y = Vector3 {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        };
+        assert_eq!(
+            x.cross(&y),
+            Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0
+            }
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use crate::roots::{solve_linear, solve_quadratic, Roots};
+use crate::{Bezier, Bezier1, Bezier2, Bezier3, PlanarPoint, Scalar};
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::{NumCast, One};
+
+impl<P: PlanarPoint> Bezier1<P> {
+    /// A line is always monotone, returns a copy of it.
+    pub fn subdivide_monotone(&self) -> Vec<Self> {
+        vec![self.clone()]
+    }
+}
+
+impl<P: PlanarPoint> Bezier2<P> {
+    /// Split the curve at the extrema of x and y, so both coordinates are monotone on every piece.
+    pub fn subdivide_monotone(&self) -> Vec<Self> {
+        split_at_all(self.clone(), &self.monotone_parameters(), Bezier2::split_at)
+    }
+
+    /// Parameters of the x and y extrema strictly inside `0..1`, in ascending order.
+    pub(crate) fn monotone_parameters(&self) -> Vec<P::Scalar> {
+        let extrema = |p0: P::Scalar, p1: P::Scalar, p2: P::Scalar| {
+            solve_linear(p0 - (p1 + p1) + p2, p1 - p0)
+        };
+        let (p0, p1, p2) = (&self.p0, &self.p1, &self.p2);
+        let x = extrema(p0.x(), p1.x(), p2.x());
+        let y = extrema(p0.y(), p1.y(), p2.y());
+        inner_parameters(x, y)
+    }
+}
+
+impl<P: PlanarPoint> Bezier3<P> {
+    /// Split the curve at the extrema of x and y, so both coordinates are monotone on every piece.
+    pub fn subdivide_monotone(&self) -> Vec<Self> {
+        split_at_all(self.clone(), &self.monotone_parameters(), Bezier3::split_at)
+    }
+
+    /// Parameters of the x and y extrema strictly inside `0..1`, in ascending order.
+    pub(crate) fn monotone_parameters(&self) -> Vec<P::Scalar> {
+        let extrema = |p0: P::Scalar, p1: P::Scalar, p2: P::Scalar, p3: P::Scalar| {
+            let three = P::Scalar::one() + P::Scalar::one() + P::Scalar::one();
+            let a = p3 - p0 + three * (p1 - p2);
+            let b = (p0 - (p1 + p1) + p2) * (P::Scalar::one() + P::Scalar::one());
+            solve_quadratic(a, b, p1 - p0)
+        };
+        let (p0, p1, p2, p3) = (&self.p0, &self.p1, &self.p2, &self.p3);
+        let x = extrema(p0.x(), p1.x(), p2.x(), p3.x());
+        let y = extrema(p0.y(), p1.y(), p2.y(), p3.y());
+        inner_parameters(x, y)
+    }
+}
+
+impl<P: PlanarPoint> Bezier<P> {
+    /// Split the curve at the extrema of x and y, so both coordinates are monotone on every piece.
+    pub fn subdivide_monotone(&self) -> Vec<Self> {
+        match self {
+            Bezier::C0(_) => vec![self.clone()],
+            Bezier::C1(c) => c.subdivide_monotone().into_iter().map(Bezier::C1).collect(),
+            Bezier::C2(c) => c.subdivide_monotone().into_iter().map(Bezier::C2).collect(),
+            Bezier::C3(c) => c.subdivide_monotone().into_iter().map(Bezier::C3).collect(),
+        }
+    }
+
+    /// Parameters of the x and y extrema strictly inside `0..1`, in ascending order.
+    pub(crate) fn monotone_parameters(&self) -> Vec<P::Scalar> {
+        match self {
+            Bezier::C0(_) | Bezier::C1(_) => Vec::new(),
+            Bezier::C2(c) => c.monotone_parameters(),
+            Bezier::C3(c) => c.monotone_parameters(),
+        }
+    }
+}
+
+/// Parameters from `x` and `y` strictly inside `0..1` without duplicates, in ascending order.
+fn inner_parameters<F: Scalar>(x: Roots<F>, y: Roots<F>) -> Vec<F> {
+    let epsilon: F = NumCast::from(1e-9).unwrap();
+    let mut ts: Vec<F> = x
+        .iter()
+        .chain(y.iter())
+        .copied()
+        .filter(|&t| t > epsilon && t < F::one() - epsilon)
+        .collect();
+    ts.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    ts.dedup_by(|a, b| *a - *b <= epsilon);
+    ts
+}
+
+/// Split `curve` at the ascending parameters `ts`.
+fn split_at_all<F: Scalar, C>(curve: C, ts: &[F], split_at: impl Fn(&C, F) -> (C, C)) -> Vec<C> {
+    let mut result = Vec::with_capacity(ts.len() + 1);
+    let mut rest = curve;
+    let mut previous = F::zero();
+    for &t in ts {
+        let (head, tail) = split_at(&rest, (t - previous) / (F::one() - previous));
+        result.push(head);
+        rest = tail;
+        previous = t;
+    }
+    result.push(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Curve;
+
+    fn assert_monotone<C: Curve<[f64; 2]>>(curve: &C) {
+        let samples: Vec<[f64; 2]> = (0..=100)
+            .map(|i| curve.value_at(i as f64 / 100.0))
+            .collect();
+        for axis in 0..2 {
+            let increasing = samples.windows(2).all(|w| w[1][axis] >= w[0][axis] - 1e-9);
+            let decreasing = samples.windows(2).all(|w| w[1][axis] <= w[0][axis] + 1e-9);
+            assert!(increasing || decreasing, "{:?}", samples);
+        }
+    }
+
+    #[test]
+    fn quadratic() {
+        let curve = Bezier2::new([0.0, 0.0], [1.0, 2.0], [2.0, 0.0]);
+        let pieces = curve.subdivide_monotone();
+        assert_eq!(pieces.len(), 2);
+        pieces.iter().for_each(assert_monotone);
+        assert_eq!(pieces[0].p2, pieces[1].p0);
+        assert_eq!(pieces[1].p2, [2.0, 0.0]);
+    }
+
+    #[test]
+    fn cubic() {
+        // A loop with extrema in both axes
+        let curve = Bezier3::new([0.0, 0.0], [3.0, 3.0], [-1.0, 3.0], [2.0, 0.0]);
+        let pieces = curve.subdivide_monotone();
+        assert!(pieces.len() >= 3);
+        pieces.iter().for_each(assert_monotone);
+        assert_eq!(pieces[0].p0, curve.p0);
+        assert_eq!(pieces.last().unwrap().p3, curve.p3);
+
+        let monotone = Bezier3::new([0.0, 0.0], [1.0, 0.0], [2.0, 1.0], [3.0, 3.0]);
+        assert_eq!(monotone.subdivide_monotone().len(), 1);
+    }
+}
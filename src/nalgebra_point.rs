@@ -0,0 +1,162 @@
+use crate::{InnerProduct, PlanarPoint, Point, Scalar, SpatialPoint};
+use ::nalgebra::{SVector, Vector2, Vector3};
+
+type NPoint<F, const N: usize> = ::nalgebra::Point<F, N>;
+
+impl<F: Scalar + ::nalgebra::Scalar, const N: usize> Point for SVector<F, N> {
+    type Scalar = F;
+
+    fn add(&self, other: &Self) -> Self {
+        self.zip_map(other, |a, b| a + b)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self.zip_map(other, |a, b| a - b)
+    }
+
+    fn multiply(&self, other: &Self) -> Self {
+        self.zip_map(other, |a, b| a * b)
+    }
+
+    fn scale(&self, s: F) -> Self {
+        self.map(|a| a * s)
+    }
+}
+
+impl<F: Scalar + ::nalgebra::Scalar, const N: usize> InnerProduct for SVector<F, N> {
+    fn dot(&self, other: &Self) -> F {
+        self.iter()
+            .zip(other.iter())
+            .fold(F::zero(), |acc, (a, b)| acc + *a * *b)
+    }
+}
+
+impl<F: Scalar + ::nalgebra::Scalar> PlanarPoint for Vector2<F> {
+    fn from_xy(x: F, y: F) -> Self {
+        Vector2::new(x, y)
+    }
+
+    fn x(&self) -> F {
+        self[0]
+    }
+
+    fn y(&self) -> F {
+        self[1]
+    }
+}
+
+impl<F: Scalar + ::nalgebra::Scalar> SpatialPoint for Vector3<F> {
+    fn from_xyz(x: F, y: F, z: F) -> Self {
+        Vector3::new(x, y, z)
+    }
+
+    fn x(&self) -> F {
+        self[0]
+    }
+
+    fn y(&self) -> F {
+        self[1]
+    }
+
+    fn z(&self) -> F {
+        self[2]
+    }
+}
+
+/// Points are handled like their coordinate vectors.
+impl<F: Scalar + ::nalgebra::Scalar, const N: usize> Point for NPoint<F, N> {
+    type Scalar = F;
+
+    fn add(&self, other: &Self) -> Self {
+        self.coords.add(&other.coords).into()
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        Point::sub(&self.coords, &other.coords).into()
+    }
+
+    fn multiply(&self, other: &Self) -> Self {
+        self.coords.multiply(&other.coords).into()
+    }
+
+    fn scale(&self, s: F) -> Self {
+        self.coords.scale(s).into()
+    }
+}
+
+impl<F: Scalar + ::nalgebra::Scalar, const N: usize> InnerProduct for NPoint<F, N> {
+    fn dot(&self, other: &Self) -> F {
+        self.coords.dot(&other.coords)
+    }
+}
+
+impl<F: Scalar + ::nalgebra::Scalar> PlanarPoint for NPoint<F, 2> {
+    fn from_xy(x: F, y: F) -> Self {
+        Vector2::new(x, y).into()
+    }
+
+    fn x(&self) -> F {
+        self[0]
+    }
+
+    fn y(&self) -> F {
+        self[1]
+    }
+}
+
+impl<F: Scalar + ::nalgebra::Scalar> SpatialPoint for NPoint<F, 3> {
+    fn from_xyz(x: F, y: F, z: F) -> Self {
+        Vector3::new(x, y, z).into()
+    }
+
+    fn x(&self) -> F {
+        self[0]
+    }
+
+    fn y(&self) -> F {
+        self[1]
+    }
+
+    fn z(&self) -> F {
+        self[2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bezier1, Bezier3, Curve, Distance, PlanarPoint, SpatialPoint};
+    use ::nalgebra::{Point2, Point3, SVector, Vector2, Vector3};
+
+    #[test]
+    fn vectors() {
+        let line = Bezier1::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 4.0));
+        assert_eq!(line.value_at(0.5), Vector2::new(1.0, 2.0));
+        assert_eq!(
+            Vector2::new(0.0, 0.0).distance(&Vector2::new(3.0, 4.0)),
+            5.0
+        );
+        assert_eq!(
+            SpatialPoint::cross(&Vector3::new(1.0, 0.0, 0.0), &Vector3::new(0.0, 1.0, 0.0)),
+            Vector3::new(0.0, 0.0, 1.0)
+        );
+
+        let a = SVector::<f32, 4>::new(1.0, 2.0, 2.0, 4.0);
+        assert_eq!(a.distance(&SVector::zeros()), 5.0);
+    }
+
+    #[test]
+    fn points() {
+        let curve = Bezier3::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(0.0, 1.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(1.0, 0.0),
+        );
+        assert_eq!(curve.value_at(0.5), Point2::new(0.5, 0.75));
+        assert_eq!(
+            PlanarPoint::cross(&Point2::new(1.0, 0.0), &Point2::new(0.0, 1.0)),
+            1.0
+        );
+        assert_eq!(Point3::new(1.0, 2.0, 2.0).distance(&Point3::origin()), 3.0);
+    }
+}
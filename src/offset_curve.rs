@@ -0,0 +1,190 @@
+use crate::curve::polyline_length;
+use crate::{Curve, Distance, PlanarPoint, Scalar};
+use core::marker::PhantomData;
+use num_traits::{NumCast, One, Zero};
+
+/// The passed curve moved by `distance` along its normal, positive distances are on the left.
+///
+/// Points are calculated lazily as the point plus the scaled unit normal, which is cheap
+/// for previews before building the stroke with
+/// [`ComposedCurve::tessellate_stroke`](crate::ComposedCurve::tessellate_stroke).
+/// Where the tangent vanishes the point isn't moved.
+pub struct OffsetCurve<P: PlanarPoint, C: Curve<P>> {
+    curve: C,
+    distance: P::Scalar,
+    phantom_data: PhantomData<P>,
+}
+
+impl<P: PlanarPoint, C: Curve<P>> OffsetCurve<P, C> {
+    pub fn new(curve: C, distance: P::Scalar) -> Self {
+        Self {
+            curve,
+            distance,
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+
+    pub fn distance(&self) -> P::Scalar {
+        self.distance
+    }
+
+    /// Unit normal at `t`, zero where the tangent vanishes.
+    fn normal_at(&self, t: P::Scalar) -> P {
+        let tangent = self.curve.tangent_at(t);
+        let length = tangent.length();
+        if length > P::Scalar::zero() {
+            tangent.perpendicular().scale(P::Scalar::one() / length)
+        } else {
+            tangent
+        }
+    }
+}
+
+impl<P: PlanarPoint, C: Curve<P>> Curve<P> for OffsetCurve<P, C> {
+    fn value_at(&self, t: P::Scalar) -> P {
+        self.curve
+            .value_at(t)
+            .add(&self.normal_at(t).scale(self.distance))
+    }
+
+    /// The normal is differentiated numerically with central differences,
+    /// curves provide only the first derivative.
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        let h: P::Scalar = NumCast::from(1e-4).unwrap();
+        let t0 = (t - h).max(P::Scalar::zero());
+        let t1 = (t + h).min(P::Scalar::one());
+        let normal_derivative = self
+            .normal_at(t1)
+            .sub(&self.normal_at(t0))
+            .scale(P::Scalar::one() / (t1 - t0));
+        self.curve
+            .tangent_at(t)
+            .add(&normal_derivative.scale(self.distance))
+    }
+
+    fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        polyline_length(self, P::Scalar::zero(), P::Scalar::one(), 16, precision)
+    }
+}
+
+/// One side of a stroke of the passed curve with the given `width`.
+///
+/// The left side goes in the direction of the curve and the right side backward,
+/// so the left side, the end cap, the right side, and the start cap form the outline.
+/// Like [`OffsetCurve`] it's evaluated lazily.
+pub struct StrokeSide<P: PlanarPoint, C: Curve<P>> {
+    offset: OffsetCurve<P, C>,
+    left: bool,
+}
+
+impl<P: PlanarPoint, C: Curve<P>> StrokeSide<P, C> {
+    pub fn left(curve: C, width: P::Scalar) -> Self {
+        let half = width / (P::Scalar::one() + P::Scalar::one());
+        Self {
+            offset: OffsetCurve::new(curve, half),
+            left: true,
+        }
+    }
+
+    pub fn right(curve: C, width: P::Scalar) -> Self {
+        let half = width / (P::Scalar::one() + P::Scalar::one());
+        Self {
+            offset: OffsetCurve::new(curve, -half),
+            left: false,
+        }
+    }
+
+    pub fn curve(&self) -> &C {
+        self.offset.curve()
+    }
+
+    pub fn is_left(&self) -> bool {
+        self.left
+    }
+
+    /// Parameter of the offset curve.
+    fn inner(&self, t: P::Scalar) -> P::Scalar {
+        if self.left {
+            t
+        } else {
+            P::Scalar::one() - t
+        }
+    }
+}
+
+impl<P: PlanarPoint, C: Curve<P>> Curve<P> for StrokeSide<P, C> {
+    fn value_at(&self, t: P::Scalar) -> P {
+        self.offset.value_at(self.inner(t))
+    }
+
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        let tangent = self.offset.tangent_at(self.inner(t));
+        if self.left {
+            tangent
+        } else {
+            tangent.scale(-P::Scalar::one())
+        }
+    }
+
+    fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        self.offset.estimate_length(precision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bezier1, Bezier3, InnerProduct, Point};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn offset_line() {
+        let line = Bezier1::new([0.0, 0.0], [10.0, 0.0]);
+        let offset = line.offset_curve(2.0);
+        assert_eq!(offset.value_at(0.5), [5.0, 2.0]);
+        assert_relative_eq!(offset.tangent_at(0.5)[0], 10.0, epsilon = 1e-9);
+        assert_relative_eq!(offset.estimate_length(1e-6), 10.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn offset_arc() {
+        // Quarter of a unit circle, its offsets are circles with other radii
+        let k = 0.5522847498;
+        let arc = Bezier3::new([1.0, 0.0], [1.0, k], [k, 1.0], [0.0, 1.0]);
+        let inner = OffsetCurve::new(arc, 0.5);
+        for i in 0..=10 {
+            let point = inner.value_at(i as f64 / 10.0);
+            assert_relative_eq!(point.length(), 0.5, epsilon = 1e-3);
+        }
+
+        let (t, h) = (0.3, 1e-6);
+        let numeric = inner
+            .value_at(t + h)
+            .sub(&inner.value_at(t - h))
+            .scale(0.5 / h);
+        assert_relative_eq!(inner.tangent_at(t).distance(&numeric), 0.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn stroke_sides() {
+        let line = Bezier1::new([0.0, 0.0], [10.0, 0.0]);
+        let left = StrokeSide::left(line, 4.0);
+        let right = StrokeSide::right(line, 4.0);
+        assert_eq!(left.start_point(), [0.0, 2.0]);
+        assert_eq!(left.end_point(), [10.0, 2.0]);
+        assert_eq!(right.start_point(), [10.0, -2.0]);
+        assert_eq!(right.end_point(), [0.0, -2.0]);
+        assert_relative_eq!(right.tangent_at(0.5)[0], -10.0, epsilon = 1e-9);
+        assert!(left.is_left() && !right.is_left());
+    }
+}
@@ -0,0 +1,208 @@
+use crate::curve::polyline_length;
+use crate::{Curve, Distance, InnerProduct, PlanarPoint, Point, Scalar, SpatialPoint};
+use core::marker::PhantomData;
+use num_traits::{NumCast, One, Zero};
+
+/// Lateral direction of a host curve used by [`OnFrame`].
+pub trait SideDirection<P: Point> {
+    /// Unit vector to the side of the curve for the unit `tangent`.
+    fn side(&self, tangent: &P) -> P;
+}
+
+/// The left side of a planar curve, the tangent rotated counter-clockwise.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Perpendicular;
+
+impl<P: PlanarPoint> SideDirection<P> for Perpendicular {
+    fn side(&self, tangent: &P) -> P {
+        tangent.perpendicular()
+    }
+}
+
+/// The side of a spatial curve given by the cross product `up × tangent`,
+/// e.g. the left side of a road for the `z` axis pointing up.
+/// It's zero where the tangent is parallel to `up`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UpVector<P>(pub P);
+
+impl<P: SpatialPoint> SideDirection<P> for UpVector<P> {
+    fn side(&self, tangent: &P) -> P {
+        let side = self.0.cross(tangent);
+        let length = side.length();
+        if length > P::Scalar::zero() {
+            side.scale(P::Scalar::one() / length)
+        } else {
+            side
+        }
+    }
+}
+
+/// A planar curve drawn relative to a host curve.
+///
+/// The `x` coordinate of the curve is the host curve parameter and the `y` coordinate is the
+/// displacement to the side of the host, so a horizontal line at `y = 2` becomes a parallel
+/// curve at the distance `2`. Points are calculated lazily, on every evaluation.
+pub struct OnFrame<
+    P: InnerProduct,
+    Q: PlanarPoint<Scalar = P::Scalar>,
+    H: Curve<P>,
+    C: Curve<Q>,
+    S: SideDirection<P>,
+> {
+    host: H,
+    curve: C,
+    side: S,
+    phantom_data: PhantomData<(P, Q)>,
+}
+
+impl<P, Q, H, C, S> OnFrame<P, Q, H, C, S>
+where
+    P: InnerProduct,
+    Q: PlanarPoint<Scalar = P::Scalar>,
+    H: Curve<P>,
+    C: Curve<Q>,
+    S: SideDirection<P>,
+{
+    pub fn new(host: H, curve: C, side: S) -> Self {
+        Self {
+            host,
+            curve,
+            side,
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub fn host(&self) -> &H {
+        &self.host
+    }
+
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+
+    /// Unit side vector at the host parameter `x`, zero where the host tangent vanishes.
+    fn side_at(&self, x: P::Scalar) -> P {
+        let tangent = self.host.tangent_at(x);
+        let length = tangent.length();
+        if length > P::Scalar::zero() {
+            self.side.side(&tangent.scale(P::Scalar::one() / length))
+        } else {
+            tangent
+        }
+    }
+}
+
+impl<P, Q, H, C, S> Curve<P> for OnFrame<P, Q, H, C, S>
+where
+    P: InnerProduct,
+    Q: PlanarPoint<Scalar = P::Scalar>,
+    H: Curve<P>,
+    C: Curve<Q>,
+    S: SideDirection<P>,
+{
+    fn value_at(&self, t: P::Scalar) -> P {
+        let q = self.curve.value_at(t);
+        self.host
+            .value_at(q.x())
+            .add(&self.side_at(q.x()).scale(q.y()))
+    }
+
+    /// The side vector is differentiated numerically with central differences,
+    /// curves provide only the first derivative.
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        let q = self.curve.value_at(t);
+        let dq = self.curve.tangent_at(t);
+        let h: P::Scalar = NumCast::from(1e-4).unwrap();
+        let x0 = (q.x() - h).max(P::Scalar::zero());
+        let x1 = (q.x() + h).min(P::Scalar::one());
+        let side_derivative = self
+            .side_at(x1)
+            .sub(&self.side_at(x0))
+            .scale(P::Scalar::one() / (x1 - x0));
+
+        self.host
+            .tangent_at(q.x())
+            .scale(dq.x())
+            .add(&self.side_at(q.x()).scale(dq.y()))
+            .add(&side_derivative.scale(dq.x() * q.y()))
+    }
+
+    fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        polyline_length(self, P::Scalar::zero(), P::Scalar::one(), 16, precision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bezier1, Bezier2};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn parallel_line() {
+        let road = Bezier1::new([0.0, 0.0], [10.0, 0.0]);
+        let bank = OnFrame::new(road, Bezier1::new([0.0, 2.0], [1.0, 2.0]), Perpendicular);
+        assert_eq!(bank.value_at(0.5), [5.0, 2.0]);
+        assert_relative_eq!(
+            bank.tangent_at(0.5).distance(&[10.0, 0.0]),
+            0.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn offset_of_curved_host() {
+        let road = Bezier2::new([0.0, 0.0], [5.0, 5.0], [10.0, 0.0]);
+        let bank = OnFrame::new(road, Bezier1::new([0.0, -1.0], [1.0, 1.0]), Perpendicular);
+        for t in [0.0, 0.25, 0.5, 0.9] {
+            let host_point = road.value_at(t);
+            let offset = -1.0 + 2.0 * t;
+            assert_relative_eq!(
+                bank.value_at(t).distance(&host_point),
+                offset.abs(),
+                epsilon = 1e-12
+            );
+        }
+        // Positive offsets are on the left side, perpendicular to the host
+        let offset = bank.value_at(0.75).sub(&road.value_at(0.75));
+        let tangent = road.tangent_at(0.75);
+        assert_relative_eq!(offset.dot(&tangent), 0.0, epsilon = 1e-12);
+        assert!(tangent.cross(&offset) > 0.0);
+
+        let (t, h) = (0.3, 1e-6);
+        let expected = bank
+            .value_at(t + h)
+            .sub(&bank.value_at(t - h))
+            .scale(0.5 / h);
+        assert_relative_eq!(bank.tangent_at(t).distance(&expected), 0.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn spatial_host() {
+        let road = Bezier1::new([0.0, 0.0, 0.0], [4.0, 0.0, 0.0]);
+        let bank = OnFrame::new(
+            road,
+            Bezier1::new([0.0, 1.0], [1.0, 1.0]),
+            UpVector([0.0, 0.0, 1.0]),
+        );
+        assert_eq!(bank.value_at(0.25), [1.0, 1.0, 0.0]);
+
+        let vertical = Bezier1::new([0.0, 0.0, 0.0], [0.0, 0.0, 4.0]);
+        let bank = OnFrame::new(
+            vertical,
+            Bezier1::new([0.0, 1.0], [1.0, 1.0]),
+            UpVector([0.0, 0.0, 1.0]),
+        );
+        assert_eq!(bank.value_at(0.5), [0.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn length() {
+        let road = Bezier1::new([0.0, 0.0], [10.0, 0.0]);
+        let bank = OnFrame::new(road, Bezier1::new([0.0, 2.0], [0.5, 2.0]), Perpendicular);
+        assert_relative_eq!(bank.estimate_length(0.01), 5.0, epsilon = 1e-9);
+    }
+}
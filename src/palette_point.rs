@@ -0,0 +1,114 @@
+use crate::{InnerProduct, Point, Scalar};
+use ::palette::{Alpha, LinSrgb, Oklab};
+
+macro_rules! impl_color_point {
+    ($color:ident, $($field:ident),+) => {
+        impl<F: Scalar> Point for $color<F> {
+            type Scalar = F;
+
+            fn add(&self, other: &Self) -> Self {
+                $color::new($(self.$field + other.$field),+)
+            }
+
+            fn sub(&self, other: &Self) -> Self {
+                $color::new($(self.$field - other.$field),+)
+            }
+
+            fn multiply(&self, other: &Self) -> Self {
+                $color::new($(self.$field * other.$field),+)
+            }
+
+            fn scale(&self, s: F) -> Self {
+                $color::new($(self.$field * s),+)
+            }
+        }
+
+        /// Euclidean distance between colors, it's perceptual only in Oklab.
+        impl<F: Scalar> InnerProduct for $color<F> {
+            fn dot(&self, other: &Self) -> F {
+                F::zero() $(+ self.$field * other.$field)+
+            }
+        }
+    };
+}
+
+impl_color_point!(LinSrgb, red, green, blue);
+impl_color_point!(Oklab, l, a, b);
+
+/// Colors with transparency, like `LinSrgba` and `Oklaba`, the alpha channel is interpolated
+/// like the other components.
+impl<C: Point> Point for Alpha<C, C::Scalar> {
+    type Scalar = C::Scalar;
+
+    fn add(&self, other: &Self) -> Self {
+        Alpha {
+            color: self.color.add(&other.color),
+            alpha: self.alpha + other.alpha,
+        }
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        Alpha {
+            color: self.color.sub(&other.color),
+            alpha: self.alpha - other.alpha,
+        }
+    }
+
+    fn multiply(&self, other: &Self) -> Self {
+        Alpha {
+            color: self.color.multiply(&other.color),
+            alpha: self.alpha * other.alpha,
+        }
+    }
+
+    fn scale(&self, s: C::Scalar) -> Self {
+        Alpha {
+            color: self.color.scale(s),
+            alpha: self.alpha * s,
+        }
+    }
+}
+
+impl<C: InnerProduct> InnerProduct for Alpha<C, C::Scalar> {
+    fn dot(&self, other: &Self) -> C::Scalar {
+        self.color.dot(&other.color) + self.alpha * other.alpha
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bezier1, Bezier2, Curve, Distance};
+    use ::palette::{LinSrgb, LinSrgba, Oklab, Oklaba};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn color_ramp() {
+        let ramp = Bezier1::new(LinSrgb::new(1.0, 0.0, 0.0), LinSrgb::new(0.0, 0.0, 1.0));
+        assert_eq!(ramp.value_at(0.5), LinSrgb::new(0.5, 0.0, 0.5));
+
+        let fade = Bezier1::new(
+            LinSrgba::new(1.0, 1.0, 1.0, 1.0),
+            LinSrgba::new(1.0, 1.0, 1.0, 0.0),
+        );
+        assert_eq!(fade.value_at(0.25), LinSrgba::new(1.0, 1.0, 1.0, 0.75));
+    }
+
+    #[test]
+    fn oklab() {
+        let gradient = Bezier2::new(
+            Oklab::new(0.0, 0.0, 0.0),
+            Oklab::new(0.5, 0.2, 0.0),
+            Oklab::new(1.0, 0.0, 0.0),
+        );
+        assert_relative_eq!(gradient.value_at(0.5).l, 0.5);
+        assert_relative_eq!(gradient.value_at(0.5).a, 0.1);
+        assert_eq!(
+            Oklab::new(0.0, 0.0, 0.0).distance(&Oklab::new(0.0, 0.3, 0.4)),
+            0.5
+        );
+        assert_eq!(
+            Oklaba::new(0.5, 0.0, 0.0, 0.0).distance(&Oklaba::new(0.5, 0.0, 0.0, 1.0)),
+            1.0
+        );
+    }
+}
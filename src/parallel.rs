@@ -0,0 +1,108 @@
+use crate::flatten::flatten_into;
+use crate::linear_speed::LinearSpeed;
+use crate::{ComposedCurve, Curve, Distance, Point};
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::{NumCast, One, Zero};
+use rayon::prelude::*;
+
+impl<P: Point + Send + Sync> ComposedCurve<P>
+where
+    P::Scalar: Send + Sync,
+{
+    /// Parallel version of [`Curve::flatten`], segments are flattened independently.
+    pub fn par_flatten(&self, tolerance: P::Scalar) -> Vec<P>
+    where
+        P: Distance,
+    {
+        let segments: Vec<Vec<P>> = self
+            .segments()
+            .par_iter()
+            .map(|segment| {
+                let mut points = Vec::new();
+                flatten_into(segment, tolerance, &mut points);
+                points
+            })
+            .collect();
+
+        let mut points = vec![self.start_point()];
+        segments
+            .into_iter()
+            .for_each(|segment| points.extend(segment));
+        points
+    }
+
+    /// Parallel version of [`Curve::estimate_length`], segments are measured independently.
+    pub fn par_estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        let lengths: Vec<P::Scalar> = self
+            .segments()
+            .par_iter()
+            .map(|segment| segment.estimate_length(precision))
+            .collect();
+        // Summed in order, so the result is the same as the serial one
+        lengths
+            .into_iter()
+            .fold(Zero::zero(), |acc, length| acc + length)
+    }
+}
+
+impl<P: Point + Distance + Send + Sync, C: Curve<P> + Sync> LinearSpeed<P, C>
+where
+    P::Scalar: Send + Sync,
+{
+    /// Parallel version of [`LinearSpeed::new`], the curve is sampled using all the available cores.
+    pub fn par_new(curve: C, table_size: usize, steps_count: usize) -> Self {
+        let inverted_steps: P::Scalar = P::Scalar::one() / NumCast::from(steps_count).unwrap();
+        let points: Vec<P> = (0..=steps_count)
+            .into_par_iter()
+            .map(|i| curve.value_at(<P::Scalar as NumCast>::from(i).unwrap() * inverted_steps))
+            .collect();
+
+        Self::from_samples(curve, table_size, &points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn curve() -> ComposedCurve<f64> {
+        let mut curve = ComposedCurve::new(0.0);
+        for i in 0..100 {
+            let i = i as f64;
+            curve.cubic_to(i + 0.5, i - 0.5, i + 1.0);
+        }
+        curve
+    }
+
+    #[test]
+    fn par_flatten() {
+        let curve = curve();
+        assert_eq!(curve.par_flatten(0.01), curve.flatten(0.01));
+    }
+
+    #[test]
+    fn par_estimate_length() {
+        let curve = curve();
+        assert_relative_eq!(
+            curve.par_estimate_length(0.01),
+            curve.estimate_length(0.01),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn par_linear_speed() {
+        let serial = LinearSpeed::new(curve(), 64, 1000);
+        let parallel = LinearSpeed::par_new(curve(), 64, 1000);
+        assert_eq!(serial.estimate_length(0.0), parallel.estimate_length(0.0));
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            assert_eq!(serial.tangent_at(t), parallel.tangent_at(t));
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{ComposedCurve, Curve, CurveError, Distance, Error, Point};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use num_traits::Zero;
+
+/// A set of contours, like a glyph outline or an SVG path with several `M` commands.
+///
+/// The building methods follow the usual outline builder interface (`move_to`, `line_to`,
+/// `quadratic_to`, `cubic_to`, and `close`), so font outlines (quadratic for TrueType, cubic for CFF)
+/// can be collected into it directly.
+#[derive(Clone, PartialEq)]
+pub struct Path<P: Point> {
+    contours: Vec<ComposedCurve<P>>,
+}
+
+impl<P: Point + Debug> Debug for Path<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Path").field(&self.contours).finish()
+    }
+}
+
+impl<P: Point> Default for Path<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Point> From<ComposedCurve<P>> for Path<P> {
+    fn from(contour: ComposedCurve<P>) -> Self {
+        Self {
+            contours: Vec::from([contour]),
+        }
+    }
+}
+
+impl<P: Point> FromIterator<ComposedCurve<P>> for Path<P> {
+    fn from_iter<I: IntoIterator<Item = ComposedCurve<P>>>(iter: I) -> Self {
+        Self {
+            contours: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<P: Point> Path<P> {
+    pub fn new() -> Self {
+        Self {
+            contours: Vec::new(),
+        }
+    }
+
+    pub fn contours(&self) -> &[ComposedCurve<P>] {
+        &self.contours
+    }
+
+    pub fn into_contours(self) -> Vec<ComposedCurve<P>> {
+        self.contours
+    }
+
+    /// Start a new contour.
+    pub fn move_to(&mut self, point: P) {
+        self.contours.push(ComposedCurve::new(point));
+    }
+
+    /// # Panics
+    /// If there is no contour started with [`Path::move_to`].
+    pub fn line_to(&mut self, point: P) {
+        self.current().line_to(point);
+    }
+
+    /// # Panics
+    /// If there is no contour started with [`Path::move_to`].
+    pub fn quadratic_to(&mut self, p1: P, p2: P) {
+        self.current().quadratic_to(p1, p2);
+    }
+
+    /// # Panics
+    /// If there is no contour started with [`Path::move_to`].
+    pub fn cubic_to(&mut self, p1: P, p2: P, p3: P) {
+        self.current().cubic_to(p1, p2, p3);
+    }
+
+    /// Close the current contour with a line to its start point.
+    pub fn close(&mut self) {
+        if let Some(contour) = self.contours.last_mut() {
+            contour.close();
+        }
+    }
+
+    /// Total length of all the contours.
+    pub fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        self.contours
+            .iter()
+            .fold(P::Scalar::zero(), |acc, contour| {
+                acc + contour.estimate_length(precision)
+            })
+    }
+
+    /// The only contour of the path, it fails with [`CurveError::Discontinuous`] if there are
+    /// several contours, and with [`CurveError::Empty`] if the contour has no segments.
+    #[cfg_attr(not(any(feature = "kurbo", feature = "lyon")), allow(dead_code))]
+    pub(crate) fn into_single_contour(self) -> Result<ComposedCurve<P>, Error> {
+        let mut contours = self.contours.into_iter();
+        let curve = contours.next().ok_or(CurveError::Empty)?;
+        if contours.next().is_some() {
+            return Err(CurveError::Discontinuous {
+                segment: curve.segments().len(),
+            }
+            .into());
+        }
+        if curve.segments().is_empty() {
+            return Err(CurveError::Empty.into());
+        }
+        Ok(curve)
+    }
+
+    fn current(&mut self) -> &mut ComposedCurve<P> {
+        self.contours
+            .last_mut()
+            .expect("move_to must be called before adding segments")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glyph_outline() {
+        // An "O"-like glyph with outer and inner contours
+        let mut path = Path::new();
+        path.move_to([0.0, 0.0]);
+        path.quadratic_to([10.0, 0.0], [10.0, 10.0]);
+        path.quadratic_to([10.0, 20.0], [0.0, 20.0]);
+        path.close();
+        path.move_to([2.0, 5.0]);
+        path.cubic_to([5.0, 5.0], [5.0, 15.0], [2.0, 15.0]);
+        path.close();
+
+        assert_eq!(path.contours().len(), 2);
+        assert_eq!(path.contours()[0].segments().len(), 3);
+        assert_eq!(path.contours()[1].segments().len(), 2);
+        assert_eq!(path.contours()[1].end_point(), [2.0, 5.0]);
+    }
+}
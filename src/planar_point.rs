@@ -0,0 +1,66 @@
+use crate::{InnerProduct, Scalar};
+
+/// A point on a plane.
+///
+/// Point traits form a hierarchy, every level adds operations required by more advanced algorithms:
+/// * [`Point`](crate::Point) - addition, subtraction, and scaling, enough to evaluate curves,
+/// * [`InnerProduct`] - dot product, lengths, and distances,
+/// * [`PlanarPoint`] - coordinates and cross product, required by 2D geometry like intersections or offsets.
+/// * [`SpatialPoint`](crate::SpatialPoint) - 3D coordinates and cross product, required by surfaces and frames.
+pub trait PlanarPoint: InnerProduct {
+    fn from_xy(x: Self::Scalar, y: Self::Scalar) -> Self;
+
+    fn x(&self) -> Self::Scalar;
+    fn y(&self) -> Self::Scalar;
+
+    /// Z component of the cross product of two vectors.
+    /// It's positive if `other` is counter-clockwise from `self`.
+    fn cross(&self, other: &Self) -> Self::Scalar {
+        self.x() * other.y() - self.y() * other.x()
+    }
+
+    /// The vector rotated by 90 degrees counter-clockwise.
+    fn perpendicular(&self) -> Self {
+        Self::from_xy(-self.y(), self.x())
+    }
+}
+
+impl<F: Scalar> PlanarPoint for [F; 2] {
+    fn from_xy(x: F, y: F) -> Self {
+        [x, y]
+    }
+
+    fn x(&self) -> F {
+        self[0]
+    }
+
+    fn y(&self) -> F {
+        self[1]
+    }
+}
+
+impl<F: Scalar> PlanarPoint for (F, F) {
+    fn from_xy(x: F, y: F) -> Self {
+        (x, y)
+    }
+
+    fn x(&self) -> F {
+        self.0
+    }
+
+    fn y(&self) -> F {
+        self.1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cross_product() {
+        assert_eq!([1.0, 0.0].cross(&[0.0, 1.0]), 1.0);
+        assert_eq!((0.0, 1.0).cross(&(1.0, 0.0)), -1.0);
+        assert_eq!([1.0, 2.0].perpendicular(), [-2.0, 1.0]);
+    }
+}
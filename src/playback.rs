@@ -0,0 +1,314 @@
+use crate::curve::polyline_length;
+use crate::{Curve, Distance, Point, Scalar};
+use core::marker::PhantomData;
+use num_traits::{NumCast, One, Zero};
+
+/// Plays the passed curve `count` times during `t` from 0 to 1.
+pub struct Repeat<P: Point, C: Curve<P>> {
+    curve: C,
+    count: usize,
+    phantom_data: PhantomData<P>,
+}
+
+/// Plays the passed curve forward and then backward, `count` times during `t` from 0 to 1.
+pub struct PingPong<P: Point, C: Curve<P>> {
+    curve: C,
+    count: usize,
+    phantom_data: PhantomData<P>,
+}
+
+/// Holds the start point of the passed curve for the first `delay` part of `t`,
+/// and plays the curve during the rest.
+pub struct Delay<P: Point, C: Curve<P>> {
+    curve: C,
+    delay: P::Scalar,
+    phantom_data: PhantomData<P>,
+}
+
+/// Plays the passed curve `scale` times faster, values outside of the curve range
+/// hold its start or end point.
+pub struct TimeScale<P: Point, C: Curve<P>> {
+    curve: C,
+    scale: P::Scalar,
+    phantom_data: PhantomData<P>,
+}
+
+impl<P: Point, C: Curve<P>> Repeat<P, C> {
+    /// # Panics
+    ///
+    /// Panics if `count` is zero.
+    pub fn new(curve: C, count: usize) -> Self {
+        assert!(count > 0, "repeat count must be positive");
+        Self {
+            curve,
+            count,
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl<P: Point, C: Curve<P>> PingPong<P, C> {
+    /// # Panics
+    ///
+    /// Panics if `count` is zero.
+    pub fn new(curve: C, count: usize) -> Self {
+        assert!(count > 0, "ping-pong count must be positive");
+        Self {
+            curve,
+            count,
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl<P: Point, C: Curve<P>> Delay<P, C> {
+    /// # Panics
+    ///
+    /// Panics if `delay` isn't in the `0..1` range.
+    pub fn new(curve: C, delay: P::Scalar) -> Self {
+        assert!(
+            delay >= P::Scalar::zero() && delay < P::Scalar::one(),
+            "delay must be in 0..1"
+        );
+        Self {
+            curve,
+            delay,
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+
+    pub fn delay(&self) -> P::Scalar {
+        self.delay
+    }
+}
+
+impl<P: Point, C: Curve<P>> TimeScale<P, C> {
+    /// # Panics
+    ///
+    /// Panics if `scale` isn't positive.
+    pub fn new(curve: C, scale: P::Scalar) -> Self {
+        assert!(scale > P::Scalar::zero(), "time scale must be positive");
+        Self {
+            curve,
+            scale,
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+
+    pub fn scale(&self) -> P::Scalar {
+        self.scale
+    }
+}
+
+/// Index of the repetition and the parameter inside it.
+fn split<F: Scalar>(t: F, count: usize) -> (usize, F) {
+    let t = t.clamp(F::zero(), F::one()) * NumCast::from(count).unwrap();
+    let i = t.floor().to_usize().unwrap();
+    if i == count {
+        (count - 1, F::one())
+    } else {
+        (i, t.fract())
+    }
+}
+
+impl<P: Point, C: Curve<P>> Curve<P> for Repeat<P, C> {
+    fn value_at(&self, t: P::Scalar) -> P {
+        self.curve.value_at(split(t, self.count).1)
+    }
+
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        let count: P::Scalar = NumCast::from(self.count).unwrap();
+        self.curve.tangent_at(split(t, self.count).1).scale(count)
+    }
+
+    fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        let count: P::Scalar = NumCast::from(self.count).unwrap();
+        self.curve.estimate_length(precision) * count
+    }
+}
+
+impl<P: Point, C: Curve<P>> PingPong<P, C> {
+    /// Parameter of the inner curve and whether it's played backward.
+    fn inner(&self, t: P::Scalar) -> (P::Scalar, bool) {
+        let (i, t) = split(t, self.count * 2);
+        if i % 2 == 0 {
+            (t, false)
+        } else {
+            (P::Scalar::one() - t, true)
+        }
+    }
+}
+
+impl<P: Point, C: Curve<P>> Curve<P> for PingPong<P, C> {
+    fn value_at(&self, t: P::Scalar) -> P {
+        self.curve.value_at(self.inner(t).0)
+    }
+
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        let (t, backward) = self.inner(t);
+        let scale: P::Scalar = NumCast::from(self.count * 2).unwrap();
+        let scale = if backward { -scale } else { scale };
+        self.curve.tangent_at(t).scale(scale)
+    }
+
+    fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        let count: P::Scalar = NumCast::from(self.count * 2).unwrap();
+        self.curve.estimate_length(precision) * count
+    }
+}
+
+impl<P: Point, C: Curve<P>> Curve<P> for Delay<P, C> {
+    fn value_at(&self, t: P::Scalar) -> P {
+        let t = (t - self.delay) / (P::Scalar::one() - self.delay);
+        self.curve
+            .value_at(t.clamp(P::Scalar::zero(), P::Scalar::one()))
+    }
+
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        let scale = P::Scalar::one() / (P::Scalar::one() - self.delay);
+        let t = (t - self.delay) * scale;
+        if t < P::Scalar::zero() {
+            self.curve
+                .tangent_at(P::Scalar::zero())
+                .scale(P::Scalar::zero())
+        } else {
+            self.curve.tangent_at(t.min(P::Scalar::one())).scale(scale)
+        }
+    }
+
+    fn start_point(&self) -> P {
+        self.curve.start_point()
+    }
+
+    fn end_point(&self) -> P {
+        self.curve.end_point()
+    }
+
+    fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        self.curve.estimate_length(precision)
+    }
+}
+
+impl<P: Point, C: Curve<P>> Curve<P> for TimeScale<P, C> {
+    fn value_at(&self, t: P::Scalar) -> P {
+        let t = t * self.scale;
+        self.curve
+            .value_at(t.clamp(P::Scalar::zero(), P::Scalar::one()))
+    }
+
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        let t = t * self.scale;
+        if t > P::Scalar::one() {
+            self.curve
+                .tangent_at(P::Scalar::one())
+                .scale(P::Scalar::zero())
+        } else {
+            self.curve
+                .tangent_at(t.max(P::Scalar::zero()))
+                .scale(self.scale)
+        }
+    }
+
+    fn start_point(&self) -> P {
+        self.curve.start_point()
+    }
+
+    fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        if self.scale >= P::Scalar::one() {
+            self.curve.estimate_length(precision)
+        } else {
+            // Only a part of the curve is played
+            polyline_length(&self.curve, P::Scalar::zero(), self.scale, 16, precision)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bezier1;
+    use approx::assert_relative_eq;
+
+    fn line() -> Bezier1<f64> {
+        Bezier1::new(0.0, 10.0)
+    }
+
+    #[test]
+    fn repeat() {
+        let curve = line().repeat(4);
+        assert_relative_eq!(curve.value_at(0.125), 5.0);
+        assert_relative_eq!(curve.value_at(0.375), 5.0);
+        assert_relative_eq!(curve.value_at(1.0), 10.0);
+        assert_relative_eq!(curve.tangent_at(0.3), 40.0);
+        assert_relative_eq!(curve.estimate_length(0.01), 40.0);
+    }
+
+    #[test]
+    fn ping_pong() {
+        let curve = line().ping_pong(2);
+        assert_relative_eq!(curve.value_at(0.125), 5.0);
+        assert_relative_eq!(curve.value_at(0.25), 10.0);
+        assert_relative_eq!(curve.value_at(0.375), 5.0);
+        assert_relative_eq!(curve.value_at(1.0), 0.0);
+        assert_relative_eq!(curve.tangent_at(0.1), 40.0);
+        assert_relative_eq!(curve.tangent_at(0.4), -40.0);
+    }
+
+    #[test]
+    fn delay_and_time_scale() {
+        let delayed = line().delay(0.5);
+        assert_relative_eq!(delayed.value_at(0.25), 0.0);
+        assert_relative_eq!(delayed.value_at(0.75), 5.0);
+        assert_relative_eq!(delayed.tangent_at(0.25), 0.0);
+        assert_relative_eq!(delayed.tangent_at(0.75), 20.0);
+
+        let fast = line().time_scale(2.0);
+        assert_relative_eq!(fast.value_at(0.25), 5.0);
+        assert_relative_eq!(fast.value_at(0.75), 10.0);
+        assert_relative_eq!(fast.tangent_at(0.75), 0.0);
+
+        let slow = line().time_scale(0.5);
+        assert_relative_eq!(slow.end_point(), 5.0);
+        assert_relative_eq!(slow.estimate_length(0.001), 5.0);
+
+        // Adaptors compose
+        let composed = line().time_scale(2.0).delay(0.5).repeat(2);
+        assert_relative_eq!(composed.value_at(0.375), 10.0);
+    }
+}
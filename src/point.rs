@@ -1,16 +1,81 @@
-use num_traits::{Float, NumOps};
+use crate::Scalar;
+use core::array;
+use num_traits::{NumCast, One};
 
 /// `Point` is a point in space that can be added, subtracted, multiplied, and scaled.
 /// This trait can be implemented for any type like Point, Vector, Color, etc.
 pub trait Point: Clone + PartialEq {
     /// The precision of the point.
-    /// It can be f32, f64, or any other type that implements Float.
-    type Scalar: Float + NumOps + Copy;
+    /// It can be f32, f64, or any other type that implements [`Scalar`].
+    type Scalar: Scalar;
 
     fn add(&self, other: &Self) -> Self;
     fn sub(&self, other: &Self) -> Self;
     fn multiply(&self, other: &Self) -> Self;
     fn scale(&self, s: Self::Scalar) -> Self;
+
+    /// Linear interpolation, returns `self` at `t = 0` and `other` at `t = 1`.
+    fn lerp(&self, other: &Self, t: Self::Scalar) -> Self {
+        self.add(&other.sub(self).scale(t))
+    }
+
+    /// The point in the middle between `self` and `other`.
+    fn midpoint(&self, other: &Self) -> Self {
+        let half = Self::Scalar::one() / (Self::Scalar::one() + Self::Scalar::one());
+        self.add(other).scale(half)
+    }
+
+    /// Evaluate the polynomial `coefficients[0] + coefficients[1] * t + ...` at every `t` from `ts`
+    /// and write the values into `out`, both slices must have the same length.
+    /// It's used by the batch methods of the curves, [`Curve::value_at_many`](crate::Curve::value_at_many)
+    /// and [`Curve::tangent_at_many`](crate::Curve::tangent_at_many).
+    ///
+    /// The default implementation uses Horner's rule, points made of coordinates forward it to
+    /// [`Scalar::polynomial_many`] one coordinate after another, so `f32` points use SIMD
+    /// with the `simd` feature.
+    fn polynomial_many<const N: usize>(
+        coefficients: &[Self; N],
+        ts: &[Self::Scalar],
+        out: &mut [Self],
+    ) {
+        assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+        for (&t, p) in ts.iter().zip(out.iter_mut()) {
+            *p = coefficients[..N - 1]
+                .iter()
+                .rev()
+                .fold(coefficients[N - 1].clone(), |acc, c| c.add(&acc.scale(t)));
+        }
+    }
+}
+
+/// Conversion between point types, e.g. between `f64` and `f32` based points.
+/// It's used by `cast` methods of the curves.
+pub trait PointCast<Q: Point>: Point {
+    fn cast(&self) -> Q;
+}
+
+impl PointCast<f32> for f32 {
+    fn cast(&self) -> f32 {
+        *self
+    }
+}
+
+impl PointCast<f64> for f32 {
+    fn cast(&self) -> f64 {
+        *self as f64
+    }
+}
+
+impl PointCast<f32> for f64 {
+    fn cast(&self) -> f32 {
+        *self as f32
+    }
+}
+
+impl PointCast<f64> for f64 {
+    fn cast(&self) -> f64 {
+        *self
+    }
 }
 
 impl Point for f32 {
@@ -31,6 +96,11 @@ impl Point for f32 {
     fn scale(&self, s: f32) -> Self {
         self * s
     }
+
+    fn polynomial_many<const N: usize>(coefficients: &[Self; N], ts: &[f32], out: &mut [Self]) {
+        assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+        <f32 as Scalar>::polynomial_many(coefficients, ts, |i, v| out[i] = v);
+    }
 }
 
 impl Point for f64 {
@@ -51,4 +121,144 @@ impl Point for f64 {
     fn scale(&self, s: f64) -> Self {
         self * s
     }
+
+    fn polynomial_many<const N: usize>(coefficients: &[Self; N], ts: &[f64], out: &mut [Self]) {
+        assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+        <f64 as Scalar>::polynomial_many(coefficients, ts, |i, v| out[i] = v);
+    }
+}
+
+impl<F: Scalar, const N: usize> Point for [F; N] {
+    type Scalar = F;
+
+    fn add(&self, other: &Self) -> Self {
+        array::from_fn(|i| self[i] + other[i])
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        array::from_fn(|i| self[i] - other[i])
+    }
+
+    fn multiply(&self, other: &Self) -> Self {
+        array::from_fn(|i| self[i] * other[i])
+    }
+
+    fn scale(&self, s: F) -> Self {
+        array::from_fn(|i| self[i] * s)
+    }
+
+    fn polynomial_many<const M: usize>(coefficients: &[Self; M], ts: &[F], out: &mut [Self]) {
+        assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+        for i in 0..N {
+            let coordinates = coefficients.map(|c| c[i]);
+            F::polynomial_many(&coordinates, ts, |j, v| out[j][i] = v);
+        }
+    }
+}
+
+impl<F: Scalar> Point for (F, F) {
+    type Scalar = F;
+
+    fn add(&self, other: &Self) -> Self {
+        (self.0 + other.0, self.1 + other.1)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        (self.0 - other.0, self.1 - other.1)
+    }
+
+    fn multiply(&self, other: &Self) -> Self {
+        (self.0 * other.0, self.1 * other.1)
+    }
+
+    fn scale(&self, s: F) -> Self {
+        (self.0 * s, self.1 * s)
+    }
+
+    fn polynomial_many<const N: usize>(coefficients: &[Self; N], ts: &[F], out: &mut [Self]) {
+        assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+        F::polynomial_many(&coefficients.map(|c| c.0), ts, |i, v| out[i].0 = v);
+        F::polynomial_many(&coefficients.map(|c| c.1), ts, |i, v| out[i].1 = v);
+    }
+}
+
+impl<F: Scalar> Point for (F, F, F) {
+    type Scalar = F;
+
+    fn add(&self, other: &Self) -> Self {
+        (self.0 + other.0, self.1 + other.1, self.2 + other.2)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        (self.0 - other.0, self.1 - other.1, self.2 - other.2)
+    }
+
+    fn multiply(&self, other: &Self) -> Self {
+        (self.0 * other.0, self.1 * other.1, self.2 * other.2)
+    }
+
+    fn scale(&self, s: F) -> Self {
+        (self.0 * s, self.1 * s, self.2 * s)
+    }
+
+    fn polynomial_many<const N: usize>(coefficients: &[Self; N], ts: &[F], out: &mut [Self]) {
+        assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+        F::polynomial_many(&coefficients.map(|c| c.0), ts, |i, v| out[i].0 = v);
+        F::polynomial_many(&coefficients.map(|c| c.1), ts, |i, v| out[i].1 = v);
+        F::polynomial_many(&coefficients.map(|c| c.2), ts, |i, v| out[i].2 = v);
+    }
+}
+
+fn cast_scalar<F: Scalar, G: Scalar>(v: F) -> G {
+    <G as NumCast>::from(v).unwrap()
+}
+
+impl<F: Scalar, G: Scalar, const N: usize> PointCast<[G; N]> for [F; N] {
+    fn cast(&self) -> [G; N] {
+        array::from_fn(|i| cast_scalar(self[i]))
+    }
+}
+
+impl<F: Scalar, G: Scalar> PointCast<(G, G)> for (F, F) {
+    fn cast(&self) -> (G, G) {
+        (cast_scalar(self.0), cast_scalar(self.1))
+    }
+}
+
+impl<F: Scalar, G: Scalar> PointCast<(G, G, G)> for (F, F, F) {
+    fn cast(&self) -> (G, G, G) {
+        (
+            cast_scalar(self.0),
+            cast_scalar(self.1),
+            cast_scalar(self.2),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bezier1, Curve, Distance, Point, PointCast};
+
+    #[test]
+    fn array_point() {
+        let line = Bezier1::new([0.0, 0.0], [2.0, 4.0]);
+        assert_eq!(line.value_at(0.5), [1.0, 2.0]);
+        assert_eq!([0.0, 0.0].distance(&[3.0, 4.0]), 5.0);
+        assert_eq!(PointCast::<[f32; 2]>::cast(&[1.0f64, 2.0]), [1.0f32, 2.0]);
+    }
+
+    #[test]
+    fn lerp_and_midpoint() {
+        assert_eq!(1.0.lerp(&3.0, 0.25), 1.5);
+        assert_eq!([0.0, 2.0].lerp(&[4.0, 6.0], 0.5), [2.0, 4.0]);
+        assert_eq!((0.0, 2.0).midpoint(&(4.0, 6.0)), (2.0, 4.0));
+    }
+
+    #[test]
+    fn tuple_point() {
+        let line = Bezier1::new((0.0, 0.0, 0.0), (2.0, 4.0, 6.0));
+        assert_eq!(line.value_at(0.5), (1.0, 2.0, 3.0));
+        assert_eq!((0.0, 0.0).distance(&(3.0, 4.0)), 5.0);
+        assert_eq!((1.0, 2.0, 2.0).distance(&(0.0, 0.0, 0.0)), 3.0);
+    }
 }
@@ -0,0 +1,150 @@
+use crate::{Bezier3, Curve, Distance, Point, PointCast};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use num_traits::One;
+
+/// Cubic curve in the power basis: `a + b * t + c * t^2 + d * t^3`.
+/// It describes the same shape as [`Bezier3`], but is cheaper to evaluate with Horner's rule.
+#[derive(Clone, PartialEq)]
+pub struct Polynomial3<P: Point> {
+    pub a: P,
+    pub b: P,
+    pub c: P,
+    pub d: P,
+}
+
+impl<P: Point> Copy for Polynomial3<P> where P: Copy {}
+
+impl<P: Point> Polynomial3<P> {
+    pub fn new(a: P, b: P, c: P, d: P) -> Self {
+        Self { a, b, c, d }
+    }
+
+    /// Convert every control point with `f`.
+    pub fn map_points<Q: Point>(&self, mut f: impl FnMut(&P) -> Q) -> Polynomial3<Q> {
+        Polynomial3::new(f(&self.a), f(&self.b), f(&self.c), f(&self.d))
+    }
+
+    /// Convert the curve to another point type, e.g. from `f64` to `f32`.
+    pub fn cast<Q: Point>(&self) -> Polynomial3<Q>
+    where
+        P: PointCast<Q>,
+    {
+        self.map_points(PointCast::cast)
+    }
+
+    /// Convert the polynomial back to the Bernstein basis.
+    pub fn to_bezier(&self) -> Bezier3<P> {
+        let one = P::Scalar::one();
+        let three = one + one + one;
+
+        let p0 = self.a.clone();
+        let p1 = p0.add(&self.b.scale(one / three));
+        let p2 = p1.add(&self.b.add(&self.c).scale(one / three));
+        let p3 = self.a.add(&self.b).add(&self.c).add(&self.d);
+
+        Bezier3::new(p0, p1, p2, p3)
+    }
+}
+
+impl<P: Point + Debug> Debug for Polynomial3<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Polynomial3")
+            .field(&self.a)
+            .field(&self.b)
+            .field(&self.c)
+            .field(&self.d)
+            .finish()
+    }
+}
+
+impl<P: Point> Curve<P> for Polynomial3<P> {
+    fn value_at(&self, t: P::Scalar) -> P {
+        self.a
+            .add(&self.b.add(&self.c.add(&self.d.scale(t)).scale(t)).scale(t))
+    }
+
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        let two = P::Scalar::one() + P::Scalar::one();
+        let three = two + P::Scalar::one();
+
+        self.b
+            .add(&self.c.scale(two).add(&self.d.scale(three * t)).scale(t))
+    }
+
+    fn value_at_many(&self, ts: &[P::Scalar], out: &mut [P]) {
+        assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+        let coefficients = [
+            self.a.clone(),
+            self.b.clone(),
+            self.c.clone(),
+            self.d.clone(),
+        ];
+        P::polynomial_many(&coefficients, ts, out);
+    }
+
+    fn tangent_at_many(&self, ts: &[P::Scalar], out: &mut [P]) {
+        assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+
+        let two = P::Scalar::one() + P::Scalar::one();
+        let three = two + P::Scalar::one();
+        let c2 = self.c.scale(two);
+        let d3 = self.d.scale(three);
+
+        P::polynomial_many(&[self.b.clone(), c2, d3], ts, out);
+    }
+
+    fn start_point(&self) -> P {
+        self.a.clone()
+    }
+
+    fn end_point(&self) -> P {
+        self.a.add(&self.b).add(&self.c).add(&self.d)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn control_points(&self) -> Vec<P> {
+        self.to_bezier().control_points()
+    }
+
+    fn estimate_length(&self, precision: P::Scalar) -> P::Scalar
+    where
+        P: Distance,
+    {
+        self.to_bezier().estimate_length(precision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn polynomial_matches_bezier() {
+        let bezier = Bezier3::new(1.0, 4.0, 2.0, 4.0);
+        let polynomial = bezier.to_polynomial();
+
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            assert_relative_eq!(polynomial.value_at(t), bezier.value_at(t), epsilon = 1e-12);
+            assert_relative_eq!(
+                polynomial.tangent_at(t),
+                bezier.tangent_at(t),
+                epsilon = 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn polynomial_round_trip() {
+        let bezier = Bezier3::new(1.0, 4.0, 2.0, 4.0);
+        let restored = bezier.to_polynomial().to_bezier();
+
+        assert_relative_eq!(restored.p0, bezier.p0, epsilon = 1e-12);
+        assert_relative_eq!(restored.p1, bezier.p1, epsilon = 1e-12);
+        assert_relative_eq!(restored.p2, bezier.p2, epsilon = 1e-12);
+        assert_relative_eq!(restored.p3, bezier.p3, epsilon = 1e-12);
+    }
+}
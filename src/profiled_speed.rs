@@ -0,0 +1,111 @@
+use crate::{Curve, Distance, LinearSpeed, Point, Scalar};
+use core::marker::PhantomData;
+use num_traits::{One, Zero};
+
+/// Moves along a curve following a speed profile over the distance.
+///
+/// `profile` maps time (`t` of this curve) to the travelled part of the length, both in `0..=1`,
+/// e.g. an easing gives slow-in/slow-out over the distance instead of over the curve parameter.
+/// Created with [`LinearSpeed::with_profile`].
+pub struct ProfiledSpeed<P: Point + Distance, C: Curve<P>, E: Curve<P::Scalar>>
+where
+    P::Scalar: Point<Scalar = P::Scalar>,
+{
+    curve: LinearSpeed<P, C>,
+    profile: E,
+    phantom_data: PhantomData<P>,
+}
+
+impl<P: Point + Distance, C: Curve<P>> LinearSpeed<P, C>
+where
+    P::Scalar: Point<Scalar = P::Scalar>,
+{
+    /// Traverse the curve with a speed profile, see [`ProfiledSpeed`].
+    pub fn with_profile<E: Curve<P::Scalar>>(self, profile: E) -> ProfiledSpeed<P, C, E> {
+        ProfiledSpeed {
+            curve: self,
+            profile,
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+impl<P: Point + Distance, C: Curve<P>, E: Curve<P::Scalar>> ProfiledSpeed<P, C, E>
+where
+    P::Scalar: Point<Scalar = P::Scalar>,
+{
+    pub fn curve(&self) -> &LinearSpeed<P, C> {
+        &self.curve
+    }
+
+    pub fn profile(&self) -> &E {
+        &self.profile
+    }
+
+    /// Travelled distance at `t`.
+    pub fn distance_at(&self, t: P::Scalar) -> P::Scalar {
+        self.distance_fraction(t) * self.curve.length
+    }
+
+    fn distance_fraction(&self, t: P::Scalar) -> P::Scalar {
+        self.profile
+            .value_at(t)
+            .clamp(P::Scalar::zero(), P::Scalar::one())
+    }
+}
+
+impl<P: Point + Distance, C: Curve<P>, E: Curve<P::Scalar>> Curve<P> for ProfiledSpeed<P, C, E>
+where
+    P::Scalar: Point<Scalar = P::Scalar>,
+{
+    fn value_at(&self, t: P::Scalar) -> P {
+        self.curve.value_at(self.distance_fraction(t))
+    }
+
+    fn tangent_at(&self, t: P::Scalar) -> P {
+        self.curve
+            .tangent_at(self.distance_fraction(t))
+            .scale(self.profile.tangent_at(t))
+    }
+
+    fn start_point(&self) -> P {
+        self.value_at(P::Scalar::zero())
+    }
+
+    fn end_point(&self) -> P {
+        self.value_at(P::Scalar::one())
+    }
+
+    fn estimate_length(&self, _precision: P::Scalar) -> P::Scalar {
+        self.distance_at(P::Scalar::one()) - self.distance_at(P::Scalar::zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::easing::CubicBezierEasing;
+    use crate::{Bezier3, Curve};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn eased_distance() {
+        let curve = Bezier3::new([0.0f64, 0.0], [0.0, 5.0], [10.0, 5.0], [10.0, 0.0]);
+        let length = curve.estimate_length(1e-6);
+        let ease = CubicBezierEasing::ease_in_out();
+        let profiled = curve.linear_speed(512, 4000).with_profile(ease);
+
+        assert_eq!(profiled.start_point(), [0.0, 0.0]);
+        assert_relative_eq!(profiled.end_point()[0], 10.0, epsilon = 1e-9);
+        assert_relative_eq!(profiled.distance_at(0.5), length / 2.0, epsilon = 1e-6);
+        assert_relative_eq!(profiled.estimate_length(0.0), length, epsilon = 1e-2);
+
+        // The speed is the length scaled by the easing derivative
+        for t in [0.1, 0.3, 0.5, 0.8] {
+            let tangent = profiled.tangent_at(t);
+            let speed = tangent[0].hypot(tangent[1]);
+            assert_relative_eq!(speed, length * ease.tangent_at(t), max_relative = 0.03);
+        }
+        let slow = profiled.tangent_at(0.01);
+        assert!(slow[0].hypot(slow[1]) < length * 0.1);
+    }
+}
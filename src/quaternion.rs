@@ -0,0 +1,364 @@
+use crate::curve::polyline_length;
+use crate::{Curve, Distance, InnerProduct, Point, Scalar, SpatialPoint};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::Mul;
+use num_traits::{NumCast, One, Zero};
+
+/// Quaternion `w + xi + yj + zk`, unit quaternions represent rotations in 3D.
+///
+/// As a [`Point`] it's a 4D vector, so the linear interpolation of ordinary curves denormalizes it,
+/// use [`Slerp`] and [`Squad`] curves to animate rotations. The `*` operator is the Hamilton product,
+/// which composes rotations, `a * b` applies `b` first.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quaternion<F: Scalar> {
+    pub w: F,
+    pub x: F,
+    pub y: F,
+    pub z: F,
+}
+
+/// Cosine of the angle above which the interpolation between quaternions falls back to lerp.
+const NEARLY_PARALLEL: f64 = 0.9995;
+
+impl<F: Scalar> Quaternion<F> {
+    pub fn new(w: F, x: F, y: F, z: F) -> Self {
+        Self { w, x, y, z }
+    }
+
+    /// No rotation.
+    pub fn identity() -> Self {
+        Self::new(F::one(), F::zero(), F::zero(), F::zero())
+    }
+
+    /// Rotation by `angle` radians counter-clockwise around `axis`, the axis doesn't have to be normalized.
+    pub fn from_axis_angle<P: SpatialPoint<Scalar = F>>(axis: &P, angle: F) -> Self {
+        let half = angle / (F::one() + F::one());
+        let length = axis.length();
+        let s = if length > F::zero() {
+            half.sin() / length
+        } else {
+            F::zero()
+        };
+        Self::new(half.cos(), axis.x() * s, axis.y() * s, axis.z() * s)
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// The quaternion scaled to the unit length, identity for zero.
+    pub fn normalize(&self) -> Self {
+        let length = self.length();
+        if length > F::zero() {
+            self.scale(F::one() / length)
+        } else {
+            Self::identity()
+        }
+    }
+
+    /// Rotates the vector `v`, the quaternion must be normalized.
+    pub fn rotate<P: SpatialPoint<Scalar = F>>(&self, v: &P) -> P {
+        let q = *self * Self::new(F::zero(), v.x(), v.y(), v.z()) * self.conjugate();
+        P::from_xyz(q.x, q.y, q.z)
+    }
+
+    /// Logarithm of a unit quaternion, a pure quaternion `(0, axis * angle / 2)`.
+    pub fn ln(&self) -> Self {
+        let v = (self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+        if v > F::zero() {
+            let s = v.atan2(self.w) / v;
+            Self::new(F::zero(), self.x * s, self.y * s, self.z * s)
+        } else {
+            Self::new(F::zero(), F::zero(), F::zero(), F::zero())
+        }
+    }
+
+    /// Exponent of a pure quaternion, the inverse of [`Quaternion::ln`].
+    pub fn exp(&self) -> Self {
+        let v = (self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+        let s = if v > F::zero() { v.sin() / v } else { F::one() };
+        Self::new(v.cos(), self.x * s, self.y * s, self.z * s)
+    }
+
+    /// Spherical linear interpolation between unit quaternions along the shortest arc,
+    /// rotates with a constant angular velocity.
+    pub fn slerp(&self, other: &Self, t: F) -> Self {
+        let (other, cos) = shortest(self, other);
+        if cos > NumCast::from(NEARLY_PARALLEL).unwrap() {
+            return self.lerp(&other, t).normalize();
+        }
+        let angle = cos.acos();
+        let sin = angle.sin();
+        let a = ((F::one() - t) * angle).sin() / sin;
+        let b = (t * angle).sin() / sin;
+        self.scale(a).add(&other.scale(b))
+    }
+}
+
+/// `other` or `-other`, whichever is closer to `q`, with the cosine of the angle between them.
+/// Both represent the same rotation.
+fn shortest<F: Scalar>(q: &Quaternion<F>, other: &Quaternion<F>) -> (Quaternion<F>, F) {
+    let cos = q.dot(other);
+    if cos < F::zero() {
+        (other.scale(-F::one()), -cos)
+    } else {
+        (*other, cos)
+    }
+}
+
+impl<F: Scalar> Mul for Quaternion<F> {
+    type Output = Self;
+
+    fn mul(self, o: Self) -> Self {
+        Self::new(
+            self.w * o.w - self.x * o.x - self.y * o.y - self.z * o.z,
+            self.w * o.x + self.x * o.w + self.y * o.z - self.z * o.y,
+            self.w * o.y - self.x * o.z + self.y * o.w + self.z * o.x,
+            self.w * o.z + self.x * o.y - self.y * o.x + self.z * o.w,
+        )
+    }
+}
+
+impl<F: Scalar> Point for Quaternion<F> {
+    type Scalar = F;
+
+    fn add(&self, o: &Self) -> Self {
+        Self::new(self.w + o.w, self.x + o.x, self.y + o.y, self.z + o.z)
+    }
+
+    fn sub(&self, o: &Self) -> Self {
+        Self::new(self.w - o.w, self.x - o.x, self.y - o.y, self.z - o.z)
+    }
+
+    /// Component-wise product, use `*` for the Hamilton product.
+    fn multiply(&self, o: &Self) -> Self {
+        Self::new(self.w * o.w, self.x * o.x, self.y * o.y, self.z * o.z)
+    }
+
+    fn scale(&self, s: F) -> Self {
+        Self::new(self.w * s, self.x * s, self.y * s, self.z * s)
+    }
+}
+
+impl<F: Scalar> InnerProduct for Quaternion<F> {
+    fn dot(&self, o: &Self) -> F {
+        self.w * o.w + self.x * o.x + self.y * o.y + self.z * o.z
+    }
+}
+
+/// Rotation between two orientations with a constant angular velocity.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Slerp<F: Scalar> {
+    pub from: Quaternion<F>,
+    pub to: Quaternion<F>,
+}
+
+impl<F: Scalar> Slerp<F> {
+    /// Both quaternions are normalized.
+    pub fn new(from: Quaternion<F>, to: Quaternion<F>) -> Self {
+        Self {
+            from: from.normalize(),
+            to: to.normalize(),
+        }
+    }
+}
+
+impl<F: Scalar> Curve<Quaternion<F>> for Slerp<F> {
+    fn value_at(&self, t: F) -> Quaternion<F> {
+        self.from.slerp(&self.to, t)
+    }
+
+    /// `q(t) * ln(from⁻¹ * to)`, the angular velocity is constant.
+    fn tangent_at(&self, t: F) -> Quaternion<F> {
+        let (to, _) = shortest(&self.from, &self.to);
+        self.value_at(t) * (self.from.conjugate() * to).ln()
+    }
+
+    fn estimate_length(
+        &self,
+        precision: <Quaternion<F> as Point>::Scalar,
+    ) -> <Quaternion<F> as Point>::Scalar
+    where
+        Quaternion<F>: Distance,
+    {
+        polyline_length(self, Zero::zero(), One::one(), 8, precision)
+    }
+}
+
+/// Smooth rotation through a sequence of orientations, spherical quadrangle interpolation.
+///
+/// Key orientations are evenly spaced along `t`, the angular velocity is continuous at them.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Squad<F: Scalar> {
+    keys: Vec<Quaternion<F>>,
+    /// Inner control quaternion for every key.
+    controls: Vec<Quaternion<F>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<F: Scalar> Squad<F> {
+    /// # Panics
+    /// If `keys` is empty.
+    pub fn new(keys: &[Quaternion<F>]) -> Self {
+        assert!(!keys.is_empty(), "at least one key is required");
+        // Consecutive keys are flipped to the same hemisphere to rotate along the shortest arcs
+        let mut normalized: Vec<Quaternion<F>> = Vec::with_capacity(keys.len());
+        for key in keys {
+            let key = key.normalize();
+            let key = match normalized.last() {
+                Some(previous) => shortest(previous, &key).0,
+                None => key,
+            };
+            normalized.push(key);
+        }
+
+        let quarter: F = NumCast::from(0.25).unwrap();
+        let last = normalized.len() - 1;
+        let controls = (0..normalized.len())
+            .map(|i| {
+                let q = normalized[i];
+                let inverse = q.conjugate();
+                let next = (inverse * normalized[(i + 1).min(last)]).ln();
+                let previous = (inverse * normalized[i.saturating_sub(1)]).ln();
+                q * next.add(&previous).scale(-quarter).exp()
+            })
+            .collect();
+
+        Self {
+            keys: normalized,
+            controls,
+        }
+    }
+
+    pub fn keys(&self) -> &[Quaternion<F>] {
+        &self.keys
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: Scalar> Curve<Quaternion<F>> for Squad<F> {
+    fn value_at(&self, t: F) -> Quaternion<F> {
+        let segments = self.keys.len() - 1;
+        if segments == 0 {
+            return self.keys[0];
+        }
+        let x = t.clamp(F::zero(), F::one()) * NumCast::from(segments).unwrap();
+        let i = x.floor().to_usize().unwrap().min(segments - 1);
+        let h = x - NumCast::from(i).unwrap();
+
+        let two = F::one() + F::one();
+        let outer = self.keys[i].slerp(&self.keys[i + 1], h);
+        let inner = self.controls[i].slerp(&self.controls[i + 1], h);
+        outer.slerp(&inner, two * h * (F::one() - h))
+    }
+
+    /// Calculated numerically with central differences.
+    fn tangent_at(&self, t: F) -> Quaternion<F> {
+        let h: F = NumCast::from(1e-4).unwrap();
+        let t0 = (t - h).max(F::zero());
+        let t1 = (t + h).min(F::one());
+        self.value_at(t1)
+            .sub(&self.value_at(t0))
+            .scale(F::one() / (t1 - t0))
+    }
+
+    fn estimate_length(
+        &self,
+        precision: <Quaternion<F> as Point>::Scalar,
+    ) -> <Quaternion<F> as Point>::Scalar
+    where
+        Quaternion<F>: Distance,
+    {
+        polyline_length(
+            self,
+            Zero::zero(),
+            One::one(),
+            8 * self.keys.len(),
+            precision,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    fn z_rotation(angle: f64) -> Quaternion<f64> {
+        Quaternion::from_axis_angle(&[0.0, 0.0, 1.0], angle)
+    }
+
+    fn assert_close(a: Quaternion<f64>, b: Quaternion<f64>) {
+        // q and -q are the same rotation
+        assert_relative_eq!(a.dot(&b).abs(), 1.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn rotation() {
+        let q = z_rotation(FRAC_PI_2);
+        let v = q.rotate(&[1.0, 0.0, 0.0]);
+        assert_relative_eq!(v.distance(&[0.0, 1.0, 0.0]), 0.0, epsilon = 1e-12);
+        assert_close(q * q, z_rotation(PI));
+        assert_close(q * q.conjugate(), Quaternion::identity());
+        assert_close(q.ln().exp(), q);
+    }
+
+    #[test]
+    fn slerp() {
+        let curve = Slerp::new(Quaternion::identity(), z_rotation(FRAC_PI_2));
+        assert_close(curve.value_at(0.5), z_rotation(FRAC_PI_2 / 2.0));
+        for t in [0.0, 0.3, 0.7, 1.0] {
+            assert_relative_eq!(curve.value_at(t).length(), 1.0, epsilon = 1e-12);
+        }
+        // Half angle changes by pi/4 per unit of t
+        assert_relative_eq!(curve.tangent_at(0.2).length(), PI / 4.0, epsilon = 1e-12);
+
+        // Takes the shortest arc even if the target is on the other hemisphere
+        let flipped = Slerp::new(Quaternion::identity(), z_rotation(FRAC_PI_2).scale(-1.0));
+        assert_close(flipped.value_at(0.5), z_rotation(FRAC_PI_2 / 2.0));
+    }
+
+    #[test]
+    fn squad_passes_through_keys() {
+        let keys = [
+            Quaternion::identity(),
+            z_rotation(FRAC_PI_2),
+            Quaternion::from_axis_angle(&[1.0, 1.0, 0.0], 1.0),
+            Quaternion::from_axis_angle(&[0.0, 1.0, 0.0], -2.0),
+        ];
+        let squad = Squad::new(&keys);
+        for (i, key) in keys.iter().enumerate() {
+            assert_close(squad.value_at(i as f64 / 3.0), *key);
+        }
+        for i in 0..=30 {
+            assert_relative_eq!(
+                squad.value_at(i as f64 / 30.0).length(),
+                1.0,
+                epsilon = 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn squad_is_smooth_at_keys() {
+        let keys = [
+            Quaternion::identity(),
+            z_rotation(1.0),
+            Quaternion::from_axis_angle(&[1.0, 0.0, 0.0], 1.0),
+        ];
+        let squad = Squad::new(&keys);
+        let (key, h) = (squad.value_at(0.5), 1e-6);
+        let before = key.sub(&squad.value_at(0.5 - h)).scale(1.0 / h);
+        let after = squad.value_at(0.5 + h).sub(&key).scale(1.0 / h);
+        assert_relative_eq!(before.distance(&after), 0.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn single_key() {
+        let squad = Squad::new(&[z_rotation(1.0)]);
+        assert_close(squad.value_at(0.5), z_rotation(1.0));
+    }
+}
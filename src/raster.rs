@@ -0,0 +1,408 @@
+use crate::{Curve, FillRule, Path, PlanarPoint, Scalar};
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::{NumCast, ToPrimitive};
+
+/// Subsamples per pixel along each axis in [`Antialiasing::Supersampled`] mode.
+const SUBSAMPLES: usize = 4;
+
+/// How partially covered pixels are computed by [`rasterize`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Antialiasing {
+    /// Exact area of the pixel covered by the flattened path. Overlapping parts of the path
+    /// inside a single pixel are approximated, the covered areas are summed with their windings.
+    #[default]
+    Analytic,
+    /// The fraction of 4×4 sample points inside the path, exact for any fill rule.
+    Supersampled,
+}
+
+/// Options of [`rasterize`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RasterOptions {
+    /// Size of the mask in pixels, it's stored in rows from the top one, one byte per pixel.
+    pub width: usize,
+    pub height: usize,
+    /// Affine transform `[a, b, c, d, e, f]` from path coordinates to pixels, like in SVG:
+    /// a point `(x, y)` goes to `(a x + c y + e, b x + d y + f)`. Pixel `(i, j)` covers
+    /// the square from `(i, j)` to `(i + 1, j + 1)`.
+    pub transform: [f64; 6],
+    pub fill_rule: FillRule,
+    pub antialiasing: Antialiasing,
+    /// Flattening tolerance in pixels.
+    pub tolerance: f64,
+}
+
+impl Default for RasterOptions {
+    fn default() -> Self {
+        Self {
+            width: 256,
+            height: 256,
+            transform: [1.0, 0.0, 0.0, 1.0, 0.0, 0.0],
+            fill_rule: FillRule::NonZero,
+            antialiasing: Antialiasing::Analytic,
+            tolerance: 0.1,
+        }
+    }
+}
+
+/// Fill `path` into the coverage `mask`, from 0 for empty pixels to 255 for covered ones.
+/// All the pixels of the mask are overwritten. Open contours are closed by implicit lines.
+///
+/// # Panics
+/// If `mask` is shorter than `options.width * options.height`.
+pub fn rasterize<P: PlanarPoint>(path: &Path<P>, mask: &mut [u8], options: &RasterOptions) {
+    let (width, height) = (options.width, options.height);
+    assert!(
+        mask.len() >= width * height,
+        "the mask is smaller than {}×{} pixels",
+        width,
+        height
+    );
+    let edges = edges(path, options);
+    let coverage = match options.antialiasing {
+        Antialiasing::Analytic => analytic_coverage(&edges, width, height, options.fill_rule),
+        Antialiasing::Supersampled => sampled_coverage(&edges, width, height, options.fill_rule),
+    };
+    for (pixel, value) in mask.iter_mut().zip(coverage) {
+        *pixel = Scalar::floor(value.clamp(0.0, 1.0) * 255.0 + 0.5) as u8;
+    }
+}
+
+/// Lines `[x0, y0, x1, y1]` of the transformed and flattened contours in pixels.
+fn edges<P: PlanarPoint>(path: &Path<P>, options: &RasterOptions) -> Vec<[f64; 4]> {
+    let [a, b, c, d, e, f] = options.transform;
+    let to_pixels = |p: &P| {
+        let (x, y) = (p.x().to_f64().unwrap(), p.y().to_f64().unwrap());
+        P::from_xy(
+            NumCast::from(a * x + c * y + e).unwrap(),
+            NumCast::from(b * x + d * y + f).unwrap(),
+        )
+    };
+    let tolerance = NumCast::from(options.tolerance).unwrap();
+    let mut edges = Vec::new();
+    for contour in path.contours() {
+        let points = contour.map_points(to_pixels).flatten(tolerance);
+        let point = |p: &P| (p.x().to_f64().unwrap(), p.y().to_f64().unwrap());
+        let closing = [points[points.len() - 1].clone(), points[0].clone()];
+        for pair in points.windows(2).chain([&closing[..]]) {
+            let ((x0, y0), (x1, y1)) = (point(&pair[0]), point(&pair[1]));
+            if y0 != y1 {
+                edges.push([x0, y0, x1, y1]);
+            }
+        }
+    }
+    edges
+}
+
+fn fill_coverage(winding: f64, fill_rule: FillRule) -> f64 {
+    let winding = winding.abs();
+    match fill_rule {
+        FillRule::NonZero => winding.min(1.0),
+        FillRule::EvenOdd => {
+            let fraction = winding - Scalar::floor(winding / 2.0) * 2.0;
+            if fraction > 1.0 {
+                2.0 - fraction
+            } else {
+                fraction
+            }
+        }
+    }
+}
+
+/// Signed areas accumulated along the rows: every line adds the area between itself and
+/// the right side of the pixels it crosses, and the pixels further right get its full height.
+fn analytic_coverage(
+    edges: &[[f64; 4]],
+    width: usize,
+    height: usize,
+    fill_rule: FillRule,
+) -> Vec<f64> {
+    let stride = width + 2;
+    let mut accumulation = vec![0.0; stride * height];
+    let right = width as f64;
+    for &[x0, y0, x1, y1] in edges {
+        // Parts left of the mask act like vertical lines at its left side,
+        // and parts right of it don't cover anything
+        for [x0, y0, x1, y1] in split_at_x(x0, y0, x1, y1, 0.0, right) {
+            accumulate_line(
+                &mut accumulation,
+                stride,
+                height,
+                x0.clamp(0.0, right),
+                y0,
+                x1.clamp(0.0, right),
+                y1,
+            );
+        }
+    }
+
+    let mut coverage = Vec::with_capacity(width * height);
+    for row in accumulation.chunks(stride) {
+        let mut winding = 0.0;
+        for &area in &row[..width] {
+            winding += area;
+            coverage.push(fill_coverage(winding, fill_rule));
+        }
+    }
+    coverage
+}
+
+/// Pieces of the line split where it crosses the vertical lines at `left` and `right`.
+fn split_at_x(x0: f64, y0: f64, x1: f64, y1: f64, left: f64, right: f64) -> Vec<[f64; 4]> {
+    let mut ts = vec![0.0];
+    for x in [left, right] {
+        if (x0 < x) != (x1 < x) {
+            ts.push((x - x0) / (x1 - x0));
+        }
+    }
+    ts.push(1.0);
+    ts.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let at = |t: f64| (x0 + (x1 - x0) * t, y0 + (y1 - y0) * t);
+    ts.windows(2)
+        .map(|pair| {
+            let ((xa, ya), (xb, yb)) = (at(pair[0]), at(pair[1]));
+            [xa, ya, xb, yb]
+        })
+        .collect()
+}
+
+/// Add the signed coverage of a line with `0 <= x <= width` to the accumulation rows.
+fn accumulate_line(
+    accumulation: &mut [f64],
+    stride: usize,
+    height: usize,
+    x0: f64,
+    y0: f64,
+    x1: f64,
+    y1: f64,
+) {
+    if y0 == y1 {
+        return;
+    }
+    let (direction, (x0, y0), (x1, y1)) = if y0 < y1 {
+        (1.0, (x0, y0), (x1, y1))
+    } else {
+        (-1.0, (x1, y1), (x0, y0))
+    };
+    let dxdy = (x1 - x0) / (y1 - y0);
+    let first_row = Scalar::floor(y0.max(0.0)) as usize;
+    let last_row = (Scalar::ceil(y1.max(0.0)) as usize).min(height);
+    for row in first_row..last_row {
+        let top = y0.max(row as f64);
+        let bottom = y1.min(row as f64 + 1.0);
+        if bottom <= top {
+            continue;
+        }
+        let height = (bottom - top) * direction;
+        let (xa, xb) = (x0 + (top - y0) * dxdy, x0 + (bottom - y0) * dxdy);
+        let (left, right) = (xa.min(xb), xa.max(xb));
+        let line = &mut accumulation[row * stride..(row + 1) * stride];
+
+        // The coverage of a pixel is the average of its part right of the line,
+        // the accumulation stores differences of coverages of neighbouring pixels
+        let first = Scalar::floor(left) as usize;
+        let last = Scalar::ceil(right) as usize;
+        let mut previous = 0.0;
+        for (column, value) in line.iter_mut().enumerate().take(last + 1).skip(first) {
+            let x = column as f64;
+            let coverage = area_left_of(x + 1.0, left, right) - area_left_of(x, left, right);
+            *value += height * (coverage - previous);
+            previous = coverage;
+        }
+    }
+}
+
+/// Average of `max(x - u, 0)` for `u` uniformly distributed between `left` and `right`.
+fn area_left_of(x: f64, left: f64, right: f64) -> f64 {
+    if x <= left {
+        0.0
+    } else if x >= right {
+        x - (left + right) / 2.0
+    } else {
+        (x - left) * (x - left) / (2.0 * (right - left))
+    }
+}
+
+/// Sample points inside the path with the half-open rule: a line covers samples from its
+/// lower end up to its upper end, and spans cover samples from their left end.
+fn sampled_coverage(
+    edges: &[[f64; 4]],
+    width: usize,
+    height: usize,
+    fill_rule: FillRule,
+) -> Vec<f64> {
+    let n = SUBSAMPLES as f64;
+    let mut counts = vec![0usize; width];
+    let mut crossings: Vec<(f64, i32)> = Vec::new();
+    let mut coverage = Vec::with_capacity(width * height);
+    for row in 0..height {
+        counts.iter_mut().for_each(|count| *count = 0);
+        for sub_row in 0..SUBSAMPLES {
+            let y = row as f64 + (sub_row as f64 + 0.5) / n;
+            crossings.clear();
+            for &[x0, y0, x1, y1] in edges {
+                let (low, high, winding) = if y0 < y1 { (y0, y1, 1) } else { (y1, y0, -1) };
+                if y >= low && y < high {
+                    crossings.push((x0 + (x1 - x0) * (y - y0) / (y1 - y0), winding));
+                }
+            }
+            crossings.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+            let mut winding = 0;
+            for pair in crossings.windows(2) {
+                winding += pair[0].1;
+                if !fill_rule.is_inside(winding) {
+                    continue;
+                }
+                // Samples at `(k + 0.5) / n` with `start <= x < end`
+                let sample =
+                    |x: f64| (Scalar::ceil(x * n - 0.5).max(0.0) as usize).min(width * SUBSAMPLES);
+                for k in sample(pair[0].0)..sample(pair[1].0) {
+                    counts[k / SUBSAMPLES] += 1;
+                }
+            }
+        }
+        let total = (SUBSAMPLES * SUBSAMPLES) as f64;
+        coverage.extend(counts.iter().map(|&count| count as f64 / total));
+    }
+    coverage
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ComposedCurve;
+
+    fn rect(x0: f64, y0: f64, x1: f64, y1: f64) -> ComposedCurve<[f64; 2]> {
+        let mut curve = ComposedCurve::new([x0, y0]);
+        curve.line_to([x1, y0]);
+        curve.line_to([x1, y1]);
+        curve.line_to([x0, y1]);
+        curve.close();
+        curve
+    }
+
+    fn render(path: &Path<[f64; 2]>, options: &RasterOptions) -> Vec<u8> {
+        let mut mask = vec![0; options.width * options.height];
+        rasterize(path, &mut mask, options);
+        mask
+    }
+
+    #[test]
+    fn pixel_aligned_square() {
+        let path = Path::from(rect(1.0, 1.0, 3.0, 3.0));
+        for antialiasing in [Antialiasing::Analytic, Antialiasing::Supersampled] {
+            let options = RasterOptions {
+                width: 4,
+                height: 4,
+                antialiasing,
+                ..RasterOptions::default()
+            };
+            let mask = render(&path, &options);
+            #[rustfmt::skip]
+            assert_eq!(mask, [
+                0, 0, 0, 0,
+                0, 255, 255, 0,
+                0, 255, 255, 0,
+                0, 0, 0, 0,
+            ]);
+        }
+    }
+
+    #[test]
+    fn partial_coverage() {
+        // Half of the first column and a quarter of the corner pixel
+        let path = Path::from(rect(0.5, 0.5, 2.0, 2.0));
+        let options = RasterOptions {
+            width: 2,
+            height: 2,
+            ..RasterOptions::default()
+        };
+        assert_eq!(render(&path, &options), [64, 128, 128, 255]);
+        let sampled = RasterOptions {
+            antialiasing: Antialiasing::Supersampled,
+            ..options
+        };
+        assert_eq!(render(&path, &sampled), [64, 128, 128, 255]);
+
+        // A diagonal half-plane covers half of the pixels on the diagonal
+        let mut triangle = ComposedCurve::new([0.0, 0.0]);
+        triangle.line_to([4.0, 4.0]);
+        triangle.line_to([0.0, 4.0]);
+        triangle.close();
+        let options = RasterOptions {
+            width: 4,
+            height: 4,
+            ..RasterOptions::default()
+        };
+        let mask = render(&Path::from(triangle), &options);
+        assert_eq!(mask[0], 128);
+        assert_eq!(mask[5], 128);
+        assert_eq!(mask[4], 255);
+        assert_eq!(mask[1], 0);
+    }
+
+    #[test]
+    fn transform_and_fill_rules() {
+        // Two nested squares in the same direction, scaled twice
+        let path: Path<_> = [rect(0.0, 0.0, 4.0, 4.0), rect(1.0, 1.0, 3.0, 3.0)]
+            .into_iter()
+            .collect();
+        for antialiasing in [Antialiasing::Analytic, Antialiasing::Supersampled] {
+            let options = RasterOptions {
+                width: 8,
+                height: 8,
+                transform: [2.0, 0.0, 0.0, 2.0, 0.0, 0.0],
+                antialiasing,
+                ..RasterOptions::default()
+            };
+            let mask = render(&path, &options);
+            assert_eq!(mask[0], 255);
+            assert_eq!(mask[3 * 8 + 3], 255);
+
+            let even_odd = RasterOptions {
+                fill_rule: FillRule::EvenOdd,
+                ..options
+            };
+            let mask = render(&path, &even_odd);
+            assert_eq!(mask[0], 255);
+            assert_eq!(mask[3 * 8 + 3], 0);
+        }
+
+        // Shapes outside of the mask are clipped
+        let path = Path::from(rect(-10.0, -10.0, 10.0, 1.0));
+        let options = RasterOptions {
+            width: 3,
+            height: 2,
+            ..RasterOptions::default()
+        };
+        assert_eq!(render(&path, &options), [255, 255, 255, 0, 0, 0]);
+    }
+
+    #[test]
+    fn circle_area() {
+        let mut circle = ComposedCurve::new([8.0, 2.0]);
+        let k = 0.5522847498 * 6.0;
+        circle.cubic_to([8.0 + k, 2.0], [14.0, 8.0 - k], [14.0, 8.0]);
+        circle.cubic_to([14.0, 8.0 + k], [8.0 + k, 14.0], [8.0, 14.0]);
+        circle.cubic_to([8.0 - k, 14.0], [2.0, 8.0 + k], [2.0, 8.0]);
+        circle.cubic_to([2.0, 8.0 - k], [8.0 - k, 2.0], [8.0, 2.0]);
+        let path = Path::from(circle);
+        for antialiasing in [Antialiasing::Analytic, Antialiasing::Supersampled] {
+            let options = RasterOptions {
+                width: 16,
+                height: 16,
+                antialiasing,
+                ..RasterOptions::default()
+            };
+            let mask = render(&path, &options);
+            let area: f64 = mask.iter().map(|&v| v as f64 / 255.0).sum();
+            assert!(
+                (area - core::f64::consts::PI * 36.0).abs() < 1.0,
+                "{}",
+                area
+            );
+        }
+    }
+}
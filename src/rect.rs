@@ -0,0 +1,237 @@
+use crate::{Bezier, Bezier1, Curve, PlanarPoint, Scalar};
+#[cfg(feature = "alloc")]
+use crate::{ComposedCurve, Path};
+use num_traits::{One, Zero};
+
+/// Subdivision depth of [`Bezier::intersects_rect`], pieces are about 4000 times smaller
+/// than the curve there.
+const MAX_RECT_DEPTH: usize = 12;
+
+/// Axis aligned rectangle, `min` has the smallest coordinates and `max` the largest ones.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rect<P: PlanarPoint> {
+    pub min: P,
+    pub max: P,
+}
+
+impl<P: PlanarPoint> Rect<P> {
+    /// Rectangle with the given opposite corners in any order.
+    pub fn new(a: P, b: P) -> Self {
+        Self {
+            min: P::from_xy(a.x().min(b.x()), a.y().min(b.y())),
+            max: P::from_xy(a.x().max(b.x()), a.y().max(b.y())),
+        }
+    }
+
+    /// The smallest rectangle containing all the points, `None` if there are no points.
+    pub fn from_points<'a>(points: impl IntoIterator<Item = &'a P>) -> Option<Self>
+    where
+        P: 'a,
+    {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let rect = Self::new(first.clone(), first.clone());
+        Some(points.fold(rect, |rect, p| rect.union(&Self::new(p.clone(), p.clone()))))
+    }
+
+    pub fn width(&self) -> P::Scalar {
+        self.max.x() - self.min.x()
+    }
+
+    pub fn height(&self) -> P::Scalar {
+        self.max.y() - self.min.y()
+    }
+
+    pub fn center(&self) -> P {
+        self.min.midpoint(&self.max)
+    }
+
+    /// The smallest rectangle containing both rectangles.
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: P::from_xy(
+                self.min.x().min(other.min.x()),
+                self.min.y().min(other.min.y()),
+            ),
+            max: P::from_xy(
+                self.max.x().max(other.max.x()),
+                self.max.y().max(other.max.y()),
+            ),
+        }
+    }
+
+    /// The rectangle grown by `amount` in every direction.
+    pub fn inflate(&self, amount: P::Scalar) -> Self {
+        let delta = P::from_xy(amount, amount);
+        Self::new(self.min.sub(&delta), self.max.add(&delta))
+    }
+
+    /// Check if the rectangles overlap, touching borders count as overlapping.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.x() <= other.max.x()
+            && other.min.x() <= self.max.x()
+            && self.min.y() <= other.max.y()
+            && other.min.y() <= self.max.y()
+    }
+
+    /// Check if the point is inside the rectangle or on its border.
+    pub fn contains(&self, point: &P) -> bool {
+        point.x() >= self.min.x()
+            && point.x() <= self.max.x()
+            && point.y() >= self.min.y()
+            && point.y() <= self.max.y()
+    }
+
+    /// Distance from the point to the closest point of the rectangle, zero for points inside.
+    pub fn distance_to(&self, point: &P) -> P::Scalar {
+        let zero = P::Scalar::zero();
+        let dx = (self.min.x() - point.x())
+            .max(point.x() - self.max.x())
+            .max(zero);
+        let dy = (self.min.y() - point.y())
+            .max(point.y() - self.max.y())
+            .max(zero);
+        P::from_xy(dx, dy).length()
+    }
+}
+
+impl<P: PlanarPoint> Bezier<P> {
+    /// Bounds of the control points, the curve is inside them.
+    pub(crate) fn control_bounds(&self) -> Rect<P> {
+        let rect = |points: &[&P]| Rect::from_points(points.iter().copied()).unwrap();
+        match self {
+            Bezier::C0(c) => rect(&[&c.point]),
+            Bezier::C1(c) => rect(&[&c.p0, &c.p1]),
+            Bezier::C2(c) => rect(&[&c.p0, &c.p1, &c.p2]),
+            Bezier::C3(c) => rect(&[&c.p0, &c.p1, &c.p2, &c.p3]),
+        }
+    }
+}
+
+impl<P: PlanarPoint> Bezier1<P> {
+    /// Check if the line touches the rectangle, clipping it by the rectangle sides.
+    pub fn intersects_rect(&self, rect: &Rect<P>) -> bool {
+        let (zero, one) = (P::Scalar::zero(), P::Scalar::one());
+        let delta = self.p1.sub(&self.p0);
+        let (mut t0, mut t1) = (zero, one);
+        let axes = [
+            (self.p0.x(), delta.x(), rect.min.x(), rect.max.x()),
+            (self.p0.y(), delta.y(), rect.min.y(), rect.max.y()),
+        ];
+        for (start, delta, min, max) in axes {
+            if delta == zero {
+                if start < min || start > max {
+                    return false;
+                }
+                continue;
+            }
+            let (a, b) = ((min - start) / delta, (max - start) / delta);
+            t0 = t0.max(a.min(b));
+            t1 = t1.min(a.max(b));
+        }
+        t0 <= t1
+    }
+}
+
+impl<P: PlanarPoint> Bezier<P> {
+    /// Check if the curve touches the rectangle, without flattening it. Curves are subdivided
+    /// while their control points bounds overlap the rectangle, so the answer is conservative:
+    /// a curve passing within a tiny fraction of its size from a corner may be reported as touching.
+    pub fn intersects_rect(&self, rect: &Rect<P>) -> bool {
+        self.intersects_rect_at(rect, 0)
+    }
+
+    fn intersects_rect_at(&self, rect: &Rect<P>, depth: usize) -> bool {
+        if !self.control_bounds().intersects(rect) {
+            return false;
+        }
+        match self {
+            Bezier::C0(c) => return rect.contains(&c.point),
+            Bezier::C1(c) => return c.intersects_rect(rect),
+            _ => {}
+        }
+        if rect.contains(&self.start_point()) || rect.contains(&self.end_point()) {
+            return true;
+        }
+        if depth >= MAX_RECT_DEPTH {
+            return true;
+        }
+        let half = P::Scalar::one() / (P::Scalar::one() + P::Scalar::one());
+        let (left, right) = self.split_at(half);
+        left.intersects_rect_at(rect, depth + 1) || right.intersects_rect_at(rect, depth + 1)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: PlanarPoint> ComposedCurve<P> {
+    /// Check if any segment touches the rectangle, see [`Bezier::intersects_rect`].
+    pub fn intersects_rect(&self, rect: &Rect<P>) -> bool {
+        self.segments()
+            .iter()
+            .any(|segment| segment.intersects_rect(rect))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: PlanarPoint> Path<P> {
+    /// Check if any contour touches the rectangle, see [`Bezier::intersects_rect`].
+    /// Only the outlines are tested, a rectangle inside a filled shape doesn't touch it.
+    pub fn intersects_rect(&self, rect: &Rect<P>) -> bool {
+        self.contours()
+            .iter()
+            .any(|contour| contour.intersects_rect(rect))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn rect() {
+        let rect = Rect::from_points(&[[1.0, 3.0], [0.0, 1.0], [2.0, 2.0]]).unwrap();
+        assert_eq!(rect, Rect::new([2.0, 1.0], [0.0, 3.0]));
+        assert_eq!(rect.center(), [1.0, 2.0]);
+        assert!(rect.contains(&[2.0, 1.5]));
+        assert!(rect.intersects(&Rect::new([2.0, 3.0], [5.0, 5.0])));
+        assert!(!rect.intersects(&Rect::new([2.5, 3.0], [5.0, 5.0])));
+        assert_relative_eq!(rect.distance_to(&[5.0, 7.0]), 5.0);
+        assert_relative_eq!(rect.distance_to(&[1.0, 2.0]), 0.0);
+    }
+
+    #[test]
+    fn lines_and_rect() {
+        let rect = Rect::new([0.0, 0.0], [2.0, 2.0]);
+        assert!(Bezier1::new([-1.0, 1.0], [3.0, 1.0]).intersects_rect(&rect));
+        assert!(Bezier1::new([1.0, 1.0], [1.5, 1.5]).intersects_rect(&rect));
+        assert!(!Bezier1::new([2.0, 3.0], [3.0, 2.5]).intersects_rect(&rect));
+        assert!(Bezier1::new([1.0, 3.0], [3.0, 1.0]).intersects_rect(&rect));
+        assert!(!Bezier1::new([3.0, 0.0], [3.0, 2.0]).intersects_rect(&rect));
+    }
+
+    #[test]
+    fn curves_and_rect() {
+        use crate::Bezier2;
+        let rect = Rect::new([0.0, 0.0], [1.0, 1.0]);
+        // The control point is inside the rectangle, the curve isn't
+        let arch = Bezier::C2(Bezier2::new([-2.0, 0.5], [0.5, 4.0], [3.0, 0.5]));
+        assert!(arch.control_bounds().intersects(&rect));
+        assert!(!arch.intersects_rect(&rect));
+
+        let low = Bezier::C2(Bezier2::new([-2.0, -1.0], [0.5, 2.0], [3.0, -1.0]));
+        assert!(low.intersects_rect(&rect));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn paths_and_rect() {
+        let mut curve = ComposedCurve::new([5.0, 5.0]);
+        curve.line_to([5.0, 6.0]);
+        curve.quadratic_to([0.0, 6.0], [0.5, 0.5]);
+        assert!(curve.intersects_rect(&Rect::new([0.0, 0.0], [1.0, 1.0])));
+        assert!(!curve.intersects_rect(&Rect::new([6.0, 0.0], [7.0, 1.0])));
+        let path = Path::from(curve);
+        assert!(!path.intersects_rect(&Rect::new([1.0, 1.0], [2.0, 2.0])));
+    }
+}
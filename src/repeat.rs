@@ -0,0 +1,140 @@
+use crate::geometry::Walker;
+use crate::{ComposedCurve, Curve, Path, PlanarPoint, Scalar};
+use num_traits::{NumCast, One, ToPrimitive, Zero};
+
+/// Every pattern segment is split into this many pieces before bending, so it follows the host curve.
+const BEND_SUBDIVISIONS: usize = 4;
+
+/// How copies of a pattern follow the curve in [`ComposedCurve::repeat_along`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlignMode {
+    /// Every copy is moved and rotated as a whole by the curve tangent at its origin.
+    Rigid,
+    /// Every copy is bent, its x axis follows the curve and y is the distance to the left of it.
+    Bend,
+}
+
+impl<P: PlanarPoint> ComposedCurve<P> {
+    /// Stamp copies of `pattern` along the curve every `spacing` units of length, starting at the
+    /// start point. The pattern is in local coordinates: its origin is placed on the curve,
+    /// the x axis goes along the tangent and the y axis to the left.
+    ///
+    /// Every copy is a separate contour of the result. Bent copies are approximated by moving the
+    /// control points of subdivided pattern segments, so they are exact only for straight parts of the curve.
+    pub fn repeat_along(
+        &self,
+        pattern: &ComposedCurve<P>,
+        spacing: P::Scalar,
+        mode: AlignMode,
+    ) -> Path<P> {
+        let Some(walker) = Walker::new(self) else {
+            return Path::new();
+        };
+        if spacing <= P::Scalar::zero() {
+            return Path::new();
+        }
+
+        let length = walker.length();
+        let epsilon: P::Scalar = NumCast::from(1e-9).unwrap();
+        let count = (length / spacing + epsilon).floor().to_usize().unwrap_or(0);
+
+        let pattern = match mode {
+            AlignMode::Rigid => pattern.clone(),
+            AlignMode::Bend => subdivide(pattern, BEND_SUBDIVISIONS),
+        };
+
+        (0..=count)
+            .map(|k| {
+                let k: P::Scalar = NumCast::from(k).unwrap();
+                let distance = k * spacing;
+                match mode {
+                    AlignMode::Rigid => {
+                        let (origin, tangent) = walker.frame(distance / length);
+                        let normal = tangent.perpendicular();
+                        pattern.map_points(|p| {
+                            origin.add(&tangent.scale(p.x())).add(&normal.scale(p.y()))
+                        })
+                    }
+                    AlignMode::Bend => pattern.map_points(|p| {
+                        let s = ((distance + p.x()) / length)
+                            .clamp(P::Scalar::zero(), P::Scalar::one());
+                        walker.offset_point(s, p.y())
+                    }),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Split every segment into `pieces` parts with equal parameter ranges.
+fn subdivide<P: PlanarPoint>(curve: &ComposedCurve<P>, pieces: usize) -> ComposedCurve<P> {
+    let mut result = ComposedCurve::new(curve.start_point());
+    for segment in curve.segments() {
+        let mut rest = segment.clone();
+        for i in 0..pieces - 1 {
+            let t = P::Scalar::one() / NumCast::from(pieces - i).unwrap();
+            let (piece, tail) = rest.split_at(t);
+            result.push_segment(piece);
+            rest = tail;
+        }
+        result.push_segment(rest);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn pattern() -> ComposedCurve<[f64; 2]> {
+        let mut pattern = ComposedCurve::new([0.0, 0.0]);
+        pattern.line_to([1.0, 0.0]);
+        pattern.line_to([1.0, 1.0]);
+        pattern.close();
+        pattern
+    }
+
+    #[test]
+    fn rigid_on_line() {
+        let mut host = ComposedCurve::new([0.0, 0.0]);
+        host.line_to([0.0, 10.0]);
+
+        let path = host.repeat_along(&pattern(), 2.5, AlignMode::Rigid);
+        assert_eq!(path.contours().len(), 5);
+        for (k, contour) in path.contours().iter().enumerate() {
+            let points = contour.control_points();
+            // The x axis of the pattern goes up along the host, y goes to the left
+            assert_relative_eq!(points[0][1], k as f64 * 2.5, epsilon = 1e-3);
+            assert_relative_eq!(points[1][1] - points[0][1], 1.0, epsilon = 1e-3);
+            assert_relative_eq!(points[2][0] - points[1][0], -1.0, epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn bend_on_circle() {
+        let k = 0.5522847498;
+        let mut host = ComposedCurve::new([10.0, 0.0]);
+        host.cubic_to([10.0, 10.0 * k], [10.0 * k, 10.0], [0.0, 10.0]);
+
+        let mut band = ComposedCurve::new([0.0, -1.0]);
+        band.line_to([3.0, -1.0]);
+        let path = host.repeat_along(&band, 5.0, AlignMode::Bend);
+        assert_eq!(path.contours().len(), 4);
+
+        // A straight band to the right of the host stays at the distance 11 from the center
+        for i in 0..=20 {
+            let p = path.contours()[1].value_at(i as f64 / 20.0);
+            assert_relative_eq!(p[0].hypot(p[1]), 11.0, epsilon = 0.02);
+        }
+    }
+
+    #[test]
+    fn degenerate() {
+        let dot = ComposedCurve::new([1.0, 1.0]);
+        assert!(dot
+            .repeat_along(&pattern(), 1.0, AlignMode::Rigid)
+            .contours()
+            .is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{Curve, Distance, LinearSpeed, Point, ProfiledSpeed, Scalar, TimedCurve};
+use num_traits::{One, Zero};
+
+/// Trapezoidal velocity profile: constant acceleration, cruising at the maximum velocity,
+/// and constant deceleration to a stop. Short distances never reach the maximum velocity
+/// and the profile becomes triangular.
+///
+/// As a curve it maps the normalized time `0..=1` to the travelled part of the distance.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TrapezoidProfile<F: Scalar> {
+    distance: F,
+    acceleration: F,
+    peak_velocity: F,
+    acceleration_time: F,
+    cruise_time: F,
+}
+
+impl<F: Scalar> TrapezoidProfile<F> {
+    /// # Panics
+    ///
+    /// Panics if `max_velocity` or `max_acceleration` isn't positive, or `distance` is negative.
+    pub fn new(distance: F, max_velocity: F, max_acceleration: F) -> Self {
+        assert!(max_velocity > F::zero() && max_acceleration > F::zero());
+        assert!(distance >= F::zero());
+
+        // Distance needed to accelerate to the maximum velocity and to stop again
+        let ramps = max_velocity * max_velocity / max_acceleration;
+        let (peak_velocity, cruise_time) = if distance >= ramps {
+            (max_velocity, (distance - ramps) / max_velocity)
+        } else {
+            ((distance * max_acceleration).sqrt(), F::zero())
+        };
+
+        Self {
+            distance,
+            acceleration: max_acceleration,
+            peak_velocity,
+            acceleration_time: peak_velocity / max_acceleration,
+            cruise_time,
+        }
+    }
+
+    /// Total time of the motion.
+    pub fn duration(&self) -> F {
+        self.acceleration_time + self.cruise_time + self.acceleration_time
+    }
+
+    pub fn peak_velocity(&self) -> F {
+        self.peak_velocity
+    }
+
+    /// Travelled distance and velocity at `time`.
+    pub fn state_at_time(&self, time: F) -> (F, F) {
+        let half = F::one() / (F::one() + F::one());
+        let time = time.clamp(F::zero(), self.duration());
+        let a = self.acceleration;
+        let ramp = self.acceleration_time;
+        let ramp_distance = half * a * ramp * ramp;
+
+        if time < ramp {
+            (half * a * time * time, a * time)
+        } else if time < ramp + self.cruise_time {
+            let cruising = time - ramp;
+            (
+                ramp_distance + self.peak_velocity * cruising,
+                self.peak_velocity,
+            )
+        } else {
+            let left = self.duration() - time;
+            (self.distance - half * a * left * left, a * left)
+        }
+    }
+}
+
+impl<F: Scalar + Point<Scalar = F>> Curve<F> for TrapezoidProfile<F> {
+    fn value_at(&self, t: F) -> F {
+        if self.distance == F::zero() {
+            return t.clamp(F::zero(), F::one());
+        }
+        self.state_at_time(t * self.duration()).0 / self.distance
+    }
+
+    fn tangent_at(&self, t: F) -> F {
+        if self.distance == F::zero() {
+            return F::one();
+        }
+        self.state_at_time(t * self.duration()).1 * self.duration() / self.distance
+    }
+
+    fn estimate_length(&self, _precision: F) -> F {
+        F::one()
+    }
+}
+
+impl<P: Point + Distance, C: Curve<P>> LinearSpeed<P, C>
+where
+    P::Scalar: Point<Scalar = P::Scalar>,
+{
+    /// Move along the curve from a stop to a stop as fast as possible while the velocity and
+    /// the acceleration along the curve stay within the limits, with a [`TrapezoidProfile`].
+    ///
+    /// The result is timed in the units of the limits, e.g. with velocity in units per second
+    /// its [`TimedCurve::duration`] is in seconds.
+    pub fn retime(
+        self,
+        max_velocity: P::Scalar,
+        max_acceleration: P::Scalar,
+    ) -> TimedCurve<P, ProfiledSpeed<P, C, TrapezoidProfile<P::Scalar>>> {
+        let profile = TrapezoidProfile::new(self.length, max_velocity, max_acceleration);
+        let duration = profile.duration();
+        let duration = if duration > P::Scalar::zero() {
+            duration
+        } else {
+            P::Scalar::one()
+        };
+        TimedCurve::new(self.with_profile(profile), duration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bezier1, Bezier3};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn trapezoid() {
+        let profile = TrapezoidProfile::new(10.0, 2.0, 1.0);
+        // 2 seconds to accelerate, 3 seconds cruising, 2 seconds to stop
+        assert_relative_eq!(profile.duration(), 7.0);
+        assert_relative_eq!(profile.state_at_time(1.0).0, 0.5);
+        assert_relative_eq!(profile.state_at_time(3.5).0, 5.0);
+        assert_relative_eq!(profile.state_at_time(7.0).0, 10.0);
+        assert_relative_eq!(profile.value_at(0.5), 0.5);
+        assert_relative_eq!(profile.tangent_at(0.5), 2.0 * 7.0 / 10.0);
+
+        let triangle = TrapezoidProfile::new(1.0, 2.0, 1.0);
+        assert_relative_eq!(triangle.peak_velocity(), 1.0);
+        assert_relative_eq!(triangle.duration(), 2.0);
+    }
+
+    #[test]
+    fn limits_are_respected() {
+        let curve = Bezier3::new([0.0f64, 0.0], [0.0, 5.0], [10.0, 5.0], [10.0, 0.0]);
+        let timed = curve.linear_speed(512, 4000).retime(3.0, 2.0);
+
+        let steps = 200;
+        let dt = timed.duration() / steps as f64;
+        let mut previous_speed = 0.0;
+        for i in 0..=steps {
+            let velocity = timed.velocity_at_time(i as f64 * dt);
+            let speed = velocity[0].hypot(velocity[1]);
+            assert!(speed <= 3.0 * 1.03, "{}", speed);
+            assert!((speed - previous_speed).abs() / dt <= 2.0 * 1.1 + 1e-9);
+            previous_speed = speed;
+        }
+        assert_relative_eq!(
+            timed.position_at_time(timed.duration())[0],
+            10.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn zero_length() {
+        let timed = Bezier1::new(1.0, 1.0 + 1e-12)
+            .linear_speed(4, 4)
+            .retime(1.0, 1.0);
+        assert!(timed.duration() > 0.0);
+    }
+}
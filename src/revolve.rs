@@ -0,0 +1,179 @@
+use crate::frames::normalize;
+use crate::{Bezier1, Curve, Scalar, SpatialPoint, Surface};
+use num_traits::One;
+
+/// Surface of revolution, see [`revolve`].
+#[derive(Clone, Debug)]
+pub struct Revolution<P: SpatialPoint, C: Curve<P>> {
+    profile: C,
+    origin: P,
+    /// Unit direction of the axis.
+    axis: P,
+    angle: P::Scalar,
+}
+
+/// Revolves the `profile` around the `axis` line going from `axis.p0` to `axis.p1` by `angle` radians,
+/// counter-clockwise when looking against the axis direction. The `u` parameter goes along the profile,
+/// `v` from the profile to the profile rotated by `angle`.
+///
+/// The surface is exact, points are rotated rather than approximated with rational patches,
+/// use [`Surface::tessellate`] to get a mesh.
+///
+/// # Panics
+/// If the axis has zero length.
+pub fn revolve<P: SpatialPoint, C: Curve<P>>(
+    profile: C,
+    axis: Bezier1<P>,
+    angle: P::Scalar,
+) -> Revolution<P, C> {
+    let direction = normalize(&axis.p1.sub(&axis.p0)).expect("axis must have a non-zero length");
+    Revolution {
+        profile,
+        origin: axis.p0,
+        axis: direction,
+        angle,
+    }
+}
+
+impl<P: SpatialPoint, C: Curve<P>> Revolution<P, C> {
+    pub fn profile(&self) -> &C {
+        &self.profile
+    }
+
+    pub fn angle(&self) -> P::Scalar {
+        self.angle
+    }
+
+    /// Rotates the vector `v` around the axis by `angle`, Rodrigues' rotation formula.
+    fn rotate(&self, v: &P, angle: P::Scalar) -> P {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let k = &self.axis;
+        v.scale(cos)
+            .add(&k.cross(v).scale(sin))
+            .add(&k.scale(k.dot(v) * (P::Scalar::one() - cos)))
+    }
+}
+
+impl<P: SpatialPoint, C: Curve<P>> Surface<P> for Revolution<P, C> {
+    fn value_at(&self, u: P::Scalar, v: P::Scalar) -> P {
+        let offset = self.profile.value_at(u).sub(&self.origin);
+        self.origin.add(&self.rotate(&offset, self.angle * v))
+    }
+
+    fn derivative_u(&self, u: P::Scalar, v: P::Scalar) -> P {
+        self.rotate(&self.profile.tangent_at(u), self.angle * v)
+    }
+
+    fn derivative_v(&self, u: P::Scalar, v: P::Scalar) -> P {
+        let offset = self.profile.value_at(u).sub(&self.origin);
+        self.axis
+            .cross(&self.rotate(&offset, self.angle * v))
+            .scale(self.angle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bezier3, Distance, Point};
+    use approx::assert_relative_eq;
+    use core::f64::consts::{PI, TAU};
+
+    fn z_axis() -> Bezier1<[f64; 3]> {
+        Bezier1::new([0.0, 0.0, 0.0], [0.0, 0.0, 1.0])
+    }
+
+    #[test]
+    fn cylinder() {
+        let profile = Bezier1::new([1.0, 0.0, 0.0], [1.0, 0.0, 2.0]);
+        let cylinder = revolve(profile, z_axis(), TAU);
+        assert_relative_eq!(
+            cylinder.value_at(0.5, 0.25).distance(&[0.0, 1.0, 1.0]),
+            0.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            cylinder.value_at(0.0, 0.5).distance(&[-1.0, 0.0, 0.0]),
+            0.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            cylinder.value_at(1.0, 1.0).distance(&[1.0, 0.0, 2.0]),
+            0.0,
+            epsilon = 1e-12
+        );
+        // Outward normal
+        assert_relative_eq!(
+            cylinder.normal_at(0.5, 0.0).distance(&[-1.0, 0.0, 0.0]),
+            0.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            cylinder.derivative_v(0.5, 0.0).distance(&[0.0, TAU, 0.0]),
+            0.0,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn offset_axis() {
+        let profile = Bezier1::new([3.0, 1.0, 0.0], [3.0, 1.0, 1.0]);
+        let axis = Bezier1::new([2.0, 1.0, 5.0], [2.0, 1.0, 7.0]);
+        let surface = revolve(profile, axis, PI);
+        assert_relative_eq!(
+            surface.value_at(0.0, 1.0).distance(&[1.0, 1.0, 0.0]),
+            0.0,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn derivatives_match_differences() {
+        let profile = Bezier3::new(
+            [1.0, 0.0, 0.0],
+            [3.0, 0.5, 1.0],
+            [0.5, 0.0, 2.0],
+            [1.0, 1.0, 3.0],
+        );
+        let axis = Bezier1::new([0.0, 0.0, 0.0], [1.0, 1.0, 2.0]);
+        let surface = revolve(profile, axis, 2.0);
+        let (u, v, h) = (0.35, 0.6, 1e-6);
+        let du = surface
+            .value_at(u + h, v)
+            .sub(&surface.value_at(u - h, v))
+            .scale(0.5 / h);
+        let dv = surface
+            .value_at(u, v + h)
+            .sub(&surface.value_at(u, v - h))
+            .scale(0.5 / h);
+        assert_relative_eq!(
+            surface.derivative_u(u, v).distance(&du),
+            0.0,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            surface.derivative_v(u, v).distance(&dv),
+            0.0,
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn mesh() {
+        let profile = Bezier1::new([1.0, 0.0, 0.0], [1.0, 0.0, 1.0]);
+        let mesh = revolve(profile, z_axis(), TAU).tessellate(0.01);
+        for p in &mesh.positions {
+            assert_relative_eq!(p[0].hypot(p[1]), 1.0, epsilon = 1e-12);
+        }
+        // A straight profile needs a single step, the circle is split into many
+        assert_eq!(mesh.positions.len() % 2, 0);
+        assert!(mesh.positions.len() > 40);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_axis() {
+        let profile = Bezier1::new([1.0, 0.0, 0.0], [1.0, 0.0, 1.0]);
+        revolve(profile, Bezier1::new([0.0; 3], [0.0; 3]), PI);
+    }
+}
@@ -0,0 +1,256 @@
+//! Real roots of low degree polynomials.
+//!
+//! The solvers are analytic, so they need no allocation and no initial guesses.
+//! Coefficients go from the highest degree to the constant term.
+
+use crate::{Bezier2, Bezier3, PlanarPoint, Scalar};
+use core::ops::Deref;
+use num_traits::NumCast;
+
+/// Up to three real roots in ascending order, dereferences to a slice.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Roots<F: Scalar> {
+    values: [F; 3],
+    len: usize,
+}
+
+impl<F: Scalar> Roots<F> {
+    fn new() -> Self {
+        Self {
+            values: [F::zero(); 3],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, value: F) {
+        self.values[self.len] = value;
+        self.len += 1;
+    }
+
+    fn sorted(mut self) -> Self {
+        let values = &mut self.values[..self.len];
+        for i in 1..values.len() {
+            let mut j = i;
+            while j > 0 && values[j - 1] > values[j] {
+                values.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+        self
+    }
+
+    /// Roots inside the `0..=1` range, the range of curve parameters.
+    /// Roots slightly outside of it due to rounding errors are clamped.
+    pub fn in_unit_interval(&self) -> Self {
+        let epsilon: F = NumCast::from(1e-9).unwrap();
+        let mut result = Self::new();
+        for &root in self.iter() {
+            if root >= -epsilon && root <= F::one() + epsilon {
+                result.push(root.clamp(F::zero(), F::one()));
+            }
+        }
+        result
+    }
+}
+
+impl<F: Scalar> Deref for Roots<F> {
+    type Target = [F];
+
+    fn deref(&self) -> &[F] {
+        &self.values[..self.len]
+    }
+}
+
+/// Roots of `a x + b = 0`, there are none if `a` is zero.
+pub fn solve_linear<F: Scalar>(a: F, b: F) -> Roots<F> {
+    let mut roots = Roots::new();
+    if a != F::zero() {
+        roots.push(-b / a);
+    }
+    roots
+}
+
+/// Roots of `a x² + b x + c = 0`, a double root is returned once.
+pub fn solve_quadratic<F: Scalar>(a: F, b: F, c: F) -> Roots<F> {
+    if a == F::zero() {
+        return solve_linear(b, c);
+    }
+
+    let two = F::one() + F::one();
+    let discriminant = b * b - two * two * a * c;
+    let mut roots = Roots::new();
+    if discriminant == F::zero() {
+        roots.push(-b / (two * a));
+    } else if discriminant > F::zero() {
+        // Numerically stable form, avoids subtracting close numbers
+        let sqrt = discriminant.sqrt();
+        let q = if b < F::zero() {
+            (sqrt - b) / two
+        } else {
+            -(b + sqrt) / two
+        };
+        roots.push(q / a);
+        roots.push(if q == F::zero() { -q / a } else { c / q });
+    }
+    roots.sorted()
+}
+
+/// Roots of `a x³ + b x² + c x + d = 0`, multiple roots are returned once.
+pub fn solve_cubic<F: Scalar>(a: F, b: F, c: F, d: F) -> Roots<F> {
+    let epsilon: F = NumCast::from(1e-12).unwrap();
+    let scale = a.abs().max(b.abs()).max(c.abs()).max(d.abs());
+    if a.abs() <= epsilon * scale {
+        return solve_quadratic(b, c, d);
+    }
+
+    let one = F::one();
+    let two = one + one;
+    let three = two + one;
+    let nine = three * three;
+    let twenty_seven = nine * three;
+
+    // Depressed cubic t³ + p t + q = 0 with x = t - b / 3a
+    let (b, c, d) = (b / a, c / a, d / a);
+    let shift = b / three;
+    let p = c - b * b / three;
+    let q = two * b * b * b / twenty_seven - b * c / three + d;
+
+    let mut roots = Roots::new();
+    let discriminant = q * q / (two * two) + p * p * p / twenty_seven;
+    let tolerance = epsilon * (one + q * q + (p * p * p).abs());
+    if p.abs() <= epsilon * (one + b.abs()) && q.abs() <= epsilon * (one + b.abs()) {
+        roots.push(-shift);
+    } else if discriminant.abs() <= tolerance {
+        // A double root and a single one
+        let u = (-q / two).cbrt();
+        roots.push(two * u - shift);
+        roots.push(-u - shift);
+    } else if discriminant > F::zero() {
+        let sqrt = discriminant.sqrt();
+        let u = (-q / two + sqrt).cbrt();
+        let v = (-q / two - sqrt).cbrt();
+        roots.push(u + v - shift);
+    } else {
+        // Three real roots, trigonometric form
+        let r = (-p / three).sqrt();
+        let cos = (three * q / (two * p * r)).clamp(-one, one);
+        let phi = cos.acos() / three;
+        let two_thirds_pi = two * F::pi() / three;
+        for k in 0..3 {
+            let k: F = NumCast::from(k).unwrap();
+            roots.push(two * r * (phi - k * two_thirds_pi).cos() - shift);
+        }
+    }
+
+    // One Newton step to polish the roots
+    for root in roots.values[..roots.len].iter_mut() {
+        let x = *root;
+        let value = ((x + b) * x + c) * x + d;
+        let derivative = (three * x + two * b) * x + c;
+        if derivative != F::zero() {
+            *root = x - value / derivative;
+        }
+    }
+    roots.sorted()
+}
+
+impl<P: PlanarPoint> Bezier2<P> {
+    /// Curve parameters in `0..=1` where the x coordinate is equal to `x`, in ascending order.
+    pub fn solve_t_for_x(&self, x: P::Scalar) -> Roots<P::Scalar> {
+        solve_bezier2(self.p0.x(), self.p1.x(), self.p2.x(), x)
+    }
+
+    /// Curve parameters in `0..=1` where the y coordinate is equal to `y`, in ascending order.
+    pub fn solve_t_for_y(&self, y: P::Scalar) -> Roots<P::Scalar> {
+        solve_bezier2(self.p0.y(), self.p1.y(), self.p2.y(), y)
+    }
+}
+
+impl<P: PlanarPoint> Bezier3<P> {
+    /// Curve parameters in `0..=1` where the x coordinate is equal to `x`, in ascending order.
+    pub fn solve_t_for_x(&self, x: P::Scalar) -> Roots<P::Scalar> {
+        solve_bezier3(self.p0.x(), self.p1.x(), self.p2.x(), self.p3.x(), x)
+    }
+
+    /// Curve parameters in `0..=1` where the y coordinate is equal to `y`, in ascending order.
+    pub fn solve_t_for_y(&self, y: P::Scalar) -> Roots<P::Scalar> {
+        solve_bezier3(self.p0.y(), self.p1.y(), self.p2.y(), self.p3.y(), y)
+    }
+}
+
+fn solve_bezier2<F: Scalar>(p0: F, p1: F, p2: F, value: F) -> Roots<F> {
+    let two = F::one() + F::one();
+    solve_quadratic(p0 - two * p1 + p2, two * (p1 - p0), p0 - value).in_unit_interval()
+}
+
+fn solve_bezier3<F: Scalar>(p0: F, p1: F, p2: F, p3: F, value: F) -> Roots<F> {
+    let three = F::one() + F::one() + F::one();
+    let a = p3 - p0 + three * (p1 - p2);
+    let b = three * (p0 - (p1 + p1) + p2);
+    let c = three * (p1 - p0);
+    solve_cubic(a, b, c, p0 - value).in_unit_interval()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn assert_roots(roots: Roots<f64>, expected: &[f64]) {
+        assert_eq!(roots.len(), expected.len(), "{:?}", roots);
+        for (root, expected) in roots.iter().zip(expected) {
+            assert_relative_eq!(*root, *expected, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn linear_and_quadratic() {
+        assert_roots(solve_linear(2.0, -1.0), &[0.5]);
+        assert_roots(solve_linear(0.0, 1.0), &[]);
+        assert_roots(solve_quadratic(1.0, -3.0, 2.0), &[1.0, 2.0]);
+        assert_roots(solve_quadratic(1.0, 2.0, 1.0), &[-1.0]);
+        assert_roots(solve_quadratic(1.0, 0.0, 1.0), &[]);
+        assert_roots(solve_quadratic(1.0, 0.0, 0.0), &[0.0]);
+        assert_roots(solve_quadratic(0.0, 2.0, -1.0), &[0.5]);
+    }
+
+    #[test]
+    fn cubic() {
+        // (x - 1)(x - 2)(x - 3)
+        assert_roots(solve_cubic(1.0, -6.0, 11.0, -6.0), &[1.0, 2.0, 3.0]);
+        // (x - 1)(x² + 1)
+        assert_roots(solve_cubic(1.0, -1.0, 1.0, -1.0), &[1.0]);
+        // (x - 1)²(x + 2)
+        assert_roots(solve_cubic(1.0, 0.0, -3.0, 2.0), &[-2.0, 1.0]);
+        // x³
+        assert_roots(solve_cubic(2.0, 0.0, 0.0, 0.0), &[0.0]);
+        // Degenerates to a quadratic
+        assert_roots(solve_cubic(0.0, 1.0, -3.0, 2.0), &[1.0, 2.0]);
+        // 2(x - 0.1)(x - 0.5)(x - 0.9)
+        assert_roots(solve_cubic(2.0, -3.0, 1.18, -0.09), &[0.1, 0.5, 0.9]);
+    }
+
+    #[test]
+    fn bezier_crossings() {
+        use crate::Curve;
+
+        let curve = Bezier3::new([0.0, 0.0], [3.0, 2.0], [-2.0, 2.0], [1.0, 0.0]);
+        let roots = curve.solve_t_for_x(0.5);
+        assert_eq!(roots.len(), 3);
+        for t in roots.iter() {
+            assert_relative_eq!(curve.value_at(*t)[0], 0.5, epsilon = 1e-9);
+        }
+        assert!(curve.solve_t_for_y(2.0).is_empty());
+        assert_eq!(curve.solve_t_for_y(0.0).len(), 2);
+
+        let curve = Bezier2::new([0.0, 0.0], [1.0, 2.0], [2.0, 0.0]);
+        assert_roots(curve.solve_t_for_y(1.0), &[0.5]);
+        assert_roots(curve.solve_t_for_x(1.5), &[0.75]);
+    }
+
+    #[test]
+    fn unit_interval() {
+        let roots = solve_cubic(1.0, -6.0, 11.0, -6.0);
+        assert_roots(roots.in_unit_interval(), &[1.0]);
+    }
+}
@@ -0,0 +1,355 @@
+use core::fmt::Debug;
+use core::ops::Neg;
+use num_traits::{Float, Num, NumCast};
+
+/// `Scalar` is a number type used for curve parameters and point coordinates.
+///
+/// Curve evaluation needs only ring operations, so besides the arithmetic the trait requires
+/// just a handful of functions. It's implemented for every [`Float`] type, and can be implemented
+/// for fixed-point numbers, which makes evaluation deterministic across platforms.
+pub trait Scalar: Num + NumCast + Neg<Output = Self> + PartialOrd + Copy + Debug {
+    fn sqrt(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+
+    fn fract(self) -> Self {
+        self - self.floor()
+    }
+
+    fn abs(self) -> Self {
+        if self < Self::zero() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    fn min(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn clamp(self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
+
+    // Trigonometry is required only by circular arcs and rotations.
+    // Default implementations go through `f64`, override them to stay deterministic.
+
+    fn pi() -> Self {
+        from_f64(core::f64::consts::PI)
+    }
+
+    fn sin(self) -> Self {
+        from_f64(Float::sin(to_f64(self)))
+    }
+
+    fn cos(self) -> Self {
+        from_f64(Float::cos(to_f64(self)))
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        from_f64(Float::atan2(to_f64(self), to_f64(other)))
+    }
+
+    fn acos(self) -> Self {
+        from_f64(Float::acos(to_f64(self)))
+    }
+
+    /// Cube root, used by the analytic cubic equation solver.
+    fn cbrt(self) -> Self {
+        from_f64(Float::cbrt(to_f64(self)))
+    }
+
+    /// Exponent and natural logarithm, used by spring easings.
+    fn exp(self) -> Self {
+        from_f64(Float::exp(to_f64(self)))
+    }
+
+    fn ln(self) -> Self {
+        from_f64(Float::ln(to_f64(self)))
+    }
+
+    /// Evaluate the polynomial `coefficients[0] + coefficients[1] * t + ...` at every `t` from `ts`,
+    /// the values are passed to `write` along with their index. It's the batch evaluation
+    /// behind [`Point::polynomial_many`](crate::Point::polynomial_many).
+    fn polynomial_many<const N: usize>(
+        coefficients: &[Self; N],
+        ts: &[Self],
+        write: impl FnMut(usize, Self),
+    ) {
+        horner_many(coefficients, ts, write)
+    }
+}
+
+fn horner_many<F: Scalar, const N: usize>(
+    coefficients: &[F; N],
+    ts: &[F],
+    mut write: impl FnMut(usize, F),
+) {
+    for (i, &t) in ts.iter().enumerate() {
+        let value = coefficients[..N - 1]
+            .iter()
+            .rev()
+            .fold(coefficients[N - 1], |acc, &c| acc * t + c);
+        write(i, value);
+    }
+}
+
+fn to_f64<F: Scalar>(v: F) -> f64 {
+    v.to_f64().unwrap()
+}
+
+fn from_f64<F: Scalar>(v: f64) -> F {
+    <F as NumCast>::from(v).unwrap()
+}
+
+/// NaN is the only value that isn't comparable to itself.
+pub(crate) fn is_nan<F: Scalar>(value: F) -> bool {
+    value.partial_cmp(&value).is_none()
+}
+
+impl<F: Float + Debug + 'static> Scalar for F {
+    fn sqrt(self) -> Self {
+        Float::sqrt(self)
+    }
+
+    fn floor(self) -> Self {
+        Float::floor(self)
+    }
+
+    fn ceil(self) -> Self {
+        Float::ceil(self)
+    }
+
+    fn fract(self) -> Self {
+        Float::fract(self)
+    }
+
+    fn abs(self) -> Self {
+        Float::abs(self)
+    }
+
+    fn min(self, other: Self) -> Self {
+        Float::min(self, other)
+    }
+
+    fn max(self, other: Self) -> Self {
+        Float::max(self, other)
+    }
+
+    fn clamp(self, min: Self, max: Self) -> Self {
+        Float::clamp(self, min, max)
+    }
+
+    fn pi() -> Self {
+        from_f64(core::f64::consts::PI)
+    }
+
+    fn sin(self) -> Self {
+        Float::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        Float::cos(self)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        Float::atan2(self, other)
+    }
+
+    fn acos(self) -> Self {
+        Float::acos(self)
+    }
+
+    fn cbrt(self) -> Self {
+        Float::cbrt(self)
+    }
+
+    fn exp(self) -> Self {
+        Float::exp(self)
+    }
+
+    fn ln(self) -> Self {
+        Float::ln(self)
+    }
+
+    /// `f32` polynomials are evaluated eight parameters at a time with the `simd` feature.
+    fn polynomial_many<const N: usize>(
+        coefficients: &[Self; N],
+        ts: &[Self],
+        write: impl FnMut(usize, Self),
+    ) {
+        #[cfg(feature = "simd")]
+        if core::any::TypeId::of::<F>() == core::any::TypeId::of::<f32>() {
+            let mut write = write;
+            // SAFETY: `F` is `f32`, so the layouts are the same
+            let (coefficients, ts) = unsafe {
+                (
+                    &*(coefficients as *const [F; N] as *const [f32; N]),
+                    core::slice::from_raw_parts(ts.as_ptr() as *const f32, ts.len()),
+                )
+            };
+            crate::simd::polynomial_many(coefficients, ts, |i, v| write(i, from_f64(v as f64)));
+            return;
+        }
+        horner_many(coefficients, ts, write)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bezier3, Curve, Point};
+    use core::ops::{Add, Div, Mul, Rem, Sub};
+    use num_traits::{One, ToPrimitive, Zero};
+
+    /// Fixed-point number with 16 fractional bits.
+    #[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+    struct Fixed(i64);
+
+    const ONE: i64 = 1 << 16;
+
+    impl Add for Fixed {
+        type Output = Self;
+        fn add(self, other: Self) -> Self {
+            Fixed(self.0 + other.0)
+        }
+    }
+
+    impl Sub for Fixed {
+        type Output = Self;
+        fn sub(self, other: Self) -> Self {
+            Fixed(self.0 - other.0)
+        }
+    }
+
+    impl Mul for Fixed {
+        type Output = Self;
+        fn mul(self, other: Self) -> Self {
+            Fixed(self.0 * other.0 / ONE)
+        }
+    }
+
+    impl Div for Fixed {
+        type Output = Self;
+        fn div(self, other: Self) -> Self {
+            Fixed(self.0 * ONE / other.0)
+        }
+    }
+
+    impl Rem for Fixed {
+        type Output = Self;
+        fn rem(self, other: Self) -> Self {
+            Fixed(self.0 % other.0)
+        }
+    }
+
+    impl Neg for Fixed {
+        type Output = Self;
+        fn neg(self) -> Self {
+            Fixed(-self.0)
+        }
+    }
+
+    impl Zero for Fixed {
+        fn zero() -> Self {
+            Fixed(0)
+        }
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    impl One for Fixed {
+        fn one() -> Self {
+            Fixed(ONE)
+        }
+    }
+
+    impl Num for Fixed {
+        type FromStrRadixErr = ();
+        fn from_str_radix(_: &str, _: u32) -> Result<Self, ()> {
+            Err(())
+        }
+    }
+
+    impl ToPrimitive for Fixed {
+        fn to_i64(&self) -> Option<i64> {
+            Some(self.0 >> 16)
+        }
+        fn to_u64(&self) -> Option<u64> {
+            (self.0 >= 0).then_some((self.0 >> 16) as u64)
+        }
+        fn to_f64(&self) -> Option<f64> {
+            Some(self.0 as f64 / ONE as f64)
+        }
+    }
+
+    impl NumCast for Fixed {
+        fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+            n.to_f64().map(|v| Fixed((v * ONE as f64) as i64))
+        }
+    }
+
+    impl Scalar for Fixed {
+        fn sqrt(self) -> Self {
+            Fixed(((self.0 as f64 / ONE as f64).sqrt() * ONE as f64) as i64)
+        }
+        fn floor(self) -> Self {
+            Fixed(self.0 & !(ONE - 1))
+        }
+        fn ceil(self) -> Self {
+            -(-self).floor()
+        }
+    }
+
+    impl Point for Fixed {
+        type Scalar = Fixed;
+        fn add(&self, other: &Self) -> Self {
+            *self + *other
+        }
+        fn sub(&self, other: &Self) -> Self {
+            *self - *other
+        }
+        fn multiply(&self, other: &Self) -> Self {
+            *self * *other
+        }
+        fn scale(&self, s: Self) -> Self {
+            *self * s
+        }
+    }
+
+    fn fixed(v: f64) -> Fixed {
+        <Fixed as NumCast>::from(v).unwrap()
+    }
+
+    #[test]
+    fn fixed_point_bezier() {
+        let curve = Bezier3::new(fixed(1.0), fixed(4.0), fixed(2.0), fixed(4.0));
+
+        assert_eq!(curve.value_at(fixed(0.0)), fixed(1.0));
+        assert_eq!(curve.value_at(fixed(0.5)), fixed(2.875));
+        assert_eq!(curve.value_at(fixed(1.0)), fixed(4.0));
+        assert_eq!(curve.tangent_at(fixed(0.5)), fixed(0.75));
+    }
+
+    #[test]
+    fn fixed_point_rounding() {
+        assert_eq!(fixed(2.5).floor(), fixed(2.0));
+        assert_eq!(fixed(-2.5).floor(), fixed(-3.0));
+        assert_eq!(fixed(2.5).ceil(), fixed(3.0));
+        assert_eq!(fixed(2.25).fract(), fixed(0.25));
+    }
+}
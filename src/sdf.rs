@@ -0,0 +1,137 @@
+use crate::{ComposedCurve, Curve, Path, PlanarPoint, SegmentBvh};
+use alloc::vec::Vec;
+use num_traits::{NumCast, ToPrimitive};
+
+impl<P: PlanarPoint> Path<P> {
+    /// Signed distance field of the filled path in a `width`×`height` grid of pixels,
+    /// stored in rows from the top one. `transform` maps path coordinates to pixels
+    /// like in SVG, a point `(x, y)` goes to `(a x + c y + e, b x + d y + f)` for `[a, b, c, d, e, f]`.
+    ///
+    /// Values are distances from pixel centers to the outline in pixels divided by `spread`
+    /// and clamped to `[-1, 1]`, positive inside the path by the non-zero rule, so the outline
+    /// is at zero. Open contours are closed by lines.
+    pub fn to_sdf(
+        &self,
+        width: usize,
+        height: usize,
+        transform: [f64; 6],
+        spread: f64,
+    ) -> Vec<f32> {
+        let [a, b, c, d, e, f] = transform;
+        let to_pixels = |p: &P| {
+            let (x, y) = (p.x().to_f64().unwrap(), p.y().to_f64().unwrap());
+            P::from_xy(
+                NumCast::from(a * x + c * y + e).unwrap(),
+                NumCast::from(b * x + d * y + f).unwrap(),
+            )
+        };
+        let contours: Path<P> = self
+            .contours()
+            .iter()
+            .map(|contour| {
+                let mut contour = contour.map_points(to_pixels);
+                if contour.start_point() != contour.end_point() {
+                    contour.close();
+                }
+                contour
+            })
+            .collect();
+        let trees: Vec<SegmentBvh<P>> = contours.contours().iter().map(SegmentBvh::new).collect();
+        let radius: P::Scalar = NumCast::from(spread).unwrap();
+
+        let mut field = Vec::with_capacity(width * height);
+        let mut crossings = Vec::new();
+        for row in 0..height {
+            let y = row as f64 + 0.5;
+            contours.horizontal_intersections(NumCast::from(y).unwrap(), &mut crossings);
+            let mut next_crossing = 0;
+            let mut winding = 0;
+            for column in 0..width {
+                let x = column as f64 + 0.5;
+                // Crossings left of the pixel center give its winding number
+                while next_crossing < crossings.len()
+                    && crossings[next_crossing].0.to_f64().unwrap() < x
+                {
+                    winding += crossings[next_crossing].1;
+                    next_crossing += 1;
+                }
+                let center = P::from_xy(NumCast::from(x).unwrap(), NumCast::from(y).unwrap());
+                let distance = trees
+                    .iter()
+                    .filter_map(|tree| tree.hit_test(&center, radius))
+                    .map(|hit| hit.distance.to_f64().unwrap())
+                    .fold(spread, f64::min);
+                let normalized = if spread > 0.0 { distance / spread } else { 0.0 };
+                let sign = if winding != 0 { 1.0 } else { -1.0 };
+                field.push((sign * normalized.min(1.0)) as f32);
+            }
+        }
+        field
+    }
+}
+
+impl<P: PlanarPoint> ComposedCurve<P> {
+    /// Signed distance field of the filled curve, see [`Path::to_sdf`].
+    pub fn to_sdf(
+        &self,
+        width: usize,
+        height: usize,
+        transform: [f64; 6],
+        spread: f64,
+    ) -> Vec<f32> {
+        Path::from(self.clone()).to_sdf(width, height, transform, spread)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IDENTITY: [f64; 6] = [1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+
+    fn square(x0: f64, y0: f64, x1: f64, y1: f64) -> ComposedCurve<[f64; 2]> {
+        let mut curve = ComposedCurve::new([x0, y0]);
+        curve.line_to([x1, y0]);
+        curve.line_to([x1, y1]);
+        curve.line_to([x0, y1]);
+        curve.close();
+        curve
+    }
+
+    #[test]
+    fn square_field() {
+        let field = square(2.0, 2.0, 8.0, 8.0).to_sdf(10, 10, IDENTITY, 4.0);
+        assert_eq!(field.len(), 100);
+        // The center pixel is 2.5 pixels from every side
+        assert_eq!(field[4 * 10 + 4], 2.5 / 4.0);
+        // Just outside the left side, and diagonally from the corner
+        assert_eq!(field[4 * 10 + 1], -0.5 / 4.0);
+        assert_eq!(field[0], -(1.5f32 * 1.5 * 2.0).sqrt() / 4.0);
+
+        let far = square(2.0, 2.0, 8.0, 8.0).to_sdf(40, 1, IDENTITY, 4.0);
+        assert_eq!(far[39], -1.0);
+    }
+
+    #[test]
+    fn transform_and_holes() {
+        // A ring scaled twice, the hole is outside
+        let outer = square(0.0, 0.0, 5.0, 5.0);
+        let inner = square(2.0, 2.0, 3.0, 3.0).map_points(|p| [p[0], 5.0 - p[1]]);
+        let path: Path<_> = [outer, inner].into_iter().collect();
+        let field = path.to_sdf(10, 10, [2.0, 0.0, 0.0, 2.0, 0.0, 0.0], 2.0);
+        assert!(field[10 + 1] > 0.0);
+        assert_eq!(field[5 * 10 + 5], -0.25);
+        assert!(field.iter().all(|v| (-1.0..=1.0).contains(v)));
+    }
+
+    #[test]
+    fn open_contours_are_closed() {
+        let mut open = ComposedCurve::new([1.0, 1.0]);
+        open.line_to([9.0, 1.0]);
+        open.line_to([9.0, 9.0]);
+        let field = open.to_sdf(10, 10, IDENTITY, 4.0);
+        // Below the closing diagonal and above it
+        assert!(field[3 * 10 + 7] > 0.0);
+        assert!(field[7 * 10 + 3] < 0.0);
+    }
+}
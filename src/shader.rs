@@ -0,0 +1,232 @@
+use crate::bezier::Bezier;
+use crate::{Bezier3, ComposedCurve, Curve, PlanarPoint};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use num_traits::ToPrimitive;
+
+/// Target language of generated shader code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ShaderLanguage {
+    /// GLSL, compatible with GLSL ES 3.0 and desktop GLSL 3.3+.
+    Glsl,
+    /// WGSL, the WebGPU shading language.
+    Wgsl,
+}
+
+impl ShaderLanguage {
+    fn header(&self, name: &str) -> String {
+        match self {
+            ShaderLanguage::Glsl => format!("vec2 {}(float t) {{\n", name),
+            ShaderLanguage::Wgsl => format!("fn {}(t: f32) -> vec2<f32> {{\n", name),
+        }
+    }
+
+    fn vec2(&self, x: f32, y: f32) -> String {
+        match self {
+            ShaderLanguage::Glsl => format!("vec2({:?}, {:?})", x, y),
+            ShaderLanguage::Wgsl => format!("vec2<f32>({:?}, {:?})", x, y),
+        }
+    }
+
+    /// Declaration of a local variable.
+    fn variable(&self, kind: &str, name: &str, value: &str) -> String {
+        match self {
+            ShaderLanguage::Glsl => format!("    {} {} = {};\n", kind, name, value),
+            ShaderLanguage::Wgsl => format!("    let {} = {};\n", name, value),
+        }
+    }
+
+    /// Evaluates the cubic with control points `p0..p3` at `s`, `p0..p3` and `s` must be declared.
+    fn cubic(&self, out: &mut String) {
+        out.push_str(&self.variable("float", "r", "1.0 - s"));
+        out.push_str(
+            "    return r * r * r * p0 + 3.0 * r * r * s * p1 + 3.0 * r * s * s * p2 + s * s * s * p3;\n}\n",
+        );
+    }
+}
+
+fn to_f32<P: PlanarPoint>(p: &P) -> (f32, f32) {
+    (p.x().to_f32().unwrap(), p.y().to_f32().unwrap())
+}
+
+/// The segment as a cubic, degree elevation is exact.
+fn to_cubic<P: PlanarPoint>(segment: &Bezier<P>) -> Bezier3<P> {
+    match segment {
+        Bezier::C0(c) => Bezier3::new(
+            c.point.clone(),
+            c.point.clone(),
+            c.point.clone(),
+            c.point.clone(),
+        ),
+        Bezier::C1(c) => c.to_quadratic().to_cubic(),
+        Bezier::C2(c) => c.to_cubic(),
+        Bezier::C3(c) => c.clone(),
+    }
+}
+
+impl<P: PlanarPoint> Bezier<P> {
+    /// Shader function `name(t) -> vec2` evaluating the curve, control points are embedded as `f32` constants.
+    pub fn to_shader_function(&self, name: &str, language: ShaderLanguage) -> String {
+        let cubic = to_cubic(self);
+        let mut out = language.header(name);
+        for (i, p) in [&cubic.p0, &cubic.p1, &cubic.p2, &cubic.p3]
+            .into_iter()
+            .enumerate()
+        {
+            let (x, y) = to_f32(p);
+            out.push_str(&language.variable("vec2", &format!("p{}", i), &language.vec2(x, y)));
+        }
+        out.push_str(&language.variable("float", "s", "clamp(t, 0.0, 1.0)"));
+        language.cubic(&mut out);
+        out
+    }
+}
+
+impl<P: PlanarPoint> ComposedCurve<P> {
+    /// Control points to upload into the array used by [`ComposedCurve::to_shader_function`]:
+    /// every segment is converted to a cubic and takes two `vec4` (eight `f32`) values, `p0` and `p1`
+    /// in the first one and `p2` and `p3` in the second. Arrays of `vec4` have the same 16 byte stride
+    /// in `std140` uniform blocks and in storage buffers, so the data fits both without padding.
+    pub fn to_shader_data(&self) -> Vec<f32> {
+        let mut data = Vec::with_capacity(self.segments().len() * 8);
+        for segment in self.segments() {
+            let cubic = to_cubic(segment);
+            for p in [&cubic.p0, &cubic.p1, &cubic.p2, &cubic.p3] {
+                let (x, y) = to_f32(p);
+                data.extend_from_slice(&[x, y]);
+            }
+        }
+        data
+    }
+
+    /// Shader function `name(t) -> vec2` evaluating the curve with the same parametrization as
+    /// [`Curve::value_at`]. Control points are read from the `vec4` array `array_name`
+    /// (a uniform or storage buffer declared by the caller) filled with [`ComposedCurve::to_shader_data`],
+    /// the number of segments is embedded into the function.
+    pub fn to_shader_function(
+        &self,
+        name: &str,
+        array_name: &str,
+        language: ShaderLanguage,
+    ) -> String {
+        let count = self.segments().len();
+        let mut out = language.header(name);
+        if count == 0 {
+            let (x, y) = to_f32(&self.start_point());
+            let _ = writeln!(out, "    return {};\n}}", language.vec2(x, y));
+            return out;
+        }
+
+        let (index, fraction, load, suffix) = match language {
+            ShaderLanguage::Glsl => (
+                format!("min(int(x), {})", count - 1),
+                "x - float(i)",
+                "2 * i + ",
+                "",
+            ),
+            ShaderLanguage::Wgsl => (
+                format!("min(u32(x), {}u)", count - 1),
+                "x - f32(i)",
+                "2u * i + ",
+                "u",
+            ),
+        };
+        out.push_str(&language.variable(
+            "float",
+            "x",
+            &format!("clamp(t, 0.0, 1.0) * {:?}", count as f32),
+        ));
+        out.push_str(&language.variable("int", "i", &index));
+        out.push_str(&language.variable("float", "s", fraction));
+        for (j, pair) in ["a", "b"].into_iter().enumerate() {
+            let value = format!("{}[{}{}{}]", array_name, load, j, suffix);
+            out.push_str(&language.variable("vec4", pair, &value));
+        }
+        for (j, point) in ["a.xy", "a.zw", "b.xy", "b.zw"].into_iter().enumerate() {
+            out.push_str(&language.variable("vec2", &format!("p{}", j), point));
+        }
+        language.cubic(&mut out);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bezier2;
+    use approx::assert_relative_eq;
+
+    fn curve() -> ComposedCurve<[f64; 2]> {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([2.0, 0.0]);
+        curve.quadratic_to([3.0, 1.0], [2.0, 2.0]);
+        curve.cubic_to([1.0, 3.0], [0.0, 2.0], [0.5, 1.0]);
+        curve
+    }
+
+    #[test]
+    fn data_matches_curve() {
+        let curve = curve();
+        let data = curve.to_shader_data();
+        assert_eq!(data.len(), 24);
+        for k in 0..=30 {
+            // Mirror of the generated code
+            let t = k as f32 / 30.0;
+            let x = t * 3.0;
+            let i = (x as usize).min(2);
+            let s = x - i as f32;
+            let r = 1.0 - s;
+            let w = [r * r * r, 3.0 * r * r * s, 3.0 * r * s * s, s * s * s];
+            let p = (0..2).map(|c| (0..4).map(|j| w[j] * data[8 * i + 2 * j + c]).sum::<f32>());
+            let expected = curve.value_at(t as f64);
+            for (value, expected) in p.zip(expected) {
+                assert_relative_eq!(value, expected as f32, epsilon = 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn glsl() {
+        let code = curve().to_shader_function("path", "points", ShaderLanguage::Glsl);
+        assert!(code.starts_with("vec2 path(float t) {\n"));
+        assert!(code.contains("    float x = clamp(t, 0.0, 1.0) * 3.0;\n"));
+        assert!(code.contains("    int i = min(int(x), 2);\n"));
+        assert!(code.contains("    float s = x - float(i);\n"));
+        assert!(code.contains("    vec4 b = points[2 * i + 1];\n"));
+        assert!(code.contains("    vec2 p3 = b.zw;\n"));
+        assert!(code.ends_with("}\n"));
+    }
+
+    #[test]
+    fn wgsl() {
+        let code = curve().to_shader_function("path", "points", ShaderLanguage::Wgsl);
+        assert!(code.starts_with("fn path(t: f32) -> vec2<f32> {\n"));
+        assert!(code.contains("    let i = min(u32(x), 2u);\n"));
+        assert!(code.contains("    let s = x - f32(i);\n"));
+        assert!(code.contains("    let a = points[2u * i + 0u];\n"));
+        assert!(code.contains("    let p0 = a.xy;\n"));
+    }
+
+    #[test]
+    fn fixed_bezier() {
+        let curve = Bezier::C2(Bezier2::new([0.0, 0.0], [3.0, 3.0], [6.0, 0.0]));
+        let code = curve.to_shader_function("arch", ShaderLanguage::Wgsl);
+        assert!(code.contains("    let p1 = vec2<f32>(2.0, 2.0);\n"));
+        assert!(code.contains("    let p3 = vec2<f32>(6.0, 0.0);\n"));
+        let code = curve.to_shader_function("arch", ShaderLanguage::Glsl);
+        assert!(code.contains("    vec2 p2 = vec2(4.0, 2.0);\n"));
+        assert!(code.contains("    float s = clamp(t, 0.0, 1.0);\n"));
+    }
+
+    #[test]
+    fn empty_curve() {
+        let code = ComposedCurve::new([1.0, 2.0]).to_shader_function(
+            "dot",
+            "points",
+            ShaderLanguage::Glsl,
+        );
+        assert_eq!(code, "vec2 dot(float t) {\n    return vec2(1.0, 2.0);\n}\n");
+    }
+}
@@ -0,0 +1,89 @@
+use wide::f32x8;
+
+/// Number of parameters evaluated at once, eight `f32` values fill a 256-bit register.
+const LANES: usize = 8;
+
+/// SIMD version of [`Scalar::polynomial_many`](crate::Scalar::polynomial_many) for `f32`,
+/// parameters are processed in blocks of [`LANES`] with Horner's rule, the last block is padded.
+pub(crate) fn polynomial_many<const N: usize>(
+    coefficients: &[f32; N],
+    ts: &[f32],
+    mut write: impl FnMut(usize, f32),
+) {
+    let highest = f32x8::splat(coefficients[N - 1]);
+    for (block, ts) in ts.chunks(LANES).enumerate() {
+        let mut lanes = [0.0; LANES];
+        lanes[..ts.len()].copy_from_slice(ts);
+        let t = f32x8::from(lanes);
+
+        let values = coefficients[..N - 1]
+            .iter()
+            .rev()
+            .fold(highest, |acc, &c| acc.mul_add(t, f32x8::splat(c)));
+        for (i, v) in values.to_array().into_iter().take(ts.len()).enumerate() {
+            write(block * LANES + i, v);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bezier2, Bezier3, Curve};
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn simd_matches_scalar() {
+        // Not a multiple of the lanes count, so the last block is padded
+        let ts: Vec<f32> = (0..=20).map(|i| i as f32 / 20.0).collect();
+        let quadratic = Bezier2::new(1.0f32, 3.0, 2.0);
+        let cubic = Bezier3::new(1.0f32, 4.0, 2.0, 4.0);
+
+        let mut out = vec![0.0f32; ts.len()];
+
+        quadratic.value_at_many(&ts, &mut out);
+        for (t, v) in ts.iter().zip(out.iter()) {
+            assert_relative_eq!(*v, quadratic.value_at(*t), epsilon = 1e-5);
+        }
+
+        quadratic.tangent_at_many(&ts, &mut out);
+        for (t, v) in ts.iter().zip(out.iter()) {
+            assert_relative_eq!(*v, quadratic.tangent_at(*t), epsilon = 1e-5);
+        }
+
+        cubic.value_at_many(&ts, &mut out);
+        for (t, v) in ts.iter().zip(out.iter()) {
+            assert_relative_eq!(*v, cubic.value_at(*t), epsilon = 1e-5);
+        }
+
+        cubic.tangent_at_many(&ts, &mut out);
+        for (t, v) in ts.iter().zip(out.iter()) {
+            assert_relative_eq!(*v, cubic.tangent_at(*t), epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    fn simd_points() {
+        let ts: Vec<f32> = (0..=10).map(|i| i as f32 / 10.0).collect();
+
+        let cubic = Bezier3::new([0.0f32, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]);
+        let mut out = vec![[0.0f32; 2]; ts.len()];
+        cubic.value_at_many(&ts, &mut out);
+        for (t, p) in ts.iter().zip(out.iter()) {
+            let expected = cubic.value_at(*t);
+            assert_relative_eq!(p[0], expected[0], epsilon = 1e-5);
+            assert_relative_eq!(p[1], expected[1], epsilon = 1e-5);
+        }
+
+        let quadratic = Bezier2::new((0.0f32, 0.0, 0.0), (1.0, 2.0, 3.0), (2.0, 0.0, 1.0));
+        let mut out = vec![(0.0f32, 0.0, 0.0); ts.len()];
+        quadratic.tangent_at_many(&ts, &mut out);
+        for (t, p) in ts.iter().zip(out.iter()) {
+            let expected = quadratic.tangent_at(*t);
+            assert_relative_eq!(p.0, expected.0, epsilon = 1e-5);
+            assert_relative_eq!(p.1, expected.1, epsilon = 1e-5);
+            assert_relative_eq!(p.2, expected.2, epsilon = 1e-5);
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use crate::geometry::distance_to_segment;
+use crate::{Curve, Distance, InnerProduct, Scalar};
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::{NumCast, One, ToPrimitive, Zero};
+
+/// Hausdorff distance between two curves, the largest distance from a point on one of the curves
+/// to the closest point on the other one. The result is expected to be within `tolerance`
+/// from the exact value.
+pub fn hausdorff_distance<P, A, B>(a: &A, b: &B, tolerance: P::Scalar) -> P::Scalar
+where
+    P: InnerProduct,
+    A: Curve<P> + ?Sized,
+    B: Curve<P> + ?Sized,
+{
+    let half = P::Scalar::one() / (P::Scalar::one() + P::Scalar::one());
+    let a_distance = directed_hausdorff(&sample(a, tolerance), &b.flatten(tolerance * half));
+    let b_distance = directed_hausdorff(&sample(b, tolerance), &a.flatten(tolerance * half));
+    a_distance.max(b_distance)
+}
+
+/// Fréchet distance between two curves, approximated by the discrete Fréchet distance
+/// of points sampled along the curves not further than `tolerance` apart.
+pub fn frechet_distance<P, A, B>(a: &A, b: &B, tolerance: P::Scalar) -> P::Scalar
+where
+    P: InnerProduct,
+    A: Curve<P> + ?Sized,
+    B: Curve<P> + ?Sized,
+{
+    discrete_frechet_distance(&sample(a, tolerance), &sample(b, tolerance))
+}
+
+/// Discrete Fréchet distance between two point sequences, the shortest leash that allows walking
+/// both sequences from the first point to the last one without going back.
+///
+/// # Panics
+/// If any of the sequences is empty.
+pub fn discrete_frechet_distance<P: InnerProduct>(a: &[P], b: &[P]) -> P::Scalar {
+    assert!(
+        !a.is_empty() && !b.is_empty(),
+        "Point sequences must not be empty"
+    );
+
+    let mut row = vec![P::Scalar::zero(); b.len()];
+    for (i, p) in a.iter().enumerate() {
+        let mut diagonal = P::Scalar::zero();
+        for (j, q) in b.iter().enumerate() {
+            let d = p.distance(q);
+            let above = row[j];
+            row[j] = match (i, j) {
+                (0, 0) => d,
+                (0, _) => row[j - 1].max(d),
+                (_, 0) => above.max(d),
+                _ => diagonal.min(above).min(row[j - 1]).max(d),
+            };
+            diagonal = above;
+        }
+    }
+    row[b.len() - 1]
+}
+
+/// Largest distance from points of `a` to the polyline `b`.
+fn directed_hausdorff<P: InnerProduct>(a: &[P], b: &[P]) -> P::Scalar {
+    a.iter()
+        .map(|p| {
+            if b.len() == 1 {
+                return p.distance(&b[0]);
+            }
+            b.windows(2)
+                .map(|s| distance_to_segment(p, &s[0], &s[1]))
+                .fold(None, |acc: Option<P::Scalar>, d| {
+                    Some(acc.map_or(d, |acc| acc.min(d)))
+                })
+                .unwrap()
+        })
+        .fold(P::Scalar::zero(), |acc, d| acc.max(d))
+}
+
+/// Points along the curve, not further than `tolerance` from the curve or from each other.
+fn sample<P, C>(curve: &C, tolerance: P::Scalar) -> Vec<P>
+where
+    P: InnerProduct,
+    C: Curve<P> + ?Sized,
+{
+    let half = P::Scalar::one() / (P::Scalar::one() + P::Scalar::one());
+    let polyline = curve.flatten(tolerance * half);
+
+    let mut points = vec![polyline[0].clone()];
+    for s in polyline.windows(2) {
+        let steps = (s[0].distance(&s[1]) / tolerance)
+            .ceil()
+            .to_usize()
+            .unwrap_or(1)
+            .max(1);
+        let n: P::Scalar = NumCast::from(steps).unwrap();
+        points.extend((1..=steps).map(|i| {
+            let t: P::Scalar = NumCast::from(i).unwrap();
+            s[0].lerp(&s[1], t / n)
+        }));
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bezier, Bezier1, Bezier3, ComposedCurve};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn parallel_lines() {
+        let a = Bezier1::new([0.0, 0.0], [10.0, 0.0]);
+        let b = Bezier1::new([0.0, 1.0], [10.0, 1.0]);
+        assert_relative_eq!(hausdorff_distance(&a, &b, 5e-2), 1.0, epsilon = 5e-2);
+        assert_relative_eq!(frechet_distance(&a, &b, 5e-2), 1.0, epsilon = 5e-2);
+    }
+
+    #[test]
+    fn reversed_curve() {
+        let a = Bezier3::new([0.0, 0.0], [2.0, 5.0], [8.0, 5.0], [10.0, 0.0]);
+        let b = Bezier3::new([10.0, 0.0], [8.0, 5.0], [2.0, 5.0], [0.0, 0.0]);
+        // The same shape, but walked in the opposite direction
+        assert!(hausdorff_distance(&a, &b, 5e-2) < 5e-2);
+        assert_relative_eq!(frechet_distance(&a, &b, 5e-2), 10.0, epsilon = 5e-2);
+    }
+
+    #[test]
+    fn partial_overlap() {
+        let a = Bezier1::new([0.0, 0.0], [10.0, 0.0]);
+        let b = Bezier1::new([0.0, 0.0], [4.0, 0.0]);
+        assert_relative_eq!(hausdorff_distance(&a, &b, 5e-2), 6.0, epsilon = 5e-2);
+    }
+
+    #[test]
+    fn discrete() {
+        let a = [[0.0, 0.0], [1.0, 1.0], [2.0, 0.0]];
+        let b = [[0.0, 0.0], [2.0, 0.0]];
+        assert_relative_eq!(discrete_frechet_distance(&a, &b), 2.0f64.sqrt());
+        assert_relative_eq!(discrete_frechet_distance(&a, &a), 0.0);
+    }
+
+    #[test]
+    fn is_close() {
+        let cubic = Bezier3::new([0.0, 0.0], [1.0, 2.0], [3.0, 2.0], [4.0, 0.0]);
+        let (a, b) = cubic.split_at(0.3);
+        let mut composed = ComposedCurve::new([0.0, 0.0]);
+        composed.push_segment(Bezier::C3(a));
+        composed.push_segment(Bezier::C3(b));
+        assert!(cubic.is_close(&composed, 1e-2));
+
+        let line = Bezier1::new([0.0, 0.0], [4.0, 0.0]);
+        assert!(!cubic.is_close(&line, 1e-2));
+        let reversed = Bezier3::new([4.0, 0.0], [3.0, 2.0], [1.0, 2.0], [0.0, 0.0]);
+        assert!(!cubic.is_close(&reversed, 1e-2));
+    }
+}
@@ -0,0 +1,195 @@
+use crate::geometry::distance_to_segment;
+use crate::{InnerProduct, Scalar};
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use num_traits::{One, Zero};
+
+/// Simplify a polyline with the Ramer–Douglas–Peucker algorithm.
+///
+/// Points are removed while every removed point stays within `tolerance` of the
+/// simplified polyline, the first and the last point are always kept.
+pub fn simplify_rdp<P: InnerProduct>(points: &[P], tolerance: P::Scalar) -> Vec<P> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    let mut stack = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = stack.pop() {
+        let mut farthest = (P::Scalar::zero(), first);
+        for (i, point) in points.iter().enumerate().take(last).skip(first + 1) {
+            let distance = distance_to_segment(point, &points[first], &points[last]);
+            if distance > farthest.0 {
+                farthest = (distance, i);
+            }
+        }
+
+        if farthest.0 > tolerance {
+            keep[farthest.1] = true;
+            stack.push((first, farthest.1));
+            stack.push((farthest.1, last));
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter(|(_, keep)| *keep)
+        .map(|(point, _)| point.clone())
+        .collect()
+}
+
+/// Simplify a polyline with the Visvalingam–Whyatt algorithm.
+///
+/// The point forming the smallest triangle with its neighbours is removed until every
+/// remaining triangle has an area of at least `min_area`, the first and the last point are always kept.
+pub fn simplify_visvalingam<P: InnerProduct>(points: &[P], min_area: P::Scalar) -> Vec<P> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    let count = points.len();
+    let mut previous: Vec<usize> = (0..count).map(|i| i.wrapping_sub(1)).collect();
+    let mut next: Vec<usize> = (1..=count).collect();
+    let mut removed = vec![false; count];
+    let mut areas = vec![P::Scalar::zero(); count];
+
+    let mut heap = BinaryHeap::with_capacity(count);
+    for i in 1..count - 1 {
+        areas[i] = triangle_area(&points[i - 1], &points[i], &points[i + 1]);
+        heap.push(Candidate {
+            area: areas[i],
+            index: i,
+        });
+    }
+
+    let mut last_area = P::Scalar::zero();
+    while let Some(Candidate { area, index }) = heap.pop() {
+        // Stale entry of a point that was updated after it was pushed
+        if removed[index] || area != areas[index] {
+            continue;
+        }
+        if area >= min_area {
+            break;
+        }
+
+        removed[index] = true;
+        let (before, after) = (previous[index], next[index]);
+        next[before] = after;
+        previous[after] = before;
+
+        // Areas never decrease, so neighbours of a removed point aren't removed before it
+        last_area = last_area.max(area);
+        for neighbour in [before, after] {
+            if neighbour == 0 || neighbour == count - 1 {
+                continue;
+            }
+            let area = triangle_area(
+                &points[previous[neighbour]],
+                &points[neighbour],
+                &points[next[neighbour]],
+            )
+            .max(last_area);
+            areas[neighbour] = area;
+            heap.push(Candidate {
+                area,
+                index: neighbour,
+            });
+        }
+    }
+
+    points
+        .iter()
+        .zip(removed)
+        .filter(|(_, removed)| !removed)
+        .map(|(point, _)| point.clone())
+        .collect()
+}
+
+fn triangle_area<P: InnerProduct>(a: &P, b: &P, c: &P) -> P::Scalar {
+    let ab = b.sub(a);
+    let ac = c.sub(a);
+    let dot = ab.dot(&ac);
+    let squared = ab.length_squared() * ac.length_squared() - dot * dot;
+    let half = P::Scalar::one() / (P::Scalar::one() + P::Scalar::one());
+    squared.max(P::Scalar::zero()).sqrt() * half
+}
+
+/// Heap entry ordered so the smallest area is popped first.
+struct Candidate<F> {
+    area: F,
+    index: usize,
+}
+
+impl<F: Scalar> PartialEq for Candidate<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<F: Scalar> Eq for Candidate<F> {}
+
+impl<F: Scalar> PartialOrd for Candidate<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F: Scalar> Ord for Candidate<F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .partial_cmp(&self.area)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zigzag() -> Vec<[f64; 2]> {
+        (0..=20)
+            .map(|i| [i as f64, if i % 2 == 0 { 0.0 } else { 0.01 }])
+            .chain([[21.0, 5.0], [22.0, 0.0]])
+            .collect()
+    }
+
+    #[test]
+    fn rdp() {
+        let points = zigzag();
+        assert_eq!(
+            simplify_rdp(&points, 0.1),
+            [[0.0, 0.0], [20.0, 0.0], [21.0, 5.0], [22.0, 0.0]]
+        );
+        assert_eq!(simplify_rdp(&points, 0.001), points);
+        assert_eq!(simplify_rdp(&points, 100.0), [[0.0, 0.0], [22.0, 0.0]]);
+    }
+
+    #[test]
+    fn visvalingam() {
+        let points = zigzag();
+        assert_eq!(
+            simplify_visvalingam(&points, 0.1),
+            [[0.0, 0.0], [20.0, 0.0], [21.0, 5.0], [22.0, 0.0]]
+        );
+        assert_eq!(simplify_visvalingam(&points, 0.0), points);
+        assert_eq!(
+            simplify_visvalingam(&points, 100.0),
+            [[0.0, 0.0], [22.0, 0.0]]
+        );
+    }
+
+    #[test]
+    fn short_input() {
+        let points = [[0.0, 0.0], [1.0, 1.0]];
+        assert_eq!(simplify_rdp(&points, 1.0), points);
+        assert_eq!(simplify_visvalingam(&points, 1.0), points);
+    }
+}
@@ -0,0 +1,270 @@
+//! Snap targets for interactive tools: anchors, segment midpoints, on-path points,
+//! intersections of paths, and grid points near the pointer.
+
+use crate::{ComposedCurve, Curve, Distance, PlanarPoint, Rect, Scalar, SegmentBvh};
+use alloc::vec::Vec;
+use num_traits::One;
+
+/// What a [`SnapTarget`] is attached to, paths are indices in the slice given to [`Snapper::new`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SnapKind<F> {
+    /// An end point of a segment, anchor `i` is the start of segment `i`.
+    Anchor { path: usize, anchor: usize },
+    /// The point at `t = 0.5` of a segment.
+    Midpoint { path: usize, segment: usize },
+    /// The nearest point of a path.
+    OnPath { path: usize, segment: usize, t: F },
+    /// An intersection of two different paths.
+    Intersection { path: usize, other_path: usize },
+    /// A point of the grid set with [`Snapper::with_grid`].
+    Grid,
+}
+
+/// A point the pointer can snap to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SnapTarget<P: PlanarPoint> {
+    pub kind: SnapKind<P::Scalar>,
+    pub point: P,
+    /// Distance from the query point.
+    pub distance: P::Scalar,
+}
+
+/// Snap queries over a set of paths.
+///
+/// Segments are indexed with a [`SegmentBvh`] per path, and the intersections of the paths
+/// are found once on creation, so queries only look at the neighbourhood of the pointer.
+#[derive(Clone)]
+pub struct Snapper<P: PlanarPoint> {
+    paths: Vec<SegmentBvh<P>>,
+    intersections: Vec<(usize, usize, P)>,
+    grid: Option<P::Scalar>,
+}
+
+impl<P: PlanarPoint> Snapper<P> {
+    /// Index `paths`, intersections are found with the `tolerance` of
+    /// [`SegmentBvh::intersections`].
+    pub fn new(paths: &[ComposedCurve<P>], tolerance: P::Scalar) -> Self {
+        let paths: Vec<_> = paths.iter().map(SegmentBvh::new).collect();
+        let mut intersections = Vec::new();
+        for (i, a) in paths.iter().enumerate() {
+            for (j, b) in paths.iter().enumerate().skip(i + 1) {
+                intersections.extend(
+                    a.intersections(b, tolerance)
+                        .into_iter()
+                        .map(|hit| (i, j, hit.point)),
+                );
+            }
+        }
+        Self {
+            paths,
+            intersections,
+            grid: None,
+        }
+    }
+
+    /// Also offer the nearest point of a square grid with `spacing` through the origin.
+    pub fn with_grid(mut self, spacing: P::Scalar) -> Self {
+        self.grid = Some(spacing);
+        self
+    }
+
+    /// All the targets within `radius` of `point`, nearest first.
+    pub fn candidates(&self, point: &P, radius: P::Scalar) -> Vec<SnapTarget<P>> {
+        let mut result = Vec::new();
+        let area = square(point, radius);
+        for (path, bvh) in self.paths.iter().enumerate() {
+            let segments = bvh.segments();
+            let nearby = bvh.query_rect(&area);
+
+            let mut anchors: Vec<usize> = nearby.iter().flat_map(|&i| [i, i + 1]).collect();
+            anchors.dedup();
+            for anchor in anchors {
+                let anchor_point = match segments.get(anchor) {
+                    Some(segment) => segment.start_point(),
+                    None => segments[anchor - 1].end_point(),
+                };
+                push_within(
+                    &mut result,
+                    SnapKind::Anchor { path, anchor },
+                    anchor_point,
+                    point,
+                    radius,
+                );
+            }
+
+            let half = P::Scalar::one() / (P::Scalar::one() + P::Scalar::one());
+            for segment in nearby {
+                push_within(
+                    &mut result,
+                    SnapKind::Midpoint { path, segment },
+                    segments[segment].value_at(half),
+                    point,
+                    radius,
+                );
+            }
+
+            if let Some(hit) = bvh.hit_test(point, radius) {
+                result.push(SnapTarget {
+                    kind: SnapKind::OnPath {
+                        path,
+                        segment: hit.segment,
+                        t: hit.t,
+                    },
+                    point: hit.point,
+                    distance: hit.distance,
+                });
+            }
+        }
+
+        for (path, other_path, intersection) in &self.intersections {
+            push_within(
+                &mut result,
+                SnapKind::Intersection {
+                    path: *path,
+                    other_path: *other_path,
+                },
+                intersection.clone(),
+                point,
+                radius,
+            );
+        }
+
+        if let Some(spacing) = self.grid {
+            push_within(
+                &mut result,
+                SnapKind::Grid,
+                snap_to_grid(point, spacing),
+                point,
+                radius,
+            );
+        }
+
+        result.sort_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap());
+        result
+    }
+
+    /// The nearest anchor within `radius`.
+    pub fn nearest_anchor(&self, point: &P, radius: P::Scalar) -> Option<SnapTarget<P>> {
+        self.candidates(point, radius)
+            .into_iter()
+            .find(|target| matches!(target.kind, SnapKind::Anchor { .. }))
+    }
+
+    /// The nearest point of any path within `radius`.
+    pub fn nearest_on_path(&self, point: &P, radius: P::Scalar) -> Option<SnapTarget<P>> {
+        self.paths
+            .iter()
+            .enumerate()
+            .filter_map(|(path, bvh)| {
+                let hit = bvh.hit_test(point, radius)?;
+                Some(SnapTarget {
+                    kind: SnapKind::OnPath {
+                        path,
+                        segment: hit.segment,
+                        t: hit.t,
+                    },
+                    point: hit.point,
+                    distance: hit.distance,
+                })
+            })
+            .min_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap())
+    }
+}
+
+/// The nearest point of a square grid with `spacing` through the origin.
+pub fn snap_to_grid<P: PlanarPoint>(point: &P, spacing: P::Scalar) -> P {
+    let half = P::Scalar::one() / (P::Scalar::one() + P::Scalar::one());
+    let snap = |value: P::Scalar| (value / spacing + half).floor() * spacing;
+    P::from_xy(snap(point.x()), snap(point.y()))
+}
+
+fn square<P: PlanarPoint>(center: &P, radius: P::Scalar) -> Rect<P> {
+    let offset = P::from_xy(radius, radius);
+    Rect::new(center.sub(&offset), center.add(&offset))
+}
+
+fn push_within<P: PlanarPoint>(
+    result: &mut Vec<SnapTarget<P>>,
+    kind: SnapKind<P::Scalar>,
+    target: P,
+    point: &P,
+    radius: P::Scalar,
+) {
+    let distance = target.distance(point);
+    if distance <= radius {
+        result.push(SnapTarget {
+            kind,
+            point: target,
+            distance,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths() -> [ComposedCurve<[f64; 2]>; 2] {
+        let mut horizontal = ComposedCurve::new([0.0, 0.0]);
+        horizontal.line_to([10.0, 0.0]);
+        horizontal.line_to([20.0, 0.0]);
+        let mut vertical = ComposedCurve::new([5.0, -5.0]);
+        vertical.quadratic_to([5.0, 0.0], [5.0, 5.0]);
+        [horizontal, vertical]
+    }
+
+    #[test]
+    fn candidates_are_sorted_by_distance() {
+        let snapper = Snapper::new(&paths(), 1e-9);
+        let candidates = snapper.candidates(&[5.5, 0.2], 1.0);
+        let kinds: Vec<_> = candidates.iter().map(|c| c.kind).collect();
+        assert!(candidates
+            .windows(2)
+            .all(|w| w[0].distance <= w[1].distance));
+        assert!(kinds.contains(&SnapKind::Midpoint {
+            path: 0,
+            segment: 0
+        }));
+        assert!(kinds.contains(&SnapKind::Intersection {
+            path: 0,
+            other_path: 1
+        }));
+        assert!(matches!(
+            candidates[0].kind,
+            SnapKind::OnPath { path: 0, .. }
+        ));
+        assert!((candidates[0].distance - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn nearest_anchor() {
+        let snapper = Snapper::new(&paths(), 1e-9);
+        let target = snapper.nearest_anchor(&[9.0, 1.0], 2.0).unwrap();
+        assert_eq!(target.kind, SnapKind::Anchor { path: 0, anchor: 1 });
+        assert_eq!(target.point, [10.0, 0.0]);
+
+        let target = snapper.nearest_anchor(&[19.0, 0.0], 2.0).unwrap();
+        assert_eq!(target.kind, SnapKind::Anchor { path: 0, anchor: 2 });
+        assert!(snapper.nearest_anchor(&[15.0, 0.0], 2.0).is_none());
+    }
+
+    #[test]
+    fn nearest_on_path() {
+        let snapper = Snapper::new(&paths(), 1e-9);
+        let target = snapper.nearest_on_path(&[5.3, 3.0], 1.0).unwrap();
+        assert!(matches!(target.kind, SnapKind::OnPath { path: 1, .. }));
+        assert!((target.point[0] - 5.0).abs() < 1e-6);
+        assert!((target.point[1] - 3.0).abs() < 1e-6);
+        assert!(snapper.nearest_on_path(&[15.0, 3.0], 1.0).is_none());
+    }
+
+    #[test]
+    fn grid() {
+        assert_eq!(snap_to_grid(&[1.2, -2.6], 0.5), [1.0, -2.5]);
+        let snapper = Snapper::new(&paths(), 1e-9).with_grid(4.0);
+        let candidates = snapper.candidates(&[15.5, 7.5], 1.0);
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].kind, SnapKind::Grid);
+        assert_eq!(candidates[0].point, [16.0, 8.0]);
+    }
+}
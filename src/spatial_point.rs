@@ -0,0 +1,67 @@
+use crate::{InnerProduct, Scalar};
+
+/// A point in 3D space, coordinates and the vector cross product.
+/// It's required by 3D geometry like surface normals and frames along curves.
+pub trait SpatialPoint: InnerProduct {
+    fn from_xyz(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self;
+
+    fn x(&self) -> Self::Scalar;
+    fn y(&self) -> Self::Scalar;
+    fn z(&self) -> Self::Scalar;
+
+    /// Cross product, perpendicular to both vectors, follows the right-hand rule.
+    fn cross(&self, other: &Self) -> Self {
+        Self::from_xyz(
+            self.y() * other.z() - self.z() * other.y(),
+            self.z() * other.x() - self.x() * other.z(),
+            self.x() * other.y() - self.y() * other.x(),
+        )
+    }
+}
+
+impl<F: Scalar> SpatialPoint for [F; 3] {
+    fn from_xyz(x: F, y: F, z: F) -> Self {
+        [x, y, z]
+    }
+
+    fn x(&self) -> F {
+        self[0]
+    }
+
+    fn y(&self) -> F {
+        self[1]
+    }
+
+    fn z(&self) -> F {
+        self[2]
+    }
+}
+
+impl<F: Scalar> SpatialPoint for (F, F, F) {
+    fn from_xyz(x: F, y: F, z: F) -> Self {
+        (x, y, z)
+    }
+
+    fn x(&self) -> F {
+        self.0
+    }
+
+    fn y(&self) -> F {
+        self.1
+    }
+
+    fn z(&self) -> F {
+        self.2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cross_product() {
+        assert_eq!([1.0, 0.0, 0.0].cross(&[0.0, 1.0, 0.0]), [0.0, 0.0, 1.0]);
+        assert_eq!((0.0, 0.0, 1.0).cross(&(0.0, 1.0, 0.0)), (-1.0, 0.0, 0.0));
+    }
+}
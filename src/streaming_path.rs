@@ -0,0 +1,316 @@
+use crate::fit::{fit_points_into, unit};
+use crate::{Bezier, ComposedCurve, Curve, Distance, InnerProduct, Scalar};
+use alloc::vec::Vec;
+use num_traits::{One, Zero};
+
+/// Number of pending points after which they are fitted and committed by default.
+const DEFAULT_CHUNK_SIZE: usize = 32;
+
+/// Path built incrementally from points or segments, e.g. live pen input.
+///
+/// Incoming points are collected in a short pending tail, once there are `chunk_size` of them
+/// the tail is fitted with cubics like [`ComposedCurve::fit_points`] and appended to the committed
+/// path. Committed pieces are never refitted, so every input event costs at most one fit of a
+/// single chunk. A new chunk keeps the end direction of the committed path unless the joint
+/// is a corner, the path stays smooth across chunks.
+///
+/// The arc length of the input is accumulated as it arrives, there's no need to build
+/// a [`LinearSpeed`](crate::LinearSpeed) while drawing.
+#[derive(Clone)]
+pub struct StreamingPath<P: InnerProduct> {
+    committed: Option<ComposedCurve<P>>,
+    pending: Vec<P>,
+    /// The point before the start of `pending`, to detect corners at the joint.
+    previous: Option<P>,
+    length: P::Scalar,
+    max_error: P::Scalar,
+    corner_angle: P::Scalar,
+    chunk_size: usize,
+}
+
+impl<P: InnerProduct> StreamingPath<P> {
+    /// Empty path, `max_error` and `corner_angle` have the same meaning as in
+    /// [`ComposedCurve::fit_points`].
+    pub fn new(max_error: P::Scalar, corner_angle: P::Scalar) -> Self {
+        Self {
+            committed: None,
+            pending: Vec::new(),
+            previous: None,
+            length: P::Scalar::zero(),
+            max_error,
+            corner_angle,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+        }
+    }
+
+    /// Number of pending points that triggers a fit, smaller chunks are cheaper per event
+    /// but give more segments.
+    ///
+    /// # Panics
+    /// If `chunk_size` is less than 2.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        assert!(chunk_size >= 2, "chunk size must be at least 2");
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Append a point, repeated points are ignored.
+    pub fn push_point(&mut self, point: P) {
+        if let Some(last) = self.pending.last() {
+            if *last == point {
+                return;
+            }
+            self.length = self.length + last.distance(&point);
+        }
+        self.pending.push(point);
+        if self.pending.len() >= self.chunk_size {
+            self.commit();
+        }
+    }
+
+    /// Append a segment as is, it starts at the current end point so its own start point
+    /// is only used for an empty path.
+    pub fn push_segment(&mut self, segment: Bezier<P>) {
+        if self.pending.is_empty() {
+            self.pending.push(segment.start_point());
+        }
+        self.commit();
+
+        let start = self.pending[0].clone();
+        let end = segment.end_point();
+        self.length = self.length + segment.estimate_length(self.max_error);
+        self.previous = Some(end.sub(&segment.tangent_at(P::Scalar::one())));
+        let committed = self
+            .committed
+            .get_or_insert_with(|| ComposedCurve::new(start.clone()));
+        committed.push_segment(segment);
+        self.pending = Vec::from([end]);
+    }
+
+    /// Arc length of everything pushed so far, measured along the input polyline
+    /// and the pushed segments.
+    pub fn length(&self) -> P::Scalar {
+        self.length
+    }
+
+    /// The part of the path that's already fitted and won't change anymore.
+    pub fn committed(&self) -> Option<&ComposedCurve<P>> {
+        self.committed.as_ref()
+    }
+
+    /// Points that aren't fitted yet, the first one is the end of the committed path.
+    pub fn pending(&self) -> &[P] {
+        &self.pending
+    }
+
+    /// Fit of the pending points alone, cheap enough to redraw on every input event.
+    pub fn provisional_tail(&self) -> Option<ComposedCurve<P>> {
+        let first = self.pending.first()?.clone();
+        let mut tail = ComposedCurve::new(first);
+        self.fit_pending(&mut tail);
+        Some(tail)
+    }
+
+    /// The whole path as it would be finished now, the pending tail may still change.
+    pub fn provisional(&self) -> Option<ComposedCurve<P>> {
+        match &self.committed {
+            Some(committed) => {
+                let mut path = committed.clone();
+                self.fit_pending(&mut path);
+                Some(path)
+            }
+            None => self.provisional_tail(),
+        }
+    }
+
+    /// Fit the pending points and return the complete path, `None` if nothing was pushed.
+    pub fn finish(mut self) -> Option<ComposedCurve<P>> {
+        self.commit();
+        self.committed
+    }
+
+    /// Fit the pending points into the committed path, the last one stays pending
+    /// as the start of the next chunk.
+    fn commit(&mut self) {
+        let Some(last) = self.pending.last().cloned() else {
+            return;
+        };
+        if self.pending.len() >= 2 {
+            let mut committed = match self.committed.take() {
+                Some(committed) => committed,
+                None => ComposedCurve::new(self.pending[0].clone()),
+            };
+            self.fit_pending(&mut committed);
+            self.committed = Some(committed);
+            self.previous = Some(self.pending[self.pending.len() - 2].clone());
+        } else if self.committed.is_none() {
+            // A single point, keep it as a degenerate path so it isn't lost
+            self.committed = Some(ComposedCurve::new(last.clone()));
+        }
+        self.pending.clear();
+        self.pending.push(last);
+    }
+
+    fn fit_pending(&self, path: &mut ComposedCurve<P>) {
+        if self.pending.len() < 2 {
+            return;
+        }
+        let start_tangent = self.joint_tangent(path);
+        fit_points_into(
+            &self.pending,
+            self.max_error,
+            self.corner_angle,
+            start_tangent.as_ref(),
+            path,
+        );
+    }
+
+    /// Direction the committed path ends in, `None` at corners and at the start.
+    fn joint_tangent(&self, path: &ComposedCurve<P>) -> Option<P> {
+        let previous = self.previous.as_ref()?;
+        let last_segment = path.segments().last()?;
+        let incoming = self.pending[0].sub(previous);
+        let outgoing = self.pending[1].sub(&self.pending[0]);
+        let corner_cos = self.corner_angle.cos();
+        if incoming.dot(&outgoing) < corner_cos * incoming.length() * outgoing.length() {
+            return None;
+        }
+
+        let tangent = last_segment.tangent_at(P::Scalar::one());
+        if tangent.length() == P::Scalar::zero() {
+            None
+        } else {
+            Some(unit(&tangent))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::distance_to_segment;
+    use crate::Bezier1;
+    use approx::assert_relative_eq;
+
+    fn circle_points(count: usize) -> Vec<[f64; 2]> {
+        (0..count)
+            .map(|i| {
+                let angle = i as f64 / (count - 1) as f64 * core::f64::consts::PI * 1.5;
+                [10.0 * angle.cos(), 10.0 * angle.sin()]
+            })
+            .collect()
+    }
+
+    fn max_deviation(path: &ComposedCurve<[f64; 2]>, points: &[[f64; 2]]) -> f64 {
+        let samples: Vec<_> = (0..=2000)
+            .map(|i| path.value_at(i as f64 / 2000.0))
+            .collect();
+        points
+            .iter()
+            .map(|point| {
+                samples
+                    .windows(2)
+                    .map(|w| distance_to_segment(point, &w[0], &w[1]))
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn tracks_length_incrementally() {
+        let points = circle_points(100);
+        let mut path = StreamingPath::new(0.01, 1.0);
+        let mut expected = 0.0;
+        for (i, point) in points.iter().enumerate() {
+            if i > 0 {
+                expected += points[i - 1].distance(point);
+            }
+            path.push_point(*point);
+            assert_relative_eq!(path.length(), expected, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn commits_in_chunks() {
+        let points = circle_points(100);
+        let mut path = StreamingPath::new(0.01, 1.0).with_chunk_size(10);
+        for point in &points[..25] {
+            path.push_point(*point);
+        }
+        assert!(path.committed().is_some());
+        assert!(path.pending().len() < 10);
+        assert_eq!(path.pending()[0], path.committed().unwrap().value_at(1.0));
+    }
+
+    #[test]
+    fn finished_path_follows_points() {
+        let points = circle_points(200);
+        let mut path = StreamingPath::new(0.01, 1.0).with_chunk_size(16);
+        for point in &points {
+            path.push_point(*point);
+        }
+        let provisional = path.provisional().unwrap();
+        let finished = path.finish().unwrap();
+        assert!(provisional == finished);
+        assert!(max_deviation(&finished, &points) < 0.02);
+        assert_eq!(finished.value_at(0.0), points[0]);
+        assert_eq!(finished.value_at(1.0), points[points.len() - 1]);
+    }
+
+    #[test]
+    fn smooth_across_chunks() {
+        let points = circle_points(200);
+        let mut path = StreamingPath::new(0.01, 1.0).with_chunk_size(16);
+        for point in &points {
+            path.push_point(*point);
+        }
+        let finished = path.finish().unwrap();
+        for pair in finished.segments().windows(2) {
+            let a = pair[0].tangent_at(1.0);
+            let b = pair[1].tangent_at(0.0);
+            let cos = a.dot(&b) / (a.length() * b.length());
+            assert!(cos > 0.999, "{cos}");
+        }
+    }
+
+    #[test]
+    fn keeps_corners_between_chunks() {
+        let mut path = StreamingPath::new(0.01, 1.0).with_chunk_size(5);
+        for i in 0..=4 {
+            path.push_point([i as f64, 0.0]);
+        }
+        for i in 1..=4 {
+            path.push_point([4.0, i as f64]);
+        }
+        let finished = path.finish().unwrap();
+        assert_relative_eq!(finished.value_at(0.5)[0], 4.0, epsilon = 1e-9);
+        assert!(max_deviation(&finished, &[[2.0, 0.0], [4.0, 2.0]]) < 1e-9);
+    }
+
+    #[test]
+    fn mixes_points_and_segments() {
+        let mut path = StreamingPath::new(0.01, 1.0);
+        path.push_point([0.0, 0.0]);
+        path.push_point([1.0, 0.0]);
+        path.push_segment(Bezier::C1(Bezier1::new([1.0, 0.0], [1.0, 2.0])));
+        path.push_point([2.0, 2.0]);
+        assert_relative_eq!(path.length(), 4.0, epsilon = 1e-9);
+
+        let finished = path.finish().unwrap();
+        assert_eq!(finished.segments().len(), 3);
+        assert_eq!(finished.value_at(1.0), [2.0, 2.0]);
+    }
+
+    #[test]
+    fn empty_and_single_point() {
+        let path = StreamingPath::<[f64; 2]>::new(0.01, 1.0);
+        assert!(path.provisional().is_none());
+        assert!(path.finish().is_none());
+
+        let mut path = StreamingPath::new(0.01, 1.0);
+        path.push_point([1.0, 2.0]);
+        assert_eq!(path.length(), 0.0);
+        let finished = path.finish().unwrap();
+        assert!(finished.segments().is_empty());
+    }
+}
@@ -0,0 +1,597 @@
+use crate::{ComposedCurve, Curve, Distance, Path, PlanarPoint, Rect, Scalar};
+use alloc::vec::Vec;
+use num_traits::{NumCast, One, ToPrimitive, Zero};
+
+/// Upper limit for the number of points approximating a round join or a half of a round cap.
+const MAX_ARC_STEPS: usize = 64;
+
+/// Shape of the outer corner where two segments of a stroke meet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum LineJoin {
+    /// Sharp corner, replaced by a bevel when it's longer than the miter limit.
+    #[default]
+    Miter,
+    Round,
+    Bevel,
+}
+
+/// Shape of the ends of an open stroke.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum LineCap {
+    /// The stroke ends exactly at the end point.
+    #[default]
+    Butt,
+    /// The stroke is extended by half of its width.
+    Square,
+    Round,
+}
+
+/// Vertices of a triangle strip, every three consecutive vertices form a triangle.
+/// Attributes are stored in separate buffers with one entry per vertex.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StrokeStrip {
+    /// Interleaved `x, y` coordinates.
+    pub positions: Vec<f32>,
+    /// Interleaved `x, y` offsets of vertices from the center line in half widths of the stroke,
+    /// zero for vertices on the center line and longer than one at miters.
+    /// They are useful for anti-aliasing and for changing the width in a shader.
+    pub normals: Vec<f32>,
+    /// Distance along the curve, useful for dashes and textures.
+    pub distances: Vec<f32>,
+}
+
+impl StrokeStrip {
+    pub fn len(&self) -> usize {
+        self.distances.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.distances.is_empty()
+    }
+
+    fn push_raw(&mut self, position: [f32; 2], normal: [f32; 2], distance: f32) {
+        self.positions.extend(position);
+        self.normals.extend(normal);
+        self.distances.push(distance);
+    }
+
+    fn vertex(&self, i: usize) -> ([f32; 2], [f32; 2], f32) {
+        let position = [self.positions[2 * i], self.positions[2 * i + 1]];
+        let normal = [self.normals[2 * i], self.normals[2 * i + 1]];
+        (position, normal, self.distances[i])
+    }
+
+    /// Append another strip, connecting them with degenerate triangles.
+    fn append(&mut self, other: StrokeStrip) {
+        if !self.is_empty() && !other.is_empty() {
+            let (position, normal, distance) = self.vertex(self.len() - 1);
+            self.push_raw(position, normal, distance);
+            let (position, normal, distance) = other.vertex(0);
+            self.push_raw(position, normal, distance);
+        }
+        self.positions.extend(other.positions);
+        self.normals.extend(other.normals);
+        self.distances.extend(other.distances);
+    }
+}
+
+impl<P: PlanarPoint> ComposedCurve<P> {
+    /// Triangle strip covering the stroke of the curve, built directly from the flattened curve
+    /// without constructing the outline. Overlapping triangles at the inner sides of corners
+    /// are fine for opaque strokes. A curve ending at its start point is stroked as closed, without caps.
+    ///
+    /// `join` and `miter_limit`, the longest miter relative to the width like in SVG, apply where
+    /// segments meet. Vertices of the flattening inside segments are rounded, so cusps don't
+    /// produce spikes and the strip stays within [`stroke_bounds`](Self::stroke_bounds) up to `tolerance`.
+    pub fn tessellate_stroke(
+        &self,
+        width: P::Scalar,
+        join: LineJoin,
+        miter_limit: P::Scalar,
+        cap: LineCap,
+        tolerance: P::Scalar,
+    ) -> StrokeStrip {
+        let half_width = width / (P::Scalar::one() + P::Scalar::one());
+        let stroker = Stroker {
+            half_width,
+            join,
+            miter_limit,
+            smooth_miter_limit: P::Scalar::one() + tolerance / half_width,
+            arc_step: arc_step(half_width, tolerance),
+            strip: StrokeStrip::default(),
+        };
+        stroker.stroke(self, cap, tolerance)
+    }
+}
+
+impl<P: PlanarPoint> ComposedCurve<P> {
+    /// Bounds of the stroke of the curve without building it: tight bounds of the curve grown
+    /// by half of the width, plus miter tips at the joints and square caps at the open ends.
+    /// `miter_limit` is the longest miter relative to the width, like in SVG. The caps are
+    /// assumed to be square, the largest ones, so round and butt caps give slightly larger bounds.
+    pub fn stroke_bounds(
+        &self,
+        width: P::Scalar,
+        join: LineJoin,
+        miter_limit: P::Scalar,
+    ) -> Rect<P> {
+        let (zero, one) = (P::Scalar::zero(), P::Scalar::one());
+        let half_width = width / (one + one);
+        let start = self.start_point();
+        let mut bounds = Rect::new(start.clone(), start);
+        for segment in self.segments() {
+            bounds = segment
+                .monotone_parameters()
+                .into_iter()
+                .map(|t| segment.value_at(t))
+                .chain([segment.start_point(), segment.end_point()])
+                .fold(bounds, |bounds, p| bounds.union(&Rect::new(p.clone(), p)));
+        }
+        bounds = bounds.inflate(half_width);
+
+        let segments = self.segments();
+        let closed = !segments.is_empty() && self.start_point() == self.end_point();
+        let direction = |p: P| {
+            let length = p.length();
+            (length > zero).then(|| p.scale(one / length))
+        };
+        let extend = |bounds: &mut Rect<P>, p: P| *bounds = bounds.union(&Rect::new(p.clone(), p));
+
+        if join == LineJoin::Miter {
+            let joints = segments.len().saturating_sub(1) + closed as usize;
+            for i in 0..joints {
+                let (before, after) = (&segments[i], &segments[(i + 1) % segments.len()]);
+                let directions = (
+                    direction(before.tangent_at(one)),
+                    direction(after.tangent_at(zero)),
+                );
+                let (Some(before_direction), Some(after_direction)) = directions else {
+                    continue;
+                };
+                // The same test as in the tessellation, the miter length relative to the width is 2 / |sum|
+                let sum = before_direction
+                    .perpendicular()
+                    .add(&after_direction.perpendicular());
+                let sum_squared = sum.length_squared();
+                if sum_squared * miter_limit * miter_limit < one + one + one + one {
+                    continue;
+                }
+                let miter = sum.scale(half_width * (one + one) / sum_squared);
+                let outer = if before_direction.cross(&after_direction) > zero {
+                    miter.scale(-one)
+                } else {
+                    miter
+                };
+                extend(&mut bounds, before.end_point().add(&outer));
+            }
+        }
+
+        if !closed && !segments.is_empty() {
+            let ends = [
+                (
+                    self.start_point(),
+                    direction(segments[0].tangent_at(zero).scale(-one)),
+                ),
+                (
+                    self.end_point(),
+                    direction(segments[segments.len() - 1].tangent_at(one)),
+                ),
+            ];
+            for (point, outwards) in ends {
+                if let Some(outwards) = outwards {
+                    let normal = outwards.perpendicular().scale(half_width);
+                    let center = point.add(&outwards.scale(half_width));
+                    extend(&mut bounds, center.add(&normal));
+                    extend(&mut bounds, center.sub(&normal));
+                }
+            }
+        }
+        bounds
+    }
+}
+
+impl<P: PlanarPoint> Path<P> {
+    /// Bounds of the strokes of all the contours, `None` for a path without contours.
+    /// See [`ComposedCurve::stroke_bounds`].
+    pub fn stroke_bounds(
+        &self,
+        width: P::Scalar,
+        join: LineJoin,
+        miter_limit: P::Scalar,
+    ) -> Option<Rect<P>> {
+        self.contours()
+            .iter()
+            .map(|contour| contour.stroke_bounds(width, join, miter_limit))
+            .reduce(|a, b| a.union(&b))
+    }
+
+    /// Triangle strip covering strokes of all the contours, they are connected with degenerate triangles.
+    /// See [`ComposedCurve::tessellate_stroke`].
+    pub fn tessellate_stroke(
+        &self,
+        width: P::Scalar,
+        join: LineJoin,
+        miter_limit: P::Scalar,
+        cap: LineCap,
+        tolerance: P::Scalar,
+    ) -> StrokeStrip {
+        let mut strip = StrokeStrip::default();
+        for contour in self.contours() {
+            strip.append(contour.tessellate_stroke(width, join, miter_limit, cap, tolerance));
+        }
+        strip
+    }
+}
+
+/// The largest angle of an arc step keeping the chord closer than `tolerance` to the arc.
+fn arc_step<F: Scalar>(radius: F, tolerance: F) -> F {
+    let ratio = (F::one() - tolerance / radius).max(F::zero());
+    let min_step = F::pi() / NumCast::from(2 * MAX_ARC_STEPS).unwrap();
+    ((ratio.acos()) * (F::one() + F::one())).max(min_step)
+}
+
+/// A vertex of the flattened curve.
+struct Vertex<P: PlanarPoint> {
+    point: P,
+    distance: P::Scalar,
+    /// Segments meet here, so the join style is applied.
+    is_joint: bool,
+}
+
+struct Stroker<P: PlanarPoint> {
+    half_width: P::Scalar,
+    join: LineJoin,
+    miter_limit: P::Scalar,
+    /// Miter limit of the flattening vertices, their miters are at most `tolerance` longer than the round join.
+    smooth_miter_limit: P::Scalar,
+    arc_step: P::Scalar,
+    strip: StrokeStrip,
+}
+
+impl<P: PlanarPoint> Stroker<P> {
+    fn stroke(
+        mut self,
+        curve: &ComposedCurve<P>,
+        cap: LineCap,
+        tolerance: P::Scalar,
+    ) -> StrokeStrip {
+        let mut vertices: Vec<Vertex<P>> = Vec::new();
+        for segment in curve.segments() {
+            // The last point of the previous segment is where they meet
+            if let Some(last) = vertices.last_mut() {
+                last.is_joint = true;
+            }
+            for point in segment.flatten(tolerance) {
+                let distance = match vertices.last() {
+                    Some(last) if last.point == point => continue,
+                    Some(last) => last.distance + last.point.distance(&point),
+                    None => P::Scalar::zero(),
+                };
+                vertices.push(Vertex {
+                    point,
+                    distance,
+                    is_joint: false,
+                });
+            }
+        }
+        if vertices.len() < 2 {
+            return self.strip;
+        }
+
+        let closed = vertices.len() > 2 && curve.start_point() == curve.end_point();
+        let length = vertices[vertices.len() - 1].distance;
+        if closed {
+            vertices.pop();
+            vertices[0].is_joint = true;
+        }
+        let n = vertices.len();
+        let directions: Vec<P> = (0..n)
+            .map(|i| unit(&vertices[(i + 1) % n].point.sub(&vertices[i].point)))
+            .collect();
+
+        if closed {
+            for i in 0..n {
+                self.join(&vertices[i], &directions[(i + n - 1) % n], &directions[i]);
+            }
+            // The last segment ends where the strip started, at the full length
+            for i in 0..2 {
+                let (position, normal, _) = self.strip.vertex(i);
+                self.strip
+                    .push_raw(position, normal, length.to_f32().unwrap());
+            }
+        } else {
+            let (first, last) = (&vertices[0], &vertices[n - 1]);
+            self.cap(first, &directions[0], cap, true);
+            self.pair(&first.point, &directions[0].perpendicular(), first.distance);
+            for i in 1..n - 1 {
+                self.join(&vertices[i], &directions[i - 1], &directions[i]);
+            }
+            self.pair(
+                &last.point,
+                &directions[n - 2].perpendicular(),
+                last.distance,
+            );
+            self.cap(last, &directions[n - 2], cap, false);
+        }
+        self.strip
+    }
+
+    fn push(&mut self, center: &P, offset: &P, distance: P::Scalar) {
+        let f = |v: P::Scalar| v.to_f32().unwrap();
+        let position = center.add(&offset.scale(self.half_width));
+        self.strip.push_raw(
+            [f(position.x()), f(position.y())],
+            [f(offset.x()), f(offset.y())],
+            f(distance),
+        );
+    }
+
+    /// Vertices at `offset` on both sides of the center line.
+    fn pair(&mut self, center: &P, offset: &P, distance: P::Scalar) {
+        self.push(center, offset, distance);
+        self.push(center, &offset.scale(-P::Scalar::one()), distance);
+    }
+
+    fn join(&mut self, vertex: &Vertex<P>, before: &P, after: &P) {
+        let (one, zero) = (P::Scalar::one(), P::Scalar::zero());
+        let (normal_before, normal_after) = (before.perpendicular(), after.perpendicular());
+        let sum = normal_before.add(&normal_after);
+        let sum_squared = sum.length_squared();
+        let (join, miter_limit) = match (vertex.is_joint, self.join) {
+            (true, LineJoin::Miter) => (LineJoin::Miter, Some(self.miter_limit)),
+            (true, join) => (join, None),
+            // Flattening vertices are rounded, with a cheaper miter for gentle turns
+            (false, _) => (LineJoin::Round, Some(self.smooth_miter_limit)),
+        };
+
+        // The miter length relative to the width is 2 / |sum|
+        if let Some(limit) = miter_limit {
+            if sum_squared * limit * limit >= one + one + one + one {
+                let miter = sum.scale((one + one) / sum_squared);
+                self.pair(&vertex.point, &miter, vertex.distance);
+                return;
+            }
+        }
+
+        let (center, distance) = (&vertex.point, vertex.distance);
+        let turns_left = before.cross(after) > zero;
+        self.pair(center, &normal_before, distance);
+        let outer_before = if turns_left {
+            normal_before.scale(-one)
+        } else {
+            normal_before.clone()
+        };
+        let outer_after = if turns_left {
+            normal_after.scale(-one)
+        } else {
+            normal_after.clone()
+        };
+        let angle = outer_before
+            .cross(&outer_after)
+            .atan2(outer_before.dot(&outer_after));
+        let steps = if join == LineJoin::Round {
+            self.steps(angle.abs())
+        } else {
+            1
+        };
+        let zero_offset = P::from_xy(zero, zero);
+        for k in 0..=steps {
+            let k: P::Scalar = NumCast::from(k).unwrap();
+            let n: P::Scalar = NumCast::from(steps).unwrap();
+            let outer = rotate(&outer_before, angle * k / n);
+            if turns_left {
+                self.push(center, &zero_offset, distance);
+                self.push(center, &outer, distance);
+            } else {
+                self.push(center, &outer, distance);
+                self.push(center, &zero_offset, distance);
+            }
+        }
+        self.pair(center, &normal_after, distance);
+    }
+
+    /// Vertices extending the stroke over its end, before the first pair if `is_start` is set,
+    /// or after the last one otherwise.
+    fn cap(&mut self, vertex: &Vertex<P>, direction: &P, cap: LineCap, is_start: bool) {
+        let one = P::Scalar::one();
+        let normal = direction.perpendicular();
+        let outwards = if is_start {
+            direction.scale(-one)
+        } else {
+            direction.clone()
+        };
+        match cap {
+            LineCap::Butt => {}
+            LineCap::Square => {
+                self.pair(
+                    &vertex.point.add(&outwards.scale(self.half_width)),
+                    &normal,
+                    vertex.distance,
+                );
+            }
+            LineCap::Round => {
+                let right_angle = P::Scalar::pi() / (one + one);
+                let steps = self.steps(right_angle);
+                let n: P::Scalar = NumCast::from(steps).unwrap();
+                for k in 0..steps {
+                    // From the tip of the cap to its sides, or back for the end cap
+                    let k = if is_start { k } else { steps - 1 - k };
+                    let angle = right_angle * NumCast::from(k).map(|k: P::Scalar| k / n).unwrap();
+                    let along = outwards.scale(angle.cos());
+                    self.pair_around(
+                        &vertex.point,
+                        &along,
+                        &normal.scale(angle.sin()),
+                        vertex.distance,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Vertices at `along ± across` from the center.
+    fn pair_around(&mut self, center: &P, along: &P, across: &P, distance: P::Scalar) {
+        self.push(center, &along.add(across), distance);
+        self.push(center, &along.sub(across), distance);
+    }
+
+    /// Number of steps approximating an arc of the given angle.
+    fn steps(&self, angle: P::Scalar) -> usize {
+        (angle / self.arc_step)
+            .ceil()
+            .to_usize()
+            .unwrap_or(1)
+            .clamp(1, MAX_ARC_STEPS)
+    }
+}
+
+fn unit<P: PlanarPoint>(v: &P) -> P {
+    v.scale(P::Scalar::one() / v.length())
+}
+
+fn rotate<P: PlanarPoint>(v: &P, angle: P::Scalar) -> P {
+    let (sin, cos) = (angle.sin(), angle.cos());
+    P::from_xy(v.x() * cos - v.y() * sin, v.x() * sin + v.y() * cos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn corner() -> ComposedCurve<[f64; 2]> {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([10.0, 0.0]);
+        curve.line_to([10.0, 10.0]);
+        curve
+    }
+
+    /// Check that `point` is covered by a triangle of the strip.
+    fn covers(strip: &StrokeStrip, point: [f32; 2]) -> bool {
+        let p = |i: usize| [strip.positions[2 * i], strip.positions[2 * i + 1]];
+        let side = |a: [f32; 2], b: [f32; 2]| {
+            (b[0] - a[0]) * (point[1] - a[1]) - (b[1] - a[1]) * (point[0] - a[0])
+        };
+        (0..strip.len().saturating_sub(2)).any(|i| {
+            let (a, b, c) = (p(i), p(i + 1), p(i + 2));
+            let (s0, s1, s2) = (side(a, b), side(b, c), side(c, a));
+            let inside =
+                (s0 >= 0.0 && s1 >= 0.0 && s2 >= 0.0) || (s0 <= 0.0 && s1 <= 0.0 && s2 <= 0.0);
+            inside && (s0 != 0.0 || s1 != 0.0 || s2 != 0.0)
+        })
+    }
+
+    #[test]
+    fn joins() {
+        let curve = corner();
+        let miter = curve.tessellate_stroke(2.0, LineJoin::Miter, 4.0, LineCap::Butt, 0.01);
+        assert!(covers(&miter, [10.9, -0.9]));
+        assert!(covers(&miter, [5.0, 0.5]));
+        assert!(covers(&miter, [10.5, 5.0]));
+        assert!(!covers(&miter, [5.0, 1.5]));
+
+        let bevel = curve.tessellate_stroke(2.0, LineJoin::Bevel, 4.0, LineCap::Butt, 0.01);
+        assert!(!covers(&bevel, [10.9, -0.9]));
+        assert!(covers(&bevel, [10.4, -0.4]));
+
+        let round = curve.tessellate_stroke(2.0, LineJoin::Round, 4.0, LineCap::Butt, 0.01);
+        assert!(covers(&round, [10.65, -0.65]));
+        assert!(!covers(&round, [10.8, -0.8]));
+
+        // The miter of a sharp turn is about 10 widths long
+        let mut sharp = ComposedCurve::new([0.0, 0.0]);
+        sharp.line_to([10.0, 0.0]);
+        sharp.line_to([0.0, 2.0]);
+        let long = sharp.tessellate_stroke(2.0, LineJoin::Miter, 20.0, LineCap::Butt, 0.01);
+        assert!(covers(&long, [15.0, 0.0]));
+        let limited = sharp.tessellate_stroke(2.0, LineJoin::Miter, 4.0, LineCap::Butt, 0.01);
+        assert!(!covers(&limited, [15.0, 0.0]));
+    }
+
+    #[test]
+    fn cusp() {
+        // Flattening vertices at the cusp turn sharply, they must not get miter spikes
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.cubic_to([10.0, 10.0], [1.0, 10.0], [10.0, 0.0]);
+        let bounds = curve.stroke_bounds(2.0, LineJoin::Round, 4.0).inflate(0.01);
+        for join in [LineJoin::Miter, LineJoin::Round, LineJoin::Bevel] {
+            let strip = curve.tessellate_stroke(2.0, join, 4.0, LineCap::Square, 0.01);
+            for i in 0..strip.len() {
+                let (position, _, _) = strip.vertex(i);
+                assert!(bounds.contains(&[position[0] as f64, position[1] as f64]));
+            }
+        }
+    }
+
+    #[test]
+    fn caps() {
+        let curve = corner();
+        let butt = curve.tessellate_stroke(2.0, LineJoin::Miter, 4.0, LineCap::Butt, 0.01);
+        assert!(!covers(&butt, [-0.5, 0.0]));
+        let square = curve.tessellate_stroke(2.0, LineJoin::Miter, 4.0, LineCap::Square, 0.01);
+        assert!(covers(&square, [-0.9, 0.9]));
+        let round = curve.tessellate_stroke(2.0, LineJoin::Miter, 4.0, LineCap::Round, 0.01);
+        assert!(covers(&round, [-0.9, 0.0]));
+        assert!(!covers(&round, [-0.9, 0.9]));
+        assert!(covers(&round, [10.0, 10.9]));
+    }
+
+    #[test]
+    fn attributes() {
+        let strip = corner().tessellate_stroke(2.0, LineJoin::Round, 4.0, LineCap::Round, 0.01);
+        assert_eq!(strip.positions.len(), 2 * strip.len());
+        assert_eq!(strip.normals.len(), 2 * strip.len());
+        assert_relative_eq!(strip.distances[0], 0.0);
+        assert_relative_eq!(strip.distances[strip.len() - 1], 20.0);
+    }
+
+    #[test]
+    fn closed() {
+        let mut square = corner();
+        square.line_to([0.0, 10.0]);
+        square.close();
+        let strip = square.tessellate_stroke(2.0, LineJoin::Miter, 4.0, LineCap::Round, 0.01);
+        assert!(covers(&strip, [-0.9, -0.9]));
+        assert!(covers(&strip, [5.0, -0.5]));
+        assert!(!covers(&strip, [5.0, 5.0]));
+        assert_eq!(
+            strip.positions[..4],
+            strip.positions[strip.positions.len() - 4..]
+        );
+        assert_relative_eq!(strip.distances[strip.len() - 1], 40.0);
+    }
+
+    #[test]
+    fn stroke_bounds() {
+        // The bounds contain the tessellated stroke
+        let strip = corner().tessellate_stroke(2.0, LineJoin::Miter, 4.0, LineCap::Square, 0.01);
+        let bounds = corner().stroke_bounds(2.0, LineJoin::Miter, 4.0);
+        assert_eq!(bounds, Rect::new([-1.0, -1.0], [11.0, 11.0]));
+        for i in 0..strip.len() {
+            let (position, _, _) = strip.vertex(i);
+            assert!(bounds.contains(&[position[0] as f64, position[1] as f64]));
+        }
+
+        // A sharp turn has a long miter, unless it's over the limit
+        let mut sharp = ComposedCurve::new([0.0, 0.0]);
+        sharp.line_to([10.0, 0.0]);
+        sharp.line_to([0.0, 2.0]);
+        let miter = sharp.stroke_bounds(2.0, LineJoin::Miter, 20.0);
+        assert!(miter.max[0] > 15.0);
+        let limited = sharp.stroke_bounds(2.0, LineJoin::Miter, 4.0);
+        let bevel = sharp.stroke_bounds(2.0, LineJoin::Bevel, 20.0);
+        assert_eq!(limited, bevel);
+        assert_relative_eq!(bevel.max[0], 11.0);
+
+        // Curves are bounded by their extrema, not by the control points
+        let mut arch = ComposedCurve::new([0.0, 0.0]);
+        arch.quadratic_to([5.0, 10.0], [10.0, 0.0]);
+        arch.close();
+        let bounds = arch.stroke_bounds(2.0, LineJoin::Round, 4.0);
+        assert_relative_eq!(bounds.max[1], 6.0);
+        assert_eq!(
+            Path::from(arch).stroke_bounds(2.0, LineJoin::Round, 4.0),
+            Some(bounds)
+        );
+    }
+}
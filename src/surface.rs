@@ -0,0 +1,314 @@
+#[cfg(feature = "alloc")]
+use crate::Scalar;
+use crate::{Bezier3, Curve, Point, SpatialPoint};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::array;
+#[cfg(feature = "alloc")]
+use num_traits::{NumCast, ToPrimitive};
+use num_traits::{One, Zero};
+
+/// Parametric surface, `u` and `v` are in the `0..=1` range.
+pub trait Surface<P: Point> {
+    fn value_at(&self, u: P::Scalar, v: P::Scalar) -> P;
+
+    /// Partial derivative by `u`.
+    fn derivative_u(&self, u: P::Scalar, v: P::Scalar) -> P;
+
+    /// Partial derivative by `v`.
+    fn derivative_v(&self, u: P::Scalar, v: P::Scalar) -> P;
+
+    /// Unit normal, the cross product of the partial derivatives.
+    /// It's zero at degenerate points, e.g. at a collapsed edge of a patch.
+    fn normal_at(&self, u: P::Scalar, v: P::Scalar) -> P
+    where
+        P: SpatialPoint,
+    {
+        let normal = self.derivative_u(u, v).cross(&self.derivative_v(u, v));
+        let length = normal.length();
+        if length > P::Scalar::zero() {
+            normal.scale(P::Scalar::one() / length)
+        } else {
+            normal
+        }
+    }
+
+    /// Triangle mesh of a regular grid with `u_steps` by `v_steps` cells.
+    ///
+    /// # Panics
+    /// If any of the steps is zero.
+    #[cfg(feature = "alloc")]
+    fn tessellate_grid(&self, u_steps: usize, v_steps: usize) -> SurfaceMesh<P>
+    where
+        P: SpatialPoint,
+    {
+        assert!(u_steps > 0 && v_steps > 0, "steps must be positive");
+        let mut mesh = SurfaceMesh::default();
+        let u_scale: P::Scalar = NumCast::from(u_steps).unwrap();
+        let v_scale: P::Scalar = NumCast::from(v_steps).unwrap();
+        for j in 0..=v_steps {
+            let v = <P::Scalar as NumCast>::from(j).unwrap() / v_scale;
+            for i in 0..=u_steps {
+                let u = <P::Scalar as NumCast>::from(i).unwrap() / u_scale;
+                mesh.positions.push(self.value_at(u, v));
+                mesh.normals.push(self.normal_at(u, v));
+            }
+        }
+        let row = u_steps as u32 + 1;
+        for j in 0..v_steps as u32 {
+            for i in 0..u_steps as u32 {
+                let a = j * row + i;
+                let (b, c, d) = (a + 1, a + row + 1, a + row);
+                mesh.indices.extend([a, b, c, a, c, d]);
+            }
+        }
+        mesh
+    }
+
+    /// Triangle mesh with a grid fine enough for the flat triangles to deviate from
+    /// the surface by about `tolerance`, estimated from the change of derivatives.
+    #[cfg(feature = "alloc")]
+    fn tessellate(&self, tolerance: P::Scalar) -> SurfaceMesh<P>
+    where
+        P: SpatialPoint,
+    {
+        let (u_steps, v_steps) = grid_steps(self, tolerance);
+        self.tessellate_grid(u_steps, v_steps)
+    }
+}
+
+/// Steps along `u` and `v`, the chord of a curve with the second derivative bounded by `m`
+/// deviates from it by at most `m * h^2 / 8`.
+#[cfg(feature = "alloc")]
+fn grid_steps<P: SpatialPoint, S: Surface<P> + ?Sized>(
+    surface: &S,
+    tolerance: P::Scalar,
+) -> (usize, usize) {
+    const SAMPLES: usize = 8;
+    const MAX_STEPS: usize = 1024;
+    let scale: P::Scalar = NumCast::from(SAMPLES).unwrap();
+    let at = |i: usize| <P::Scalar as NumCast>::from(i).unwrap() / scale;
+    let (mut mu, mut mv) = (P::Scalar::zero(), P::Scalar::zero());
+    for i in 0..SAMPLES {
+        for j in 0..=SAMPLES {
+            let du = surface
+                .derivative_u(at(i + 1), at(j))
+                .sub(&surface.derivative_u(at(i), at(j)));
+            let dv = surface
+                .derivative_v(at(j), at(i + 1))
+                .sub(&surface.derivative_v(at(j), at(i)));
+            mu = mu.max(du.length() * scale);
+            mv = mv.max(dv.length() * scale);
+        }
+    }
+    let eight: P::Scalar = NumCast::from(8).unwrap();
+    let steps = |m: P::Scalar| {
+        (m / (eight * tolerance))
+            .sqrt()
+            .ceil()
+            .to_usize()
+            .unwrap_or(MAX_STEPS)
+            .clamp(1, MAX_STEPS)
+    };
+    (steps(mu), steps(mv))
+}
+
+/// Triangles approximating a surface.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SurfaceMesh<P> {
+    pub positions: Vec<P>,
+    /// Unit normals per vertex, zero at degenerate points.
+    pub normals: Vec<P>,
+    /// Three vertex indices per triangle, counter-clockwise around the normal.
+    pub indices: Vec<u32>,
+}
+
+#[cfg(feature = "alloc")]
+impl<P> Default for SurfaceMesh<P> {
+    fn default() -> Self {
+        Self {
+            positions: Vec::new(),
+            normals: Vec::new(),
+            indices: Vec::new(),
+        }
+    }
+}
+
+/// Bicubic bezier patch, `points[i][j]` is the control point `i` along `u` and `j` along `v`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BezierPatch3<P: Point> {
+    pub points: [[P; 4]; 4],
+}
+
+impl<P: Point> BezierPatch3<P> {
+    pub fn new(points: [[P; 4]; 4]) -> Self {
+        Self { points }
+    }
+
+    /// Convert every control point with `f`.
+    pub fn map_points<Q: Point>(&self, mut f: impl FnMut(&P) -> Q) -> BezierPatch3<Q> {
+        BezierPatch3::new(array::from_fn(|i| {
+            array::from_fn(|j| f(&self.points[i][j]))
+        }))
+    }
+
+    /// Isoparametric curve with fixed `v`, parametrized by `u`.
+    pub fn curve_u(&self, v: P::Scalar) -> Bezier3<P> {
+        let [p0, p1, p2, p3] = array::from_fn(|i| row(&self.points[i]).value_at(v));
+        Bezier3::new(p0, p1, p2, p3)
+    }
+
+    /// Isoparametric curve with fixed `u`, parametrized by `v`.
+    pub fn curve_v(&self, u: P::Scalar) -> Bezier3<P> {
+        let [p0, p1, p2, p3] = array::from_fn(|j| {
+            Bezier3::new(
+                self.points[0][j].clone(),
+                self.points[1][j].clone(),
+                self.points[2][j].clone(),
+                self.points[3][j].clone(),
+            )
+            .value_at(u)
+        });
+        Bezier3::new(p0, p1, p2, p3)
+    }
+}
+
+fn row<P: Point>(points: &[P; 4]) -> Bezier3<P> {
+    let [p0, p1, p2, p3] = points.clone();
+    Bezier3::new(p0, p1, p2, p3)
+}
+
+impl<P: Point> Surface<P> for BezierPatch3<P> {
+    fn value_at(&self, u: P::Scalar, v: P::Scalar) -> P {
+        self.curve_u(v).value_at(u)
+    }
+
+    fn derivative_u(&self, u: P::Scalar, v: P::Scalar) -> P {
+        self.curve_u(v).tangent_at(u)
+    }
+
+    fn derivative_v(&self, u: P::Scalar, v: P::Scalar) -> P {
+        self.curve_v(u).tangent_at(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Distance, InnerProduct, SpatialPoint};
+    use approx::assert_relative_eq;
+
+    /// Patch over the `[0, 3] x [0, 3]` square, heights are given per control point.
+    fn patch(heights: [[f64; 4]; 4]) -> BezierPatch3<[f64; 3]> {
+        BezierPatch3::new(array::from_fn(|i| {
+            array::from_fn(|j| [i as f64, j as f64, heights[i][j]])
+        }))
+    }
+
+    #[test]
+    fn flat_patch() {
+        let patch = patch([[0.0; 4]; 4]);
+        let point = patch.value_at(0.5, 0.25);
+        assert_relative_eq!(point[0], 1.5);
+        assert_relative_eq!(point[1], 0.75);
+        assert_relative_eq!(
+            patch.derivative_u(0.3, 0.7).distance(&[3.0, 0.0, 0.0]),
+            0.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            patch.derivative_v(0.3, 0.7).distance(&[0.0, 3.0, 0.0]),
+            0.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            patch.normal_at(0.3, 0.7).distance(&[0.0, 0.0, 1.0]),
+            0.0,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn corners_and_edges() {
+        let mut heights = [[0.0; 4]; 4];
+        heights[1][2] = 4.0;
+        heights[3][3] = 1.0;
+        let patch = patch(heights);
+        assert_eq!(patch.value_at(0.0, 0.0), [0.0, 0.0, 0.0]);
+        assert_eq!(patch.value_at(1.0, 1.0), [3.0, 3.0, 1.0]);
+        assert_eq!(patch.curve_v(1.0), row(&patch.points[3]));
+
+        let point = patch.value_at(0.4, 0.6);
+        assert_relative_eq!(
+            point[0],
+            patch.curve_v(0.4).value_at(0.6)[0],
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            point[2],
+            patch.curve_v(0.4).value_at(0.6)[2],
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn derivatives_match_differences() {
+        let patch = patch([
+            [0.0, 1.0, 0.5, 0.0],
+            [1.0, 2.0, -1.0, 0.0],
+            [0.0, 3.0, 1.0, 2.0],
+            [1.0, 0.0, 0.0, 1.0],
+        ]);
+        let (u, v, h) = (0.3, 0.6, 1e-6);
+        let du = patch
+            .value_at(u + h, v)
+            .sub(&patch.value_at(u - h, v))
+            .scale(0.5 / h);
+        let dv = patch
+            .value_at(u, v + h)
+            .sub(&patch.value_at(u, v - h))
+            .scale(0.5 / h);
+        for k in 0..3 {
+            assert_relative_eq!(patch.derivative_u(u, v)[k], du[k], epsilon = 1e-6);
+            assert_relative_eq!(patch.derivative_v(u, v)[k], dv[k], epsilon = 1e-6);
+        }
+        assert_relative_eq!(patch.normal_at(u, v).length(), 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn grid_mesh() {
+        let mesh = patch([[0.0; 4]; 4]).tessellate_grid(3, 2);
+        assert_eq!(mesh.positions.len(), 12);
+        assert_eq!(mesh.indices.len(), 36);
+        assert_eq!(mesh.positions[11], [3.0, 3.0, 0.0]);
+        assert!(mesh
+            .normals
+            .iter()
+            .all(|n| n.distance(&[0.0, 0.0, 1.0]) < 1e-12));
+        // The first triangle is counter-clockwise around the normal
+        let [a, b, c] = [0, 1, 2].map(|k| mesh.positions[mesh.indices[k] as usize]);
+        assert!(b.sub(&a).cross(&c.sub(&a))[2] > 0.0);
+    }
+
+    #[test]
+    fn tolerance_mesh() {
+        let flat = patch([[0.0; 4]; 4]).tessellate(0.01);
+        assert_eq!(flat.positions.len(), 4);
+
+        let mut heights = [[0.0; 4]; 4];
+        heights[1][1] = 3.0;
+        heights[2][2] = 3.0;
+        let patch = patch(heights);
+        let coarse = patch.tessellate(0.1);
+        let fine = patch.tessellate(0.001);
+        assert!(coarse.positions.len() > 4);
+        assert!(fine.positions.len() > coarse.positions.len());
+    }
+
+    #[test]
+    fn degenerate_normal() {
+        let patch = BezierPatch3::new([[[1.0, 1.0, 1.0]; 4]; 4]);
+        assert_eq!(patch.normal_at(0.5, 0.5), [0.0, 0.0, 0.0]);
+    }
+}
@@ -0,0 +1,320 @@
+use crate::bezier::Bezier;
+use crate::{ComposedCurve, Curve, Path, PlanarPoint, Scalar};
+use alloc::string::String;
+use core::fmt::Write;
+use num_traits::{NumCast, One, ToPrimitive, Zero};
+
+/// Writes SVG path data, keeps track of the current point and the last control point
+/// to emit shorthand commands when it's possible.
+struct SvgWriter<P: PlanarPoint> {
+    output: String,
+    precision: usize,
+    relative: bool,
+    /// Current point, rounded to the output precision
+    current: P,
+    /// The last command letter, it's omitted if the next command is the same
+    command: char,
+    /// The second control point of the last cubic segment
+    cubic_control: Option<P>,
+    /// The control point of the last quadratic segment
+    quadratic_control: Option<P>,
+}
+
+impl<P: PlanarPoint> SvgWriter<P> {
+    fn new(precision: usize, relative: bool) -> Self {
+        Self {
+            output: String::new(),
+            precision,
+            relative,
+            // The initial move is relative to the origin
+            current: P::from_xy(P::Scalar::zero(), P::Scalar::zero()),
+            command: ' ',
+            cubic_control: None,
+            quadratic_control: None,
+        }
+    }
+
+    /// Rounding is done in `f64`, so the scale doesn't overflow for `f32` at a high precision.
+    /// Values that can't be scaled without overflow are already exact at this precision.
+    fn round(&self, v: P::Scalar) -> P::Scalar {
+        let x = v.to_f64().unwrap();
+        let scale = (0..self.precision).fold(1.0f64, |acc, _| acc * 10.0);
+        let scaled = x * scale;
+        if !scaled.is_finite() {
+            return v;
+        }
+        NumCast::from(Scalar::floor(scaled + 0.5) / scale).unwrap_or(v)
+    }
+
+    fn round_point(&self, p: &P) -> P {
+        P::from_xy(self.round(p.x()), self.round(p.y()))
+    }
+
+    /// Points are considered equal if they are the same after rounding.
+    fn same(&self, p1: &P, p2: &P) -> bool {
+        self.round_point(p1) == self.round_point(p2)
+    }
+
+    fn command(&mut self, absolute: char, relative: char) {
+        let c = if self.relative { relative } else { absolute };
+        if c != self.command || c == 'M' || c == 'm' {
+            self.output.push(c);
+            self.command = c;
+        } else {
+            self.separator(false);
+        }
+    }
+
+    fn separator(&mut self, negative: bool) {
+        let last = self.output.chars().last();
+        if !negative && last.is_some_and(|c| c.is_ascii_digit() || c == '.') {
+            self.output.push(' ');
+        }
+    }
+
+    fn number(&mut self, v: P::Scalar) {
+        let v = v.to_f64().unwrap();
+        let mut text = String::new();
+        write!(text, "{:.*}", self.precision, v).unwrap();
+        if text.contains('.') {
+            text.truncate(text.trim_end_matches('0').trim_end_matches('.').len());
+        }
+        if text == "-0" {
+            text = String::from("0");
+        }
+        if let Some(stripped) = text.strip_prefix("0.") {
+            text = String::from(".") + stripped;
+        } else if let Some(stripped) = text.strip_prefix("-0.") {
+            text = String::from("-.") + stripped;
+        }
+
+        let negative = text.starts_with('-');
+        let fraction_after_fraction = text.starts_with('.')
+            && self
+                .output
+                .rsplit(|c: char| !(c.is_ascii_digit() || c == '.'))
+                .next()
+                .is_some_and(|n| n.contains('.'));
+        if fraction_after_fraction {
+            // ".5.5" is a valid pair of numbers
+        } else {
+            self.separator(negative);
+        }
+        self.output.push_str(&text);
+    }
+
+    fn point(&mut self, p: &P) {
+        let p = if self.relative {
+            self.round_point(p).sub(&self.current)
+        } else {
+            p.clone()
+        };
+        self.number(p.x());
+        self.number(p.y());
+    }
+
+    fn line(&mut self, p: &P) {
+        let rounded = self.round_point(p);
+        if rounded.y() == self.current.y() && rounded.x() != self.current.x() {
+            self.command('H', 'h');
+            let x = if self.relative {
+                rounded.x() - self.current.x()
+            } else {
+                p.x()
+            };
+            self.number(x);
+        } else if rounded.x() == self.current.x() && rounded.y() != self.current.y() {
+            self.command('V', 'v');
+            let y = if self.relative {
+                rounded.y() - self.current.y()
+            } else {
+                p.y()
+            };
+            self.number(y);
+        } else {
+            self.command('L', 'l');
+            self.point(p);
+        }
+        self.finish(p, None, None);
+    }
+
+    fn quadratic(&mut self, p1: &P, p2: &P) {
+        let reflected = self.reflect(&self.quadratic_control);
+        if reflected.is_some_and(|r| self.same(&r, p1)) {
+            self.command('T', 't');
+        } else {
+            self.command('Q', 'q');
+            self.point(p1);
+        }
+        self.point(p2);
+        self.finish(p2, None, Some(p1.clone()));
+    }
+
+    fn cubic(&mut self, p1: &P, p2: &P, p3: &P) {
+        let reflected = self.reflect(&self.cubic_control);
+        if reflected.is_some_and(|r| self.same(&r, p1)) {
+            self.command('S', 's');
+        } else {
+            self.command('C', 'c');
+            self.point(p1);
+        }
+        self.point(p2);
+        self.point(p3);
+        self.finish(p3, Some(p2.clone()), None);
+    }
+
+    fn move_to(&mut self, p: &P) {
+        self.command('M', 'm');
+        self.point(p);
+        self.finish(p, None, None);
+    }
+
+    fn contour(&mut self, contour: &ComposedCurve<P>) {
+        let start = contour.start_point();
+        self.move_to(&start);
+
+        let segments = contour.segments();
+        for (i, segment) in segments.iter().enumerate() {
+            match segment {
+                Bezier::C0(_) => {}
+                Bezier::C1(line) => {
+                    let is_last = i + 1 == segments.len();
+                    if is_last && segments.len() > 1 && line.p1 == start {
+                        self.close();
+                    } else {
+                        self.line(&line.p1);
+                    }
+                }
+                Bezier::C2(curve) => self.quadratic(&curve.p1, &curve.p2),
+                Bezier::C3(curve) => self.cubic(&curve.p1, &curve.p2, &curve.p3),
+            }
+        }
+    }
+
+    fn close(&mut self) {
+        self.output.push(if self.relative { 'z' } else { 'Z' });
+        self.command = 'Z';
+    }
+
+    /// Reflection of the control point relative to the current point
+    fn reflect(&self, control: &Option<P>) -> Option<P> {
+        control.as_ref().map(|c| {
+            let two = P::Scalar::one() + P::Scalar::one();
+            self.current.scale(two).sub(c)
+        })
+    }
+
+    fn finish(&mut self, end: &P, cubic_control: Option<P>, quadratic_control: Option<P>) {
+        self.current = self.round_point(end);
+        self.cubic_control = cubic_control;
+        self.quadratic_control = quadratic_control;
+    }
+}
+
+impl<P: PlanarPoint> ComposedCurve<P> {
+    /// Build SVG path data (the `d` attribute) with absolute commands.
+    /// Coordinates are rounded to `precision` digits after the decimal point,
+    /// shorthand commands (`H`, `V`, `S`, `T`, and `Z`) are used where the rounded geometry allows.
+    pub fn to_svg_path(&self, precision: usize) -> String {
+        self.write_svg_path(precision, false)
+    }
+
+    /// The same as [`ComposedCurve::to_svg_path`], but with relative commands,
+    /// which usually results in shorter output.
+    pub fn to_relative_svg_path(&self, precision: usize) -> String {
+        self.write_svg_path(precision, true)
+    }
+
+    fn write_svg_path(&self, precision: usize, relative: bool) -> String {
+        let mut writer = SvgWriter::new(precision, relative);
+        writer.contour(self);
+        writer.output
+    }
+}
+
+impl<P: PlanarPoint> Path<P> {
+    /// Build SVG path data with absolute commands, each contour starts with its own `M` command.
+    /// See [`ComposedCurve::to_svg_path`] for the rounding and shorthand rules.
+    pub fn to_svg_path(&self, precision: usize) -> String {
+        self.write_svg_path(precision, false)
+    }
+
+    /// The same as [`Path::to_svg_path`], but with relative commands.
+    /// The `m` of every contour after the first one is relative to the end of the previous contour.
+    pub fn to_relative_svg_path(&self, precision: usize) -> String {
+        self.write_svg_path(precision, true)
+    }
+
+    fn write_svg_path(&self, precision: usize, relative: bool) -> String {
+        let mut writer = SvgWriter::new(precision, relative);
+        for contour in self.contours() {
+            writer.contour(contour);
+        }
+        writer.output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn absolute_path() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([10.0, 0.0]);
+        curve.line_to([10.0, 10.5]);
+        curve.cubic_to([10.0, 20.0], [0.0, 20.0], [0.0, 10.0]);
+        curve.cubic_to([0.0, 0.0], [5.0, -0.25], [5.0, 5.0]);
+        curve.close();
+
+        assert_eq!(
+            curve.to_svg_path(2),
+            "M0 0H10V10.5C10 20 0 20 0 10S5-.25 5 5Z"
+        );
+    }
+
+    #[test]
+    fn relative_path() {
+        let mut curve = ComposedCurve::new((1.0, 1.0));
+        curve.quadratic_to((2.0, 3.0), (3.0, 1.0));
+        curve.quadratic_to((4.0, -1.0), (5.0, 1.0));
+        curve.line_to((6.0, 2.0));
+
+        assert_eq!(curve.to_relative_svg_path(3), "m1 1q1 2 2 0t2 0l1 1");
+    }
+
+    #[test]
+    fn rounding() {
+        let mut curve = ComposedCurve::new([0.123456, -0.0001]);
+        curve.line_to([1.0 / 3.0, 2.0 / 3.0]);
+
+        assert_eq!(curve.to_svg_path(3), "M.123 0L.333.667");
+    }
+
+    #[test]
+    fn high_precision() {
+        let mut curve = ComposedCurve::new([0.5f32, 1.0]);
+        curve.line_to([1.5, 2.0]);
+
+        assert_eq!(curve.to_relative_svg_path(40), "m.5 1l1 1");
+        assert_eq!(curve.to_relative_svg_path(400), "m.5 1l1 1");
+    }
+
+    #[test]
+    fn path() {
+        let mut path = Path::new();
+        path.move_to([0.0, 0.0]);
+        path.line_to([10.0, 0.0]);
+        path.line_to([0.0, 10.0]);
+        path.close();
+        path.move_to([20.0, 5.0]);
+        path.quadratic_to([25.0, 10.0], [30.0, 5.0]);
+
+        assert_eq!(path.to_svg_path(2), "M0 0H10L0 10ZM20 5Q25 10 30 5");
+        assert_eq!(
+            path.to_relative_svg_path(2),
+            "m0 0h10l-10 10zm20-5q5 5 10 0"
+        );
+        assert_eq!(Path::<[f64; 2]>::new().to_svg_path(2), "");
+    }
+}
@@ -0,0 +1,238 @@
+use crate::frames::{rotation_minimizing_frames, Frame};
+use crate::{Curve, PlanarPoint, Point, Scalar, SpatialPoint, Surface};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use num_traits::{NumCast, One, ToPrimitive, Zero};
+
+/// Surface swept by a planar profile moving along a path, see [`sweep`].
+#[derive(Clone, Debug)]
+pub struct Sweep<P: SpatialPoint, Q: PlanarPoint<Scalar = P::Scalar>, S: Curve<Q>, C: Curve<P>> {
+    profile: S,
+    path: C,
+    frames: Vec<Frame<P>>,
+    _profile_point: PhantomData<Q>,
+}
+
+/// Sweeps the `profile` along the `path`: the `u` parameter goes along the profile, `v` along the path.
+///
+/// Profile `x` and `y` coordinates are measured along the normal and the binormal of
+/// `frames` rotation minimizing frames of the path, the frames are linearly interpolated
+/// in between, so more frames give a more precise surface.
+///
+/// # Panics
+/// If `frames` is less than 2.
+pub fn sweep<P, Q, S, C>(profile: S, path: C, frames: usize) -> Sweep<P, Q, S, C>
+where
+    P: SpatialPoint,
+    Q: PlanarPoint<Scalar = P::Scalar>,
+    S: Curve<Q>,
+    C: Curve<P>,
+{
+    let frames = rotation_minimizing_frames(&path, frames);
+    Sweep {
+        profile,
+        path,
+        frames,
+        _profile_point: PhantomData,
+    }
+}
+
+impl<P, Q, S, C> Sweep<P, Q, S, C>
+where
+    P: SpatialPoint,
+    Q: PlanarPoint<Scalar = P::Scalar>,
+    S: Curve<Q>,
+    C: Curve<P>,
+{
+    pub fn profile(&self) -> &S {
+        &self.profile
+    }
+
+    pub fn path(&self) -> &C {
+        &self.path
+    }
+
+    pub fn frames(&self) -> &[Frame<P>] {
+        &self.frames
+    }
+
+    /// Frames around `v` and the interpolation factor between them.
+    fn frames_at(&self, v: P::Scalar) -> (&Frame<P>, &Frame<P>, P::Scalar) {
+        let last = self.frames.len() - 1;
+        let scale: P::Scalar = NumCast::from(last).unwrap();
+        let x = v.clamp(P::Scalar::zero(), P::Scalar::one()) * scale;
+        let i = x.floor().to_usize().unwrap().min(last - 1);
+        let s = x - NumCast::from(i).unwrap();
+        (&self.frames[i], &self.frames[i + 1], s)
+    }
+}
+
+impl<P, Q, S, C> Surface<P> for Sweep<P, Q, S, C>
+where
+    P: SpatialPoint,
+    Q: PlanarPoint<Scalar = P::Scalar>,
+    S: Curve<Q>,
+    C: Curve<P>,
+{
+    fn value_at(&self, u: P::Scalar, v: P::Scalar) -> P {
+        let (a, b, s) = self.frames_at(v);
+        let p = self.profile.value_at(u);
+        let normal = a.normal.lerp(&b.normal, s);
+        let binormal = a.binormal.lerp(&b.binormal, s);
+        self.path
+            .value_at(v)
+            .add(&normal.scale(p.x()))
+            .add(&binormal.scale(p.y()))
+    }
+
+    fn derivative_u(&self, u: P::Scalar, v: P::Scalar) -> P {
+        let (a, b, s) = self.frames_at(v);
+        let d = self.profile.tangent_at(u);
+        let normal = a.normal.lerp(&b.normal, s);
+        let binormal = a.binormal.lerp(&b.binormal, s);
+        normal.scale(d.x()).add(&binormal.scale(d.y()))
+    }
+
+    fn derivative_v(&self, u: P::Scalar, v: P::Scalar) -> P {
+        let (a, b, _) = self.frames_at(v);
+        let p = self.profile.value_at(u);
+        let scale: P::Scalar = NumCast::from(self.frames.len() - 1).unwrap();
+        let normal = b.normal.sub(&a.normal).scale(scale);
+        let binormal = b.binormal.sub(&a.binormal).scale(scale);
+        self.path
+            .tangent_at(v)
+            .add(&normal.scale(p.x()))
+            .add(&binormal.scale(p.y()))
+    }
+}
+
+/// Surface of a curve moved along a vector, see [`extrude`].
+#[derive(Clone, Debug)]
+pub struct Extrusion<P: Point, C: Curve<P>> {
+    curve: C,
+    direction: P,
+}
+
+/// Extrudes the `curve` along `direction`: the `u` parameter goes along the curve,
+/// `v` from the curve to the curve moved by `direction`.
+pub fn extrude<P: Point, C: Curve<P>>(curve: C, direction: P) -> Extrusion<P, C> {
+    Extrusion { curve, direction }
+}
+
+impl<P: Point, C: Curve<P>> Extrusion<P, C> {
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+
+    pub fn direction(&self) -> &P {
+        &self.direction
+    }
+}
+
+impl<P: Point, C: Curve<P>> Surface<P> for Extrusion<P, C> {
+    fn value_at(&self, u: P::Scalar, v: P::Scalar) -> P {
+        self.curve.value_at(u).add(&self.direction.scale(v))
+    }
+
+    fn derivative_u(&self, u: P::Scalar, _v: P::Scalar) -> P {
+        self.curve.tangent_at(u)
+    }
+
+    fn derivative_v(&self, _u: P::Scalar, _v: P::Scalar) -> P {
+        self.direction.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bezier1, Bezier3, Distance, InnerProduct};
+    use approx::assert_relative_eq;
+
+    /// Square profile around the path.
+    fn square() -> crate::ComposedCurve<[f64; 2]> {
+        let mut profile = crate::ComposedCurve::new([-1.0, -1.0]);
+        profile.line_to([1.0, -1.0]);
+        profile.line_to([1.0, 1.0]);
+        profile.line_to([-1.0, 1.0]);
+        profile.close();
+        profile
+    }
+
+    #[test]
+    fn tube_along_line() {
+        let path = Bezier1::new([0.0, 0.0, 0.0], [0.0, 0.0, 10.0]);
+        let tube = sweep(square(), path, 4);
+        for (u, v) in [(0.0, 0.0), (0.3, 0.5), (0.9, 1.0)] {
+            let point = tube.value_at(u, v);
+            assert_relative_eq!(point[2], 10.0 * v, epsilon = 1e-12);
+            let radius = point[0].abs().max(point[1].abs());
+            assert_relative_eq!(radius, 1.0, epsilon = 1e-12);
+        }
+        assert_relative_eq!(
+            tube.derivative_v(0.2, 0.4).distance(&[0.0, 0.0, 10.0]),
+            0.0,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn profile_stays_at_distance() {
+        let path = Bezier3::new(
+            [0.0, 0.0, 0.0],
+            [4.0, 0.0, 1.0],
+            [4.0, 4.0, -1.0],
+            [0.0, 4.0, 2.0],
+        );
+        let mut circle = crate::ComposedCurve::new([1.0, 0.0]);
+        circle.line_to([0.0, 1.0]);
+        let tube = sweep(circle, path, 256);
+        for v in [0.0, 0.25, 0.5, 0.77, 1.0] {
+            let point = tube.value_at(0.0, v);
+            assert_relative_eq!(point.distance(&path.value_at(v)), 1.0, epsilon = 1e-4);
+            let offset = point.sub(&path.value_at(v));
+            let tangent = path.tangent_at(v);
+            assert_relative_eq!(offset.dot(&tangent) / tangent.length(), 0.0, epsilon = 1e-2);
+        }
+    }
+
+    #[test]
+    fn sweep_derivatives() {
+        let path = Bezier3::new(
+            [0.0, 0.0, 0.0],
+            [4.0, 0.0, 1.0],
+            [4.0, 4.0, -1.0],
+            [0.0, 4.0, 2.0],
+        );
+        let tube = sweep(square(), path, 32);
+        let (u, v, h) = (0.3, 0.41, 1e-7);
+        let du = tube
+            .value_at(u + h, v)
+            .sub(&tube.value_at(u - h, v))
+            .scale(0.5 / h);
+        let dv = tube
+            .value_at(u, v + h)
+            .sub(&tube.value_at(u, v - h))
+            .scale(0.5 / h);
+        assert_relative_eq!(tube.derivative_u(u, v).distance(&du), 0.0, epsilon = 1e-5);
+        assert_relative_eq!(tube.derivative_v(u, v).distance(&dv), 0.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn extrusion() {
+        let curve = Bezier3::new(
+            [0.0, 0.0, 0.0],
+            [1.0, 2.0, 0.0],
+            [2.0, 2.0, 0.0],
+            [3.0, 0.0, 0.0],
+        );
+        let wall = extrude(curve, [0.0, 0.0, 2.0]);
+        assert_eq!(
+            wall.value_at(0.5, 0.5),
+            curve.value_at(0.5).add(&[0.0, 0.0, 1.0])
+        );
+        assert_eq!(wall.derivative_v(0.1, 0.9), [0.0, 0.0, 2.0]);
+        let normal = wall.normal_at(0.5, 0.3);
+        assert_relative_eq!(normal.distance(&[0.0, -1.0, 0.0]), 0.0, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,255 @@
+use crate::{Curve, Path, PlanarPoint, Scalar};
+use alloc::vec::Vec;
+use num_traits::{One, Zero};
+
+/// Rule deciding which points are inside a path with overlapping or nested contours.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum FillRule {
+    /// Points with a non-zero winding number are inside.
+    #[default]
+    NonZero,
+    /// Points with an odd winding number are inside.
+    EvenOdd,
+}
+
+impl FillRule {
+    pub fn is_inside(&self, winding: i32) -> bool {
+        match self {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+}
+
+/// Triangles ready to be uploaded to a GPU.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Mesh {
+    /// Interleaved `x, y` coordinates of vertices.
+    pub vertices: Vec<f32>,
+    /// Three vertex indices per triangle, counter-clockwise for the y axis pointing up.
+    pub indices: Vec<u32>,
+}
+
+impl Mesh {
+    fn push_vertex(&mut self, x: f32, y: f32) -> u32 {
+        let index = (self.vertices.len() / 2) as u32;
+        self.vertices.extend([x, y]);
+        index
+    }
+}
+
+/// A non-horizontal edge of the flattened path going from `top` to `bottom`, `top` has the smaller y.
+struct Edge<P: PlanarPoint> {
+    top: P,
+    bottom: P,
+    winding: i32,
+}
+
+impl<P: PlanarPoint> Edge<P> {
+    fn x_at(&self, y: P::Scalar) -> P::Scalar {
+        let t = (y - self.top.y()) / (self.bottom.y() - self.top.y());
+        self.top.x() + (self.bottom.x() - self.top.x()) * t
+    }
+}
+
+impl<P: PlanarPoint> Path<P> {
+    /// Triangulate the area inside the path according to `fill_rule`. Contours are flattened
+    /// with `tolerance` and closed implicitly, they can overlap, intersect themselves, and form holes.
+    ///
+    /// The area is cut into horizontal slabs at every vertex and every edge crossing, inside
+    /// every slab spans between edges are filled with trapezoids. Edge crossings are found by
+    /// checking all pairs of edges, so the cost grows quadratically with the number of edges.
+    pub fn tessellate_fill(&self, tolerance: P::Scalar, fill_rule: FillRule) -> Mesh {
+        let edges = self.fill_edges(tolerance);
+
+        let mut ys: Vec<P::Scalar> = edges
+            .iter()
+            .flat_map(|edge| [edge.top.y(), edge.bottom.y()])
+            .collect();
+        for (i, a) in edges.iter().enumerate() {
+            for b in &edges[i + 1..] {
+                if let Some(y) = crossing_y(a, b) {
+                    ys.push(y);
+                }
+            }
+        }
+        ys.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        ys.dedup();
+
+        let half = P::Scalar::one() / (P::Scalar::one() + P::Scalar::one());
+        let mut mesh = Mesh::default();
+        let mut active: Vec<(P::Scalar, &Edge<P>)> = Vec::new();
+        for slab in ys.windows(2) {
+            let (y0, y1) = (slab[0], slab[1]);
+            let middle = (y0 + y1) * half;
+            active.clear();
+            active.extend(
+                edges
+                    .iter()
+                    .filter(|edge| edge.top.y() <= y0 && edge.bottom.y() >= y1)
+                    .map(|edge| (edge.x_at(middle), edge)),
+            );
+            active.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+            let mut winding = 0;
+            let mut span_start = None;
+            for (_, edge) in &active {
+                let (x0, x1) = (edge.x_at(y0), edge.x_at(y1));
+                let was_inside = fill_rule.is_inside(winding);
+                winding += edge.winding;
+                match (was_inside, fill_rule.is_inside(winding)) {
+                    (false, true) => span_start = Some((x0, x1)),
+                    (true, false) => {
+                        let (left0, left1) = span_start.take().unwrap();
+                        push_trapezoid(&mut mesh, y0, y1, (left0, x0), (left1, x1));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        mesh
+    }
+
+    fn fill_edges(&self, tolerance: P::Scalar) -> Vec<Edge<P>> {
+        let mut edges = Vec::new();
+        for contour in self.contours() {
+            let mut points = contour.flatten(tolerance);
+            points.push(contour.start_point());
+            for pair in points.windows(2) {
+                let (a, b) = (&pair[0], &pair[1]);
+                if a.y() < b.y() {
+                    edges.push(Edge {
+                        top: a.clone(),
+                        bottom: b.clone(),
+                        winding: -1,
+                    });
+                } else if a.y() > b.y() {
+                    edges.push(Edge {
+                        top: b.clone(),
+                        bottom: a.clone(),
+                        winding: 1,
+                    });
+                }
+            }
+        }
+        edges
+    }
+}
+
+/// The y coordinate where two edges cross strictly inside both of them.
+fn crossing_y<P: PlanarPoint>(a: &Edge<P>, b: &Edge<P>) -> Option<P::Scalar> {
+    let top = a.top.y().max(b.top.y());
+    let bottom = a.bottom.y().min(b.bottom.y());
+    if top >= bottom {
+        return None;
+    }
+    // Difference of x coordinates at the ends of the common range
+    let d0 = a.x_at(top) - b.x_at(top);
+    let d1 = a.x_at(bottom) - b.x_at(bottom);
+    let zero = P::Scalar::zero();
+    if (d0 < zero && d1 > zero) || (d0 > zero && d1 < zero) {
+        Some(top + (bottom - top) * d0 / (d0 - d1))
+    } else {
+        None
+    }
+}
+
+/// Two triangles between the horizontal lines `y0` and `y1`, skipping degenerate ones.
+fn push_trapezoid<F: Scalar>(
+    mesh: &mut Mesh,
+    y0: F,
+    y1: F,
+    (left0, right0): (F, F),
+    (left1, right1): (F, F),
+) {
+    let f = |v: F| v.to_f32().unwrap();
+    let top_width = right0 - left0;
+    let bottom_width = right1 - left1;
+    if top_width <= F::zero() && bottom_width <= F::zero() {
+        return;
+    }
+    let a = mesh.push_vertex(f(left0), f(y0));
+    let b = mesh.push_vertex(f(right0), f(y0));
+    let c = mesh.push_vertex(f(right1), f(y1));
+    let d = mesh.push_vertex(f(left1), f(y1));
+    if top_width > F::zero() {
+        mesh.indices.extend([a, b, c]);
+    }
+    if bottom_width > F::zero() {
+        mesh.indices.extend([a, c, d]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ComposedCurve;
+    use approx::assert_relative_eq;
+
+    fn area(mesh: &Mesh) -> f32 {
+        mesh.indices
+            .chunks(3)
+            .map(|t| {
+                let p = |i: u32| {
+                    [
+                        mesh.vertices[2 * i as usize],
+                        mesh.vertices[2 * i as usize + 1],
+                    ]
+                };
+                let (a, b, c) = (p(t[0]), p(t[1]), p(t[2]));
+                ((b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])) / 2.0
+            })
+            .sum()
+    }
+
+    fn square(min: f64, max: f64, clockwise: bool) -> ComposedCurve<[f64; 2]> {
+        let mut corners = [[min, min], [max, min], [max, max], [min, max]];
+        if clockwise {
+            corners.reverse();
+        }
+        let mut curve = ComposedCurve::new(corners[0]);
+        corners[1..].iter().for_each(|&p| curve.line_to(p));
+        curve.close();
+        curve
+    }
+
+    #[test]
+    fn holes() {
+        let same = Path::from_iter([square(0.0, 4.0, false), square(1.0, 3.0, false)]);
+        assert_relative_eq!(area(&same.tessellate_fill(0.1, FillRule::NonZero)), 16.0);
+        assert_relative_eq!(area(&same.tessellate_fill(0.1, FillRule::EvenOdd)), 12.0);
+
+        let reversed = Path::from_iter([square(0.0, 4.0, false), square(1.0, 3.0, true)]);
+        assert_relative_eq!(
+            area(&reversed.tessellate_fill(0.1, FillRule::NonZero)),
+            12.0
+        );
+    }
+
+    #[test]
+    fn self_intersecting() {
+        // A bow tie, two triangles of area 1 meeting at the center
+        let mut bow_tie = ComposedCurve::new([0.0, 0.0]);
+        bow_tie.line_to([2.0, 2.0]);
+        bow_tie.line_to([2.0, 0.0]);
+        bow_tie.line_to([0.0, 2.0]);
+        let mesh = Path::from(bow_tie).tessellate_fill(0.1, FillRule::NonZero);
+        assert_relative_eq!(area(&mesh), 2.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn circle() {
+        let mut circle = ComposedCurve::new([1.0, 0.0]);
+        let k = 0.5522847498;
+        circle.cubic_to([1.0, k], [k, 1.0], [0.0, 1.0]);
+        circle.cubic_to([-k, 1.0], [-1.0, k], [-1.0, 0.0]);
+        circle.cubic_to([-1.0, -k], [-k, -1.0], [0.0, -1.0]);
+        circle.cubic_to([k, -1.0], [1.0, -k], [1.0, 0.0]);
+        let mesh = Path::from(circle).tessellate_fill(1e-3, FillRule::NonZero);
+        assert_relative_eq!(area(&mesh), core::f32::consts::PI, epsilon = 1e-2);
+        assert!(mesh
+            .indices
+            .iter()
+            .all(|&i| (i as usize) < mesh.vertices.len() / 2));
+    }
+}
@@ -0,0 +1,245 @@
+use crate::bezier::Bezier;
+use crate::{Bezier0, ComposedCurve, Curve, PlanarPoint};
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+/// Error returned when parsing a curve from its textual representation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseCurveError {
+    /// The input doesn't start with the `M` command.
+    MissingMove,
+    /// Unknown command letter.
+    UnknownCommand(char),
+    /// A number can't be parsed, the value is the token index.
+    InvalidNumber(usize),
+    /// The input ended in the middle of a command.
+    UnexpectedEnd,
+    /// The input has more segments than the target type can hold.
+    TooManySegments,
+}
+
+impl Display for ParseCurveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCurveError::MissingMove => write!(f, "curve must start with the M command"),
+            ParseCurveError::UnknownCommand(c) => write!(f, "unknown command '{}'", c),
+            ParseCurveError::InvalidNumber(i) => write!(f, "invalid number at token {}", i),
+            ParseCurveError::UnexpectedEnd => write!(f, "unexpected end of input"),
+            ParseCurveError::TooManySegments => write!(f, "too many segments"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCurveError {}
+
+/// Writes points separated by spaces, `Display` of floats is the shortest round-trip representation.
+fn write_points<P: PlanarPoint>(f: &mut Formatter<'_>, command: char, points: &[&P]) -> fmt::Result
+where
+    P::Scalar: Display,
+{
+    write!(f, "{}", command)?;
+    for p in points {
+        write!(f, " {} {}", p.x(), p.y())?;
+    }
+    Ok(())
+}
+
+fn write_segment<P: PlanarPoint>(f: &mut Formatter<'_>, segment: &Bezier<P>) -> fmt::Result
+where
+    P::Scalar: Display,
+{
+    match segment {
+        Bezier::C0(_) => Ok(()),
+        Bezier::C1(c) => write_points(f, 'L', &[&c.p1]),
+        Bezier::C2(c) => write_points(f, 'Q', &[&c.p1, &c.p2]),
+        Bezier::C3(c) => write_points(f, 'C', &[&c.p1, &c.p2, &c.p3]),
+    }
+}
+
+/// Format a bezier curve as SVG path data with absolute commands, e.g. `M 0 0 C 0 1 1 1 1 0`.
+impl<P: PlanarPoint> Display for Bezier<P>
+where
+    P::Scalar: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_points(f, 'M', &[&self.start_point()])?;
+        if !matches!(self, Bezier::C0(_)) {
+            write!(f, " ")?;
+        }
+        write_segment(f, self)
+    }
+}
+
+/// Format a composed curve as SVG path data with absolute commands, e.g. `M 0 0 L 1 0 Q 2 0 2 1`.
+impl<P: PlanarPoint> Display for ComposedCurve<P>
+where
+    P::Scalar: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_points(f, 'M', &[&self.start_point()])?;
+        for segment in self.segments() {
+            write!(f, " ")?;
+            write_segment(f, segment)?;
+        }
+        Ok(())
+    }
+}
+
+/// Tokens are numbers and command letters, numbers are separated by whitespace or commas.
+fn tokens(s: &str) -> impl Iterator<Item = &str> {
+    s.split(|c: char| c.is_whitespace() || c == ',')
+        .flat_map(|mut rest| {
+            let mut parts = Vec::new();
+            while let Some(i) = rest.find(is_command) {
+                parts.push(&rest[..i]);
+                parts.push(&rest[i..i + 1]);
+                rest = &rest[i + 1..];
+            }
+            parts.push(rest);
+            parts
+        })
+        .filter(|token| !token.is_empty())
+}
+
+/// Letters that are parts of numbers like `1e-5` are not commands.
+fn is_command(c: char) -> bool {
+    c.is_ascii_alphabetic() && c != 'e' && c != 'E'
+}
+
+struct Parser<'a, I: Iterator<Item = &'a str>> {
+    tokens: core::iter::Peekable<core::iter::Enumerate<I>>,
+}
+
+impl<'a, I: Iterator<Item = &'a str>> Parser<'a, I> {
+    fn command(&mut self) -> Option<Result<char, ParseCurveError>> {
+        self.tokens.next().map(|(_, token)| {
+            let mut chars = token.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if is_command(c) => Ok(c),
+                (Some(c), _) => Err(ParseCurveError::UnknownCommand(c)),
+                (None, _) => Err(ParseCurveError::UnexpectedEnd),
+            }
+        })
+    }
+
+    fn scalar<F: FromStr>(&mut self) -> Result<F, ParseCurveError> {
+        let (i, token) = self.tokens.next().ok_or(ParseCurveError::UnexpectedEnd)?;
+        token.parse().map_err(|_| ParseCurveError::InvalidNumber(i))
+    }
+
+    fn point<P: PlanarPoint>(&mut self) -> Result<P, ParseCurveError>
+    where
+        P::Scalar: FromStr,
+    {
+        let x = self.scalar()?;
+        let y = self.scalar()?;
+        Ok(P::from_xy(x, y))
+    }
+}
+
+/// Parse SVG-like path data with absolute `M`, `L`, `Q`, `C`, and `Z` commands.
+impl<P: PlanarPoint> FromStr for ComposedCurve<P>
+where
+    P::Scalar: FromStr,
+{
+    type Err = ParseCurveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            tokens: tokens(s).enumerate().peekable(),
+        };
+
+        match parser.command() {
+            Some(Ok('M')) => {}
+            Some(Ok(_)) | None => return Err(ParseCurveError::MissingMove),
+            Some(Err(e)) => return Err(e),
+        }
+
+        let mut curve = ComposedCurve::new(parser.point()?);
+        while let Some(command) = parser.command() {
+            match command? {
+                'L' => curve.line_to(parser.point()?),
+                'Q' => curve.quadratic_to(parser.point()?, parser.point()?),
+                'C' => curve.cubic_to(parser.point()?, parser.point()?, parser.point()?),
+                'Z' => curve.close(),
+                c => return Err(ParseCurveError::UnknownCommand(c)),
+            }
+        }
+
+        Ok(curve)
+    }
+}
+
+/// Parse a single segment in the same format as [`ComposedCurve`], e.g. `M 0 0 Q 1 1 2 0`.
+impl<P: PlanarPoint> FromStr for Bezier<P>
+where
+    P::Scalar: FromStr,
+{
+    type Err = ParseCurveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let curve: ComposedCurve<P> = s.parse()?;
+        match curve.segments() {
+            [] => Ok(Bezier::C0(Bezier0::new(curve.start_point()))),
+            [segment] => Ok(segment.clone()),
+            _ => Err(ParseCurveError::TooManySegments),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bezier3;
+    use alloc::string::ToString;
+
+    #[test]
+    fn round_trip() {
+        let mut curve = ComposedCurve::new([0.1, -2.0]);
+        curve.line_to([1.0 / 3.0, 2.5e-7]);
+        curve.quadratic_to([1.0, 2.0], [3.0, 4.0]);
+        curve.cubic_to([5.0, 6.0], [7.0, 8.0], [9.0, 10.0]);
+
+        let text = curve.to_string();
+        assert_eq!(
+            text,
+            "M 0.1 -2 L 0.3333333333333333 0.00000025 Q 1 2 3 4 C 5 6 7 8 9 10"
+        );
+        assert_eq!(text.parse::<ComposedCurve<[f64; 2]>>(), Ok(curve));
+    }
+
+    #[test]
+    fn parse_compact() {
+        let curve: ComposedCurve<(f64, f64)> = "M0,0L1,0Q2,0,2,1Z".parse().unwrap();
+        assert_eq!(curve.to_string(), "M 0 0 L 1 0 Q 2 0 2 1 L 0 0");
+
+        let bezier: Bezier<(f64, f64)> = "M 0 0 C 0 1 1e0 1 1 0".parse().unwrap();
+        assert_eq!(
+            bezier,
+            Bezier::C3(Bezier3::new((0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0)))
+        );
+        assert_eq!(bezier.to_string(), "M 0 0 C 0 1 1 1 1 0");
+    }
+
+    #[test]
+    fn parse_errors() {
+        type Curve = ComposedCurve<[f64; 2]>;
+        assert_eq!("L 1 1".parse::<Curve>(), Err(ParseCurveError::MissingMove));
+        assert_eq!("M 1".parse::<Curve>(), Err(ParseCurveError::UnexpectedEnd));
+        assert_eq!(
+            "M 1 2 x".parse::<Curve>(),
+            Err(ParseCurveError::UnknownCommand('x'))
+        );
+        assert_eq!(
+            "M 1 2 L 3 .".parse::<Curve>(),
+            Err(ParseCurveError::InvalidNumber(5))
+        );
+        assert_eq!(
+            "M 0 0 L 1 1 L 2 2".parse::<Bezier<[f64; 2]>>(),
+            Err(ParseCurveError::TooManySegments)
+        );
+    }
+}
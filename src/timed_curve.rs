@@ -0,0 +1,130 @@
+use crate::{Curve, Point, Scalar};
+use core::marker::PhantomData;
+use num_traits::{NumCast, One, Zero};
+
+/// A curve played during `duration` seconds, with velocity and acceleration in units per second.
+///
+/// Time is mapped to `t` linearly, so to move with a constant speed wrap the curve into
+/// [`LinearSpeed`](crate::LinearSpeed) first, derivatives go through it with the chain rule.
+/// Time outside of `0..=duration` is clamped, the curve stays at its ends there,
+/// so the velocity and the acceleration are zero.
+pub struct TimedCurve<P: Point, C: Curve<P>> {
+    curve: C,
+    duration: P::Scalar,
+    phantom_data: PhantomData<P>,
+}
+
+impl<P: Point, C: Curve<P>> TimedCurve<P, C> {
+    /// # Panics
+    ///
+    /// Panics if `duration` isn't positive.
+    pub fn new(curve: C, duration: P::Scalar) -> Self {
+        assert!(duration > P::Scalar::zero(), "duration must be positive");
+        Self {
+            curve,
+            duration,
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+
+    pub fn into_inner(self) -> C {
+        self.curve
+    }
+
+    /// Duration in seconds.
+    pub fn duration(&self) -> P::Scalar {
+        self.duration
+    }
+
+    /// Curve parameter for `time` in seconds.
+    pub fn t_at_time(&self, time: P::Scalar) -> P::Scalar {
+        (time / self.duration).clamp(P::Scalar::zero(), P::Scalar::one())
+    }
+
+    /// Time in seconds for the curve parameter `t`.
+    pub fn time_at_t(&self, t: P::Scalar) -> P::Scalar {
+        t * self.duration
+    }
+
+    pub fn position_at_time(&self, time: P::Scalar) -> P {
+        self.curve.value_at(self.t_at_time(time))
+    }
+
+    /// Derivative of the position by time, the curve tangent divided by the duration.
+    /// It's zero outside of `0..=duration`.
+    pub fn velocity_at_time(&self, time: P::Scalar) -> P {
+        if !self.contains_time(time) {
+            return self.curve.start_point().scale(P::Scalar::zero());
+        }
+        self.curve
+            .tangent_at(self.t_at_time(time))
+            .scale(P::Scalar::one() / self.duration)
+    }
+
+    /// Second derivative of the position by time.
+    ///
+    /// Curves provide only the first derivative, so it's differentiated numerically
+    /// with central differences (one-sided at the ends). It's zero outside of `0..=duration`.
+    pub fn acceleration_at_time(&self, time: P::Scalar) -> P {
+        if !self.contains_time(time) {
+            return self.curve.start_point().scale(P::Scalar::zero());
+        }
+        let h: P::Scalar = NumCast::from(1e-4).unwrap();
+        let t = self.t_at_time(time);
+        let t0 = (t - h).max(P::Scalar::zero());
+        let t1 = (t + h).min(P::Scalar::one());
+
+        let d0 = self.curve.tangent_at(t0);
+        let d1 = self.curve.tangent_at(t1);
+        d1.sub(&d0)
+            .scale(P::Scalar::one() / ((t1 - t0) * self.duration * self.duration))
+    }
+
+    fn contains_time(&self, time: P::Scalar) -> bool {
+        time >= P::Scalar::zero() && time <= self.duration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bezier1, Bezier2, Bezier3, LinearSpeed};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn units_per_second() {
+        // x = 2 t², t = time / 4
+        let curve = TimedCurve::new(Bezier2::new(0.0, 0.0, 2.0), 4.0);
+        assert_relative_eq!(curve.position_at_time(2.0), 0.5);
+        assert_relative_eq!(curve.velocity_at_time(2.0), 0.5);
+        assert_relative_eq!(curve.acceleration_at_time(2.0), 0.25, epsilon = 1e-6);
+        assert_relative_eq!(curve.acceleration_at_time(0.0), 0.25, epsilon = 1e-6);
+        assert_relative_eq!(curve.position_at_time(10.0), 2.0);
+        assert_relative_eq!(curve.velocity_at_time(4.0), 1.0);
+        assert_eq!(curve.velocity_at_time(10.0), 0.0);
+        assert_eq!(curve.velocity_at_time(-1.0), 0.0);
+        assert_eq!(curve.acceleration_at_time(10.0), 0.0);
+        assert_relative_eq!(curve.t_at_time(1.0), 0.25);
+        assert_relative_eq!(curve.time_at_t(0.25), 1.0);
+    }
+
+    #[test]
+    fn through_linear_speed() {
+        let curve = Bezier3::new([0.0, 0.0], [0.0, 1.0], [9.0, 1.0], [10.0, 0.0]);
+        let length = curve.estimate_length(1e-6);
+        let timed = TimedCurve::new(LinearSpeed::new(curve, 512, 4000), 2.0);
+
+        for i in 1..10 {
+            let velocity = timed.velocity_at_time(i as f64 / 5.0);
+            let speed = (velocity[0] * velocity[0] + velocity[1] * velocity[1]).sqrt();
+            assert_relative_eq!(speed, length / 2.0, max_relative = 0.02);
+        }
+
+        let line = TimedCurve::new(LinearSpeed::new(Bezier1::new(0.0, 3.0), 16, 16), 3.0);
+        assert_relative_eq!(line.acceleration_at_time(1.5), 0.0, epsilon = 1e-6);
+    }
+}
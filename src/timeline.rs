@@ -0,0 +1,170 @@
+use crate::{Curve, Point, Scalar};
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use num_traits::{One, Zero};
+
+/// A named curve placed on a [`Timeline`].
+pub struct Track<P: Point> {
+    name: String,
+    curve: Box<dyn Curve<P>>,
+    start: P::Scalar,
+    duration: P::Scalar,
+}
+
+impl<P: Point> Track<P> {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn curve(&self) -> &dyn Curve<P> {
+        self.curve.as_ref()
+    }
+
+    pub fn start(&self) -> P::Scalar {
+        self.start
+    }
+
+    pub fn duration(&self) -> P::Scalar {
+        self.duration
+    }
+
+    pub fn end(&self) -> P::Scalar {
+        self.start + self.duration
+    }
+
+    /// Value of the curve at `time`, the first value is held before the start of the track
+    /// and the last one after its end.
+    pub fn value_at_time(&self, time: P::Scalar) -> P {
+        let t = if self.duration > P::Scalar::zero() {
+            (time - self.start) / self.duration
+        } else if time < self.start {
+            P::Scalar::zero()
+        } else {
+            P::Scalar::one()
+        };
+        self.curve
+            .value_at(t.clamp(P::Scalar::zero(), P::Scalar::one()))
+    }
+}
+
+impl<P: Point> Debug for Track<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Track")
+            .field("name", &self.name)
+            .field("start", &self.start)
+            .field("duration", &self.duration)
+            .finish()
+    }
+}
+
+/// Several named curves, each one played during its own time span, sampled together.
+///
+/// ```
+/// use baiser::{Bezier1, Timeline};
+///
+/// let mut timeline = Timeline::new();
+/// timeline.add_track("opacity", Bezier1::new(0.0, 1.0), 0.0, 0.5);
+/// timeline.add_track("scale", Bezier1::new(1.0, 2.0), 0.5, 1.0);
+///
+/// assert_eq!(timeline.duration(), 1.5);
+/// assert_eq!(timeline.sample(1.0), [("opacity", 1.0), ("scale", 1.5)]);
+/// ```
+pub struct Timeline<P: Point> {
+    tracks: Vec<Track<P>>,
+}
+
+impl<P: Point> Default for Timeline<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Point> Debug for Timeline<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Timeline").field(&self.tracks).finish()
+    }
+}
+
+impl<P: Point> Timeline<P> {
+    pub fn new() -> Self {
+        Self { tracks: Vec::new() }
+    }
+
+    /// Add a curve played from `start` for `duration`, both in the same units as the sampling time.
+    pub fn add_track(
+        &mut self,
+        name: impl Into<String>,
+        curve: impl Curve<P> + 'static,
+        start: P::Scalar,
+        duration: P::Scalar,
+    ) -> &mut Self {
+        self.tracks.push(Track {
+            name: name.into(),
+            curve: Box::new(curve),
+            start,
+            duration,
+        });
+        self
+    }
+
+    pub fn tracks(&self) -> &[Track<P>] {
+        &self.tracks
+    }
+
+    pub fn track(&self, name: &str) -> Option<&Track<P>> {
+        self.tracks.iter().find(|track| track.name == name)
+    }
+
+    /// Time when the last track ends.
+    pub fn duration(&self) -> P::Scalar {
+        self.tracks
+            .iter()
+            .fold(P::Scalar::zero(), |acc, track| acc.max(track.end()))
+    }
+
+    /// Values of all the tracks at `time`, in the order the tracks were added.
+    pub fn sample(&self, time: P::Scalar) -> Vec<(&str, P)> {
+        self.tracks
+            .iter()
+            .map(|track| (track.name(), track.value_at_time(time)))
+            .collect()
+    }
+
+    /// Value of the track named `name` at `time`.
+    pub fn sample_track(&self, name: &str, time: P::Scalar) -> Option<P> {
+        self.track(name).map(|track| track.value_at_time(time))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::easing::CubicBezierEasing;
+    use crate::{Bezier1, Bezier3};
+
+    #[test]
+    fn tracks_hold_values() {
+        let mut timeline = Timeline::new();
+        timeline
+            .add_track("position", Bezier3::new(0.0, 0.0, 10.0, 10.0), 1.0, 2.0)
+            .add_track("fade", CubicBezierEasing::ease(), 0.0, 1.0)
+            .add_track("flash", Bezier1::new(0.0, 1.0), 0.5, 0.0);
+
+        assert_eq!(timeline.duration(), 3.0);
+        assert_eq!(timeline.sample_track("position", 0.0), Some(0.0));
+        assert_eq!(timeline.sample_track("position", 2.0), Some(5.0));
+        assert_eq!(timeline.sample_track("position", 5.0), Some(10.0));
+        assert_eq!(timeline.sample_track("flash", 0.4), Some(0.0));
+        assert_eq!(timeline.sample_track("flash", 0.5), Some(1.0));
+        assert_eq!(timeline.sample_track("missing", 0.5), None);
+
+        let names: Vec<_> = timeline
+            .sample(0.5)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, ["position", "fade", "flash"]);
+    }
+}
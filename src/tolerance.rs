@@ -0,0 +1,86 @@
+use crate::Scalar;
+
+/// Accuracy of an approximation, used by [`Curve::length_within`](crate::Curve::length_within),
+/// [`Curve::flatten_within`](crate::Curve::flatten_within), and
+/// [`ComposedCurve::fit_points_within`](crate::ComposedCurve::fit_points_within).
+///
+/// Relative tolerances are fractions of the size of the approximated geometry, which is its length,
+/// absolute tolerances are in the units of the points.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tolerance<F: Scalar> {
+    Relative(F),
+    Absolute(F),
+}
+
+impl<F: Scalar> Tolerance<F> {
+    /// The absolute tolerance for geometry of the given `size`.
+    pub fn absolute_for(&self, size: F) -> F {
+        match *self {
+            Tolerance::Relative(fraction) => fraction * size,
+            Tolerance::Absolute(tolerance) => tolerance,
+        }
+    }
+
+    /// The relative tolerance for geometry of the given `size`, zero sized geometry
+    /// is always within an absolute tolerance so the tolerance is kept as is.
+    pub fn relative_for(&self, size: F) -> F {
+        match *self {
+            Tolerance::Relative(fraction) => fraction,
+            Tolerance::Absolute(tolerance) if size > F::zero() => tolerance / size,
+            Tolerance::Absolute(tolerance) => tolerance,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bezier3, Curve};
+    #[cfg(feature = "alloc")]
+    use crate::{ComposedCurve, Distance};
+    #[cfg(feature = "alloc")]
+    use alloc::vec::Vec;
+
+    #[test]
+    fn absolute_length_of_tiny_curve() {
+        let curve = Bezier3::new([0.0, 0.0], [0.0, 1e-6], [1e-6, 1e-6], [1e-6, 0.0]);
+        let exact = curve.estimate_length(1e-9);
+        let length = curve.length_within(Tolerance::Absolute(1e-9));
+        assert!((length - exact).abs() < 1e-9);
+
+        let large = curve.map_points(|p| [p[0] * 1e6, p[1] * 1e6]);
+        let length = large.length_within(Tolerance::Absolute(0.01));
+        assert!((length - exact * 1e6).abs() < 0.01);
+        let length = large.length_within(Tolerance::Relative(1e-3));
+        assert!((length - exact * 1e6).abs() < 1e-3 * length);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn flattening_and_fitting() {
+        let curve = Bezier3::new([0.0, 0.0], [0.0, 100.0], [100.0, 100.0], [100.0, 0.0]);
+        let absolute = curve.flatten_within(Tolerance::Absolute(0.5));
+        let relative = curve.flatten_within(Tolerance::Relative(0.5 / curve.estimate_length(1e-6)));
+        assert!((absolute.len() as isize - relative.len() as isize).abs() <= 2);
+        assert!(curve.flatten_within(Tolerance::Relative(1e-4)).len() > absolute.len());
+
+        let points: Vec<_> = (0..=50).map(|i| curve.value_at(i as f64 / 50.0)).collect();
+        let fitted =
+            ComposedCurve::fit_points_within(&points, Tolerance::Relative(1e-3), 1.0).unwrap();
+        for point in &points {
+            let nearest = (0..=1000)
+                .map(|i| fitted.value_at(i as f64 / 1000.0).distance(point))
+                .fold(f64::INFINITY, f64::min);
+            assert!(nearest < 0.5);
+        }
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Tolerance::Relative(0.1).absolute_for(20.0), 2.0);
+        assert_eq!(Tolerance::Absolute(0.5).absolute_for(20.0), 0.5);
+        assert_eq!(Tolerance::Relative(0.1).relative_for(20.0), 0.1);
+        assert_eq!(Tolerance::Absolute(0.5).relative_for(20.0), 0.025);
+        assert_eq!(Tolerance::Absolute(0.5).relative_for(0.0), 0.5);
+    }
+}
@@ -0,0 +1,278 @@
+use crate::arc::Arc;
+use crate::{ComposedCurve, Curve, Distance, PlanarPoint, Point, Scalar};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use num_traits::{One, ToPrimitive, Zero};
+
+/// A single machine move, the start of the move is the end of the previous one.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Move<P: PlanarPoint> {
+    /// `G1` linear move.
+    Line { to: P, feed: P::Scalar },
+    /// `G2` (clockwise) or `G3` (counter-clockwise) circular move around `center`.
+    Arc {
+        to: P,
+        center: P,
+        clockwise: bool,
+        feed: P::Scalar,
+    },
+}
+
+/// A sequence of machine moves approximating a curve, see [`Toolpath::new`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Toolpath<P: PlanarPoint> {
+    pub start: P,
+    pub moves: Vec<Move<P>>,
+}
+
+impl<P: PlanarPoint> Toolpath<P>
+where
+    P::Scalar: Point<Scalar = P::Scalar>,
+{
+    /// Approximate the curve with line moves, and with circular arcs if `fit_arcs` is set.
+    /// The distance between the toolpath and the curve is expected to be less than `tolerance`.
+    ///
+    /// Feed rate of every move is taken from `speed_profile` at the traveled distance
+    /// normalized to the `0..1` range, so a constant profile gives a constant feed rate.
+    pub fn new<S: Curve<P::Scalar>>(
+        curve: &ComposedCurve<P>,
+        tolerance: P::Scalar,
+        fit_arcs: bool,
+        speed_profile: &S,
+    ) -> Self {
+        let half = P::Scalar::one() / (P::Scalar::one() + P::Scalar::one());
+        let points = if fit_arcs {
+            curve.flatten(tolerance * half)
+        } else {
+            curve.flatten(tolerance)
+        };
+
+        let total_length = points
+            .windows(2)
+            .fold(P::Scalar::zero(), |acc, w| acc + w[0].distance(&w[1]));
+        let feed_at = |offset: P::Scalar| {
+            let t = if total_length > P::Scalar::zero() {
+                offset / total_length
+            } else {
+                P::Scalar::zero()
+            };
+            speed_profile.value_at(t)
+        };
+
+        let mut moves = Vec::new();
+        let mut offset = P::Scalar::zero();
+        let mut i = 0;
+        while i + 1 < points.len() {
+            let arc = if fit_arcs {
+                fit_arc(&points[i..], tolerance * half)
+            } else {
+                None
+            };
+
+            match arc {
+                Some((end, arc)) => {
+                    let length = points[i..=i + end]
+                        .windows(2)
+                        .fold(P::Scalar::zero(), |acc, w| acc + w[0].distance(&w[1]));
+                    moves.push(Move::Arc {
+                        to: points[i + end].clone(),
+                        clockwise: arc.is_clockwise(),
+                        center: arc.center,
+                        feed: feed_at(offset + length * half),
+                    });
+                    offset = offset + length;
+                    i += end;
+                }
+                None => {
+                    let length = points[i].distance(&points[i + 1]);
+                    moves.push(Move::Line {
+                        to: points[i + 1].clone(),
+                        feed: feed_at(offset + length * half),
+                    });
+                    offset = offset + length;
+                    i += 1;
+                }
+            }
+        }
+
+        Self {
+            start: curve.start_point(),
+            moves,
+        }
+    }
+
+    /// Format the toolpath as G-code: a rapid `G0` move to the start followed by `G1`/`G2`/`G3` moves.
+    /// Coordinates are rounded to `precision` digits, feed rate is written only when it changes.
+    pub fn to_gcode(&self, precision: usize) -> String {
+        let mut output = String::new();
+        let number = |v: P::Scalar| {
+            let mut text = String::new();
+            write!(text, "{:.*}", precision, v.to_f64().unwrap()).unwrap();
+            if text.contains('.') {
+                text.truncate(text.trim_end_matches('0').trim_end_matches('.').len());
+            }
+            if text == "-0" {
+                text = String::from("0");
+            }
+            text
+        };
+
+        writeln!(
+            output,
+            "G0 X{} Y{}",
+            number(self.start.x()),
+            number(self.start.y())
+        )
+        .unwrap();
+
+        let mut last_feed = None;
+        let mut current = self.start.clone();
+        for m in &self.moves {
+            let (to, feed) = match m {
+                Move::Line { to, feed } => {
+                    write!(output, "G1 X{} Y{}", number(to.x()), number(to.y())).unwrap();
+                    (to, *feed)
+                }
+                Move::Arc {
+                    to,
+                    center,
+                    clockwise,
+                    feed,
+                } => {
+                    let offset = center.sub(&current);
+                    write!(
+                        output,
+                        "{} X{} Y{} I{} J{}",
+                        if *clockwise { "G2" } else { "G3" },
+                        number(to.x()),
+                        number(to.y()),
+                        number(offset.x()),
+                        number(offset.y())
+                    )
+                    .unwrap();
+                    (to, *feed)
+                }
+            };
+
+            let feed = number(feed);
+            if last_feed.as_ref() != Some(&feed) {
+                write!(output, " F{}", feed).unwrap();
+                last_feed = Some(feed);
+            }
+            output.push('\n');
+            current = to.clone();
+        }
+
+        output
+    }
+}
+
+/// Find the longest arc starting at `points[0]` that passes within `tolerance` from the points it covers.
+/// Returns the index of the last covered point and the arc.
+fn fit_arc<P: PlanarPoint>(points: &[P], tolerance: P::Scalar) -> Option<(usize, Arc<P>)> {
+    if points.len() < 3 {
+        return None;
+    }
+    let turn_at = |i: usize| {
+        points[i]
+            .sub(&points[i - 1])
+            .cross(&points[i + 1].sub(&points[i]))
+    };
+    let turn = turn_at(1);
+
+    let mut best = None;
+    for end in 2..points.len() {
+        // Turns at the previously covered points are already checked
+        if turn_at(end - 1) * turn <= P::Scalar::zero() {
+            break;
+        }
+        let Some(arc) = Arc::through(points[0].clone(), &points[end / 2], &points[end]) else {
+            break;
+        };
+        // At most a half circle, so the arc never ends near its start,
+        // controllers may read such a move as a full circle
+        if arc.sweep.abs() > P::Scalar::pi() {
+            break;
+        }
+        if !points[1..end]
+            .iter()
+            .all(|p| arc.distance_to(p) <= tolerance)
+        {
+            break;
+        }
+        best = Some((end, arc));
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bezier0;
+
+    #[test]
+    fn lines() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([10.0, 0.0]);
+        curve.line_to([10.0, 5.0]);
+
+        let toolpath = Toolpath::new(&curve, 0.01, false, &Bezier0::new(100.0));
+        let gcode = toolpath.to_gcode(3);
+
+        assert_eq!(
+            gcode,
+            "G0 X0 Y0\nG1 X2.5 Y0 F100\nG1 X5 Y0\nG1 X7.5 Y0\nG1 X10 Y0\n\
+             G1 X10 Y1.25\nG1 X10 Y2.5\nG1 X10 Y3.75\nG1 X10 Y5\n"
+        );
+    }
+
+    #[test]
+    fn arcs() {
+        // A quarter of a unit circle
+        let k = 0.5522847498;
+        let mut curve = ComposedCurve::new([1.0, 0.0]);
+        curve.cubic_to([1.0, k], [k, 1.0], [0.0, 1.0]);
+
+        let mut speed = ComposedCurve::new(100.0);
+        speed.line_to(200.0);
+
+        let toolpath = Toolpath::new(&curve, 0.001, true, &speed);
+
+        assert_eq!(toolpath.moves.len(), 1);
+        match &toolpath.moves[0] {
+            Move::Arc {
+                to,
+                center,
+                clockwise,
+                feed,
+            } => {
+                assert_eq!(to, &[0.0, 1.0]);
+                assert!(center[0].abs() < 0.001 && center[1].abs() < 0.001);
+                assert!(!clockwise);
+                assert!((feed - 150.0).abs() < 1.0);
+            }
+            m => panic!("unexpected move {:?}", m),
+        }
+
+        assert_eq!(toolpath.to_gcode(1), "G0 X1 Y0\nG3 X0 Y1 I-1 J0 F150\n");
+    }
+
+    #[test]
+    fn full_circle() {
+        // Arcs are at most half circles, so a full circle takes two moves
+        let k = 0.5522847498;
+        let mut curve = ComposedCurve::new([1.0, 0.0]);
+        curve.cubic_to([1.0, -k], [k, -1.0], [0.0, -1.0]);
+        curve.cubic_to([-k, -1.0], [-1.0, -k], [-1.0, 0.0]);
+        curve.cubic_to([-1.0, k], [-k, 1.0], [0.0, 1.0]);
+        curve.cubic_to([k, 1.0], [1.0, k], [1.0, 0.0]);
+
+        let toolpath = Toolpath::new(&curve, 0.001, true, &Bezier0::new(100.0));
+        assert_eq!(
+            toolpath.to_gcode(3),
+            "G0 X1 Y0\nG2 X-1 Y0 I-1 J0 F100\nG2 X1 Y0 I1 J0\n"
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{Path, PlanarPoint};
+use ::ttf_parser::OutlineBuilder;
+
+/// Collect glyph outlines with `ttf_parser::Face::outline_glyph`, quadratic segments
+/// for TrueType fonts and cubic ones for CFF fonts.
+impl<P: PlanarPoint<Scalar = f32>> OutlineBuilder for Path<P> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        Path::move_to(self, P::from_xy(x, y));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        Path::line_to(self, P::from_xy(x, y));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.quadratic_to(P::from_xy(x1, y1), P::from_xy(x, y));
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.cubic_to(P::from_xy(x1, y1), P::from_xy(x2, y2), P::from_xy(x, y));
+    }
+
+    fn close(&mut self) {
+        Path::close(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Curve;
+
+    fn outline(builder: &mut dyn OutlineBuilder) {
+        builder.move_to(0.0, 0.0);
+        builder.line_to(10.0, 0.0);
+        builder.quad_to(10.0, 10.0, 0.0, 10.0);
+        builder.close();
+        builder.move_to(2.0, 2.0);
+        builder.curve_to(4.0, 2.0, 4.0, 4.0, 2.0, 4.0);
+        builder.close();
+    }
+
+    #[test]
+    fn glyph_outline() {
+        let mut path = Path::<[f32; 2]>::new();
+        outline(&mut path);
+
+        assert_eq!(path.contours().len(), 2);
+        assert_eq!(path.contours()[0].segments().len(), 3);
+        assert_eq!(path.contours()[0].value_at(0.5), [7.5, 7.5]);
+        assert_eq!(path.contours()[1].segments().len(), 2);
+        assert_eq!(path.contours()[1].end_point(), [2.0, 2.0]);
+    }
+}
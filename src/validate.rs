@@ -0,0 +1,240 @@
+use crate::{Bezier, Bezier0, Bezier1, Bezier2, Bezier3, Point, Scalar};
+#[cfg(feature = "alloc")]
+use crate::{ComposedCurve, Curve};
+use core::fmt::{self, Display, Formatter};
+
+/// Problem found by the `validate` methods of the curves, e.g. [`Bezier3::validate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CurveError {
+    /// The control point `point` of the segment `segment` has a NaN or infinite coordinate,
+    /// the segment is always 0 for a single bezier curve.
+    NonFinite { segment: usize, point: usize },
+    /// The composed curve has no segments.
+    Empty,
+    /// The segment `segment` doesn't start where the previous one ends.
+    Discontinuous { segment: usize },
+    /// The curve parameter is NaN or outside of the `[0, 1]` range.
+    ParameterOutOfRange,
+}
+
+impl Display for CurveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CurveError::NonFinite { segment, point } => write!(
+                f,
+                "control point {} of segment {} is not finite",
+                point, segment
+            ),
+            CurveError::Empty => write!(f, "the curve has no segments"),
+            CurveError::Discontinuous { segment } => write!(
+                f,
+                "segment {} doesn't start at the end of the previous one",
+                segment
+            ),
+            CurveError::ParameterOutOfRange => write!(f, "the parameter is outside of [0, 1]"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CurveError {}
+
+/// Check that `t` is a valid curve parameter, it's in `[0, 1]` and not NaN.
+pub fn validate_parameter<F: Scalar>(t: F) -> Result<(), CurveError> {
+    if t >= F::zero() && t <= F::one() {
+        Ok(())
+    } else {
+        Err(CurveError::ParameterOutOfRange)
+    }
+}
+
+/// Panics in debug builds with the `debug-checks` feature if `t` isn't a valid curve parameter,
+/// so NaN values are caught where they enter the curve instead of where they come out.
+#[inline]
+pub(crate) fn debug_check_parameter<F: Scalar>(_t: F) {
+    #[cfg(feature = "debug-checks")]
+    debug_assert!(
+        validate_parameter(_t).is_ok(),
+        "curve parameter is NaN or outside of [0, 1]"
+    );
+}
+
+/// [`debug_check_parameter`] for every parameter of a batch evaluation.
+#[inline]
+pub(crate) fn debug_check_parameters<F: Scalar>(_ts: &[F]) {
+    #[cfg(feature = "debug-checks")]
+    _ts.iter().for_each(|&t| debug_check_parameter(t));
+}
+
+/// A point is finite if subtracting it from itself gives a point equal to itself,
+/// NaN and infinite coordinates give NaN, which isn't equal to anything.
+fn is_finite<P: Point>(point: &P) -> bool {
+    let difference = point.sub(point);
+    #[allow(clippy::eq_op)]
+    let finite = difference == difference;
+    finite
+}
+
+fn validate_points<'a, P: Point + 'a>(
+    segment: usize,
+    points: impl IntoIterator<Item = &'a P>,
+) -> Result<(), CurveError> {
+    match points.into_iter().position(|point| !is_finite(point)) {
+        Some(point) => Err(CurveError::NonFinite { segment, point }),
+        None => Ok(()),
+    }
+}
+
+impl<P: Point> Bezier0<P> {
+    /// Check that the point is finite.
+    pub fn validate(&self) -> Result<(), CurveError> {
+        validate_points(0, [&self.point])
+    }
+}
+
+impl<P: Point> Bezier1<P> {
+    /// Check that all the control points are finite.
+    pub fn validate(&self) -> Result<(), CurveError> {
+        validate_points(0, [&self.p0, &self.p1])
+    }
+}
+
+impl<P: Point> Bezier2<P> {
+    /// Check that all the control points are finite.
+    pub fn validate(&self) -> Result<(), CurveError> {
+        validate_points(0, [&self.p0, &self.p1, &self.p2])
+    }
+}
+
+impl<P: Point> Bezier3<P> {
+    /// Check that all the control points are finite.
+    pub fn validate(&self) -> Result<(), CurveError> {
+        validate_points(0, [&self.p0, &self.p1, &self.p2, &self.p3])
+    }
+}
+
+impl<P: Point> Bezier<P> {
+    /// Check that all the control points are finite.
+    pub fn validate(&self) -> Result<(), CurveError> {
+        match self {
+            Bezier::C0(c) => c.validate(),
+            Bezier::C1(c) => c.validate(),
+            Bezier::C2(c) => c.validate(),
+            Bezier::C3(c) => c.validate(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: Point> ComposedCurve<P> {
+    /// Check that the curve has segments, all of them are finite, and every segment
+    /// starts exactly where the previous one ends. The first problem found is returned.
+    pub fn validate(&self) -> Result<(), CurveError> {
+        if self.segments().is_empty() {
+            return Err(CurveError::Empty);
+        }
+        for (i, segment) in self.segments().iter().enumerate() {
+            segment.validate().map_err(|error| match error {
+                CurveError::NonFinite { point, .. } => CurveError::NonFinite { segment: i, point },
+                error => error,
+            })?;
+            if i > 0 && segment.start_point() != self.segments()[i - 1].end_point() {
+                return Err(CurveError::Discontinuous { segment: i });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use alloc::string::ToString;
+
+    #[test]
+    fn non_finite_points() {
+        assert_eq!(Bezier0::new([0.0, 1.0]).validate(), Ok(()));
+        assert_eq!(
+            Bezier3::new([0.0, 0.0], [1.0, 1.0], [f64::NAN, 1.0], [2.0, 0.0]).validate(),
+            Err(CurveError::NonFinite {
+                segment: 0,
+                point: 2
+            })
+        );
+        assert_eq!(
+            Bezier::C2(Bezier2::new(0.0, f64::INFINITY, 1.0)).validate(),
+            Err(CurveError::NonFinite {
+                segment: 0,
+                point: 1
+            })
+        );
+    }
+
+    #[test]
+    fn parameters() {
+        assert_eq!(validate_parameter(0.0), Ok(()));
+        assert_eq!(validate_parameter(1.0), Ok(()));
+        assert_eq!(
+            validate_parameter(1.5),
+            Err(CurveError::ParameterOutOfRange)
+        );
+        assert_eq!(
+            validate_parameter(f32::NAN),
+            Err(CurveError::ParameterOutOfRange)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn composed_curves() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        assert_eq!(curve.validate(), Err(CurveError::Empty));
+        curve.line_to([1.0, 0.0]);
+        curve.cubic_to([2.0, 0.0], [2.0, 1.0], [2.0, 2.0]);
+        assert_eq!(curve.validate(), Ok(()));
+
+        curve.line_to([3.0, f64::NAN]);
+        assert_eq!(
+            curve.validate(),
+            Err(CurveError::NonFinite {
+                segment: 2,
+                point: 1
+            })
+        );
+
+        let mut broken = ComposedCurve::new([0.0, 0.0]);
+        broken.line_to([1.0, 0.0]);
+        broken.splice_segments(
+            1..1,
+            alloc::vec![Bezier::C1(Bezier1::new([5.0, 0.0], [6.0, 0.0]))],
+        );
+        assert_eq!(
+            broken.validate(),
+            Err(CurveError::Discontinuous { segment: 1 })
+        );
+        assert!(broken
+            .validate()
+            .unwrap_err()
+            .to_string()
+            .contains("segment 1"));
+    }
+
+    #[cfg(all(feature = "debug-checks", debug_assertions))]
+    #[test]
+    #[should_panic(expected = "curve parameter")]
+    fn debug_checks() {
+        use crate::Curve;
+        Bezier1::new(0.0, 1.0).value_at(f64::NAN);
+    }
+
+    #[cfg(all(feature = "debug-checks", debug_assertions))]
+    #[test]
+    #[should_panic(expected = "curve parameter")]
+    fn debug_checks_many() {
+        use crate::Curve;
+        let mut out = [0.0; 2];
+        Bezier3::new(0.0, 1.0, 2.0, 3.0).value_at_many(&[0.5, 1.5], &mut out);
+    }
+}
@@ -0,0 +1,196 @@
+use crate::flatten::flatten_with;
+use crate::{Curve, Distance, PlanarPoint};
+use alloc::vec::Vec;
+use num_traits::{ToPrimitive, Zero};
+
+/// Placement of vertex attributes in an interleaved `f32` buffer, offsets and the stride
+/// are measured in `f32` values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VertexLayout {
+    /// Number of values per vertex.
+    pub stride: usize,
+    /// Offset of the `x, y` position.
+    pub position: usize,
+    /// Offset of the `x, y` unit tangent.
+    pub tangent: Option<usize>,
+    /// Offset of the distance along the curve.
+    pub distance: Option<usize>,
+}
+
+impl Default for VertexLayout {
+    fn default() -> Self {
+        Self::positions()
+    }
+}
+
+impl VertexLayout {
+    /// Tightly packed positions.
+    pub fn positions() -> Self {
+        Self {
+            stride: 2,
+            position: 0,
+            tangent: None,
+            distance: None,
+        }
+    }
+
+    pub fn with_stride(self, stride: usize) -> Self {
+        Self { stride, ..self }
+    }
+
+    pub fn with_position(self, offset: usize) -> Self {
+        Self {
+            position: offset,
+            ..self
+        }
+    }
+
+    pub fn with_tangent(self, offset: usize) -> Self {
+        Self {
+            tangent: Some(offset),
+            ..self
+        }
+    }
+
+    pub fn with_distance(self, offset: usize) -> Self {
+        Self {
+            distance: Some(offset),
+            ..self
+        }
+    }
+
+    /// # Panics
+    /// If an attribute doesn't fit into the stride.
+    fn validate(&self) {
+        let fits =
+            |offset: Option<usize>, size: usize| offset.is_none_or(|o| o + size <= self.stride);
+        assert!(
+            fits(Some(self.position), 2) && fits(self.tangent, 2) && fits(self.distance, 1),
+            "vertex attributes must fit into the stride"
+        );
+    }
+}
+
+/// Writes flattened vertices into a buffer, keeping the distance across several curves.
+pub(crate) struct VertexWriter<'a, P: PlanarPoint> {
+    buffer: &'a mut Vec<f32>,
+    layout: VertexLayout,
+    last: Option<P>,
+    distance: P::Scalar,
+    count: usize,
+}
+
+impl<'a, P: PlanarPoint> VertexWriter<'a, P> {
+    pub(crate) fn new(buffer: &'a mut Vec<f32>, layout: &VertexLayout) -> Self {
+        layout.validate();
+        Self {
+            buffer,
+            layout: *layout,
+            last: None,
+            distance: P::Scalar::zero(),
+            count: 0,
+        }
+    }
+
+    /// Append vertices of the curve, the start point is skipped if it was the last written vertex.
+    pub(crate) fn write<C: Curve<P> + ?Sized>(&mut self, curve: &C, tolerance: P::Scalar) {
+        let start = curve.start_point();
+        if self.last.as_ref() != Some(&start) {
+            self.push(curve, P::Scalar::zero(), start);
+        }
+        flatten_with(curve, tolerance, &mut |t, point| self.push(curve, t, point));
+    }
+
+    /// Number of written vertices.
+    pub(crate) fn count(&self) -> usize {
+        self.count
+    }
+
+    fn push<C: Curve<P> + ?Sized>(&mut self, curve: &C, t: P::Scalar, point: P) {
+        let f = |v: P::Scalar| v.to_f32().unwrap();
+        if let Some(last) = &self.last {
+            self.distance = self.distance + last.distance(&point);
+        }
+
+        let start = self.buffer.len();
+        self.buffer.resize(start + self.layout.stride, 0.0);
+        let vertex = &mut self.buffer[start..];
+        let position = self.layout.position;
+        vertex[position] = f(point.x());
+        vertex[position + 1] = f(point.y());
+        if let Some(offset) = self.layout.tangent {
+            let tangent = curve.tangent_at(t);
+            let length = tangent.length();
+            if length > P::Scalar::zero() {
+                vertex[offset] = f(tangent.x() / length);
+                vertex[offset + 1] = f(tangent.y() / length);
+            }
+        }
+        if let Some(offset) = self.layout.distance {
+            vertex[offset] = f(self.distance);
+        }
+
+        self.last = Some(point);
+        self.count += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bezier1, Bezier2, ComposedCurve};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn positions() {
+        let mut buffer = Vec::new();
+        let line = Bezier1::new([0.0, 0.0], [4.0, 0.0]);
+        let count = line.flatten_into(&mut buffer, &VertexLayout::positions(), 0.1);
+        assert_eq!(count, 5);
+        assert_eq!(buffer, [0.0, 0.0, 1.0, 0.0, 2.0, 0.0, 3.0, 0.0, 4.0, 0.0]);
+    }
+
+    #[test]
+    fn interleaved() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([4.0, 0.0]);
+        curve.line_to([4.0, 4.0]);
+        let layout = VertexLayout::positions()
+            .with_stride(6)
+            .with_position(1)
+            .with_tangent(3)
+            .with_distance(5);
+
+        let mut buffer = Vec::from([7.0]);
+        let count = curve.flatten_into(&mut buffer, &layout, 0.1);
+        assert_eq!(count, 9);
+        assert_eq!(buffer.len(), 1 + 6 * count);
+        let last = &buffer[buffer.len() - 6..];
+        assert_eq!(last, [0.0, 4.0, 4.0, 0.0, 1.0, 8.0]);
+        assert_eq!(buffer[0], 7.0);
+    }
+
+    #[test]
+    fn tangents() {
+        let mut buffer = Vec::new();
+        let arch = Bezier2::new([0.0, 0.0], [1.0, 1.0], [2.0, 0.0]);
+        arch.flatten_into(
+            &mut buffer,
+            &VertexLayout::positions().with_stride(4).with_tangent(2),
+            0.01,
+        );
+        assert_relative_eq!(buffer[2], core::f32::consts::FRAC_1_SQRT_2);
+        assert_relative_eq!(buffer[3], core::f32::consts::FRAC_1_SQRT_2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_layout() {
+        let line = Bezier1::new([0.0, 0.0], [4.0, 0.0]);
+        line.flatten_into(
+            &mut Vec::new(),
+            &VertexLayout::positions().with_tangent(1),
+            0.1,
+        );
+    }
+}
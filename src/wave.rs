@@ -0,0 +1,149 @@
+use crate::geometry::Walker;
+use crate::{ComposedCurve, Curve, PlanarPoint, Scalar};
+use alloc::vec::Vec;
+use num_traits::{NumCast, ToPrimitive, Zero};
+
+/// Corners of the source path sharper than this stay corners in the wave.
+const CORNER_ANGLE: f64 = core::f64::consts::FRAC_PI_4;
+
+impl<P: PlanarPoint> ComposedCurve<P> {
+    /// Replace the curve with a sine wave along it. `wavelength` is measured along the curve
+    /// and adjusted a bit so a whole number of waves fits, `amplitude` is the largest distance
+    /// from the curve to the left (positive) or to the right (negative).
+    /// The wave is fitted with cubic curves within `tolerance`.
+    pub fn wave(
+        &self,
+        wavelength: P::Scalar,
+        amplitude: P::Scalar,
+        tolerance: P::Scalar,
+    ) -> ComposedCurve<P> {
+        let Some((walker, waves)) = walker(self, wavelength) else {
+            return self.clone();
+        };
+
+        let steps = self.flatten(tolerance).len().max(waves * 32);
+        let two_pi = P::Scalar::pi() + P::Scalar::pi();
+        let steps_scalar: P::Scalar = NumCast::from(steps).unwrap();
+        let waves_scalar: P::Scalar = NumCast::from(waves).unwrap();
+
+        let points: Vec<P> = (0..=steps)
+            .map(|i| {
+                let i: P::Scalar = NumCast::from(i).unwrap();
+                let s = i / steps_scalar;
+                walker.offset_point(s, amplitude * (two_pi * waves_scalar * s).sin())
+            })
+            .collect();
+
+        ComposedCurve::fit_points(&points, tolerance, NumCast::from(CORNER_ANGLE).unwrap()).unwrap()
+    }
+
+    /// Replace the curve with a zigzag line along it. Every `wavelength` (measured along the curve
+    /// and adjusted a bit so a whole number of teeth fits) the line goes to `amplitude` on the left
+    /// and then to `amplitude` on the right of the curve.
+    pub fn zigzag(&self, wavelength: P::Scalar, amplitude: P::Scalar) -> ComposedCurve<P> {
+        let Some((walker, teeth)) = walker(self, wavelength) else {
+            return self.clone();
+        };
+
+        // Peaks are at odd quarters of every period
+        let quarters = teeth * 4;
+        let quarters_scalar: P::Scalar = NumCast::from(quarters).unwrap();
+        let mut result = ComposedCurve::new(self.start_point());
+        for k in (1..quarters).step_by(2) {
+            let k_scalar: P::Scalar = NumCast::from(k).unwrap();
+            let side = if k % 4 == 1 { amplitude } else { -amplitude };
+            result.line_to(walker.offset_point(k_scalar / quarters_scalar, side));
+        }
+        result.line_to(self.end_point());
+        result
+    }
+}
+
+/// Walker along the curve and the number of periods of `wavelength` that fit into it.
+fn walker<P: PlanarPoint>(
+    curve: &ComposedCurve<P>,
+    wavelength: P::Scalar,
+) -> Option<(Walker<P>, usize)> {
+    if wavelength <= P::Scalar::zero() {
+        return None;
+    }
+    let walker = Walker::new(curve)?;
+    let periods = (walker.length() / wavelength + NumCast::from(0.5).unwrap())
+        .floor()
+        .to_usize()
+        .unwrap_or(0)
+        .max(1);
+    Some((walker, periods))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn line() -> ComposedCurve<[f64; 2]> {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([10.0, 0.0]);
+        curve
+    }
+
+    #[test]
+    fn zigzag_line() {
+        let zigzag = line().zigzag(2.0, 0.5);
+        let points = zigzag.control_points();
+        assert_eq!(points.len(), 12);
+        assert_eq!(points[0], [0.0, 0.0]);
+        assert_eq!(points[11], [10.0, 0.0]);
+        for (i, point) in points[1..11].iter().enumerate() {
+            assert_relative_eq!(point[0], 0.5 + i as f64, epsilon = 0.01);
+            assert_relative_eq!(point[1], if i % 2 == 0 { 0.5 } else { -0.5 });
+        }
+    }
+
+    #[test]
+    fn wave_line() {
+        let wave = line().wave(3.0, 1.0, 0.001);
+        assert_eq!(wave.start_point(), [0.0, 0.0]);
+        assert_relative_eq!(wave.end_point()[0], 10.0, epsilon = 1e-9);
+
+        // Three waves fit into the length of 10
+        let samples: Vec<_> = (0..=5000)
+            .map(|i| wave.value_at(i as f64 / 5000.0))
+            .collect();
+        for i in 0..=60 {
+            let x = i as f64 / 6.0;
+            let y = (core::f64::consts::TAU * 3.0 * x / 10.0).sin();
+            let distance = samples
+                .iter()
+                .map(|q| (q[0] - x).hypot(q[1] - y))
+                .fold(f64::INFINITY, f64::min);
+            assert!(distance < 0.02, "{} at {}", distance, x);
+        }
+    }
+
+    #[test]
+    fn wave_follows_curve() {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.cubic_to([0.0, 10.0], [10.0, 10.0], [10.0, 0.0]);
+
+        let wave = curve.wave(1.0, 0.2, 0.01);
+        let base: Vec<_> = (0..=2000)
+            .map(|i| curve.value_at(i as f64 / 2000.0))
+            .collect();
+        for i in 0..=200 {
+            let p = wave.value_at(i as f64 / 200.0);
+            let distance = base
+                .iter()
+                .map(|q| (q[0] - p[0]).hypot(q[1] - p[1]))
+                .fold(f64::INFINITY, f64::min);
+            assert!(distance < 0.25, "{}", distance);
+        }
+    }
+
+    #[test]
+    fn degenerate() {
+        let dot = ComposedCurve::new([1.0, 1.0]);
+        assert_eq!(dot.wave(1.0, 1.0, 0.1), dot);
+        assert_eq!(line().zigzag(0.0, 1.0), line());
+    }
+}
@@ -0,0 +1,360 @@
+use crate::{Bezier, Bezier1, ComposedCurve, Curve, Path, PlanarPoint, Scalar};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use num_traits::{NumCast, One, Zero};
+
+/// Number of bisection steps used to find a crossing on a monotone piece.
+const BISECTION_STEPS: usize = 64;
+
+/// A point where a ray meets a path.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RayHit<P: PlanarPoint> {
+    /// Index of the segment, the implicit closing line of an open contour
+    /// has the index equal to the number of segments.
+    pub segment: usize,
+    /// Parameter of the point on the segment.
+    pub t: P::Scalar,
+    /// Position on the ray, the point is `origin + direction * distance`.
+    pub distance: P::Scalar,
+    pub point: P,
+    /// `1` if the path crosses the ray from its right side to the left one,
+    /// `-1` if it crosses from left to right, and `0` if it only touches the ray.
+    pub crossing: i32,
+}
+
+impl<P: PlanarPoint> ComposedCurve<P> {
+    /// Points where the ray from `origin` along `direction` meets the contour, ordered by the distance.
+    /// An open contour is closed by an implicit line from its end point to its start point.
+    ///
+    /// Segments are split into pieces monotone relative to the ray, and every piece includes
+    /// only its lower end, so a ray passing exactly through a joint or touching a curve
+    /// is counted consistently. Pieces lying on the ray are ignored.
+    pub fn ray_intersections(&self, origin: &P, direction: &P) -> Vec<RayHit<P>> {
+        let mut result = Vec::new();
+        if direction.length_squared() == P::Scalar::zero() {
+            return result;
+        }
+
+        // Coordinates where the ray goes along the x axis from zero
+        let to_ray = |p: &P| {
+            let d = p.sub(origin);
+            P::from_xy(d.dot(direction), direction.cross(&d))
+        };
+        let closing = (self.end_point() != self.start_point())
+            .then(|| Bezier::C1(Bezier1::new(self.end_point(), self.start_point())));
+
+        for (index, segment) in self.segments().iter().chain(closing.iter()).enumerate() {
+            let local = segment.map_points(to_ray);
+            let mut ts = Vec::from([P::Scalar::zero()]);
+            ts.extend(local.monotone_parameters());
+            ts.push(P::Scalar::one());
+            for range in ts.windows(2) {
+                if let Some((t, crossing)) = crossing(&local, range[0], range[1]) {
+                    let point = segment.value_at(t);
+                    let distance = local.value_at(t).x() / direction.length_squared();
+                    if distance >= P::Scalar::zero() {
+                        result.push(RayHit {
+                            segment: index,
+                            t,
+                            distance,
+                            point,
+                            crossing,
+                        });
+                    }
+                }
+            }
+        }
+
+        // Ends of neighbouring pieces meet at the same point, merge them
+        result.sort_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap());
+        let mut merged: Vec<RayHit<P>> = Vec::with_capacity(result.len());
+        for hit in result {
+            match merged.last_mut() {
+                Some(last) if last.point == hit.point => last.crossing += hit.crossing,
+                _ => merged.push(hit),
+            }
+        }
+        merged
+    }
+
+    /// Replace `out` with the points where the horizontal line at `y` crosses the contour
+    /// as `(x, winding)` pairs sorted by x, the winding is `1` where the contour goes up
+    /// and `-1` where it goes down. An open contour is closed by an implicit line.
+    ///
+    /// Every piece of the contour monotone in y covers `y` from its lower end up to,
+    /// but not including, its upper end, so scanlines through vertices and extrema count
+    /// each crossing once and skip touches. Crossings are found with the analytic solvers.
+    /// It's the scanline query of a rasterizer, the winding sums of the pairs give the spans to fill.
+    pub fn horizontal_intersections(&self, y: P::Scalar, out: &mut Vec<(P::Scalar, i32)>) {
+        out.clear();
+        self.push_horizontal_intersections(y, out);
+        sort_by_x(out);
+    }
+
+    fn push_horizontal_intersections(&self, y: P::Scalar, out: &mut Vec<(P::Scalar, i32)>) {
+        let closing = (self.end_point() != self.start_point())
+            .then(|| Bezier::C1(Bezier1::new(self.end_point(), self.start_point())));
+        for segment in self.segments().iter().chain(closing.iter()) {
+            let mut ts = Vec::from([P::Scalar::zero()]);
+            ts.extend(segment.monotone_parameters());
+            ts.push(P::Scalar::one());
+            for range in ts.windows(2) {
+                if let Some(hit) = horizontal_crossing(segment, y, range[0], range[1]) {
+                    out.push(hit);
+                }
+            }
+        }
+    }
+
+    /// Winding number of the contour around `point`, positive for counter-clockwise contours.
+    /// An open contour is closed by an implicit line. Points on the contour may get any value.
+    pub fn winding_at(&self, point: &P) -> i32 {
+        let direction = P::from_xy(P::Scalar::one(), P::Scalar::zero());
+        self.ray_intersections(point, &direction)
+            .iter()
+            .map(|hit| hit.crossing)
+            .sum()
+    }
+}
+
+impl<P: PlanarPoint> Path<P> {
+    /// Points where the ray meets the path as `(contour index, hit)` pairs ordered by the distance,
+    /// see [`ComposedCurve::ray_intersections`].
+    pub fn ray_intersections(&self, origin: &P, direction: &P) -> Vec<(usize, RayHit<P>)> {
+        let mut result: Vec<(usize, RayHit<P>)> = self
+            .contours()
+            .iter()
+            .enumerate()
+            .flat_map(|(i, contour)| {
+                contour
+                    .ray_intersections(origin, direction)
+                    .into_iter()
+                    .map(move |hit| (i, hit))
+            })
+            .collect();
+        result.sort_by(|a, b| a.1.distance.partial_cmp(&b.1.distance).unwrap());
+        result
+    }
+
+    /// Replace `out` with the points where the horizontal line at `y` crosses any contour,
+    /// sorted by x. See [`ComposedCurve::horizontal_intersections`].
+    pub fn horizontal_intersections(&self, y: P::Scalar, out: &mut Vec<(P::Scalar, i32)>) {
+        out.clear();
+        for contour in self.contours() {
+            contour.push_horizontal_intersections(y, out);
+        }
+        sort_by_x(out);
+    }
+
+    /// Sum of winding numbers of all the contours around `point`.
+    /// The non-zero fill rule fills points with a non-zero value, the even-odd one fills odd values.
+    pub fn winding_at(&self, point: &P) -> i32 {
+        self.contours()
+            .iter()
+            .map(|contour| contour.winding_at(point))
+            .sum()
+    }
+}
+
+fn sort_by_x<F: PartialOrd>(hits: &mut [(F, i32)]) {
+    hits.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+}
+
+/// The x coordinate and the direction where the piece of `curve` between `t0` and `t1`,
+/// monotone in y, crosses the line at `y`. The piece covers its lower end but not the upper one.
+fn horizontal_crossing<P: PlanarPoint>(
+    curve: &Bezier<P>,
+    y: P::Scalar,
+    t0: P::Scalar,
+    t1: P::Scalar,
+) -> Option<(P::Scalar, i32)> {
+    let (start, end) = (curve.value_at(t0), curve.value_at(t1));
+    let (y0, y1) = (start.y(), end.y());
+    let winding = if y0 < y1 {
+        1
+    } else if y0 > y1 {
+        -1
+    } else {
+        return None;
+    };
+    if y < y0.min(y1) || y >= y0.max(y1) {
+        return None;
+    }
+    if y == y0 {
+        return Some((start.x(), winding));
+    }
+
+    let epsilon: P::Scalar = NumCast::from(1e-9).unwrap();
+    let roots = match curve {
+        Bezier::C0(_) => return None,
+        Bezier::C1(c) => {
+            return Some((
+                c.p0.x() + (c.p1.x() - c.p0.x()) * (y - y0) / (y1 - y0),
+                winding,
+            ))
+        }
+        Bezier::C2(c) => c.solve_t_for_y(y),
+        Bezier::C3(c) => c.solve_t_for_y(y),
+    };
+    let t = match roots
+        .iter()
+        .find(|&&t| t >= t0 - epsilon && t <= t1 + epsilon)
+    {
+        Some(&t) => t.clamp(t0, t1),
+        // Rounding moved the root out of the piece, bisect instead
+        None => {
+            let shifted = curve.map_points(|p| P::from_xy(p.x(), p.y() - y));
+            crossing(&shifted, t0, t1)?.0
+        }
+    };
+    Some((curve.value_at(t).x(), winding))
+}
+
+/// Parameter where the piece of `curve` between `t0` and `t1`, monotone in y, meets `y = 0`
+/// and the crossing direction. The piece includes its lower end only.
+fn crossing<P: PlanarPoint>(
+    curve: &Bezier<P>,
+    t0: P::Scalar,
+    t1: P::Scalar,
+) -> Option<(P::Scalar, i32)> {
+    let zero = P::Scalar::zero();
+    let (y0, y1) = (curve.value_at(t0).y(), curve.value_at(t1).y());
+    let (crossing, lower_end) = if y0 < y1 {
+        (1, y0)
+    } else if y0 > y1 {
+        (-1, y1)
+    } else {
+        return None;
+    };
+    let upper_end = if crossing > 0 { y1 } else { y0 };
+    if zero < lower_end || zero > upper_end {
+        return None;
+    }
+    // The upper end is a touch, it counts only if the other piece at this point crosses
+    let crossing = if zero == upper_end { 0 } else { crossing };
+
+    if y0 == zero {
+        return Some((t0, crossing));
+    }
+    if y1 == zero {
+        return Some((t1, crossing));
+    }
+    let half = P::Scalar::one() / (P::Scalar::one() + P::Scalar::one());
+    let (mut low, mut high) = (t0, t1);
+    for _ in 0..BISECTION_STEPS {
+        let middle = (low + high) * half;
+        if middle == low || middle == high {
+            break;
+        }
+        if (curve.value_at(middle).y() < zero) == (y0 < zero) {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    Some(((low + high) * half, crossing))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square() -> ComposedCurve<[f64; 2]> {
+        let mut curve = ComposedCurve::new([0.0, 0.0]);
+        curve.line_to([2.0, 0.0]);
+        curve.line_to([2.0, 2.0]);
+        curve.line_to([0.0, 2.0]);
+        curve.close();
+        curve
+    }
+
+    #[test]
+    fn square_winding() {
+        let square = square();
+        assert_eq!(square.winding_at(&[1.0, 1.0]), 1);
+        assert_eq!(square.winding_at(&[3.0, 1.0]), 0);
+        // The ray goes exactly through the corners and along the edges
+        assert_eq!(square.winding_at(&[-1.0, 0.0]), 0);
+        assert_eq!(square.winding_at(&[-1.0, 2.0]), 0);
+        assert_eq!(square.winding_at(&[1.0, 2.0 - 1e-12]), 1);
+
+        let reversed = square.map_points(|p| [p[0], -p[1]]);
+        assert_eq!(reversed.winding_at(&[1.0, -1.0]), -1);
+    }
+
+    #[test]
+    fn grazing() {
+        // A cap touching the ray at its top
+        let mut cap = ComposedCurve::new([0.0, 0.0]);
+        cap.cubic_to([0.0, 4.0 / 3.0], [2.0, 4.0 / 3.0], [2.0, 0.0]);
+        cap.close();
+        assert_eq!(cap.winding_at(&[1.0, 0.5]), -1);
+        assert_eq!(cap.winding_at(&[-1.0, 1.0]), 0);
+
+        // A triangle touching the ray with its top vertex
+        let mut triangle = ComposedCurve::new([0.0, 0.0]);
+        triangle.line_to([2.0, 0.0]);
+        triangle.line_to([1.0, 1.0]);
+        triangle.close();
+        let hits = triangle.ray_intersections(&[-1.0, 1.0], &[1.0, 0.0]);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].point, [1.0, 1.0]);
+        assert_eq!(hits[0].crossing, 0);
+        assert_eq!(triangle.winding_at(&[-1.0, 1.0]), 0);
+        assert_eq!(triangle.winding_at(&[-1.0, 0.0]), 0);
+        assert_eq!(triangle.winding_at(&[1.0, 0.5]), 1);
+    }
+
+    #[test]
+    fn ray() {
+        let hits = square().ray_intersections(&[1.0, 1.0], &[1.0, 1.0]);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].point, [2.0, 2.0]);
+        assert_eq!(hits[0].distance, 1.0);
+    }
+
+    #[test]
+    fn path_with_hole() {
+        let mut path = Path::from(square());
+        path.move_to([0.5, 0.5]);
+        path.line_to([0.5, 1.5]);
+        path.line_to([1.5, 1.5]);
+        path.line_to([1.5, 0.5]);
+        path.close();
+        assert_eq!(path.winding_at(&[1.0, 1.0]), 0);
+        assert_eq!(path.winding_at(&[0.25, 1.0]), 1);
+        assert_eq!(path.ray_intersections(&[1.0, 1.0], &[1.0, 0.0]).len(), 2);
+    }
+
+    #[test]
+    fn scanlines() {
+        let mut out = Vec::new();
+        square().horizontal_intersections(1.0, &mut out);
+        assert_eq!(out, [(0.0, -1), (2.0, 1)]);
+        // The bottom edge is covered, the top one isn't
+        square().horizontal_intersections(0.0, &mut out);
+        assert_eq!(out, [(0.0, -1), (2.0, 1)]);
+        square().horizontal_intersections(2.0, &mut out);
+        assert!(out.is_empty());
+
+        // An open cap closed implicitly, crossed symmetrically
+        let mut cap = ComposedCurve::new([0.0, 0.0]);
+        cap.cubic_to([0.0, 4.0 / 3.0], [2.0, 4.0 / 3.0], [2.0, 0.0]);
+        cap.horizontal_intersections(0.5, &mut out);
+        assert_eq!(out.len(), 2);
+        assert_eq!((out[0].1, out[1].1), (1, -1));
+        assert!((out[0].0 + out[1].0 - 2.0).abs() < 1e-9);
+        let top = cap.segments()[0].value_at(0.5);
+        assert!(out[0].0 > 0.0 && out[0].0 < top[0]);
+
+        let path: Path<_> = [square(), square().map_points(|p| [p[0] + 1.0, p[1]])]
+            .into_iter()
+            .collect();
+        path.horizontal_intersections(1.0, &mut out);
+        assert_eq!(
+            out.iter().map(|hit| hit.0).collect::<Vec<_>>(),
+            [0.0, 1.0, 2.0, 3.0]
+        );
+        assert_eq!(out.iter().map(|hit| hit.1).sum::<i32>(), 0);
+    }
+}